@@ -0,0 +1,125 @@
+//! Generates `ErrorCode` and its metadata (category, recoverable set,
+//! default hints) from `schemas/error-codes.yaml` — the single source of
+//! truth shared with Python's `core/errors.py` — so the two never drift
+//! out of hand-maintained sync.
+//!
+//! Output lands at `$OUT_DIR/error_codes.rs` and is pulled into
+//! `src/error.rs` via `include!`.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const SCHEMA_PATH: &str = "schemas/error-codes.yaml";
+
+#[derive(Debug, Deserialize)]
+struct ErrorCodeSpec {
+    code: u16,
+    name: String,
+    category: String,
+    recoverable: bool,
+    #[serde(default)]
+    hints: Vec<String>,
+}
+
+/// Maps a category name to the thousands digit its codes must share.
+/// `code: 0` is the sole exception (the "unknown" fallback).
+fn expected_prefix(category: &str) -> Option<u16> {
+    match category {
+        "model" => Some(1),
+        "lens" => Some(2),
+        "tool" => Some(3),
+        "validation" => Some(4),
+        "config" => Some(5),
+        "runtime" => Some(6),
+        "io" => Some(7),
+        "unknown" => None,
+        other => panic!("error-codes.yaml: unrecognized category '{}'", other),
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SCHEMA_PATH);
+
+    let yaml = fs::read_to_string(SCHEMA_PATH)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", SCHEMA_PATH, e));
+    let specs: Vec<ErrorCodeSpec> =
+        serde_yaml::from_str(&yaml).unwrap_or_else(|e| panic!("failed to parse {}: {}", SCHEMA_PATH, e));
+
+    let mut seen_codes: HashMap<u16, &str> = HashMap::new();
+    for spec in &specs {
+        if let Some(prefix) = expected_prefix(&spec.category) {
+            if spec.code != 0 && spec.code / 1000 != prefix {
+                panic!(
+                    "error-codes.yaml: '{}' (code {}) is in category '{}', which requires a {}xxx code",
+                    spec.name, spec.code, spec.category, prefix
+                );
+            }
+        } else if spec.code != 0 {
+            panic!("error-codes.yaml: '{}' (code {}) has the 'unknown' category reserved for code 0", spec.name, spec.code);
+        }
+
+        if let Some(existing) = seen_codes.insert(spec.code, spec.name.as_str()) {
+            panic!("error-codes.yaml: code {} is used by both '{}' and '{}'", spec.code, existing, spec.name);
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let dest_path = Path::new(&out_dir).join("error_codes.rs");
+    fs::write(&dest_path, render(&specs)).unwrap_or_else(|e| panic!("failed to write {:?}: {}", dest_path, e));
+}
+
+fn render(specs: &[ErrorCodeSpec]) -> String {
+    let mut out = String::new();
+
+    out.push_str("/// Error codes matching Python's ErrorCode enum.\n");
+    out.push_str("/// Generated from schemas/error-codes.yaml by build.rs — do not hand-edit.\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]\n");
+    out.push_str("#[repr(u16)]\n");
+    out.push_str("pub enum ErrorCode {\n");
+    for spec in specs {
+        writeln!(out, "    {} = {},", spec.name, spec.code).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl ErrorCode {\n");
+
+    out.push_str("    /// Get the category name for this error code.\n");
+    out.push_str("    pub fn category(&self) -> &'static str {\n");
+    out.push_str("        match self {\n");
+    for spec in specs {
+        writeln!(out, "            ErrorCode::{} => \"{}\",", spec.name, spec.category).unwrap();
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    /// Whether this error is typically recoverable.\n");
+    out.push_str("    pub fn is_recoverable(&self) -> bool {\n");
+    out.push_str("        match self {\n");
+    for spec in specs {
+        writeln!(out, "            ErrorCode::{} => {},", spec.name, spec.recoverable).unwrap();
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    /// Default recovery hints for this error code.\n");
+    out.push_str("    pub fn default_hints(&self) -> Vec<&'static str> {\n");
+    out.push_str("        match self {\n");
+    for spec in specs {
+        if spec.hints.is_empty() {
+            writeln!(out, "            ErrorCode::{} => vec![],", spec.name).unwrap();
+        } else {
+            let hints = spec.hints.iter().map(|h| format!("{:?}", h)).collect::<Vec<_>>().join(", ");
+            writeln!(out, "            ErrorCode::{} => vec![{}],", spec.name, hints).unwrap();
+        }
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+
+    out.push_str("}\n");
+
+    out
+}
@@ -3,16 +3,44 @@
 //! The agent outputs NDJSON events that we parse and forward to the frontend.
 
 use crate::error::{ErrorCode, SunwellError};
+use crate::job_manager::JobManager;
 use crate::sunwell_err;
 use crate::util::{parse_json_safe, sunwell_command};
 use serde::{Deserialize, Serialize};
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-use std::process::{Child, Stdio};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter};
 
+/// Identifies one concurrent agent run within an `AgentBridge`.
+///
+/// Opaque to callers beyond equality/formatting — generate one with
+/// `new_session_id()` before calling `run_goal`/`resume_goal`/
+/// `run_backlog_goal`, then reuse it for `stop`, `send_approval`, and
+/// `is_running` against the same run.
+pub type SessionId = String;
+
+static SESSION_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Generate a process-unique session identifier.
+///
+/// Combines a monotonic counter with the current timestamp so IDs stay
+/// unique across multiple sessions started in the same instant, without
+/// pulling in a `uuid` dependency for one call site.
+pub fn new_session_id() -> SessionId {
+    let seq = SESSION_SEQ.fetch_add(1, Ordering::SeqCst);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("sess-{:x}-{:x}", nanos, seq)
+}
+
 /// Agent event types matching Python's EventType enum (sunwell.adaptive.events).
 ///
 /// KEEP IN SYNC WITH: src/sunwell/adaptive/events.py
@@ -284,8 +312,13 @@ impl Default for UIHints {
 /// RFC-097: UI-enriched event for frontend.
 ///
 /// Wraps the raw AgentEvent with computed UI hints for richer rendering.
+///
+/// Carries `session_id` so a frontend juggling multiple concurrent agent
+/// runs can route each event to the right panel off a single `agent-event`
+/// channel, rather than us minting one Tauri event name per session.
 #[derive(Debug, Clone, Serialize)]
 pub struct UIEvent {
+    pub session_id: SessionId,
     /// The original event data
     #[serde(flatten)]
     pub event: AgentEvent,
@@ -293,294 +326,612 @@ pub struct UIEvent {
     pub ui: UIHints,
 }
 
-/// Manages the agent subprocess.
-pub struct AgentBridge {
-    process: Option<Child>,
+/// Payload for the `agent-stopped` event, fired once a session's reader
+/// thread exits for any reason (stopped, completed, or retries exhausted).
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentStoppedEvent {
+    pub session_id: SessionId,
+}
+
+/// Payload for the `agent-stopping` event, fired as soon as `stop()` asks a
+/// session to wind down, before it has actually exited.
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentStoppingEvent {
+    pub session_id: SessionId,
+}
+
+/// How long `stop()` waits for a session to exit on its own — after a
+/// cooperative stop request — before force-killing it.
+#[derive(Debug, Clone, Copy)]
+pub struct ShutdownPolicy {
+    pub grace_period: Duration,
+}
+
+impl Default for ShutdownPolicy {
+    fn default() -> Self {
+        Self { grace_period: Duration::from_secs(10) }
+    }
+}
+
+/// How many events accumulate between `agent-metrics` broadcasts.
+const METRICS_EMIT_INTERVAL: u32 = 10;
+
+/// Live counters/gauges folded from a session's event stream as it streams
+/// by, exposed via `AgentBridge::metrics` and periodically broadcast as
+/// `agent-metrics` so the frontend can render a dashboard without re-parsing
+/// the whole event log.
+///
+/// KEEP IN SYNC WITH: src/sunwell/adaptive/events.py — the `id` and `tokens`
+/// keys read out of each event's `data` mirror whatever the Python agent
+/// puts there; a missing key just skips that update rather than erroring.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SessionMetrics {
+    pub tasks_started: u64,
+    pub tasks_completed: u64,
+    pub tasks_failed: u64,
+    pub model_tokens: u64,
+    pub gates_passed: u64,
+    pub gates_failed: u64,
+    pub validations_passed: u64,
+    pub validations_failed: u64,
+    pub fix_attempts: u64,
+    pub fix_completed: u64,
+    pub fix_failed: u64,
+    /// Cumulative wall-clock seconds across matched `task_start`/
+    /// `task_complete` pairs, matched by the `id` field in `data`.
+    pub task_duration_secs: f64,
+    /// In-flight `task_start` timestamps, keyed by task id, waiting for a
+    /// matching `task_complete`/`task_failed`.
+    #[serde(skip)]
+    task_started_at: HashMap<String, f64>,
+}
+
+impl SessionMetrics {
+    /// Fold one more event from the stream into the running totals.
+    fn fold(&mut self, event: &AgentEvent) {
+        let task_id = || event.data.get("id").and_then(|v| v.as_str()).map(str::to_string);
+
+        match event.event_type.as_str() {
+            "task_start" => {
+                self.tasks_started += 1;
+                if let Some(id) = task_id() {
+                    self.task_started_at.insert(id, event.timestamp);
+                }
+            }
+            "task_complete" => {
+                self.tasks_completed += 1;
+                if let Some(started) = task_id().and_then(|id| self.task_started_at.remove(&id)) {
+                    self.task_duration_secs += (event.timestamp - started).max(0.0);
+                }
+            }
+            "task_failed" => {
+                self.tasks_failed += 1;
+                if let Some(id) = task_id() {
+                    self.task_started_at.remove(&id);
+                }
+            }
+            "model_tokens" => {
+                if let Some(count) = event.data.get("tokens").and_then(|v| v.as_u64()) {
+                    self.model_tokens += count;
+                }
+            }
+            "gate_pass" => self.gates_passed += 1,
+            "gate_fail" => self.gates_failed += 1,
+            "validate_pass" => self.validations_passed += 1,
+            "validate_error" => self.validations_failed += 1,
+            "fix_attempt" => self.fix_attempts += 1,
+            "fix_complete" => self.fix_completed += 1,
+            "fix_failed" => self.fix_failed += 1,
+            _ => {}
+        }
+    }
+}
+
+/// Payload for the periodic `agent-metrics` event.
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentMetricsEvent {
+    pub session_id: SessionId,
+    #[serde(flatten)]
+    pub metrics: SessionMetrics,
+}
+
+/// Commands sent back to the Python agent over stdin (NDJSON).
+///
+/// Forms the write side of a typed duplex channel with `AgentEvent` on the
+/// read side, so the frontend can answer `SecurityApprovalRequested` prompts,
+/// cancel an in-flight task, or inject a clarification without killing the
+/// process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AgentCommand {
+    /// Response to a `SecurityApprovalRequested` event.
+    ApproveSecurity {
+        request_id: String,
+        approved: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<serde_json::Value>,
+    },
+    /// Cancel a single in-flight task without stopping the whole agent.
+    CancelTask { task_id: String },
+    /// Inject a mid-run clarification from the user.
+    Clarify { message: String },
+}
+
+/// Exponential-backoff retry policy for auto-resuming a session after the
+/// `sunwell` subprocess exits abnormally (crash, OOM, dropped model
+/// connection) without emitting a terminal `complete`/`error` event.
+///
+/// The delay for attempt `n` is `min(initial_interval * backoff_coefficient^n,
+/// max_interval)` plus up to 25% jitter, so concurrent sessions recovering
+/// from the same outage don't all reconnect in lockstep.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub initial_interval: Duration,
+    pub backoff_coefficient: f64,
+    pub max_interval: Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            backoff_coefficient: 2.0,
+            max_interval: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let scaled =
+            self.initial_interval.as_secs_f64() * self.backoff_coefficient.powi(attempt as i32);
+        let capped = scaled.min(self.max_interval.as_secs_f64());
+        Duration::from_secs_f64(capped * (1.0 + jitter_fraction()))
+    }
+}
+
+/// A pseudo-random fraction in `[0.0, 0.25)`, good enough to stagger retry
+/// delays without pulling in a `rand` dependency for one call site.
+fn jitter_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1000) as f64 / 1000.0 * 0.25
+}
+
+/// Payload for the synthetic `agent-retry` event emitted when the bridge
+/// auto-resumes after an abnormal subprocess exit.
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentRetryEvent {
+    pub session_id: SessionId,
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub delay_ms: u64,
+    pub reason: String,
+}
+
+/// State for a single concurrent agent run, owned by an `AgentBridge`.
+///
+/// Everything here was previously a direct field of `AgentBridge` itself;
+/// pulling it out lets the bridge hold many of these side by side, keyed
+/// by `SessionId`, instead of only ever managing one run at a time.
+#[derive(Clone)]
+struct AgentSession {
+    process: Arc<Mutex<Option<Child>>>,
     running: Arc<AtomicBool>,
+    /// Write side of the duplex channel (RFC-089 security approvals).
+    /// `None` whenever this session has no agent process running.
+    stdin: Arc<Mutex<Option<ChildStdin>>>,
+    /// Set by `stop()` so a retry loop can tell a user-initiated kill apart
+    /// from an abnormal exit.
+    stopping: Arc<AtomicBool>,
+    /// Live counters folded from this session's event stream.
+    metrics: Arc<Mutex<SessionMetrics>>,
+}
+
+impl AgentSession {
+    fn new() -> Self {
+        Self {
+            process: Arc::new(Mutex::new(None)),
+            running: Arc::new(AtomicBool::new(false)),
+            stdin: Arc::new(Mutex::new(None)),
+            stopping: Arc::new(AtomicBool::new(false)),
+            metrics: Arc::new(Mutex::new(SessionMetrics::default())),
+        }
+    }
+}
+
+/// Manages agent subprocesses, one per concurrent `SessionId`.
+///
+/// A session's entry stays in the map after its run finishes (success,
+/// failure, or stop) so `is_running` and future replay/metrics lookups
+/// (see the event journal) can still find it; only a fresh `run_goal`/
+/// `resume_goal`/`run_backlog_goal` call for that same ID replaces it.
+pub struct AgentBridge {
+    sessions: Mutex<HashMap<SessionId, AgentSession>>,
+    /// Backoff policy used when auto-resuming after an abnormal exit.
+    retry_policy: RetryPolicy,
+    /// Grace period given to a session to exit on its own after `stop()`.
+    shutdown_policy: ShutdownPolicy,
 }
 
 impl AgentBridge {
     pub fn new() -> Self {
         Self {
-            process: None,
-            running: Arc::new(AtomicBool::new(false)),
+            sessions: Mutex::new(HashMap::new()),
+            retry_policy: RetryPolicy::default(),
+            shutdown_policy: ShutdownPolicy::default(),
+        }
+    }
+
+    /// Override the default exponential-backoff retry policy.
+    #[allow(dead_code)]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Override the default graceful-shutdown grace period.
+    #[allow(dead_code)]
+    pub fn with_shutdown_policy(mut self, policy: ShutdownPolicy) -> Self {
+        self.shutdown_policy = policy;
+        self
+    }
+
+    /// Register a fresh `AgentSession` for `session_id`, erroring if a prior
+    /// run under the same ID is still in flight.
+    fn start_session(&self, session_id: &SessionId) -> Result<AgentSession, SunwellError> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent session map lock poisoned"))?;
+
+        if let Some(existing) = sessions.get(session_id) {
+            if existing.running.load(Ordering::SeqCst) {
+                return Err(sunwell_err!(
+                    RuntimeConcurrentLimit,
+                    "Agent session '{}' is already running",
+                    session_id
+                )
+                .with_hints(vec!["Wait for the current operation to complete", "Or stop this session first"]));
+            }
+        }
+
+        let handles = AgentSession::new();
+        let clone = handles.clone();
+        sessions.insert(session_id.clone(), handles);
+        Ok(clone)
+    }
+
+    /// Request that `session` wind down on its own — a raw `{"command":
+    /// "stop"}` line over stdin plus SIGTERM on Unix — then force-kill it if
+    /// it hasn't exited by the end of `policy.grace_period`.
+    ///
+    /// Mirrors `stop_project_run`'s `kill -TERM`/`taskkill` split for
+    /// terminating an external process without a signal-handling crate.
+    /// Runs the wait in a background thread so callers return immediately;
+    /// the spawn-and-stream reader thread notices the exit (or this
+    /// function's eventual force-kill) and emits `agent-stopped` itself.
+    fn request_graceful_stop(session_id: SessionId, session: AgentSession, app: AppHandle, policy: ShutdownPolicy) {
+        session.stopping.store(true, Ordering::SeqCst);
+
+        let _ = app.emit("agent-stopping", AgentStoppingEvent { session_id: session_id.clone() });
+
+        if let Ok(mut guard) = session.stdin.lock() {
+            if let Some(stdin) = guard.as_mut() {
+                let _ = stdin.write_all(b"{\"command\":\"stop\"}\n");
+                let _ = stdin.flush();
+            }
+        }
+
+        #[cfg(unix)]
+        {
+            let pid = session.process.lock().ok().and_then(|guard| guard.as_ref().map(|c| c.id()));
+            if let Some(pid) = pid {
+                let _ = std::process::Command::new("kill").args(["-TERM", &pid.to_string()]).output();
+            }
+        }
+
+        std::thread::spawn(move || {
+            let deadline = std::time::Instant::now() + policy.grace_period;
+            while session.running.load(Ordering::SeqCst) && std::time::Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+
+            if session.running.load(Ordering::SeqCst) {
+                // Didn't wind down on its own within the grace period.
+                if let Ok(mut guard) = session.process.lock() {
+                    if let Some(mut process) = guard.take() {
+                        let _ = process.kill();
+                    }
+                }
+                session.running.store(false, Ordering::SeqCst);
+            }
+        });
+    }
+
+    /// Write an `AgentCommand` to `session_id`'s stdin as an NDJSON line.
+    pub fn send_command(&self, session_id: &SessionId, command: &AgentCommand) -> Result<(), SunwellError> {
+        let sessions = self
+            .sessions
+            .lock()
+            .map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent session map lock poisoned"))?;
+        let session = sessions.get(session_id).ok_or_else(|| {
+            sunwell_err!(RuntimeStateInvalid, "No such agent session: {}", session_id)
+        })?;
+
+        let mut line = serde_json::to_string(command)?;
+        line.push('\n');
+
+        let mut guard = session
+            .stdin
+            .lock()
+            .map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent stdin lock poisoned"))?;
+
+        match guard.as_mut() {
+            Some(stdin) => {
+                stdin.write_all(line.as_bytes())?;
+                stdin.flush()?;
+                Ok(())
+            }
+            None => Err(sunwell_err!(
+                RuntimeStateInvalid,
+                "No agent is running for this session; there is no stdin channel to write to"
+            )),
         }
     }
 
-    /// Run a goal and stream events to the frontend.
+    /// Answer a `SecurityApprovalRequested` event (RFC-089).
+    pub fn send_approval(
+        &self,
+        session_id: &SessionId,
+        request_id: &str,
+        approved: bool,
+        scope: Option<serde_json::Value>,
+    ) -> Result<(), SunwellError> {
+        self.send_command(
+            session_id,
+            &AgentCommand::ApproveSecurity {
+                request_id: request_id.to_string(),
+                approved,
+                scope,
+            },
+        )
+    }
+
+    /// Run a goal under `session_id` and stream events to the frontend.
     ///
     /// RFC-064: Supports optional lens selection.
     /// RFC-Cloud-Model-Parity: Supports optional provider selection.
     /// - `lens`: Explicit lens name (e.g., "coder", "tech-writer")
     /// - `auto_lens`: Whether to auto-detect lens based on goal (default: true)
     /// - `provider`: Model provider (e.g., "openai", "anthropic", "ollama")
+    #[allow(clippy::too_many_arguments)]
     pub fn run_goal(
-        &mut self,
+        &self,
+        session_id: SessionId,
         app: AppHandle,
         goal: &str,
         project_path: &Path,
         lens: Option<&str>,
         auto_lens: bool,
         provider: Option<&str>,
+        job_manager: Arc<JobManager>,
     ) -> Result<(), SunwellError> {
-        if self.running.load(Ordering::SeqCst) {
-            return Err(sunwell_err!(RuntimeConcurrentLimit, "Agent already running")
-                .with_hints(vec!["Wait for the current operation to complete", "Or stop the agent first"]));
-        }
+        let session = self.start_session(&session_id)?;
 
         // Build args with optional lens parameters (RFC-064)
-        let mut args = vec!["agent", "run", "--json", "--strategy", "harmonic"];
+        let mut args: Vec<String> =
+            vec!["agent".into(), "run".into(), "--json".into(), "--strategy".into(), "harmonic".into()];
 
         // Add lens flag if explicitly specified
-        let lens_owned: String;
         if let Some(lens_name) = lens {
-            args.push("--lens");
-            lens_owned = lens_name.to_string();
-            args.push(&lens_owned);
+            args.push("--lens".into());
+            args.push(lens_name.to_string());
         }
 
         // Disable auto-lens if requested
         if !auto_lens {
-            args.push("--no-auto-lens");
+            args.push("--no-auto-lens".into());
         }
 
         // Add provider flag if explicitly specified (RFC-Cloud-Model-Parity)
-        let provider_owned: String;
         if let Some(provider_name) = provider {
-            args.push("--provider");
-            provider_owned = provider_name.to_string();
-            args.push(&provider_owned);
+            args.push("--provider".into());
+            args.push(provider_name.to_string());
         }
 
-        args.push(goal);
+        args.push(goal.to_string());
 
         // Start the Sunwell agent with JSON output
         // Use harmonic planning for better high-level plans, then artifact-first for execution
         // HarmonicPlanner generates multiple candidates and selects best, then uses ArtifactPlanner
         // for execution (which supports automatic incremental builds)
-        let mut child = sunwell_command()
-            .args(&args)
-            .current_dir(project_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec![
-                    "Check if sunwell CLI is installed",
-                    "Try running 'sunwell --help' to verify",
-                    "Check your PATH includes sunwell",
-                ]))?;
-
-        let stdout = child.stdout.take().ok_or_else(|| 
-            sunwell_err!(RuntimeProcessFailed, "Failed to capture agent stdout"))?;
-        let stderr = child.stderr.take();
-        self.process = Some(child);
-        self.running.store(true, Ordering::SeqCst);
-
-        let running = self.running.clone();
-
-        // Spawn thread to drain stderr (prevents blocking if buffer fills)
-        if let Some(stderr) = stderr {
-            std::thread::spawn(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines() {
-                    match line {
-                        Ok(err_line) if !err_line.is_empty() => {
-                            eprintln!("[sunwell stderr] {}", err_line);
-                        }
-                        Err(_) => break,
-                        _ => {}
-                    }
-                }
-            });
-        }
-
-        // Spawn thread to read NDJSON events from stdout
-        std::thread::spawn(move || {
-            let reader = BufReader::new(stdout);
-
-            for line in reader.lines() {
-                if !running.load(Ordering::SeqCst) {
-                    break;
-                }
-
-                match line {
-                    Ok(json_line) => {
-                        if json_line.is_empty() {
-                            continue;
-                        }
-
-                        match parse_json_safe::<AgentEvent>(&json_line) {
-                            Ok(event) => {
-                                // RFC-097: Wrap event with UI hints for richer frontend rendering
-                                let ui_event = UIEvent {
-                                    ui: UIHints::from_event(&event),
-                                    event: event.clone(),
-                                };
-                                // Emit enriched event to frontend
-                                let _ = app.emit("agent-event", &ui_event);
-
-                                // Check if this is a terminal event
-                                if event.event_type == "complete" || event.event_type == "error" {
-                                    running.store(false, Ordering::SeqCst);
-                                    break;
-                                }
-                            }
-                            Err(e) => {
-                                eprintln!("Failed to parse event: {} - {}", e, json_line);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to read line: {}", e);
-                        break;
-                    }
-                }
-            }
-
-            running.store(false, Ordering::SeqCst);
-            let _ = app.emit("agent-stopped", ());
-        });
-
-        Ok(())
+        Self::spawn_and_stream(
+            app,
+            session_id,
+            args,
+            project_path.to_path_buf(),
+            provider.map(str::to_string),
+            session.running,
+            session.stdin,
+            session.process,
+            session.stopping,
+            session.metrics,
+            self.retry_policy,
+            0,
+            job_manager,
+        )
     }
 
-    /// Resume an interrupted goal and stream events to the frontend.
+    /// Resume an interrupted goal under `session_id` and stream events to
+    /// the frontend.
     ///
     /// RFC-Cloud-Model-Parity: Supports optional provider selection.
+    /// `checkpoint_path` optionally rewinds to an earlier checkpoint file
+    /// instead of the latest one the CLI would pick on its own.
+    #[allow(clippy::too_many_arguments)]
     pub fn resume_goal(
-        &mut self,
+        &self,
+        session_id: SessionId,
         app: AppHandle,
         project_path: &Path,
         provider: Option<&str>,
+        checkpoint_path: Option<&Path>,
+        job_manager: Arc<JobManager>,
     ) -> Result<(), SunwellError> {
-        if self.running.load(Ordering::SeqCst) {
-            return Err(sunwell_err!(RuntimeConcurrentLimit, "Agent already running")
-                .with_hints(vec!["Wait for the current operation to complete", "Or stop the agent first"]));
-        }
-
-        // Build args with optional provider (RFC-Cloud-Model-Parity)
-        let mut args = vec!["agent", "resume", "--json"];
-        let provider_owned: String;
-        if let Some(provider_name) = provider {
-            args.push("--provider");
-            provider_owned = provider_name.to_string();
-            args.push(&provider_owned);
-        }
+        let session = self.start_session(&session_id)?;
 
         // Start the Sunwell agent in resume mode with JSON output
-        let mut child = sunwell_command()
-            .args(&args)
-            .current_dir(project_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec![
-                    "Check if sunwell CLI is installed",
-                    "Try running 'sunwell --help' to verify",
-                ]))?;
-
-        let stdout = child.stdout.take().ok_or_else(|| 
-            sunwell_err!(RuntimeProcessFailed, "Failed to capture agent stdout"))?;
-        let stderr = child.stderr.take();
-        self.process = Some(child);
-        self.running.store(true, Ordering::SeqCst);
-
-        let running = self.running.clone();
-
-        // Spawn thread to drain stderr (prevents blocking if buffer fills)
-        if let Some(stderr) = stderr {
-            std::thread::spawn(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines() {
-                    match line {
-                        Ok(err_line) if !err_line.is_empty() => {
-                            eprintln!("[sunwell stderr] {}", err_line);
-                        }
-                        Err(_) => break,
-                        _ => {}
-                    }
-                }
-            });
-        }
-
-        // Spawn thread to read NDJSON events from stdout
-        std::thread::spawn(move || {
-            let reader = BufReader::new(stdout);
-
-            for line in reader.lines() {
-                if !running.load(Ordering::SeqCst) {
-                    break;
-                }
-
-                match line {
-                    Ok(json_line) => {
-                        if json_line.is_empty() {
-                            continue;
-                        }
-
-                        match parse_json_safe::<AgentEvent>(&json_line) {
-                            Ok(event) => {
-                                // RFC-097: Wrap event with UI hints
-                                let ui_event = UIEvent {
-                                    ui: UIHints::from_event(&event),
-                                    event: event.clone(),
-                                };
-                                let _ = app.emit("agent-event", &ui_event);
-
-                                if event.event_type == "complete" || event.event_type == "error" {
-                                    running.store(false, Ordering::SeqCst);
-                                    break;
-                                }
-                            }
-                            Err(e) => {
-                                eprintln!("Failed to parse event: {} - {}", e, json_line);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to read line: {}", e);
-                        break;
-                    }
-                }
-            }
-
-            running.store(false, Ordering::SeqCst);
-            let _ = app.emit("agent-stopped", ());
-        });
-
-        Ok(())
+        Self::spawn_and_stream(
+            app,
+            session_id,
+            Self::resume_args(provider, checkpoint_path),
+            project_path.to_path_buf(),
+            provider.map(str::to_string),
+            session.running,
+            session.stdin,
+            session.process,
+            session.stopping,
+            session.metrics,
+            self.retry_policy,
+            0,
+            job_manager,
+        )
     }
 
-    /// Run a specific backlog goal by ID (RFC-056).
+    /// Run a specific backlog goal by ID under `session_id` (RFC-056).
     ///
     /// RFC-Cloud-Model-Parity: Supports optional provider selection.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_backlog_goal(
-        &mut self,
+        &self,
+        session_id: SessionId,
         app: AppHandle,
         goal_id: &str,
         project_path: &Path,
         provider: Option<&str>,
+        job_manager: Arc<JobManager>,
     ) -> Result<(), SunwellError> {
-        if self.running.load(Ordering::SeqCst) {
-            return Err(sunwell_err!(RuntimeConcurrentLimit, "Agent already running")
-                .with_hints(vec!["Wait for the current operation to complete", "Or stop the agent first"]));
-        }
+        let session = self.start_session(&session_id)?;
 
         // Build args with optional provider (RFC-Cloud-Model-Parity)
-        let mut args = vec!["backlog", "run", goal_id, "--json"];
-        let provider_owned: String;
+        let mut args: Vec<String> =
+            vec!["backlog".into(), "run".into(), goal_id.to_string(), "--json".into()];
         if let Some(provider_name) = provider {
-            args.push("--provider");
-            provider_owned = provider_name.to_string();
-            args.push(&provider_owned);
+            args.push("--provider".into());
+            args.push(provider_name.to_string());
         }
 
         // Start the Sunwell agent with backlog run command
+        Self::spawn_and_stream(
+            app,
+            session_id,
+            args,
+            project_path.to_path_buf(),
+            provider.map(str::to_string),
+            session.running,
+            session.stdin,
+            session.process,
+            session.stopping,
+            session.metrics,
+            self.retry_policy,
+            0,
+            job_manager,
+        )
+    }
+
+    /// Path to `session_id`'s append-only NDJSON event journal under the
+    /// project's `.sunwell` dir.
+    fn journal_path(project_path: &Path, session_id: &SessionId) -> PathBuf {
+        project_path
+            .join(".sunwell")
+            .join("sessions")
+            .join(format!("{}.ndjson", session_id))
+    }
+
+    /// Re-read `session_id`'s event journal and re-emit the `UIEvent` stream,
+    /// so the UI can reconstruct a completed or crashed run without
+    /// re-executing the agent.
+    pub fn replay_session(
+        &self,
+        session_id: &SessionId,
+        app: &AppHandle,
+        project_path: &Path,
+    ) -> Result<(), SunwellError> {
+        let path = Self::journal_path(project_path, session_id);
+        let file = std::fs::File::open(&path).map_err(|e| {
+            SunwellError::from_error(ErrorCode::FileNotFound, e)
+                .with_hints(vec!["No event journal exists for this session"])
+        })?;
+
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let event: AgentEvent = parse_json_safe(&line)?;
+            let ui_event = UIEvent {
+                session_id: session_id.clone(),
+                ui: UIHints::from_event(&event),
+                event,
+            };
+            let _ = app.emit("agent-event", &ui_event);
+        }
+
+        Ok(())
+    }
+
+    /// Build the `agent resume --json [--provider ...]` args used both by
+    /// `resume_goal` and by the auto-retry loop after an abnormal exit.
+    fn resume_args(provider: Option<&str>, checkpoint_path: Option<&Path>) -> Vec<String> {
+        let mut args: Vec<String> = vec!["agent".into(), "resume".into(), "--json".into()];
+        if let Some(provider_name) = provider {
+            args.push("--provider".into());
+            args.push(provider_name.to_string());
+        }
+        if let Some(path) = checkpoint_path {
+            args.push("--checkpoint".into());
+            args.push(path.to_string_lossy().into_owned());
+        }
+        args
+    }
+
+    /// Spawn the `sunwell` subprocess for `args`, wire up the duplex
+    /// stdin/stdout channel, and stream `session_id`-tagged events to the
+    /// frontend.
+    ///
+    /// If the child exits abnormally without having emitted a terminal
+    /// `complete`/`error` event (crash, OOM, dropped model connection), and
+    /// the exit wasn't caused by an explicit `stop()`, this automatically
+    /// re-spawns via the `agent resume` path according to `policy`, emitting
+    /// an `agent-retry` event before each attempt and a final `error` event
+    /// once `max_attempts` is exhausted.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_and_stream(
+        app: AppHandle,
+        session_id: SessionId,
+        args: Vec<String>,
+        project_path: PathBuf,
+        provider: Option<String>,
+        running: Arc<AtomicBool>,
+        stdin_slot: Arc<Mutex<Option<ChildStdin>>>,
+        process_slot: Arc<Mutex<Option<Child>>>,
+        stopping: Arc<AtomicBool>,
+        metrics: Arc<Mutex<SessionMetrics>>,
+        policy: RetryPolicy,
+        attempt: u32,
+        job_manager: Arc<JobManager>,
+    ) -> Result<(), SunwellError> {
+        stopping.store(false, Ordering::SeqCst);
+
         let mut child = sunwell_command()
             .args(&args)
-            .current_dir(project_path)
+            .current_dir(&project_path)
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
@@ -590,13 +941,14 @@ impl AgentBridge {
                     "Try running 'sunwell --help' to verify",
                 ]))?;
 
-        let stdout = child.stdout.take().ok_or_else(|| 
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take().ok_or_else(||
             sunwell_err!(RuntimeProcessFailed, "Failed to capture agent stdout"))?;
         let stderr = child.stderr.take();
-        self.process = Some(child);
-        self.running.store(true, Ordering::SeqCst);
 
-        let running = self.running.clone();
+        *stdin_slot.lock().map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent stdin lock poisoned"))? = stdin;
+        *process_slot.lock().map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent process lock poisoned"))? = Some(child);
+        running.store(true, Ordering::SeqCst);
 
         // Spawn thread to drain stderr (prevents blocking if buffer fills)
         if let Some(stderr) = stderr {
@@ -614,12 +966,33 @@ impl AgentBridge {
             });
         }
 
+        let thread_app = app.clone();
+        let thread_session_id = session_id.clone();
+        let thread_running = running.clone();
+        let thread_stdin_slot = stdin_slot.clone();
+        let thread_process_slot = process_slot.clone();
+        let thread_stopping = stopping.clone();
+        let thread_metrics = metrics.clone();
+        let thread_job_manager = job_manager.clone();
+
+        // Best-effort append-only event journal under the project's
+        // `.sunwell` dir (build-o-tron-inspired). A journal we can't open
+        // just means replay won't be available for this run; it never
+        // blocks streaming.
+        let journal_path = Self::journal_path(&project_path, &session_id);
+        let mut journal = journal_path
+            .parent()
+            .map(std::fs::create_dir_all)
+            .and_then(|_| OpenOptions::new().create(true).append(true).open(&journal_path).ok());
+
         // Spawn thread to read NDJSON events from stdout
         std::thread::spawn(move || {
             let reader = BufReader::new(stdout);
+            let mut saw_terminal = false;
+            let mut events_since_metrics_emit: u32 = 0;
 
             for line in reader.lines() {
-                if !running.load(Ordering::SeqCst) {
+                if !thread_running.load(Ordering::SeqCst) {
                     break;
                 }
 
@@ -631,17 +1004,47 @@ impl AgentBridge {
 
                         match parse_json_safe::<AgentEvent>(&json_line) {
                             Ok(event) => {
-                                // RFC-097: Wrap event with UI hints
+                                if let Some(journal) = journal.as_mut() {
+                                    if let Ok(journal_line) = serde_json::to_string(&event) {
+                                        let _ = writeln!(journal, "{}", journal_line);
+                                    }
+                                }
+
+                                // RFC-097: Wrap event with UI hints for richer frontend rendering
                                 let ui_event = UIEvent {
+                                    session_id: thread_session_id.clone(),
                                     ui: UIHints::from_event(&event),
                                     event: event.clone(),
                                 };
                                 // Emit enriched event to frontend
-                                let _ = app.emit("agent-event", &ui_event);
+                                let _ = thread_app.emit("agent-event", &ui_event);
+
+                                // Roll the event into this session's running
+                                // counters and broadcast a snapshot every
+                                // `METRICS_EMIT_INTERVAL` events so the
+                                // frontend can render live progress without
+                                // polling.
+                                thread_job_manager.record_event(&thread_session_id, &event);
+
+                                if let Ok(mut guard) = thread_metrics.lock() {
+                                    guard.fold(&event);
+                                    events_since_metrics_emit += 1;
+                                    if events_since_metrics_emit >= METRICS_EMIT_INTERVAL {
+                                        events_since_metrics_emit = 0;
+                                        let _ = thread_app.emit(
+                                            "agent-metrics",
+                                            AgentMetricsEvent {
+                                                session_id: thread_session_id.clone(),
+                                                metrics: guard.clone(),
+                                            },
+                                        );
+                                    }
+                                }
 
                                 // Check if this is a terminal event
                                 if event.event_type == "complete" || event.event_type == "error" {
-                                    running.store(false, Ordering::SeqCst);
+                                    saw_terminal = true;
+                                    thread_running.store(false, Ordering::SeqCst);
                                     break;
                                 }
                             }
@@ -657,29 +1060,156 @@ impl AgentBridge {
                 }
             }
 
-            running.store(false, Ordering::SeqCst);
-            let _ = app.emit("agent-stopped", ());
+            thread_running.store(false, Ordering::SeqCst);
+            if let Ok(mut guard) = thread_stdin_slot.lock() {
+                *guard = None;
+            }
+
+            // Reap the child to find out whether it exited abnormally.
+            let exited_abnormally = thread_process_slot
+                .lock()
+                .ok()
+                .and_then(|mut guard| guard.take())
+                .and_then(|mut child| child.wait().ok())
+                .map(|status| !status.success())
+                .unwrap_or(false);
+
+            let was_stopped = thread_stopping.load(Ordering::SeqCst);
+
+            if !saw_terminal && exited_abnormally && !was_stopped {
+                if attempt < policy.max_attempts {
+                    let delay = policy.delay_for_attempt(attempt);
+                    let _ = thread_app.emit(
+                        "agent-retry",
+                        AgentRetryEvent {
+                            session_id: thread_session_id.clone(),
+                            attempt: attempt + 1,
+                            max_attempts: policy.max_attempts,
+                            delay_ms: delay.as_millis() as u64,
+                            reason: "subprocess exited abnormally without a terminal event".to_string(),
+                        },
+                    );
+
+                    std::thread::sleep(delay);
+
+                    let _ = Self::spawn_and_stream(
+                        thread_app.clone(),
+                        thread_session_id.clone(),
+                        Self::resume_args(provider.as_deref(), None),
+                        project_path.clone(),
+                        provider.clone(),
+                        thread_running.clone(),
+                        thread_stdin_slot.clone(),
+                        thread_process_slot.clone(),
+                        thread_stopping.clone(),
+                        thread_metrics.clone(),
+                        policy,
+                        attempt + 1,
+                        thread_job_manager.clone(),
+                    );
+                    // The recursive call above owns emitting its own
+                    // `agent-stopped`; avoid emitting a premature one here.
+                    return;
+                } else {
+                    let error_event = AgentEvent {
+                        event_type: "error".to_string(),
+                        data: serde_json::json!({
+                            "message": format!(
+                                "Agent failed after {} retry attempts and could not be auto-resumed",
+                                policy.max_attempts
+                            ),
+                        }),
+                        timestamp: SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map(|d| d.as_secs_f64())
+                            .unwrap_or(0.0),
+                        ui_hints: None,
+                    };
+                    let ui_event = UIEvent {
+                        session_id: thread_session_id.clone(),
+                        ui: UIHints::from_event(&error_event),
+                        event: error_event,
+                    };
+                    let _ = thread_app.emit("agent-event", &ui_event);
+                }
+            }
+
+            // Final snapshot so the frontend's last-seen counters always
+            // match what actually happened, even if the interval boundary
+            // was never hit (e.g. a very short run).
+            if let Ok(guard) = thread_metrics.lock() {
+                let _ = thread_app.emit(
+                    "agent-metrics",
+                    AgentMetricsEvent {
+                        session_id: thread_session_id.clone(),
+                        metrics: guard.clone(),
+                    },
+                );
+            }
+
+            thread_job_manager.finish(&thread_session_id);
+
+            let _ = thread_app.emit(
+                "agent-stopped",
+                AgentStoppedEvent { session_id: thread_session_id.clone() },
+            );
         });
 
         Ok(())
     }
 
-    /// Stop the running agent.
-    pub fn stop(&mut self) -> Result<(), SunwellError> {
-        self.running.store(false, Ordering::SeqCst);
+    /// Gracefully stop the agent running under `session_id`: ask it to wind
+    /// down on its own, then force-kill it if it ignores the request.
+    pub fn stop(&self, session_id: &SessionId, app: &AppHandle) -> Result<(), SunwellError> {
+        let session = {
+            let sessions = self
+                .sessions
+                .lock()
+                .map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent session map lock poisoned"))?;
+            sessions
+                .get(session_id)
+                .ok_or_else(|| sunwell_err!(RuntimeStateInvalid, "No such agent session: {}", session_id))?
+                .clone()
+        };
+        Self::request_graceful_stop(session_id.clone(), session, app.clone(), self.shutdown_policy);
+        Ok(())
+    }
 
-        if let Some(mut process) = self.process.take() {
-            process.kill().map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["The agent process may have already terminated"]))?;
+    /// Gracefully stop every currently-running session.
+    #[allow(dead_code)]
+    pub fn stop_all(&self, app: &AppHandle) -> Result<(), SunwellError> {
+        let sessions: Vec<(SessionId, AgentSession)> = self
+            .sessions
+            .lock()
+            .map_err(|_| sunwell_err!(RuntimeStateInvalid, "Agent session map lock poisoned"))?
+            .iter()
+            .map(|(id, session)| (id.clone(), session.clone()))
+            .collect();
+        for (session_id, session) in sessions {
+            Self::request_graceful_stop(session_id, session, app.clone(), self.shutdown_policy);
         }
-
         Ok(())
     }
 
-    /// Check if agent is running.
+    /// Check if the given session is running.
+    #[allow(dead_code)]
+    pub fn is_running(&self, session_id: &SessionId) -> bool {
+        self.sessions
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(session_id).map(|s| s.running.load(Ordering::SeqCst)))
+            .unwrap_or(false)
+    }
+
+    /// Snapshot of the live counters accumulated for `session_id`, if it
+    /// exists. Returns `None` once the session has been evicted from the
+    /// map, not merely once it has stopped running.
     #[allow(dead_code)]
-    pub fn is_running(&self) -> bool {
-        self.running.load(Ordering::SeqCst)
+    pub fn metrics(&self, session_id: &SessionId) -> Option<SessionMetrics> {
+        self.sessions
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(session_id).and_then(|s| s.metrics.lock().ok().map(|m| m.clone())))
     }
 }
 
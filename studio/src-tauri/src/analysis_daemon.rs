@@ -0,0 +1,163 @@
+//! Persistent `sunwell serve --stdio` analysis daemon client (RFC-079 addendum).
+//!
+//! `analyze_project`, `analyze_monorepo`, and `get_project_signals` each used
+//! to spawn a fresh `sunwell` process, paying full cold-start every call and
+//! losing any in-memory cache the CLI built. This holds one long-lived
+//! `sunwell serve --stdio` child alive behind a JSON-RPC framing, similar to
+//! an LSP client: requests/responses are line-delimited JSON-RPC `Call`/
+//! `Output` frames matched by a monotonically increasing id. Callers that get
+//! `None` back should fall back to the one-shot subprocess path — this
+//! covers a missing daemon subcommand on older CLI versions as well as a
+//! daemon that died mid-request.
+
+use crate::util::{parse_json_safe, sunwell_command_async};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{oneshot, Mutex as AsyncMutex};
+
+/// How long to wait for a daemon response (handshake or request) before
+/// giving up and falling back to a one-shot subprocess call.
+const DAEMON_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+type PendingMap = Arc<std::sync::Mutex<HashMap<u64, oneshot::Sender<serde_json::Value>>>>;
+
+/// A JSON-RPC `Output` frame read back from the daemon's stdout.
+#[derive(Deserialize)]
+struct RpcOutput {
+    id: u64,
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+struct DaemonProcess {
+    stdin: tokio::process::ChildStdin,
+    pending: PendingMap,
+    next_id: AtomicU64,
+}
+
+/// Persistent client for the `sunwell serve --stdio` analysis daemon.
+pub struct AnalysisDaemonClient {
+    process: AsyncMutex<Option<DaemonProcess>>,
+}
+
+impl AnalysisDaemonClient {
+    pub fn new() -> Self {
+        Self {
+            process: AsyncMutex::new(None),
+        }
+    }
+
+    /// Send a JSON-RPC request to the daemon, spawning it first if it isn't
+    /// already running. Returns `None` if the daemon can't be reached,
+    /// doesn't answer the handshake, or times out — callers should fall back
+    /// to a one-shot subprocess call in that case.
+    pub async fn request(&self, method: &str, params: serde_json::Value) -> Option<serde_json::Value> {
+        let mut guard = self.process.lock().await;
+
+        if guard.is_none() {
+            *guard = Self::spawn().await;
+        }
+
+        let process = guard.as_mut()?;
+        let id = process.next_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        process.pending.lock().unwrap().insert(id, tx);
+
+        let call = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        let Ok(mut line) = serde_json::to_string(&call) else {
+            process.pending.lock().unwrap().remove(&id);
+            return None;
+        };
+        line.push('\n');
+
+        if process.stdin.write_all(line.as_bytes()).await.is_err() {
+            // The daemon's stdin is gone — drop it so the next call respawns.
+            *guard = None;
+            return None;
+        }
+
+        // Release the lock while we wait so other callers can pipeline
+        // requests through the same daemon instead of queuing behind us.
+        drop(guard);
+
+        match tokio::time::timeout(DAEMON_REQUEST_TIMEOUT, rx).await {
+            Ok(Ok(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Spawn `sunwell serve --stdio`, start its reader/reaper tasks, and
+    /// confirm it answers a handshake ping before handing the client back.
+    async fn spawn() -> Option<DaemonProcess> {
+        let mut child = sunwell_command_async()
+            .args(["serve", "--stdio"])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .ok()?;
+
+        let stdin = child.stdin.take()?;
+        let stdout = child.stdout.take()?;
+        let pending: PendingMap = Arc::new(std::sync::Mutex::new(HashMap::new()));
+
+        // Reader: dispatch each `Output` frame to whichever caller is
+        // waiting on its id.
+        let reader_pending = pending.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let Ok(output) = parse_json_safe::<RpcOutput>(&line) else {
+                    continue;
+                };
+                if let Some(tx) = reader_pending.lock().unwrap().remove(&output.id) {
+                    let value = output.result.or(output.error).unwrap_or(serde_json::Value::Null);
+                    let _ = tx.send(value);
+                }
+            }
+        });
+
+        // Reaper: once the daemon exits, drop any requests still waiting on
+        // it so they fail fast instead of waiting out the full timeout.
+        let reaper_pending = pending.clone();
+        tokio::spawn(async move {
+            let _ = child.wait().await;
+            reaper_pending.lock().unwrap().clear();
+        });
+
+        let mut process = DaemonProcess {
+            stdin,
+            pending,
+            next_id: AtomicU64::new(1),
+        };
+
+        // Handshake so a CLI that doesn't support `serve --stdio` falls back
+        // to the one-shot path cleanly instead of hanging on the first real
+        // request.
+        let (tx, rx) = oneshot::channel();
+        process.pending.lock().unwrap().insert(0, tx);
+        let ping = serde_json::json!({ "jsonrpc": "2.0", "id": 0, "method": "ping", "params": {} });
+        let mut line = serde_json::to_string(&ping).ok()?;
+        line.push('\n');
+        if process.stdin.write_all(line.as_bytes()).await.is_err() {
+            return None;
+        }
+        // A timeout, or a sender dropped because the daemon already exited,
+        // both mean there's no usable daemon.
+        if !matches!(tokio::time::timeout(DAEMON_REQUEST_TIMEOUT, rx).await, Ok(Ok(_))) {
+            return None;
+        }
+
+        Some(process)
+    }
+}
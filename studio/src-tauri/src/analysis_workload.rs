@@ -0,0 +1,134 @@
+//! Batch project-analysis workload harness (RFC-079 addendum).
+//!
+//! Mirrors the workload-file + results-report pattern from the DAG
+//! benchmark harness (`benchmark.rs`): a JSON file lists N project paths
+//! plus expected `project_type`/`confidence` thresholds, this runs
+//! `analyze_project` over each and produces one aggregate report capturing
+//! per-project confidence, classification source, detected project type,
+//! and wall-clock duration, plus environment metadata. Results serialize to
+//! JSON so two reports can be diffed to catch a CLI change that degrades
+//! detection accuracy, or POSTed to a collection endpoint.
+
+use crate::commands::{analyze_project, AppState};
+use crate::util::{parse_json_safe, sunwell_command};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tauri::State;
+
+/// Expected classification thresholds for one project in a workload file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkloadProject {
+    pub path: String,
+    pub expected_project_type: Option<String>,
+    pub min_confidence: Option<f64>,
+}
+
+/// A workload file: a list of projects to analyze plus their expected
+/// classification thresholds, modeled on `BenchmarkWorkload`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnalysisWorkload {
+    pub name: String,
+    pub projects: Vec<WorkloadProject>,
+}
+
+/// Per-project result captured for one workload entry.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisWorkloadResult {
+    pub path: String,
+    pub project_type: Option<String>,
+    pub confidence: Option<f64>,
+    pub classification_source: Option<String>,
+    pub wall_clock_ms: u128,
+    pub matches_expected_type: Option<bool>,
+    pub meets_min_confidence: Option<bool>,
+    pub error: Option<String>,
+}
+
+/// Environment metadata captured alongside a report, so two reports run on
+/// different machines or CLI versions can be told apart at a glance.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadEnvironment {
+    pub os: String,
+    pub cli_version: Option<String>,
+}
+
+/// Aggregate report for one workload file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisWorkloadReport {
+    pub name: String,
+    pub environment: WorkloadEnvironment,
+    pub results: Vec<AnalysisWorkloadResult>,
+    pub total_wall_clock_ms: u128,
+}
+
+fn detect_cli_version() -> Option<String> {
+    let output = sunwell_command().arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Run `analyze_project` over every project in a workload file and produce
+/// one aggregate report, so classification accuracy can be regression
+/// tested across a corpus.
+#[tauri::command]
+pub async fn run_analysis_workload(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    workload_path: String,
+) -> Result<AnalysisWorkloadReport, String> {
+    let content = std::fs::read_to_string(&workload_path)
+        .map_err(|e| format!("Failed to read workload file {}: {}", workload_path, e))?;
+    let workload: AnalysisWorkload =
+        parse_json_safe(&content).map_err(|e| format!("Failed to parse workload file: {}", e))?;
+
+    let mut results = Vec::with_capacity(workload.projects.len());
+    for project in &workload.projects {
+        let started = Instant::now();
+        let outcome = analyze_project(app.clone(), state.clone(), project.path.clone(), None, None).await;
+        let wall_clock_ms = started.elapsed().as_millis();
+
+        let result = match outcome {
+            Ok(analysis) => AnalysisWorkloadResult {
+                path: project.path.clone(),
+                project_type: Some(analysis.project_type.clone()),
+                confidence: Some(analysis.confidence),
+                classification_source: Some(analysis.classification_source.clone()),
+                wall_clock_ms,
+                matches_expected_type: project
+                    .expected_project_type
+                    .as_ref()
+                    .map(|expected| expected == &analysis.project_type),
+                meets_min_confidence: project.min_confidence.map(|min| analysis.confidence >= min),
+                error: None,
+            },
+            Err(e) => AnalysisWorkloadResult {
+                path: project.path.clone(),
+                project_type: None,
+                confidence: None,
+                classification_source: None,
+                wall_clock_ms,
+                matches_expected_type: None,
+                meets_min_confidence: None,
+                error: Some(e.to_string()),
+            },
+        };
+        results.push(result);
+    }
+
+    let total_wall_clock_ms = results.iter().map(|r| r.wall_clock_ms).sum();
+
+    Ok(AnalysisWorkloadReport {
+        name: workload.name,
+        environment: WorkloadEnvironment {
+            os: std::env::consts::OS.to_string(),
+            cli_version: detect_cli_version(),
+        },
+        results,
+        total_wall_clock_ms,
+    })
+}
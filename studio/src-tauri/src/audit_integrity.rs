@@ -0,0 +1,208 @@
+//! Native Audit Log Hash-Chain Verification (RFC-109 addendum)
+//!
+//! `verify_audit_integrity` used to trust `sunwell security audit --verify`'s
+//! JSON entirely — if the CLI itself were the thing that had been tampered
+//! with, it could report a clean chain regardless of what's actually on
+//! disk. This module re-derives the same hash chain the audit log is
+//! defined by, independently of the CLI, by reading the raw
+//! newline-delimited JSON log directly:
+//!
+//!     entry_hash = SHA-256(prev_hash_bytes || canonical_json(entry_without_hash))
+//!
+//! with the genesis record's `prev_hash` all zero. Walking the file in
+//! order and recomputing each hash catches the first record whose content,
+//! position, or predecessor was altered, without trusting the process that
+//! wrote it.
+//!
+//! The log's final line, if present, is a seal record — `{"seal_hmac": ..}`
+//! — an HMAC-SHA256 of the last entry's `entry_hash` under a key compiled
+//! into this binary. A missing or mismatched seal flags the log as
+//! `truncated`: someone removed entries from the tail without re-sealing.
+//! This only detects tampering by something that doesn't also have this
+//! binary's source — it's tamper-evidence against a truncated/edited log
+//! file, not a cryptographic guarantee against an attacker who controls
+//! both the CLI and the Studio build.
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// HMAC key for the tail seal. Not a secret in the cryptographic sense —
+/// anyone with this binary has it too — it exists to catch accidental or
+/// naive truncation of the log file, not a sophisticated adversary with
+/// both read and write access to this binary.
+const SEAL_KEY: &[u8] = b"sunwell-audit-seal-v1";
+
+/// Result of independently re-verifying the on-disk audit log's hash chain.
+#[derive(Debug, Clone)]
+pub struct ChainVerification {
+    /// Number of entries confirmed to chain correctly from genesis.
+    pub total_verified: u64,
+    /// Index (0-based, counting blank lines) of the first entry whose
+    /// recomputed hash doesn't match what's recorded, or `None` if every
+    /// entry checked out.
+    pub first_broken_index: Option<u64>,
+    /// Whether the log's tail is missing or doesn't match the expected
+    /// seal — see the module doc comment.
+    pub truncated: bool,
+}
+
+fn default_audit_log_path() -> PathBuf {
+    crate::workspace::default_config_root()
+        .join("security")
+        .join("audit.jsonl")
+}
+
+/// Independently verify the raw audit log's hash chain and tail seal.
+/// Returns a clean, all-verified result if the log doesn't exist yet —
+/// there's nothing to have been tampered with.
+pub fn verify_audit_log_chain() -> ChainVerification {
+    verify_chain_at(&default_audit_log_path())
+}
+
+fn verify_chain_at(path: &PathBuf) -> ChainVerification {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return ChainVerification {
+            total_verified: 0,
+            first_broken_index: None,
+            truncated: false,
+        };
+    };
+
+    let mut expected_prev_hash = "0".repeat(64);
+    let mut total_verified: u64 = 0;
+    let mut first_broken_index: Option<u64> = None;
+    let mut last_entry_hash: Option<String> = None;
+    let mut seal_line: Option<Map<String, Value>> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some(record) = serde_json::from_str::<Value>(line)
+            .ok()
+            .and_then(|v| v.as_object().cloned())
+        else {
+            first_broken_index = Some(index as u64);
+            break;
+        };
+
+        if record.contains_key("seal_hmac") {
+            seal_line = Some(record);
+            continue;
+        }
+
+        let (Some(recorded_prev), Some(recorded_entry_hash)) = (
+            record.get("prev_hash").and_then(Value::as_str),
+            record.get("entry_hash").and_then(Value::as_str),
+        ) else {
+            first_broken_index = Some(index as u64);
+            break;
+        };
+
+        let mut entry_without_hash = record.clone();
+        entry_without_hash.remove("prev_hash");
+        entry_without_hash.remove("entry_hash");
+        let canonical = canonical_json(&Value::Object(entry_without_hash));
+
+        let mut hasher = Sha256::new();
+        hasher.update(recorded_prev.as_bytes());
+        hasher.update(canonical.as_bytes());
+        let computed_hash = format!("{:x}", hasher.finalize());
+
+        if recorded_prev != expected_prev_hash || computed_hash != recorded_entry_hash {
+            first_broken_index = Some(index as u64);
+            break;
+        }
+
+        total_verified += 1;
+        expected_prev_hash = recorded_entry_hash.to_string();
+        last_entry_hash = Some(recorded_entry_hash.to_string());
+    }
+
+    let truncated = first_broken_index.is_none() && is_truncated(&last_entry_hash, &seal_line);
+
+    ChainVerification {
+        total_verified,
+        first_broken_index,
+        truncated,
+    }
+}
+
+/// Whether the log's tail is missing or doesn't match the expected seal.
+/// An empty log (no entries, no seal) is not truncated — it's simply new.
+fn is_truncated(last_entry_hash: &Option<String>, seal_line: &Option<Map<String, Value>>) -> bool {
+    match (last_entry_hash, seal_line) {
+        (None, None) => false,
+        (Some(last_hash), Some(seal)) => {
+            let expected = seal.get("seal_hmac").and_then(Value::as_str).unwrap_or("");
+            let computed = to_hex(&hmac_sha256(SEAL_KEY, last_hash.as_bytes()));
+            computed != expected
+        }
+        // Entries with no seal, or a seal with no entries to seal: either
+        // way the tail doesn't describe the log that's actually on disk.
+        _ => true,
+    }
+}
+
+/// Deterministic JSON serialization: object keys sorted, no extra
+/// whitespace — so the same logical entry always hashes to the same
+/// bytes regardless of how it happened to be written.
+fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let parts: Vec<String> = keys
+                .into_iter()
+                .map(|k| format!("{}:{}", Value::String(k.clone()), canonical_json(&map[k])))
+                .collect();
+            format!("{{{}}}", parts.join(","))
+        }
+        Value::Array(items) => {
+            let parts: Vec<String> = items.iter().map(canonical_json).collect();
+            format!("[{}]", parts.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hand-rolled HMAC-SHA256 (no `hmac` crate in this tree — same call as
+/// `metrics`'s hand-rolled Prometheus exposition: a few lines of RFC 2104
+/// beat a new dependency for one call site).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut key_block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let mut hasher = Sha256::new();
+        hasher.update(key);
+        let hashed = hasher.finalize();
+        key_block[..hashed.len()].copy_from_slice(&hashed);
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; BLOCK_SIZE];
+    let mut opad = [0x5cu8; BLOCK_SIZE];
+    for i in 0..BLOCK_SIZE {
+        ipad[i] ^= key_block[i];
+        opad[i] ^= key_block[i];
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(ipad);
+    inner.update(message);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(opad);
+    outer.update(inner_hash);
+    outer.finalize().into()
+}
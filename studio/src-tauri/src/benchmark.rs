@@ -0,0 +1,185 @@
+//! DAG Planning & Incremental Execution Benchmarks (RFC-105 addendum)
+//!
+//! Replays a reproducible "workload" file — a fixture project plus a
+//! sequence of operations (`plan`, `execute`, `edit`, `replan`) — against
+//! the existing RFC-074/RFC-105 pipeline (`get_incremental_plan`,
+//! `execute_dag_node`, `get_cache_stats`, `get_artifact_impact`) and
+//! records wall-clock time plus skip/impact metrics per step. The
+//! resulting `BenchmarkReport` is a structured document maintainers can
+//! diff against a stored baseline to prove a change actually improves
+//! skip rates instead of regressing them.
+
+use crate::util::parse_json_safe;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// One step in a workload's operation sequence.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum WorkloadStep {
+    /// Compute the incremental plan via `get_incremental_plan`.
+    Plan,
+    /// Execute a DAG node via `execute_dag_node`.
+    Execute { node_id: String },
+    /// Overwrite an artifact file's contents, to force the next `plan`/
+    /// `execute` step to see it (and its dependents) as stale.
+    Edit { artifact_path: String, contents: String },
+    /// Re-run planning after an edit — identical to `Plan`, kept as a
+    /// distinct variant so the step label in the report reads `replan`.
+    Replan,
+    /// Analyze downstream impact of an artifact via `get_artifact_impact`.
+    Impact { artifact_id: String },
+}
+
+/// A workload file: a fixture project plus the operations to replay
+/// against it, modeled on `EvalWorkload` in `eval.rs`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkWorkload {
+    pub name: String,
+    pub project_path: String,
+    pub steps: Vec<WorkloadStep>,
+}
+
+/// Metrics captured for a single workload step.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StepResult {
+    pub op: String,
+    pub wall_clock_ms: u128,
+    pub to_execute_count: Option<usize>,
+    pub to_skip_count: Option<usize>,
+    pub skip_percentage: Option<f32>,
+    pub by_status: Option<std::collections::HashMap<String, i64>>,
+    pub impacted_count: Option<usize>,
+    pub error: Option<String>,
+}
+
+/// Result of replaying one workload file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReport {
+    pub name: String,
+    pub steps: Vec<StepResult>,
+    pub total_wall_clock_ms: u128,
+}
+
+/// Aggregate of running several workload files in one invocation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateBenchmarkReport {
+    pub reports: Vec<BenchmarkReport>,
+    pub total_wall_clock_ms: u128,
+}
+
+/// Run every step of a single workload file, in order, against the
+/// existing DAG pipeline, and return a per-step timing/metrics report.
+#[tauri::command]
+pub async fn run_benchmark(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+    workload_path: String,
+) -> Result<BenchmarkReport, String> {
+    let content = std::fs::read_to_string(&workload_path)
+        .map_err(|e| format!("Failed to read workload file {}: {}", workload_path, e))?;
+    let workload: BenchmarkWorkload =
+        parse_json_safe(&content).map_err(|e| format!("Failed to parse workload file: {}", e))?;
+
+    run_workload(app, state, &workload).await
+}
+
+/// Run several workload files in one invocation and aggregate the
+/// results, so a regression suite can be expressed as a directory of
+/// workload files rather than one command invocation each.
+#[tauri::command]
+pub async fn run_benchmarks(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+    workload_paths: Vec<String>,
+) -> Result<AggregateBenchmarkReport, String> {
+    let mut reports = Vec::new();
+    for workload_path in &workload_paths {
+        let content = std::fs::read_to_string(workload_path)
+            .map_err(|e| format!("Failed to read workload file {}: {}", workload_path, e))?;
+        let workload: BenchmarkWorkload =
+            parse_json_safe(&content).map_err(|e| format!("Failed to parse workload file {}: {}", workload_path, e))?;
+        reports.push(run_workload(app.clone(), state.clone(), &workload).await?);
+    }
+
+    let total_wall_clock_ms = reports.iter().map(|r| r.total_wall_clock_ms).sum();
+    Ok(AggregateBenchmarkReport { reports, total_wall_clock_ms })
+}
+
+async fn run_workload(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+    workload: &BenchmarkWorkload,
+) -> Result<BenchmarkReport, String> {
+    let project_path = workload.project_path.clone();
+    let mut steps = Vec::with_capacity(workload.steps.len());
+
+    for step in &workload.steps {
+        let started = Instant::now();
+        let result = match step {
+            WorkloadStep::Plan | WorkloadStep::Replan => run_plan_step(&project_path).await,
+            WorkloadStep::Execute { node_id } => run_execute_step(app.clone(), &state, &project_path, node_id).await,
+            WorkloadStep::Edit { artifact_path, contents } => run_edit_step(&project_path, artifact_path, contents),
+            WorkloadStep::Impact { artifact_id } => run_impact_step(&project_path, artifact_id).await,
+        };
+
+        let mut step_result = result.unwrap_or_else(|e| StepResult { error: Some(e), ..Default::default() });
+        step_result.op = step_label(step).to_string();
+        step_result.wall_clock_ms = started.elapsed().as_millis();
+        steps.push(step_result);
+    }
+
+    let total_wall_clock_ms = steps.iter().map(|s| s.wall_clock_ms).sum();
+    Ok(BenchmarkReport { name: workload.name.clone(), steps, total_wall_clock_ms })
+}
+
+fn step_label(step: &WorkloadStep) -> &'static str {
+    match step {
+        WorkloadStep::Plan => "plan",
+        WorkloadStep::Replan => "replan",
+        WorkloadStep::Execute { .. } => "execute",
+        WorkloadStep::Edit { .. } => "edit",
+        WorkloadStep::Impact { .. } => "impact",
+    }
+}
+
+async fn run_plan_step(project_path: &str) -> Result<StepResult, String> {
+    let plan = crate::dag::get_incremental_plan(project_path.to_string()).await?;
+    let stats = crate::dag::get_cache_stats(project_path.to_string()).await.ok();
+
+    Ok(StepResult {
+        to_execute_count: Some(plan.to_execute.len()),
+        to_skip_count: Some(plan.to_skip.len()),
+        skip_percentage: Some(plan.skip_percentage),
+        by_status: stats.map(|s| s.by_status),
+        ..Default::default()
+    })
+}
+
+async fn run_execute_step(
+    app: tauri::AppHandle,
+    state: &tauri::State<'_, crate::commands::AppState>,
+    project_path: &str,
+    node_id: &str,
+) -> Result<StepResult, String> {
+    crate::dag::execute_dag_node(app, state.clone(), project_path.to_string(), node_id.to_string()).await?;
+    Ok(StepResult::default())
+}
+
+fn run_edit_step(project_path: &str, artifact_path: &str, contents: &str) -> Result<StepResult, String> {
+    let full_path = PathBuf::from(project_path).join(artifact_path);
+    if let Some(dir) = full_path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create artifact directory: {}", e))?;
+    }
+    std::fs::write(&full_path, contents).map_err(|e| format!("Failed to edit artifact {}: {}", artifact_path, e))?;
+    Ok(StepResult::default())
+}
+
+async fn run_impact_step(project_path: &str, artifact_id: &str) -> Result<StepResult, String> {
+    let impacted = crate::dag::get_artifact_impact(project_path.to_string(), artifact_id.to_string()).await?;
+    Ok(StepResult { impacted_count: Some(impacted.len()), ..Default::default() })
+}
@@ -4,6 +4,7 @@
 //! The briefing is a compressed "where are we now" that provides context
 //! at session start without requiring retrieval.
 
+use crate::error::ErrorClass;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use thiserror::Error;
@@ -18,13 +19,35 @@ pub enum BriefingError {
     ParseError(#[from] serde_json::Error),
 }
 
-// Implement serialization for Tauri
+impl BriefingError {
+    /// Map this error to a stable machine class so the frontend can tell a
+    /// missing briefing from a malformed one without string-matching the
+    /// message.
+    fn class(&self) -> ErrorClass {
+        match self {
+            BriefingError::ReadError(e) => match e.kind() {
+                std::io::ErrorKind::NotFound => ErrorClass::NotFound,
+                std::io::ErrorKind::PermissionDenied => ErrorClass::PermissionDenied,
+                _ => ErrorClass::ProcessFailed,
+            },
+            BriefingError::ParseError(_) => ErrorClass::ParseError,
+        }
+    }
+}
+
+// Implement serialization for Tauri: emit `{ "class": ..., "message": ... }`
+// instead of a flat string, so the Studio UI can drive recovery UX off the
+// class without parsing the message.
 impl serde::Serialize for BriefingError {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BriefingError", 2)?;
+        state.serialize_field("class", &self.class())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
     }
 }
 
@@ -0,0 +1,87 @@
+//! Capability-Gated Command Authorization (RFC-085 addendum)
+//!
+//! Source-mutating `self_knowledge` commands (`self_approve_proposal`,
+//! `self_apply_proposal`, `self_rollback_proposal`) can rewrite Sunwell's
+//! own running source, so authorization for them can't rest on subprocess
+//! exit codes alone. This module resolves a declarative capability
+//! manifest — modeled on Tauri's own runtime authority — at build time,
+//! and exposes `authorize` for each command to consult before it shells
+//! out via `sunwell_command()`.
+//!
+//! Manifests live under `capabilities/*.json` and are compiled into the
+//! binary with `include_str!`, so a shipped build's grants can't be
+//! edited after the fact. The active manifest is chosen per build
+//! profile: `capabilities/maintainer.json` when the `maintainer` Cargo
+//! feature is enabled, `capabilities/read-only.json` otherwise. A
+//! read-only build therefore structurally cannot approve, apply, or roll
+//! back a proposal — there is no runtime flag that grants it.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::util::sunwell_command;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+#[cfg(feature = "maintainer")]
+const MANIFEST_JSON: &str = include_str!("../capabilities/maintainer.json");
+#[cfg(not(feature = "maintainer"))]
+const MANIFEST_JSON: &str = include_str!("../capabilities/read-only.json");
+
+#[derive(Debug, Deserialize)]
+struct CapabilityManifest {
+    profile: String,
+    #[allow(dead_code)] // surfaced to maintainers reading the manifest, not consumed in code
+    description: String,
+    allow: Vec<String>,
+}
+
+struct ResolvedAuthority {
+    profile: String,
+    allow: HashSet<String>,
+}
+
+static AUTHORITY: OnceLock<ResolvedAuthority> = OnceLock::new();
+
+fn authority() -> &'static ResolvedAuthority {
+    AUTHORITY.get_or_init(|| {
+        let manifest: CapabilityManifest =
+            serde_json::from_str(MANIFEST_JSON).expect("capability manifest is invalid JSON");
+        ResolvedAuthority { profile: manifest.profile, allow: manifest.allow.into_iter().collect() }
+    })
+}
+
+/// Check whether `command` is granted by the build's compiled-in
+/// capability manifest. On denial, returns a `ToolPermissionDenied`
+/// `SunwellError` and records the decision to the security audit log; a
+/// grant is recorded too, so the audit trail shows every self-mutating
+/// command that actually ran, not just the ones that were refused.
+pub fn authorize(command: &str) -> Result<(), SunwellError> {
+    let authority = authority();
+    let allowed = authority.allow.contains(command);
+    record_decision(command, &authority.profile, allowed);
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(SunwellError::new(
+            ErrorCode::ToolPermissionDenied,
+            format!("'{}' is not granted by the '{}' capability profile", command, authority.profile),
+        )
+        .with_hints(vec!["Rebuild Studio with the \"maintainer\" feature to grant self-mutating commands"]))
+    }
+}
+
+/// Best-effort write to the existing security audit log — mirrors
+/// `dag_store::sync_goal`'s stance that a side-channel record must never
+/// fail the primary operation, so a logging hiccup doesn't also block a
+/// legitimately denied (or granted) command from being reported.
+fn record_decision(command: &str, profile: &str, allowed: bool) {
+    let decision = if allowed { "allow" } else { "deny" };
+    let result = sunwell_command()
+        .args(["security", "audit", "record", "--command", command, "--decision", decision, "--profile", profile])
+        .output();
+
+    if let Err(e) = result {
+        eprintln!("capability: failed to record audit decision for '{}': {}", command, e);
+    }
+}
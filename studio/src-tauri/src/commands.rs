@@ -1,20 +1,31 @@
 //! Tauri IPC commands — interface between frontend and Rust backend.
 
-use crate::agent::AgentBridge;
-use crate::util::{parse_json_safe, sunwell_command};
+use crate::agent::{new_session_id, AgentBridge};
+use crate::analysis_daemon::AnalysisDaemonClient;
+use crate::coordinator::CoordinatorStreamManager;
+use crate::demo::DemoManager;
+use crate::demo_transport::DemoSessionManager;
+use crate::file_watcher::ProjectFileWatcherManager;
+use crate::intelligence_watcher::IntelligenceWatcherManager;
+use crate::job_manager::JobManager;
+use crate::lens_watcher::LensWatcherManager;
+use crate::memory_watcher::MemoryWatcherManager;
 use crate::preview::PreviewManager;
 use crate::project::{Project, ProjectDetector, RecentProject};
+use crate::self_knowledge::{SelfProposalSessionManager, SourceWatcherManager};
+use crate::util::{parse_json_safe, sunwell_command};
 use crate::workspace::{
-    create_recent_project, default_workspace_root, ensure_workspace_exists,
-    extract_project_name, resolve_workspace, shorten_path, slugify,
-    RecentProjectsStore, ResolutionSource, SavedPrompt, SavedPromptsStore, WorkspaceResult,
+    clone_workspace, create_recent_project, default_workspace_root, ensure_workspace_exists,
+    extract_project_name, resolve_workspace, shorten_path, slugify, RecentProjectsStore,
+    ResolutionSource, SavedPrompt, SavedPromptsStore, WorkspaceResult,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
 use std::hash::{Hash, Hasher};
-use std::path::PathBuf;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use tauri::{Emitter, State};
 
 /// Application state shared across commands.
@@ -25,6 +36,30 @@ pub struct AppState {
     pub current_project: Mutex<Option<Project>>,
     pub recent_projects: Mutex<RecentProjectsStore>,
     pub saved_prompts: Mutex<SavedPromptsStore>,
+    pub demos: DemoManager,
+    pub demo_sessions: DemoSessionManager,
+    pub lens_watcher: Mutex<LensWatcherManager>,
+    pub self_proposals: SelfProposalSessionManager,
+    pub source_watcher: Mutex<SourceWatcherManager>,
+    pub coordinator_streams: CoordinatorStreamManager,
+    pub job_manager: Arc<JobManager>,
+    pub file_watcher: ProjectFileWatcherManager,
+    pub memory_watcher: MemoryWatcherManager,
+    pub intelligence_watcher: IntelligenceWatcherManager,
+    /// Ring buffers of captured stdout/stderr lines per run session id, so a
+    /// reopened log panel can backfill instead of only seeing new output.
+    pub run_session_logs: Arc<Mutex<HashMap<String, VecDeque<RunSessionLog>>>>,
+    /// Live `run_project` processes, keyed by session id, so they can be
+    /// stopped, restarted, and queried by status instead of only by a PID
+    /// parsed back out of the session id string.
+    run_sessions: Arc<Mutex<HashMap<String, RunningProcess>>>,
+    /// Long-lived `sunwell serve --stdio` client shared across project
+    /// analysis commands, so repeated calls skip the CLI's cold start.
+    pub analysis_daemon: AnalysisDaemonClient,
+    /// Cancellation handles for in-flight `analyze_project` calls, keyed by
+    /// project path, so `cancel_analysis` can abort a stuck scan instead of
+    /// leaking its child process.
+    analysis_cancellations: Arc<Mutex<HashMap<String, Arc<tokio::sync::Notify>>>>,
 }
 
 impl Default for AppState {
@@ -36,6 +71,20 @@ impl Default for AppState {
             current_project: Mutex::new(None),
             recent_projects: Mutex::new(RecentProjectsStore::load()),
             saved_prompts: Mutex::new(SavedPromptsStore::load()),
+            demos: DemoManager::new(),
+            demo_sessions: DemoSessionManager::new(),
+            lens_watcher: Mutex::new(LensWatcherManager::new()),
+            self_proposals: SelfProposalSessionManager::new(),
+            source_watcher: Mutex::new(SourceWatcherManager::new()),
+            coordinator_streams: CoordinatorStreamManager::new(),
+            job_manager: Arc::new(JobManager::new()),
+            file_watcher: ProjectFileWatcherManager::new(),
+            memory_watcher: MemoryWatcherManager::new(),
+            intelligence_watcher: IntelligenceWatcherManager::new(),
+            run_session_logs: Arc::new(Mutex::new(HashMap::new())),
+            run_sessions: Arc::new(Mutex::new(HashMap::new())),
+            analysis_daemon: AnalysisDaemonClient::new(),
+            analysis_cancellations: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
@@ -46,6 +95,9 @@ pub struct RunGoalResult {
     pub success: bool,
     pub message: String,
     pub workspace_path: String,
+    /// Identifies this run for `stop_agent`/`send_agent_approval` and for
+    /// routing `agent-event` payloads when several runs are in flight.
+    pub session_id: String,
 }
 
 /// Workspace resolution info for frontend.
@@ -69,6 +121,7 @@ impl From<WorkspaceResult> for WorkspaceInfo {
                 ResolutionSource::Explicit => "explicit".to_string(),
                 ResolutionSource::Detected => "detected".to_string(),
                 ResolutionSource::Default => "default".to_string(),
+                ResolutionSource::Clone => "clone".to_string(),
             },
             confidence: result.confidence,
             needs_confirmation: result.needs_confirmation(),
@@ -87,11 +140,22 @@ pub async fn resolve_workspace_for_goal(
     let explicit = explicit_path.map(PathBuf::from);
     let project_name = extract_project_name(&goal);
 
-    let result = resolve_workspace(
-        explicit.as_deref(),
-        project_name.as_deref(),
-    );
+    let result = resolve_workspace(explicit.as_deref(), project_name.as_deref());
+
+    Ok(result.into())
+}
 
+/// Clone a git remote into its resolved workspace location.
+///
+/// `resolve_workspace_for_goal`/`resolve_workspace` already detect a git
+/// remote URL passed as `explicit_path` and resolve it to a
+/// `ResolutionSource::Clone` result pointing at where it would live; this
+/// actually performs the clone (a no-op if that path already exists) and
+/// records the result as a recent project.
+#[tauri::command]
+pub async fn clone_workspace_from_url(url: String) -> Result<WorkspaceInfo, String> {
+    let result = resolve_workspace(Some(&PathBuf::from(&url)), None);
+    clone_workspace(&result, &url).map_err(|e| e.to_string())?;
     Ok(result.into())
 }
 
@@ -115,6 +179,12 @@ pub async fn create_project(
     ensure_workspace_exists(&path)
         .map_err(|e| format!("Failed to create project directory: {}", e))?;
 
+    // Mint a real UUID up front for a genuinely new project, rather than
+    // letting `ensure_project_id` migrate a legacy hash id for it later.
+    if !path.join(".sunwell").join("project.json").exists() {
+        write_project_identity(&path, &uuid::Uuid::new_v4().to_string());
+    }
+
     // Detect project type
     let project = state.detector.detect(&path)?;
 
@@ -155,10 +225,7 @@ pub async fn run_goal(
     let explicit = project_path.map(PathBuf::from);
     let project_name = extract_project_name(&goal);
 
-    let resolution = resolve_workspace(
-        explicit.as_deref(),
-        project_name.as_deref(),
-    );
+    let resolution = resolve_workspace(explicit.as_deref(), project_name.as_deref());
 
     let workspace_path = resolution.path.clone();
 
@@ -175,14 +242,24 @@ pub async fn run_goal(
     drop(prompts_store);
 
     // Start agent with lens and provider selection (RFC-064, RFC-Cloud-Model-Parity)
-    let mut agent = state.agent.lock().map_err(|e| e.to_string())?;
+    let session_id = new_session_id();
+    state.job_manager.start(
+        session_id.clone(),
+        &workspace_path,
+        &goal,
+        provider.clone(),
+        lens.clone(),
+    );
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
     agent.run_goal(
+        session_id.clone(),
         app,
         &goal,
         &workspace_path,
         lens.as_deref(),
         auto_lens.unwrap_or(true),
         provider.as_deref(),
+        state.job_manager.clone(),
     )?;
 
     // Update recent projects
@@ -203,42 +280,108 @@ pub async fn run_goal(
         success: true,
         message: "Agent started".to_string(),
         workspace_path: shorten_path(&workspace_path),
+        session_id,
     })
 }
 
-/// Stop the running agent.
+/// Stop a running agent session.
+#[tauri::command]
+pub async fn stop_agent(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+) -> Result<(), String> {
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
+    agent.stop(&session_id, &app)
+}
+
+/// Answer a `SecurityApprovalRequested` event from a running agent session (RFC-089).
 #[tauri::command]
-pub async fn stop_agent(state: State<'_, AppState>) -> Result<(), String> {
-    let mut agent = state.agent.lock().map_err(|e| e.to_string())?;
-    agent.stop()
+pub async fn send_agent_approval(
+    state: State<'_, AppState>,
+    session_id: String,
+    request_id: String,
+    approved: bool,
+    scope: Option<serde_json::Value>,
+) -> Result<(), String> {
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
+    agent.send_approval(&session_id, &request_id, approved, scope)?;
+    Ok(())
 }
 
-/// Get list of recent projects.
+/// Re-emit a session's persisted event journal so the UI can reconstruct a
+/// completed or crashed run without re-executing the agent.
 #[tauri::command]
-pub async fn get_recent_projects(
+pub async fn replay_agent_session(
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
-) -> Result<Vec<RecentProject>, String> {
+    session_id: String,
+    project_path: String,
+) -> Result<(), String> {
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
+    agent.replay_session(&session_id, &app, &PathBuf::from(project_path))?;
+    Ok(())
+}
+
+/// Get list of recent projects.
+#[tauri::command]
+pub async fn get_recent_projects(state: State<'_, AppState>) -> Result<Vec<RecentProject>, String> {
     let recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
     Ok(recent_store.get_all().to_vec())
 }
 
 /// Remove a project from recent list.
 #[tauri::command]
-pub async fn remove_recent_project(
+pub async fn remove_recent_project(state: State<'_, AppState>, path: String) -> Result<(), String> {
+    let mut recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
+    recent_store.remove(&PathBuf::from(path));
+    recent_store.save().map_err(|e| e.to_string())
+}
+
+/// Add a tag to a recent project.
+#[tauri::command]
+pub async fn tag_recent_project(
     state: State<'_, AppState>,
     path: String,
+    tag: String,
 ) -> Result<(), String> {
     let mut recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
-    recent_store.remove(&PathBuf::from(path));
+    recent_store.add_tag(&PathBuf::from(path), tag);
     recent_store.save().map_err(|e| e.to_string())
 }
 
-/// Open a project from a path.
+/// Remove a tag from a recent project.
 #[tauri::command]
-pub async fn open_project(
+pub async fn untag_recent_project(
     state: State<'_, AppState>,
     path: String,
-) -> Result<Project, String> {
+    tag: String,
+) -> Result<(), String> {
+    let mut recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
+    recent_store.remove_tag(&PathBuf::from(path), &tag);
+    recent_store.save().map_err(|e| e.to_string())
+}
+
+/// Get all recent projects carrying a given tag.
+#[tauri::command]
+pub async fn get_recent_projects_by_tag(
+    state: State<'_, AppState>,
+    tag: String,
+) -> Result<Vec<RecentProject>, String> {
+    let recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
+    Ok(recent_store.tagged(&tag).into_iter().cloned().collect())
+}
+
+/// Get the set of all tags currently in use across recent projects.
+#[tauri::command]
+pub async fn get_recent_project_tags(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let recent_store = state.recent_projects.lock().map_err(|e| e.to_string())?;
+    Ok(recent_store.all_tags())
+}
+
+/// Open a project from a path.
+#[tauri::command]
+pub async fn open_project(state: State<'_, AppState>, path: String) -> Result<Project, String> {
     let path = PathBuf::from(&path);
 
     if !path.exists() {
@@ -273,19 +416,24 @@ pub async fn get_project_info(state: State<'_, AppState>) -> Result<Option<Proje
     Ok(current.clone())
 }
 
-/// Launch preview for the current project.
+/// Launch preview for the current project. `tunnel`, if provided, requests
+/// a shareable public URL for web previews (see `PreviewManager::launch`);
+/// it's ignored for every other preview kind. `watch`, if true, starts a
+/// live hot-reload file watcher on the project so the preview refreshes
+/// itself on change instead of needing a manual relaunch.
 #[tauri::command]
 pub async fn launch_preview(
     state: State<'_, AppState>,
+    app: tauri::AppHandle,
+    tunnel: Option<crate::preview::TunnelConfig>,
+    watch: Option<bool>,
 ) -> Result<crate::preview::PreviewSession, String> {
     let current = state.current_project.lock().map_err(|e| e.to_string())?;
 
-    let project = current
-        .as_ref()
-        .ok_or("No project open")?;
+    let project = current.as_ref().ok_or("No project open")?;
 
     let mut preview = state.preview.lock().map_err(|e| e.to_string())?;
-    preview.launch(project)
+    preview.launch(project, tunnel, watch.unwrap_or(false), app)
 }
 
 /// Stop the running preview.
@@ -380,7 +528,7 @@ pub struct ProjectStatus {
 #[tauri::command]
 pub async fn scan_projects() -> Result<Vec<ProjectStatus>, String> {
     let projects_root = default_workspace_root();
-    
+
     if !projects_root.exists() {
         return Ok(vec![]);
     }
@@ -392,7 +540,7 @@ pub async fn scan_projects() -> Result<Vec<ProjectStatus>, String> {
 
     for entry in entries.flatten() {
         let path = entry.path();
-        
+
         // Skip non-directories
         if !path.is_dir() {
             continue;
@@ -421,6 +569,63 @@ pub async fn scan_projects() -> Result<Vec<ProjectStatus>, String> {
     Ok(projects)
 }
 
+/// One project discovered with an incomplete checkpoint at startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoverableJob {
+    pub path: String,
+    pub display_path: String,
+    pub goal: String,
+    pub tasks_completed: u32,
+    pub tasks_total: u32,
+    pub last_activity: String,
+}
+
+/// Scan `default_workspace_root()` for projects with an incomplete
+/// checkpoint and emit a `jobs-recoverable` event listing them, most
+/// recently interrupted first, so the frontend can offer to auto-resume
+/// the top one via `resume_project`. Call at app startup.
+#[tauri::command]
+pub async fn recover_jobs(app: tauri::AppHandle) -> Result<Vec<RecoverableJob>, String> {
+    let projects_root = default_workspace_root();
+    if !projects_root.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut recoverable = Vec::new();
+    let entries = std::fs::read_dir(&projects_root)
+        .map_err(|e| format!("Failed to read projects directory: {}", e))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if name.starts_with('.') {
+                continue;
+            }
+        }
+
+        if let Ok(status) = get_project_status_internal(&path) {
+            if status.status == ExecutionStatus::Interrupted {
+                recoverable.push(RecoverableJob {
+                    path: status.path,
+                    display_path: status.display_path,
+                    goal: status.last_goal.unwrap_or_default(),
+                    tasks_completed: status.tasks_completed.unwrap_or(0),
+                    tasks_total: status.tasks_total.unwrap_or(0),
+                    last_activity: status.last_activity.unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    recoverable.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
+    let _ = app.emit("jobs-recoverable", &recoverable);
+
+    Ok(recoverable)
+}
+
 /// Get status for a specific project.
 #[tauri::command]
 pub async fn get_project_status(path: String) -> Result<ProjectStatus, String> {
@@ -442,7 +647,7 @@ fn get_project_status_internal(path: &PathBuf) -> Result<ProjectStatus, String>
     // Check for checkpoints (interrupted execution)
     let checkpoint_info = find_latest_checkpoint(&checkpoints_dir);
 
-    let (status, last_goal, tasks_completed, tasks_total, tasks, last_activity) = 
+    let (status, last_goal, tasks_completed, tasks_total, tasks, last_activity) =
         if let Some(info) = checkpoint_info {
             (
                 if info.is_complete {
@@ -457,18 +662,17 @@ fn get_project_status_internal(path: &PathBuf) -> Result<ProjectStatus, String>
                 Some(info.timestamp),
             )
         } else {
-            (ExecutionStatus::None, None, None, None, None, get_dir_mtime(path))
+            (
+                ExecutionStatus::None,
+                None,
+                None,
+                None,
+                None,
+                get_dir_mtime(path),
+            )
         };
 
-    // Generate stable ID from path
-    let id = {
-        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
-        let path_str = canonical.to_string_lossy();
-        let mut hasher = DefaultHasher::new();
-        path_str.hash(&mut hasher);
-        let hash = hasher.finish();
-        format!("{:012x}", hash)
-    };
+    let id = ensure_project_id(path);
 
     Ok(ProjectStatus {
         id,
@@ -484,6 +688,57 @@ fn get_project_status_internal(path: &PathBuf) -> Result<ProjectStatus, String>
     })
 }
 
+/// A project's persisted identity, written to `.sunwell/project.json` so
+/// `ProjectStatus.id` survives the project being moved or renamed.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectIdentity {
+    id: String,
+}
+
+/// Load this project's persistent id from `.sunwell/project.json`,
+/// creating it on first detection. A project that already has state but
+/// predates this file migrates by adopting its old canonicalized-path
+/// hash as the persisted id, so any frontend state already keyed on it
+/// keeps working; `create_project` mints a real `uuid::Uuid` up front for
+/// genuinely new projects, so this path only runs for those once.
+fn ensure_project_id(path: &Path) -> String {
+    let identity_path = path.join(".sunwell").join("project.json");
+
+    if let Ok(content) = std::fs::read_to_string(&identity_path) {
+        if let Ok(identity) = serde_json::from_str::<ProjectIdentity>(&content) {
+            return identity.id;
+        }
+    }
+
+    let id = legacy_hash_id(path);
+    write_project_identity(path, &id);
+    id
+}
+
+/// The pre-migration stable-ID algorithm: a hash of the canonicalized
+/// path. Kept only so `ensure_project_id` can adopt it for projects that
+/// predate `.sunwell/project.json`.
+fn legacy_hash_id(path: &Path) -> String {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let path_str = canonical.to_string_lossy();
+    let mut hasher = DefaultHasher::new();
+    path_str.hash(&mut hasher);
+    format!("{:012x}", hasher.finish())
+}
+
+/// Persist `id` as this project's identity. Best effort: a write failure
+/// (disk full, permissions) just means the id gets re-derived next time
+/// rather than aborting whatever triggered the write.
+fn write_project_identity(path: &Path, id: &str) {
+    let sunwell_dir = path.join(".sunwell");
+    if std::fs::create_dir_all(&sunwell_dir).is_err() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(&ProjectIdentity { id: id.to_string() }) {
+        let _ = std::fs::write(sunwell_dir.join("project.json"), json);
+    }
+}
+
 /// Checkpoint summary info.
 struct CheckpointInfo {
     goal: String,
@@ -494,33 +749,78 @@ struct CheckpointInfo {
     tasks: Vec<CheckpointTask>,
 }
 
-/// Find and parse the latest checkpoint file.
+/// Find and parse the latest checkpoint file, legacy `.json` (ad hoc,
+/// guessed field names) or versioned `.msgpack` (strongly typed via
+/// `job_manager::JobCheckpoint`), whichever extension is newest.
 fn find_latest_checkpoint(checkpoints_dir: &PathBuf) -> Option<CheckpointInfo> {
+    let (path, _) = latest_checkpoint_path(checkpoints_dir)?;
+    parse_checkpoint_file(&path)
+}
+
+/// List every checkpoint file under `checkpoints_dir`, parsed and paired
+/// with the file stem used as its `id` (e.g. `studio-<session_id>` for a
+/// Studio-written msgpack checkpoint). Unparseable files are skipped.
+fn list_checkpoint_files(checkpoints_dir: &Path) -> Vec<(String, CheckpointInfo)> {
+    let Ok(entries) = std::fs::read_dir(checkpoints_dir) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let extension = path.extension().and_then(|e| e.to_str());
+            if extension != Some("json") && extension != Some("msgpack") {
+                return None;
+            }
+            let id = path.file_stem().and_then(|s| s.to_str())?.to_string();
+            let info = parse_checkpoint_file(&path)?;
+            Some((id, info))
+        })
+        .collect()
+}
+
+/// Find the checkpoint file under `checkpoints_dir` with the latest mtime
+/// among `.json`/`.msgpack` extensions.
+fn latest_checkpoint_path(checkpoints_dir: &Path) -> Option<(PathBuf, std::time::SystemTime)> {
     if !checkpoints_dir.exists() {
         return None;
     }
 
     let entries = std::fs::read_dir(checkpoints_dir).ok()?;
-    
+
     let mut latest: Option<(PathBuf, std::time::SystemTime)> = None;
 
     for entry in entries.flatten() {
         let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) == Some("json") {
-            if let Ok(meta) = path.metadata() {
-                if let Ok(mtime) = meta.modified() {
-                    if latest.is_none() || mtime > latest.as_ref().unwrap().1 {
-                        latest = Some((path, mtime));
-                    }
+        let extension = path.extension().and_then(|e| e.to_str());
+        if extension != Some("json") && extension != Some("msgpack") {
+            continue;
+        }
+        if let Ok(meta) = path.metadata() {
+            if let Ok(mtime) = meta.modified() {
+                if latest.is_none() || mtime > latest.as_ref().unwrap().1 {
+                    latest = Some((path, mtime));
                 }
             }
         }
     }
 
-    let (checkpoint_path, mtime) = latest?;
-    
+    latest
+}
+
+/// Parse a single checkpoint file, dispatching on extension, and stamping
+/// the result with its own mtime as the timestamp.
+fn parse_checkpoint_file(checkpoint_path: &Path) -> Option<CheckpointInfo> {
+    let mtime = checkpoint_path.metadata().ok()?.modified().ok()?;
+    let timestamp = format_mtime(mtime);
+
+    if checkpoint_path.extension().and_then(|e| e.to_str()) == Some("msgpack") {
+        return parse_msgpack_checkpoint(checkpoint_path, timestamp);
+    }
+
     // Parse checkpoint JSON
-    let content = std::fs::read_to_string(&checkpoint_path).ok()?;
+    let content = std::fs::read_to_string(checkpoint_path).ok()?;
     let json: serde_json::Value = serde_json::from_str(&content).ok()?;
 
     let goal = json.get("goal")?.as_str()?.to_string();
@@ -531,39 +831,33 @@ fn find_latest_checkpoint(checkpoints_dir: &PathBuf) -> Option<CheckpointInfo> {
         .iter()
         .filter_map(|v| v.as_str().map(|s| s.to_string()))
         .collect();
-    
+
     // Parse task details
     let tasks: Vec<CheckpointTask> = tasks_json
         .iter()
         .filter_map(|t| {
             let id = t.get("id")?.as_str()?.to_string();
             // Try different possible field names for description
-            let description = t.get("description")
+            let description = t
+                .get("description")
                 .or_else(|| t.get("title"))
                 .or_else(|| t.get("name"))
                 .and_then(|v| v.as_str())
                 .unwrap_or("Task")
                 .to_string();
             let completed = completed_ids.contains(&id);
-            Some(CheckpointTask { id, description, completed })
+            Some(CheckpointTask {
+                id,
+                description,
+                completed,
+            })
         })
         .collect();
-    
+
     let total = tasks.len() as u32;
     let completed = tasks.iter().filter(|t| t.completed).count() as u32;
     let is_complete = completed >= total && total > 0;
 
-    // Format timestamp
-    let timestamp = mtime
-        .duration_since(std::time::UNIX_EPOCH)
-        .ok()
-        .map(|d| {
-            DateTime::<Utc>::from_timestamp(d.as_secs() as i64, 0)
-                .map(|dt| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string())
-                .unwrap_or_default()
-        })
-        .unwrap_or_default();
-
     Some(CheckpointInfo {
         goal,
         completed,
@@ -574,6 +868,40 @@ fn find_latest_checkpoint(checkpoints_dir: &PathBuf) -> Option<CheckpointInfo> {
     })
 }
 
+/// Parse a versioned msgpack checkpoint written by `JobManager`. Unlike the
+/// legacy JSON path, field names are guaranteed by `JobCheckpoint`'s schema
+/// rather than guessed.
+fn parse_msgpack_checkpoint(checkpoint_path: &Path, timestamp: String) -> Option<CheckpointInfo> {
+    let bytes = std::fs::read(checkpoint_path).ok()?;
+    let checkpoint: crate::job_manager::JobCheckpoint = rmp_serde::from_slice(&bytes).ok()?;
+
+    let total = checkpoint.tasks.len() as u32;
+    let completed = checkpoint.tasks.iter().filter(|t| t.completed).count() as u32;
+    let is_complete = completed >= total && total > 0;
+
+    Some(CheckpointInfo {
+        goal: checkpoint.goal,
+        completed,
+        total,
+        is_complete,
+        timestamp,
+        tasks: checkpoint.tasks,
+    })
+}
+
+/// Format a file's mtime as an ISO-8601 UTC timestamp string.
+fn format_mtime(mtime: std::time::SystemTime) -> String {
+    mtime
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| {
+            DateTime::<Utc>::from_timestamp(d.as_secs() as i64, 0)
+                .map(|dt| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+}
+
 /// Get directory modification time as ISO string.
 fn get_dir_mtime(path: &PathBuf) -> Option<String> {
     let meta = std::fs::metadata(path).ok()?;
@@ -583,15 +911,56 @@ fn get_dir_mtime(path: &PathBuf) -> Option<String> {
         .map(|dt| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string())
 }
 
+/// One checkpoint in a project's execution timeline, as returned by
+/// `list_checkpoints`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointSummary {
+    pub id: String,
+    pub timestamp: String,
+    pub goal: String,
+    pub completed: u32,
+    pub total: u32,
+    pub is_complete: bool,
+}
+
+/// List every checkpoint in a project's `.sunwell/checkpoints/`, most
+/// recent first, so a user can rewind to an earlier point instead of only
+/// continuing the latest via `resume_project`.
+#[tauri::command]
+pub async fn list_checkpoints(path: String) -> Result<Vec<CheckpointSummary>, String> {
+    let project_path = PathBuf::from(&path);
+    let checkpoints_dir = project_path.join(".sunwell").join("checkpoints");
+
+    let mut summaries: Vec<CheckpointSummary> = list_checkpoint_files(&checkpoints_dir)
+        .into_iter()
+        .map(|(id, info)| CheckpointSummary {
+            id,
+            timestamp: info.timestamp,
+            goal: info.goal,
+            completed: info.completed,
+            total: info.total,
+            is_complete: info.is_complete,
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(summaries)
+}
+
 /// Resume an interrupted project.
 ///
 /// RFC-Cloud-Model-Parity: Accepts optional provider selection.
+///
+/// `checkpoint_id` optionally rewinds to an earlier point in the timeline
+/// (an `id` from `list_checkpoints`) instead of continuing from the latest
+/// checkpoint.
 #[tauri::command]
 pub async fn resume_project(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
     path: String,
     provider: Option<String>,
+    checkpoint_id: Option<String>,
 ) -> Result<RunGoalResult, String> {
     let project_path = PathBuf::from(&path);
 
@@ -599,23 +968,59 @@ pub async fn resume_project(
         return Err(format!("Project path does not exist: {}", path));
     }
 
+    let checkpoints_dir = project_path.join(".sunwell").join("checkpoints");
+    let checkpoint_path = match &checkpoint_id {
+        Some(id) => Some(resolve_checkpoint_path(&checkpoints_dir, id)?),
+        None => None,
+    };
+
     // Check if there's something to resume
     let status = get_project_status_internal(&project_path)?;
-    if status.status != ExecutionStatus::Interrupted {
+    if checkpoint_id.is_none() && status.status != ExecutionStatus::Interrupted {
         return Err("No interrupted execution to resume".to_string());
     }
 
     // Start agent in resume mode with optional provider (RFC-Cloud-Model-Parity)
-    let mut agent = state.agent.lock().map_err(|e| e.to_string())?;
-    agent.resume_goal(app, &project_path, provider.as_deref())?;
+    let session_id = new_session_id();
+    let goal = status.last_goal.clone().unwrap_or_default();
+    state.job_manager.start(
+        session_id.clone(),
+        &project_path,
+        &goal,
+        provider.clone(),
+        None,
+    );
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
+    agent.resume_goal(
+        session_id.clone(),
+        app,
+        &project_path,
+        provider.as_deref(),
+        checkpoint_path.as_deref(),
+        state.job_manager.clone(),
+    )?;
 
     Ok(RunGoalResult {
         success: true,
         message: "Agent resumed".to_string(),
         workspace_path: shorten_path(&project_path),
+        session_id,
     })
 }
 
+/// Resolve a `list_checkpoints` `id` to its checkpoint file under
+/// `checkpoints_dir`, trying both extensions since the id is just the
+/// file stem.
+fn resolve_checkpoint_path(checkpoints_dir: &Path, id: &str) -> Result<PathBuf, String> {
+    for extension in ["msgpack", "json"] {
+        let candidate = checkpoints_dir.join(format!("{}.{}", id, extension));
+        if candidate.exists() {
+            return Ok(candidate);
+        }
+    }
+    Err(format!("Checkpoint '{}' not found", id))
+}
+
 // =============================================================================
 // Project Access Commands (files, terminal, edit)
 // =============================================================================
@@ -624,7 +1029,7 @@ pub async fn resume_project(
 #[tauri::command]
 pub async fn open_in_finder(path: String) -> Result<(), String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
@@ -660,7 +1065,7 @@ pub async fn open_in_finder(path: String) -> Result<(), String> {
 #[tauri::command]
 pub async fn open_terminal(path: String) -> Result<(), String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
@@ -701,7 +1106,13 @@ pub async fn open_terminal(path: String) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
         std::process::Command::new("cmd")
-            .args(["/c", "start", "cmd", "/k", &format!("cd /d {}", path.display())])
+            .args([
+                "/c",
+                "start",
+                "cmd",
+                "/k",
+                &format!("cd /d {}", path.display()),
+            ])
             .spawn()
             .map_err(|e| format!("Failed to open terminal: {}", e))?;
     }
@@ -711,19 +1122,19 @@ pub async fn open_terminal(path: String) -> Result<(), String> {
         // Try common terminal emulators
         let terminals = ["gnome-terminal", "konsole", "xfce4-terminal", "xterm"];
         let mut success = false;
-        
+
         for term in &terminals {
             let result = std::process::Command::new(term)
                 .arg("--working-directory")
                 .arg(&path)
                 .spawn();
-            
+
             if result.is_ok() {
                 success = true;
                 break;
             }
         }
-        
+
         if !success {
             return Err("No supported terminal emulator found".to_string());
         }
@@ -744,51 +1155,73 @@ pub struct FileEntry {
 
 /// List files in a project directory (for file tree display).
 #[tauri::command]
-pub async fn list_project_files(path: String, max_depth: Option<u32>) -> Result<Vec<FileEntry>, String> {
+pub async fn list_project_files(
+    path: String,
+    max_depth: Option<u32>,
+) -> Result<Vec<FileEntry>, String> {
     let path = PathBuf::from(&path);
-    
-    if !path.exists() {
+
+    if tokio::fs::metadata(&path).await.is_err() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
 
     let max_depth = max_depth.unwrap_or(3);
-    let entries = list_dir_recursive(&path, 0, max_depth)?;
+    let entries = list_dir_recursive(path, 0, max_depth).await?;
     Ok(entries)
 }
 
-/// Recursively list directory contents.
-fn list_dir_recursive(dir: &PathBuf, depth: u32, max_depth: u32) -> Result<Vec<FileEntry>, String> {
+/// Whether a file/directory name should be skipped when walking a project
+/// tree — hidden entries plus common build/dependency directories. Shared
+/// with `file_watcher`'s live watch so a watched tree and a freshly scanned
+/// one agree on what counts as noise.
+pub(crate) fn is_ignored_entry_name(name: &str) -> bool {
+    name.starts_with('.')
+        || name == "node_modules"
+        || name == "__pycache__"
+        || name == "target"
+        || name == "venv"
+        || name == ".venv"
+        || name == "dist"
+        || name == "build"
+}
+
+/// Recursively list directory contents without blocking a Tauri async
+/// worker thread for the duration of a large walk. Takes `dir` by value
+/// (rather than `&PathBuf`) so `async_recursion` doesn't have to reason
+/// about a borrow living across the recursive `.await`.
+#[async_recursion::async_recursion]
+async fn list_dir_recursive(
+    dir: PathBuf,
+    depth: u32,
+    max_depth: u32,
+) -> Result<Vec<FileEntry>, String> {
     let mut entries = Vec::new();
-    
-    let read_dir = std::fs::read_dir(dir)
+
+    let mut read_dir = tokio::fs::read_dir(&dir)
+        .await
         .map_err(|e| format!("Failed to read directory: {}", e))?;
 
-    for entry in read_dir.flatten() {
+    while let Ok(Some(entry)) = read_dir.next_entry().await {
         let path = entry.path();
         let name = entry.file_name().to_string_lossy().to_string();
-        
-        // Skip hidden files and common ignored directories
-        if name.starts_with('.') 
-            || name == "node_modules" 
-            || name == "__pycache__"
-            || name == "target"
-            || name == "venv"
-            || name == ".venv"
-            || name == "dist"
-            || name == "build"
-        {
+
+        if is_ignored_entry_name(&name) {
             continue;
         }
 
         let is_dir = path.is_dir();
         let size = if !is_dir {
-            std::fs::metadata(&path).ok().map(|m| m.len())
+            tokio::fs::metadata(&path).await.ok().map(|m| m.len())
         } else {
             None
         };
 
         let children = if is_dir && depth < max_depth {
-            Some(list_dir_recursive(&path, depth + 1, max_depth).unwrap_or_default())
+            Some(
+                list_dir_recursive(path.clone(), depth + 1, max_depth)
+                    .await
+                    .unwrap_or_default(),
+            )
         } else if is_dir {
             Some(vec![]) // Indicate it's expandable but not loaded
         } else {
@@ -805,12 +1238,10 @@ fn list_dir_recursive(dir: &PathBuf, depth: u32, max_depth: u32) -> Result<Vec<F
     }
 
     // Sort: directories first, then alphabetically
-    entries.sort_by(|a, b| {
-        match (a.is_dir, b.is_dir) {
-            (true, false) => std::cmp::Ordering::Less,
-            (false, true) => std::cmp::Ordering::Greater,
-            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-        }
+    entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
     });
 
     Ok(entries)
@@ -821,23 +1252,21 @@ fn list_dir_recursive(dir: &PathBuf, depth: u32, max_depth: u32) -> Result<Vec<F
 pub async fn read_file_contents(path: String, max_size: Option<u64>) -> Result<String, String> {
     let path = PathBuf::from(&path);
     let max_size = max_size.unwrap_or(100_000); // 100KB default
-    
-    if !path.exists() {
-        return Err("File does not exist".to_string());
-    }
-    
-    if !path.is_file() {
+
+    let metadata = tokio::fs::metadata(&path)
+        .await
+        .map_err(|_| "File does not exist".to_string())?;
+
+    if !metadata.is_file() {
         return Err("Path is not a file".to_string());
     }
 
-    let metadata = std::fs::metadata(&path)
-        .map_err(|e| format!("Failed to read metadata: {}", e))?;
-    
     if metadata.len() > max_size {
         return Err(format!("File too large ({} bytes)", metadata.len()));
     }
 
-    std::fs::read_to_string(&path)
+    tokio::fs::read_to_string(&path)
+        .await
         .map_err(|e| format!("Failed to read file: {}", e))
 }
 
@@ -845,19 +1274,17 @@ pub async fn read_file_contents(path: String, max_size: Option<u64>) -> Result<S
 #[tauri::command]
 pub async fn open_in_editor(path: String) -> Result<(), String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
 
     // Try editors in order of preference
     let editors = ["cursor", "code", "codium", "subl", "atom"];
-    
+
     for editor in &editors {
-        let result = std::process::Command::new(editor)
-            .arg(&path)
-            .spawn();
-        
+        let result = std::process::Command::new(editor).arg(&path).spawn();
+
         if result.is_ok() {
             return Ok(());
         }
@@ -896,12 +1323,13 @@ pub async fn delete_project(
     path: String,
 ) -> Result<ProjectManageResult, String> {
     let path = PathBuf::from(&path);
-    
-    if !path.exists() {
+
+    if tokio::fs::metadata(&path).await.is_err() {
         return Err(format!("Project does not exist: {}", path.display()));
     }
 
-    let name = path.file_name()
+    let name = path
+        .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("project")
         .to_string();
@@ -913,7 +1341,8 @@ pub async fn delete_project(
     drop(recent_store);
 
     // Delete the directory
-    std::fs::remove_dir_all(&path)
+    tokio::fs::remove_dir_all(&path)
+        .await
         .map_err(|e| format!("Failed to delete project: {}", e))?;
 
     Ok(ProjectManageResult {
@@ -930,23 +1359,26 @@ pub async fn archive_project(
     path: String,
 ) -> Result<ProjectManageResult, String> {
     let path = PathBuf::from(&path);
-    
-    if !path.exists() {
+
+    if tokio::fs::metadata(&path).await.is_err() {
         return Err(format!("Project does not exist: {}", path.display()));
     }
 
-    let name = path.file_name()
+    let name = path
+        .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("project")
         .to_string();
 
     // Create archive directory
-    let archive_root = default_workspace_root().parent()
+    let archive_root = default_workspace_root()
+        .parent()
         .map(|p| p.to_path_buf())
         .unwrap_or_else(|| dirs::home_dir().unwrap_or_default().join("Sunwell"))
         .join("archived");
-    
-    std::fs::create_dir_all(&archive_root)
+
+    tokio::fs::create_dir_all(&archive_root)
+        .await
         .map_err(|e| format!("Failed to create archive directory: {}", e))?;
 
     // Generate unique archive name with timestamp
@@ -955,7 +1387,8 @@ pub async fn archive_project(
     let archive_path = archive_root.join(&archive_name);
 
     // Move the project
-    std::fs::rename(&path, &archive_path)
+    tokio::fs::rename(&path, &archive_path)
+        .await
         .map_err(|e| format!("Failed to archive project: {}", e))?;
 
     // Remove from recent projects
@@ -1024,8 +1457,13 @@ fn extract_project_learnings(path: &PathBuf) -> ProjectLearnings {
             for line in content.lines() {
                 if let Ok(json) = parse_json_safe::<serde_json::Value>(line) {
                     if let Some(approach) = json.get("approach").and_then(|a| a.as_str()) {
-                        let reason = json.get("reason").and_then(|r| r.as_str()).unwrap_or("failed");
-                        learnings.failures.push(format!("{} ({})", approach, reason));
+                        let reason = json
+                            .get("reason")
+                            .and_then(|r| r.as_str())
+                            .unwrap_or("failed");
+                        learnings
+                            .failures
+                            .push(format!("{} ({})", approach, reason));
                     }
                 }
             }
@@ -1035,72 +1473,290 @@ fn extract_project_learnings(path: &PathBuf) -> ProjectLearnings {
     learnings
 }
 
-/// Iterate on a project - create a new version informed by learnings.
-#[tauri::command]
-pub async fn iterate_project(
-    app: tauri::AppHandle,
-    state: State<'_, AppState>,
-    path: String,
-    new_goal: Option<String>,
-) -> Result<ProjectManageResult, String> {
-    let path = PathBuf::from(&path);
-    
-    if !path.exists() {
-        return Err(format!("Project does not exist: {}", path.display()));
-    }
+/// Number of decisions/failures to surface per iteration, whether chosen by
+/// embedding similarity or by the plain-recency fallback.
+const ITERATION_RELEVANCE_TOP_K: usize = 3;
 
-    let name = path.file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("project")
-        .to_string();
+/// A single cached embedding, keyed by the sha256 of the text it was computed
+/// from. Stored one JSON object per line in `embeddings.bin` so unchanged
+/// decisions/failures aren't re-embedded on every iteration.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedEmbedding {
+    hash: String,
+    vector: Vec<f32>,
+}
 
-    // Extract learnings from original project
-    let learnings = extract_project_learnings(&path);
+/// Load the embedding cache for a project's intelligence directory.
+fn load_embedding_cache(intelligence_dir: &Path) -> HashMap<String, Vec<f32>> {
+    let cache_path = intelligence_dir.join("embeddings.bin");
+    let Ok(content) = std::fs::read_to_string(&cache_path) else {
+        return HashMap::new();
+    };
 
-    // Generate new project name (increment version)
-    let new_name = generate_iteration_name(&name);
-    let new_path = path.parent()
-        .map(|p| p.join(&new_name))
-        .ok_or("Failed to determine new project path")?;
+    content
+        .lines()
+        .filter_map(|line| parse_json_safe::<CachedEmbedding>(line).ok())
+        .map(|entry| (entry.hash, entry.vector))
+        .collect()
+}
 
-    // Create new project directory
-    ensure_workspace_exists(&new_path)
-        .map_err(|e| format!("Failed to create iteration directory: {}", e))?;
+/// Persist the embedding cache, overwriting any previous contents.
+fn save_embedding_cache(intelligence_dir: &Path, cache: &HashMap<String, Vec<f32>>) {
+    let body = cache
+        .iter()
+        .filter_map(|(hash, vector)| {
+            serde_json::to_string(&CachedEmbedding {
+                hash: hash.clone(),
+                vector: vector.clone(),
+            })
+            .ok()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
 
-    // Create .sunwell directory with inherited learnings
-    let new_sunwell = new_path.join(".sunwell");
-    std::fs::create_dir_all(&new_sunwell)
-        .map_err(|e| format!("Failed to create .sunwell directory: {}", e))?;
+    let _ = std::fs::write(intelligence_dir.join("embeddings.bin"), body);
+}
 
-    // Write learnings context for the agent to consume
-    let context_path = new_sunwell.join("iteration_context.json");
-    let context_json = serde_json::json!({
-        "iteration_of": path.to_string_lossy(),
-        "original_goal": learnings.original_goal,
-        "learned_decisions": learnings.decisions,
-        "failed_approaches": learnings.failures,
-        "completed_in_previous": learnings.completed_tasks,
-        "pending_from_previous": learnings.pending_tasks,
-    });
-    std::fs::write(&context_path, serde_json::to_string_pretty(&context_json).unwrap_or_default())
-        .map_err(|e| format!("Failed to write iteration context: {}", e))?;
+/// Ask the sunwell CLI to embed a batch of texts, returning one vector per
+/// input in the same order. The request is written to a scratch file rather
+/// than piped over stdin, matching how the rest of this module hands the CLI
+/// structured input. Returns `None` if the CLI is unavailable or its output
+/// doesn't line up with the request — callers should fall back to
+/// non-semantic behavior in that case.
+fn embed_texts(intelligence_dir: &Path, texts: &[String]) -> Option<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Some(Vec::new());
+    }
 
-    // Formulate the iteration goal
-    let iteration_goal = if let Some(goal) = new_goal {
-        goal
+    let request_path = intelligence_dir.join("_embed_request.json");
+    let request_json = serde_json::to_string(&serde_json::json!({ "texts": texts })).ok()?;
+    std::fs::write(&request_path, request_json).ok()?;
+
+    let output = sunwell_command()
+        .args(["intelligence", "embed", "--json", "--input"])
+        .arg(&request_path)
+        .output();
+
+    let _ = std::fs::remove_file(&request_path);
+
+    let output = output.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let vectors: Vec<Vec<f32>> = parse_json_safe(&stdout).ok()?;
+    if vectors.len() != texts.len() {
+        return None;
+    }
+
+    Some(vectors)
+}
+
+/// Cosine similarity between two equal-length vectors, in `[-1.0, 1.0]`.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|y| y * y).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
+/// Select the `top_k` candidates most semantically similar to `goal`,
+/// embedding only what isn't already in the cache. Returns `None` (caller
+/// should fall back to `.take(top_k)`) when the embedding CLI isn't
+/// available.
+fn select_relevant_by_embedding(
+    intelligence_dir: &Path,
+    goal: &str,
+    candidates: &[String],
+    top_k: usize,
+) -> Option<Vec<String>> {
+    if candidates.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut cache = load_embedding_cache(intelligence_dir);
+    let hashes: Vec<String> = candidates
+        .iter()
+        .map(|text| crate::lens::sha256_hex(text))
+        .collect();
+    let to_embed: Vec<String> = candidates
+        .iter()
+        .zip(&hashes)
+        .filter(|(_, hash)| !cache.contains_key(*hash))
+        .map(|(text, _)| text.clone())
+        .collect();
+
+    if !to_embed.is_empty() {
+        let vectors = embed_texts(intelligence_dir, &to_embed)?;
+        for (text, vector) in to_embed.iter().zip(vectors) {
+            cache.insert(crate::lens::sha256_hex(text), vector);
+        }
+        std::fs::create_dir_all(intelligence_dir).ok()?;
+        save_embedding_cache(intelligence_dir, &cache);
+    }
+
+    let goal_vector = embed_texts(intelligence_dir, &[goal.to_string()])?
+        .into_iter()
+        .next()?;
+
+    let mut scored: Vec<(f32, &String)> = hashes
+        .iter()
+        .zip(candidates)
+        .filter_map(|(hash, text)| {
+            cache
+                .get(hash)
+                .map(|vector| (cosine_similarity(&goal_vector, vector), text))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    Some(
+        scored
+            .into_iter()
+            .take(top_k)
+            .map(|(_, text)| text.clone())
+            .collect(),
+    )
+}
+
+/// Iterate on a project - create a new version informed by learnings.
+#[tauri::command]
+pub async fn iterate_project(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+    new_goal: Option<String>,
+) -> Result<ProjectManageResult, String> {
+    let path = PathBuf::from(&path);
+
+    if !path.exists() {
+        return Err(format!("Project does not exist: {}", path.display()));
+    }
+
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("project")
+        .to_string();
+
+    // Extract learnings from original project
+    let learnings = extract_project_learnings(&path);
+
+    // Generate new project name (increment version)
+    let new_name = generate_iteration_name(&name);
+    let new_path = path
+        .parent()
+        .map(|p| p.join(&new_name))
+        .ok_or("Failed to determine new project path")?;
+
+    // Create new project directory
+    ensure_workspace_exists(&new_path)
+        .map_err(|e| format!("Failed to create iteration directory: {}", e))?;
+
+    // Create .sunwell directory with inherited learnings
+    let new_sunwell = new_path.join(".sunwell");
+    std::fs::create_dir_all(&new_sunwell)
+        .map_err(|e| format!("Failed to create .sunwell directory: {}", e))?;
+
+    // When a new goal is supplied, narrow the inherited decisions/failures to
+    // the ones most relevant to it via embedding similarity (falling back to
+    // the plain "most recent" slice if the embedding CLI is unavailable).
+    // Without a new goal there's nothing to rank against, so the full history
+    // is carried forward unfiltered, as before.
+    let intelligence_dir = path.join(".sunwell").join("intelligence");
+    let (relevant_decisions, relevant_failures, iteration_goal) = if let Some(goal) = new_goal {
+        let decisions = select_relevant_by_embedding(
+            &intelligence_dir,
+            &goal,
+            &learnings.decisions,
+            ITERATION_RELEVANCE_TOP_K,
+        )
+        .unwrap_or_else(|| {
+            learnings
+                .decisions
+                .iter()
+                .rev()
+                .take(ITERATION_RELEVANCE_TOP_K)
+                .cloned()
+                .collect()
+        });
+        let failures = select_relevant_by_embedding(
+            &intelligence_dir,
+            &goal,
+            &learnings.failures,
+            ITERATION_RELEVANCE_TOP_K,
+        )
+        .unwrap_or_else(|| {
+            learnings
+                .failures
+                .iter()
+                .rev()
+                .take(ITERATION_RELEVANCE_TOP_K)
+                .cloned()
+                .collect()
+        });
+        (decisions, failures, goal)
     } else if let Some(original) = &learnings.original_goal {
-        format!(
+        let goal = format!(
             "Iterate on: {} — Build an improved version using learnings from the previous attempt. Avoid: {:?}",
             original,
             learnings.failures.iter().take(3).collect::<Vec<_>>()
+        );
+        (
+            learnings.decisions.clone(),
+            learnings.failures.clone(),
+            goal,
         )
     } else {
-        format!("Continue developing {} with improvements", name)
+        let goal = format!("Continue developing {} with improvements", name);
+        (
+            learnings.decisions.clone(),
+            learnings.failures.clone(),
+            goal,
+        )
     };
 
+    // Write learnings context for the agent to consume
+    let context_path = new_sunwell.join("iteration_context.json");
+    let context_json = serde_json::json!({
+        "iteration_of": path.to_string_lossy(),
+        "original_goal": learnings.original_goal,
+        "learned_decisions": relevant_decisions,
+        "failed_approaches": relevant_failures,
+        "completed_in_previous": learnings.completed_tasks,
+        "pending_from_previous": learnings.pending_tasks,
+    });
+    std::fs::write(
+        &context_path,
+        serde_json::to_string_pretty(&context_json).unwrap_or_default(),
+    )
+    .map_err(|e| format!("Failed to write iteration context: {}", e))?;
+
     // Start agent with the new goal (auto-lens for iterations, no provider override)
-    let mut agent = state.agent.lock().map_err(|e| e.to_string())?;
-    agent.run_goal(app, &iteration_goal, &new_path, None, true, None)?;
+    let session_id = new_session_id();
+    state
+        .job_manager
+        .start(session_id.clone(), &new_path, &iteration_goal, None, None);
+    let agent = state.agent.lock().map_err(|e| e.to_string())?;
+    agent.run_goal(
+        session_id,
+        app,
+        &iteration_goal,
+        &new_path,
+        None,
+        true,
+        None,
+        state.job_manager.clone(),
+    )?;
 
     Ok(ProjectManageResult {
         success: true,
@@ -1113,7 +1769,7 @@ pub async fn iterate_project(
 fn generate_iteration_name(name: &str) -> String {
     // Check if name already has version suffix
     let version_re = regex::Regex::new(r"-v(\d+)$").ok();
-    
+
     if let Some(re) = version_re {
         if let Some(caps) = re.captures(name) {
             if let Some(v) = caps.get(1) {
@@ -1124,7 +1780,7 @@ fn generate_iteration_name(name: &str) -> String {
             }
         }
     }
-    
+
     format!("{}-v2", name)
 }
 
@@ -1132,7 +1788,7 @@ fn generate_iteration_name(name: &str) -> String {
 #[tauri::command]
 pub async fn get_project_learnings(path: String) -> Result<ProjectLearnings, String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Project does not exist: {}", path.display()));
     }
@@ -1146,19 +1802,14 @@ pub async fn get_project_learnings(path: String) -> Result<ProjectLearnings, Str
 
 /// Get all saved prompts.
 #[tauri::command]
-pub async fn get_saved_prompts(
-    state: State<'_, AppState>,
-) -> Result<Vec<SavedPrompt>, String> {
+pub async fn get_saved_prompts(state: State<'_, AppState>) -> Result<Vec<SavedPrompt>, String> {
     let prompts_store = state.saved_prompts.lock().map_err(|e| e.to_string())?;
     Ok(prompts_store.get_all().to_vec())
 }
 
 /// Save a prompt (or update its last_used timestamp).
 #[tauri::command]
-pub async fn save_prompt(
-    state: State<'_, AppState>,
-    prompt: String,
-) -> Result<(), String> {
+pub async fn save_prompt(state: State<'_, AppState>, prompt: String) -> Result<(), String> {
     let mut prompts_store = state.saved_prompts.lock().map_err(|e| e.to_string())?;
     prompts_store.add(prompt);
     prompts_store.save().map_err(|e| e.to_string())
@@ -1166,10 +1817,7 @@ pub async fn save_prompt(
 
 /// Remove a prompt from saved list.
 #[tauri::command]
-pub async fn remove_saved_prompt(
-    state: State<'_, AppState>,
-    prompt: String,
-) -> Result<(), String> {
+pub async fn remove_saved_prompt(state: State<'_, AppState>, prompt: String) -> Result<(), String> {
     let mut prompts_store = state.saved_prompts.lock().map_err(|e| e.to_string())?;
     prompts_store.remove(&prompt);
     prompts_store.save().map_err(|e| e.to_string())
@@ -1179,14 +1827,18 @@ pub async fn remove_saved_prompt(
 // Run Analysis Commands (RFC-066: Intelligent Run Button)
 // =============================================================================
 
-use crate::heuristic_detect::heuristic_detect;
-use crate::run_analysis::{validate_command_safety, RunAnalysis, RunSession, Source};
+use crate::heuristic_detect::{
+    enumerate_monorepo_members, heuristic_detect, inspect_environment, ProjectEnvironment,
+};
+use crate::run_analysis::{
+    validate_command_safety_with_config, RunAnalysis, RunSafetyConfig, RunSession, Source,
+};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::time::timeout;
 
 /// Analyze project to determine how to run it.
 /// Returns cached result if available and project unchanged.
-/// 
+///
 /// Timeout: 10 seconds. Falls back to heuristic detection if AI unavailable.
 #[tauri::command]
 pub async fn analyze_project_for_run(
@@ -1194,24 +1846,21 @@ pub async fn analyze_project_for_run(
     force_refresh: bool,
 ) -> Result<RunAnalysis, String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
-    
+
     // Check for user-saved command first (highest priority)
     if !force_refresh {
         if let Some(saved) = load_saved_run_command(&path) {
             return Ok(saved);
         }
     }
-    
+
     // Try AI analysis with timeout
-    let ai_result = timeout(
-        Duration::from_secs(10),
-        call_python_run_analyzer(&path)
-    ).await;
-    
+    let ai_result = timeout(Duration::from_secs(10), call_python_run_analyzer(&path)).await;
+
     match ai_result {
         Ok(Ok(analysis)) => Ok(analysis),
         Ok(Err(e)) => {
@@ -1229,23 +1878,41 @@ pub async fn analyze_project_for_run(
     }
 }
 
+/// Inspect a project's runtime/toolchain so the frontend can warn about a
+/// missing `node`/`cargo` before `launch_preview` or `run_goal` fails on it.
+#[tauri::command]
+pub async fn get_project_environment(path: String) -> Result<ProjectEnvironment, String> {
+    let path = PathBuf::from(&path);
+
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+
+    Ok(inspect_environment(&path))
+}
+
 /// Call Python run analyzer via subprocess.
 async fn call_python_run_analyzer(path: &PathBuf) -> Result<RunAnalysis, String> {
-    use std::process::Command;
-    
+    use tokio::process::Command;
+
     let output = Command::new("python")
-        .args(["-m", "sunwell.tools.run_analyzer", "--path", &path.to_string_lossy()])
+        .args([
+            "-m",
+            "sunwell.tools.run_analyzer",
+            "--path",
+            &path.to_string_lossy(),
+        ])
         .output()
+        .await
         .map_err(|e| format!("Failed to run Python analyzer: {}", e))?;
-    
+
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(format!("Python analyzer failed: {}", stderr));
     }
-    
+
     let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&stdout)
-        .map_err(|e| format!("Failed to parse analyzer output: {}", e))
+    parse_json_safe(&stdout).map_err(|e| format!("Failed to parse analyzer output: {}", e))
 }
 
 /// Load user-saved run command for a project.
@@ -1254,14 +1921,14 @@ fn load_saved_run_command(path: &PathBuf) -> Option<RunAnalysis> {
     if !run_json_path.exists() {
         return None;
     }
-    
+
     let content = std::fs::read_to_string(&run_json_path).ok()?;
     let mut analysis: RunAnalysis = serde_json::from_str(&content).ok()?;
-    
+
     // Mark as user-saved
     analysis.source = Source::User;
     analysis.user_saved = true;
-    
+
     Some(analysis)
 }
 
@@ -1270,44 +1937,68 @@ fn load_saved_run_command(path: &PathBuf) -> Option<RunAnalysis> {
 #[tauri::command]
 pub async fn run_project(
     app: tauri::AppHandle,
+    state: State<'_, AppState>,
     path: String,
     command: String,
     install_first: bool,
     save_command: bool,
 ) -> Result<RunSession, String> {
     let path = PathBuf::from(&path);
-    
+
     if !path.exists() {
         return Err(format!("Path does not exist: {}", path.display()));
     }
-    
-    // Re-validate command against allowlist (even if user edited it)
-    validate_command_safety(&command)
+
+    // Re-validate command against allowlist (even if user edited it),
+    // expanding any user-defined alias (e.g. `dev` -> `npm run dev`) first.
+    let safety_config = RunSafetyConfig::load();
+    let command = validate_command_safety_with_config(&command, &safety_config)
         .map_err(|e| format!("Command validation failed: {}", e))?;
-    
+
     // Optionally run install command first
     if install_first {
         run_install_command(&path).await?;
     }
-    
+
     // Save command if requested
     if save_command {
         save_run_command_internal(&path, &command)?;
     }
-    
-    // Execute the run command
-    let session = spawn_run_process(&path, &command)?;
-    
+
+    // Execute the run command, streaming its output to the frontend
+    let session = spawn_run_process(
+        &path,
+        &command,
+        app.clone(),
+        state.run_session_logs.clone(),
+        state.run_sessions.clone(),
+    )
+    .await?;
+
     // Emit event to frontend
     let _ = app.emit("run-session-started", &session);
-    
+
     Ok(session)
 }
 
+/// Backfill a run session's captured output — e.g. when its log panel is
+/// reopened after having missed the live `run-session-log` events.
+#[tauri::command]
+pub async fn get_run_session_logs(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<RunSessionLog>, String> {
+    let logs = state.run_session_logs.lock().map_err(|e| e.to_string())?;
+    Ok(logs
+        .get(&session_id)
+        .map(|ring| ring.iter().cloned().collect())
+        .unwrap_or_default())
+}
+
 /// Run install command (npm install, pip install, etc.)
 async fn run_install_command(path: &PathBuf) -> Result<(), String> {
-    use std::process::Command;
-    
+    use tokio::process::Command;
+
     // Detect package manager and run install
     let (cmd, args): (&str, &[&str]) = if path.join("package.json").exists() {
         if path.join("pnpm-lock.yaml").exists() {
@@ -1328,53 +2019,383 @@ async fn run_install_command(path: &PathBuf) -> Result<(), String> {
     } else {
         return Ok(()); // Nothing to install
     };
-    
+
     let output = Command::new(cmd)
         .args(args)
         .current_dir(path)
         .output()
+        .await
         .map_err(|e| format!("Failed to run install: {}", e))?;
-    
+
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(format!("Install failed: {}", stderr));
     }
-    
+
     Ok(())
 }
 
-/// Spawn the run process.
-fn spawn_run_process(path: &PathBuf, command: &str) -> Result<RunSession, String> {
-    use std::process::Command;
-    
-    let parts: Vec<&str> = command.split_whitespace().collect();
-    if parts.is_empty() {
-        return Err("Empty command".to_string());
+/// Maximum number of sub-project installs to run at once, so a monorepo
+/// with dozens of members doesn't saturate every CPU/network slot at once.
+const MONOREPO_INSTALL_CONCURRENCY: usize = 4;
+
+/// Outcome of installing a single monorepo sub-project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubProjectInstallResult {
+    pub name: String,
+    pub path: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Emitted once per sub-project as its install finishes, so the frontend
+/// can render a live progress list instead of waiting for the whole batch.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MonorepoInstallProgress {
+    completed: usize,
+    total: usize,
+    result: SubProjectInstallResult,
+}
+
+/// Enumerate every package in a monorepo by walking the filesystem
+/// downward, rather than relying on a declared workspace manifest — finds
+/// packages a `pnpm-workspace.yaml`/`package.json workspaces`/Cargo
+/// `[workspace]` wouldn't list, at the cost of also surfacing nested
+/// packages that may just share a parent's lockfile (reflected in their
+/// lower confidence).
+#[tauri::command]
+pub async fn enumerate_monorepo_projects(
+    path: String,
+) -> Result<Vec<crate::project::DetectedProject>, String> {
+    let path = PathBuf::from(&path);
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
     }
-    
-    let (cmd, args) = parts.split_first().unwrap();
-    
-    // Spawn process (don't wait for it)
-    let child = Command::new(cmd)
+    Ok(crate::project::enumerate_projects(&path))
+}
+
+/// Install every sub-project of a monorepo concurrently (workspace members
+/// from `pnpm-workspace.yaml`, `package.json` `workspaces`, or Cargo
+/// `[workspace].members`), rather than only the top-level path. Failures
+/// are collected per sub-project instead of aborting the whole batch.
+#[tauri::command]
+pub async fn install_monorepo(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<Vec<SubProjectInstallResult>, String> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::Semaphore;
+
+    let path = PathBuf::from(&path);
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+
+    let members = enumerate_monorepo_members(&path);
+    if members.is_empty() {
+        return Err(
+            "No workspace members found (expected pnpm-workspace.yaml, package.json \
+             `workspaces`, or a Cargo workspace)"
+                .to_string(),
+        );
+    }
+
+    let total = members.len();
+    let semaphore = Arc::new(Semaphore::new(MONOREPO_INSTALL_CONCURRENCY));
+    let completed = Arc::new(AtomicUsize::new(0));
+
+    let mut handles = Vec::with_capacity(total);
+    for member in members {
+        let semaphore = semaphore.clone();
+        let completed = completed.clone();
+        let app = app.clone();
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let outcome = run_install_command(&member.path).await;
+            let result = SubProjectInstallResult {
+                name: member.name,
+                path: member.path.to_string_lossy().to_string(),
+                success: outcome.is_ok(),
+                error: outcome.err(),
+            };
+
+            let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
+            let _ = app.emit(
+                "monorepo-install-progress",
+                &MonorepoInstallProgress {
+                    completed: done,
+                    total,
+                    result: result.clone(),
+                },
+            );
+
+            result
+        }));
+    }
+
+    let mut results = Vec::with_capacity(total);
+    for handle in handles {
+        results.push(
+            handle
+                .await
+                .map_err(|e| format!("Install task panicked: {}", e))?,
+        );
+    }
+
+    Ok(results)
+}
+
+/// Number of trailing output lines retained per run session so a reopened
+/// log panel can backfill instead of only seeing output emitted after it.
+const RUN_LOG_BUFFER_LINES: usize = 2000;
+
+/// A single line of captured stdout/stderr from a run session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunSessionLog {
+    pub session_id: String,
+    pub line: String,
+    pub is_stderr: bool,
+}
+
+/// Emitted once, the first time a run session's dev server port is detected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunSessionReady {
+    session_id: String,
+    port: u16,
+    expected_url: String,
+}
+
+/// Regexes matching common "listening on <port>" output from dev servers.
+/// Cached at first use, mirroring the `OnceLock`-cached pattern used for
+/// capability authority resolution (see `capability.rs`).
+fn port_detection_regexes() -> &'static [regex::Regex] {
+    static REGEXES: std::sync::OnceLock<Vec<regex::Regex>> = std::sync::OnceLock::new();
+    REGEXES.get_or_init(|| {
+        [
+            r"https?://localhost:(\d+)",
+            r"https?://127\.0\.0\.1:(\d+)",
+            r"Local:\s+https?://[^:]+:(\d+)",
+            r"\bport\s+(\d+)",
+            r"running on port (\d+)",
+        ]
+        .iter()
+        .map(|pattern| regex::Regex::new(pattern).expect("static port detection regex is valid"))
+        .collect()
+    })
+}
+
+/// Scan a single line of run-process output for a dev server port.
+fn detect_port_in_line(line: &str) -> Option<u16> {
+    port_detection_regexes().iter().find_map(|re| {
+        re.captures(line)
+            .and_then(|caps| caps.get(1))
+            .and_then(|m| m.as_str().parse().ok())
+    })
+}
+
+/// Push a line into a session's ring buffer, trimming it to
+/// `RUN_LOG_BUFFER_LINES`, and return the log entry for the caller to emit.
+fn push_run_session_log(
+    logs: &Arc<Mutex<HashMap<String, VecDeque<RunSessionLog>>>>,
+    session_id: &str,
+    line: String,
+    is_stderr: bool,
+) -> RunSessionLog {
+    let entry = RunSessionLog {
+        session_id: session_id.to_string(),
+        line,
+        is_stderr,
+    };
+    if let Ok(mut logs) = logs.lock() {
+        let buffer = logs.entry(session_id.to_string()).or_default();
+        buffer.push_back(entry.clone());
+        while buffer.len() > RUN_LOG_BUFFER_LINES {
+            buffer.pop_front();
+        }
+    }
+    entry
+}
+
+/// Lifecycle state of a tracked run session.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "state")]
+pub enum RunSessionState {
+    Running,
+    Exited { code: Option<i32> },
+    Killed,
+}
+
+/// A tracked run session's live process record, kept in `AppState` so
+/// `stop_project_run`/`restart_project_run`/status queries can look up what's
+/// actually running instead of reconstructing a PID from the session id
+/// string.
+struct RunningProcess {
+    project_path: String,
+    command: String,
+    pid: u32,
+    started_at: u64,
+    status: Arc<Mutex<RunSessionState>>,
+}
+
+/// Public snapshot of a tracked run session's status, for `list_run_sessions`
+/// and `get_run_session_status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunSessionStatusInfo {
+    pub session_id: String,
+    pub project_path: String,
+    pub command: String,
+    pub pid: u32,
+    pub started_at: u64,
+    pub status: RunSessionState,
+}
+
+/// Emitted once a tracked run session's process has exited, whether on its
+/// own or via `stop_project_run`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RunSessionExited {
+    session_id: String,
+    status: RunSessionState,
+}
+
+/// Spawn the run process, streaming its stdout/stderr to the frontend,
+/// auto-detecting the dev server port from its output, and registering it
+/// in `run_sessions` so its lifecycle can be tracked and controlled.
+async fn spawn_run_process(
+    path: &PathBuf,
+    command: &str,
+    app: tauri::AppHandle,
+    run_session_logs: Arc<Mutex<HashMap<String, VecDeque<RunSessionLog>>>>,
+    run_sessions: Arc<Mutex<HashMap<String, RunningProcess>>>,
+) -> Result<RunSession, String> {
+    use std::process::Stdio;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::process::Command;
+
+    let parts = crate::run_analysis::tokenize_command(command)?;
+    let (cmd, args) = parts
+        .split_first()
+        .ok_or_else(|| "Empty command".to_string())?;
+
+    let mut child = Command::new(cmd)
         .args(args)
         .current_dir(path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| format!("Failed to start process: {}", e))?;
-    
-    let pid = child.id();
+
+    let pid = child.id().unwrap_or(0);
     let session_id = format!("run-{}", pid);
-    
+
     let started_at = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
-    
+
+    let port_resolved = Arc::new(Mutex::new(false));
+
+    for (reader, is_stderr) in [
+        (child.stdout.take().map(BufReader::new), false),
+        (child.stderr.take().map(BufReader::new), true),
+    ] {
+        let Some(reader) = reader else { continue };
+        let app = app.clone();
+        let run_session_logs = run_session_logs.clone();
+        let session_id = session_id.clone();
+        let port_resolved = port_resolved.clone();
+
+        tokio::spawn(async move {
+            let mut lines = reader.lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let port = detect_port_in_line(&line);
+                let entry = push_run_session_log(&run_session_logs, &session_id, line, is_stderr);
+                let _ = app.emit("run-session-log", &entry);
+
+                if let Some(port) = port {
+                    let mut resolved = match port_resolved.lock() {
+                        Ok(guard) => guard,
+                        Err(_) => continue,
+                    };
+                    if !*resolved {
+                        *resolved = true;
+                        let expected_url = format!("http://localhost:{}", port);
+                        let _ = app.emit(
+                            "run-session-ready",
+                            &RunSessionReady {
+                                session_id: session_id.clone(),
+                                port,
+                                expected_url,
+                            },
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    let status = Arc::new(Mutex::new(RunSessionState::Running));
+    if let Ok(mut sessions) = run_sessions.lock() {
+        sessions.insert(
+            session_id.clone(),
+            RunningProcess {
+                project_path: path.to_string_lossy().to_string(),
+                command: command.to_string(),
+                pid,
+                started_at,
+                status: status.clone(),
+            },
+        );
+    }
+
+    // Reap the child and record its final status as soon as it exits,
+    // whether on its own or via `stop_project_run`'s signal.
+    tokio::spawn({
+        let app = app.clone();
+        let session_id = session_id.clone();
+        let run_sessions = run_sessions.clone();
+        async move {
+            let exit = child.wait().await;
+            let exited_status = RunSessionState::Exited {
+                code: exit.ok().and_then(|s| s.code()),
+            };
+
+            if let Ok(mut current) = status.lock() {
+                // A deliberate `stop_project_run` already recorded `Killed`;
+                // don't let the natural exit status overwrite that.
+                if *current != RunSessionState::Killed {
+                    *current = exited_status.clone();
+                }
+                let _ = app.emit(
+                    "run-session-exited",
+                    &RunSessionExited {
+                        session_id: session_id.clone(),
+                        status: current.clone(),
+                    },
+                );
+            }
+
+            if let Ok(mut sessions) = run_sessions.lock() {
+                sessions.remove(&session_id);
+            }
+        }
+    });
+
     Ok(RunSession {
         id: session_id,
         project_path: path.to_string_lossy().to_string(),
         command: command.to_string(),
         pid,
-        port: None, // Would need to detect this from output
+        port: None,
+        expected_url: None,
         started_at,
     })
 }
@@ -1384,9 +2405,9 @@ fn save_run_command_internal(path: &PathBuf, command: &str) -> Result<(), String
     let sunwell_dir = path.join(".sunwell");
     std::fs::create_dir_all(&sunwell_dir)
         .map_err(|e| format!("Failed to create .sunwell directory: {}", e))?;
-    
+
     let run_json_path = sunwell_dir.join("run.json");
-    
+
     // Create a minimal analysis to save
     let analysis = RunAnalysis {
         project_type: "User-configured".to_string(),
@@ -1397,6 +2418,7 @@ fn save_run_command_internal(path: &PathBuf, command: &str) -> Result<(), String
         working_dir: None,
         alternatives: vec![],
         prerequisites: vec![],
+        env: vec![],
         expected_port: None,
         expected_url: None,
         confidence: crate::run_analysis::Confidence::High,
@@ -1404,66 +2426,204 @@ fn save_run_command_internal(path: &PathBuf, command: &str) -> Result<(), String
         from_cache: false,
         user_saved: true,
     };
-    
+
     let json = serde_json::to_string_pretty(&analysis)
         .map_err(|e| format!("Failed to serialize: {}", e))?;
-    
-    std::fs::write(&run_json_path, json)
-        .map_err(|e| format!("Failed to save run command: {}", e))
+
+    std::fs::write(&run_json_path, json).map_err(|e| format!("Failed to save run command: {}", e))
 }
 
-/// Stop a running project.
-#[tauri::command]
-pub async fn stop_project_run(
-    app: tauri::AppHandle,
-    session_id: String,
-) -> Result<(), String> {
-    // Extract PID from session ID
-    let pid_str = session_id.strip_prefix("run-")
-        .ok_or("Invalid session ID")?;
-    let pid: u32 = pid_str.parse()
-        .map_err(|_| "Invalid session ID")?;
-    
-    // Kill the process
+/// How long to wait after SIGTERM before escalating to SIGKILL.
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Send a termination signal to a process by PID: `SIGTERM`/`SIGKILL` on
+/// Unix, `taskkill /F` on Windows (Windows has no graceful-vs-forceful
+/// distinction for an arbitrary external process, so both signals force it).
+async fn send_kill_signal(pid: u32, signal: &str) -> Result<(), String> {
+    use tokio::process::Command;
+
     #[cfg(unix)]
     {
-        use std::process::Command;
         Command::new("kill")
-            .args(["-TERM", &pid.to_string()])
+            .args([format!("-{}", signal), pid.to_string()])
             .output()
-            .map_err(|e| format!("Failed to stop process: {}", e))?;
+            .await
+            .map_err(|e| format!("Failed to send SIG{} to process {}: {}", signal, pid, e))?;
     }
-    
+
     #[cfg(windows)]
     {
-        use std::process::Command;
+        let _ = signal;
         Command::new("taskkill")
             .args(["/PID", &pid.to_string(), "/F"])
             .output()
-            .map_err(|e| format!("Failed to stop process: {}", e))?;
+            .await
+            .map_err(|e| format!("Failed to stop process {}: {}", pid, e))?;
     }
-    
-    // Emit event to frontend
+
+    Ok(())
+}
+
+/// Look up a tracked session's PID, mark it `Killed`, send SIGTERM, and
+/// schedule a SIGKILL if it hasn't exited within `STOP_GRACE_PERIOD`. Shared
+/// by `stop_project_run` and `restart_project_run`.
+async fn stop_run_session_internal(
+    app: tauri::AppHandle,
+    state: &AppState,
+    session_id: &str,
+) -> Result<(), String> {
+    let pid = {
+        let sessions = state.run_sessions.lock().map_err(|e| e.to_string())?;
+        let process = sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Unknown or already-exited run session: {}", session_id))?;
+        *process.status.lock().map_err(|e| e.to_string())? = RunSessionState::Killed;
+        process.pid
+    };
+
+    send_kill_signal(pid, "TERM").await?;
+
+    let run_sessions = state.run_sessions.clone();
+    let session_id = session_id.to_string();
+    tokio::spawn(async move {
+        tokio::time::sleep(STOP_GRACE_PERIOD).await;
+        let still_tracked = run_sessions
+            .lock()
+            .map(|s| s.contains_key(&session_id))
+            .unwrap_or(false);
+        if still_tracked {
+            let _ = send_kill_signal(pid, "KILL").await;
+        }
+    });
+
     let _ = app.emit("run-session-stopped", &session_id);
-    
     Ok(())
 }
 
-/// Save user's preferred command for a project.
+/// Stop a running project: SIGTERM first, escalating to SIGKILL if it's
+/// still running after a grace period.
 #[tauri::command]
-pub async fn save_run_command(
-    path: String,
-    command: String,
+pub async fn stop_project_run(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
 ) -> Result<(), String> {
+    stop_run_session_internal(app, &state, &session_id).await
+}
+
+/// List every currently-tracked run session and its last-known status.
+#[tauri::command]
+pub async fn list_run_sessions(
+    state: State<'_, AppState>,
+) -> Result<Vec<RunSessionStatusInfo>, String> {
+    let sessions = state.run_sessions.lock().map_err(|e| e.to_string())?;
+    sessions
+        .iter()
+        .map(|(session_id, process)| {
+            Ok(RunSessionStatusInfo {
+                session_id: session_id.clone(),
+                project_path: process.project_path.clone(),
+                command: process.command.clone(),
+                pid: process.pid,
+                started_at: process.started_at,
+                status: process.status.lock().map_err(|e| e.to_string())?.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Look up a single tracked run session's current status.
+#[tauri::command]
+pub async fn get_run_session_status(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<RunSessionStatusInfo, String> {
+    let sessions = state.run_sessions.lock().map_err(|e| e.to_string())?;
+    let process = sessions
+        .get(&session_id)
+        .ok_or_else(|| format!("Unknown or already-exited run session: {}", session_id))?;
+    Ok(RunSessionStatusInfo {
+        session_id,
+        project_path: process.project_path.clone(),
+        command: process.command.clone(),
+        pid: process.pid,
+        started_at: process.started_at,
+        status: process.status.lock().map_err(|e| e.to_string())?.clone(),
+    })
+}
+
+/// Stop a tracked run session and start it again with the same project path
+/// and command.
+#[tauri::command]
+pub async fn restart_project_run(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+) -> Result<RunSession, String> {
+    let (project_path, command) = {
+        let sessions = state.run_sessions.lock().map_err(|e| e.to_string())?;
+        let process = sessions
+            .get(&session_id)
+            .ok_or_else(|| format!("Unknown or already-exited run session: {}", session_id))?;
+        (process.project_path.clone(), process.command.clone())
+    };
+
+    stop_run_session_internal(app.clone(), &state, &session_id).await?;
+
+    // Give the old process a moment to actually exit and deregister before
+    // we bind the same port again.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let path = PathBuf::from(&project_path);
+    let session = spawn_run_process(
+        &path,
+        &command,
+        app.clone(),
+        state.run_session_logs.clone(),
+        state.run_sessions.clone(),
+    )
+    .await?;
+
+    let _ = app.emit("run-session-started", &session);
+    Ok(session)
+}
+
+/// Save user's preferred command for a project.
+#[tauri::command]
+pub async fn save_run_command(path: String, command: String) -> Result<(), String> {
     let path = PathBuf::from(&path);
-    
-    // Validate first
-    validate_command_safety(&command)
+
+    // Validate first, expanding any user-defined alias.
+    let safety_config = RunSafetyConfig::load();
+    let command = validate_command_safety_with_config(&command, &safety_config)
         .map_err(|e| format!("Command validation failed: {}", e))?;
-    
+
     save_run_command_internal(&path, &command)
 }
 
+/// Trust an extra binary beyond the built-in allowlist for run commands.
+#[tauri::command]
+pub async fn add_safe_run_command(binary: String) -> Result<(), String> {
+    let mut config = RunSafetyConfig::load();
+    config.add_safe_command(binary)?;
+    config.save().map_err(|e| e.to_string())
+}
+
+/// Define a named run-command alias (e.g. `dev` -> `npm run dev`).
+#[tauri::command]
+pub async fn set_run_command_alias(name: String, command: String) -> Result<(), String> {
+    let mut config = RunSafetyConfig::load();
+    config.set_alias(name, command)?;
+    config.save().map_err(|e| e.to_string())
+}
+
+/// Get the current user-configured run-safety config (extra allowlisted
+/// binaries and aliases).
+#[tauri::command]
+pub async fn get_run_safety_config() -> Result<RunSafetyConfig, String> {
+    Ok(RunSafetyConfig::load())
+}
+
 // =============================================================================
 // RFC-079: Project Intent Analysis
 // =============================================================================
@@ -1573,105 +2733,363 @@ pub struct MonorepoAnalysis {
     pub sub_projects: Vec<SubProject>,
 }
 
+/// Progress frame forwarded to the frontend while `analyze_project` streams
+/// from the CLI, mirroring the `completion_percent`/`current_step` fields of
+/// the final `ProjectAnalysis`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectAnalysisProgress {
+    path: String,
+    completion_percent: f64,
+    current_step: String,
+}
+
+/// Named failure classes for `analyze_project`/`analyze_monorepo`/
+/// `get_project_signals`, so the frontend can branch on what actually went
+/// wrong (offer an "install sunwell CLI" prompt for `CliNotFound`, a "retry
+/// fresh" affordance for `ParseFailed`, etc.) instead of string-matching a
+/// flat message.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AnalysisError {
+    /// The project path doesn't exist.
+    PathNotFound { path: String },
+    /// The `sunwell` CLI (or its Python fallback) couldn't be spawned at all.
+    CliNotFound { detail: String },
+    /// The CLI ran but exited non-zero.
+    NonZeroExit { code: Option<i32>, stderr: String },
+    /// The CLI's output didn't parse as the expected JSON shape.
+    ParseFailed { snippet: String, source: String },
+    /// The call didn't finish within its time budget.
+    Timeout,
+    /// The caller cancelled the in-flight analysis via `cancel_analysis`.
+    Cancelled,
+}
+
+impl std::fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalysisError::PathNotFound { path } => write!(f, "Path does not exist: {}", path),
+            AnalysisError::CliNotFound { detail } => write!(f, "Failed to run sunwell: {}", detail),
+            AnalysisError::NonZeroExit { code, stderr } => {
+                write!(f, "Analysis failed (exit {:?}): {}", code, stderr)
+            }
+            AnalysisError::ParseFailed { snippet, source } => {
+                write!(
+                    f,
+                    "Failed to parse analysis result: {} ({})",
+                    source, snippet
+                )
+            }
+            AnalysisError::Timeout => write!(f, "Analysis timed out"),
+            AnalysisError::Cancelled => write!(f, "Analysis was cancelled"),
+        }
+    }
+}
+
+impl AnalysisError {
+    /// Truncate a long JSON payload so `ParseFailed`'s snippet stays a
+    /// reasonable size for display.
+    fn snippet(text: &str) -> String {
+        const MAX_LEN: usize = 200;
+        if text.len() > MAX_LEN {
+            format!("{}…", &text[..MAX_LEN])
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Default upper bound on a single `analyze_project` subprocess call, used
+/// when the caller doesn't supply `timeout_ms`.
+const DEFAULT_ANALYSIS_TIMEOUT_MS: u64 = 120_000;
+
 /// RFC-079: Analyze a project to understand its intent and state.
-/// 
+///
 /// Calls `sunwell project analyze --json` to get universal project understanding.
 /// Includes automatic retry with sanitization if JSON parsing fails.
 #[tauri::command]
 pub async fn analyze_project(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
     path: String,
     fresh: Option<bool>,
-) -> Result<ProjectAnalysis, String> {
+    timeout_ms: Option<u64>,
+) -> Result<ProjectAnalysis, AnalysisError> {
     let project_path = PathBuf::from(&path);
-    
+
     if !project_path.exists() {
-        return Err(format!("Path does not exist: {}", path));
+        return Err(AnalysisError::PathNotFound { path });
+    }
+
+    // Fast path: route through the persistent analysis daemon if it's
+    // reachable, skipping the CLI's cold start entirely.
+    if let Some(value) = state
+        .analysis_daemon
+        .request(
+            "project/analyze",
+            serde_json::json!({ "path": path, "fresh": fresh }),
+        )
+        .await
+    {
+        if let Ok(analysis) = serde_json::from_value::<ProjectAnalysis>(value) {
+            return Ok(analysis);
+        }
+        // Malformed daemon response — fall through to the one-shot path.
     }
-    
-    // Try up to 2 times: first with cached, then fresh if parse fails
+
+    let timeout_duration = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_ANALYSIS_TIMEOUT_MS));
+
+    // Try up to 2 times: first with cached, then fresh if the first attempt
+    // fails for any reason other than cancellation
     let max_attempts = if fresh.unwrap_or(false) { 1 } else { 2 };
-    let mut last_error = String::new();
-    
+    let mut last_error = AnalysisError::Timeout;
+
     for attempt in 0..max_attempts {
         let mut args = vec!["project", "analyze", "--json"];
         // Use fresh on retry (attempt > 0) or if explicitly requested
         if fresh.unwrap_or(false) || attempt > 0 {
             args.push("--fresh");
         }
-        
-        let output = sunwell_command()
-            .args(&args)
-            .current_dir(&project_path)
-            .output()
-            .map_err(|e| format!("Failed to run sunwell project analyze: {}", e))?;
-        
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            last_error = format!("Project analysis failed: {}", stderr);
+
+        let cancel = Arc::new(tokio::sync::Notify::new());
+        if let Ok(mut cancellations) = state.analysis_cancellations.lock() {
+            cancellations.insert(path.clone(), cancel.clone());
+        }
+
+        let outcome = tokio::select! {
+            result = tokio::time::timeout(timeout_duration, stream_project_analysis(&app, &project_path, &path, &args)) => {
+                result.unwrap_or(Err(AnalysisError::Timeout))
+            }
+            _ = cancel.notified() => Err(AnalysisError::Cancelled),
+        };
+
+        if let Ok(mut cancellations) = state.analysis_cancellations.lock() {
+            cancellations.remove(&path);
+        }
+
+        match outcome {
+            Ok(analysis) => return Ok(analysis),
+            Err(AnalysisError::Cancelled) => return Err(AnalysisError::Cancelled),
+            Err(e) => last_error = e,
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Abort an in-flight `analyze_project` call for `path`, if one is running —
+/// e.g. when the user navigates away before a large monorepo scan finishes.
+/// Returns `true` if a running analysis was found and cancelled.
+#[tauri::command]
+pub async fn cancel_analysis(state: State<'_, AppState>, path: String) -> Result<bool, String> {
+    let Ok(cancellations) = state.analysis_cancellations.lock() else {
+        return Ok(false);
+    };
+
+    if let Some(notify) = cancellations.get(&path) {
+        notify.notify_waiters();
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+/// Spawn `sunwell project analyze` with piped stdout and forward any
+/// progress frame (`{"phase": "signals", "percent": 40}`) as a
+/// `project-analysis-progress` event, resolving once the terminal
+/// `ProjectAnalysis` frame is read. Modeled on an LSP-style line-buffered
+/// stdout consumer: read a line, trim it, try to parse it, dispatch on what
+/// it turned out to be.
+async fn stream_project_analysis(
+    app: &tauri::AppHandle,
+    project_path: &Path,
+    display_path: &str,
+    args: &[&str],
+) -> Result<ProjectAnalysis, AnalysisError> {
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    let mut child = crate::util::sunwell_command_async()
+        .args(args)
+        .current_dir(project_path)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        // So a timed-out or cancelled call (which drops this future, and
+        // with it `child`) actually kills the subprocess instead of leaking it.
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| AnalysisError::CliNotFound {
+            detail: e.to_string(),
+        })?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| AnalysisError::CliNotFound {
+            detail: "failed to capture analyzer stdout".to_string(),
+        })?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| AnalysisError::CliNotFound {
+            detail: "failed to capture analyzer stderr".to_string(),
+        })?;
+
+    let stderr_task = tokio::spawn(async move {
+        let mut lines = BufReader::new(stderr).lines();
+        let mut collected = String::new();
+        while let Ok(Some(line)) = lines.next_line().await {
+            collected.push_str(&line);
+            collected.push('\n');
+        }
+        collected
+    });
+
+    let mut lines = BufReader::new(stdout).lines();
+    let mut final_analysis: Option<ProjectAnalysis> = None;
+    // (unparsed line, parse error) of the most recent non-`ProjectAnalysis` frame.
+    let mut last_parse_error: Option<(String, String)> = None;
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        let line = line.trim();
+        if line.is_empty() {
             continue;
         }
-        
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        
+
         // RFC-091: Use parse_json_safe for lazy sanitization
-        match parse_json_safe::<ProjectAnalysis>(&stdout) {
-            Ok(analysis) => return Ok(analysis),
-            Err(e) => {
-                last_error = format!("Failed to parse analysis result: {}", e);
-                // Continue to retry with --fresh
+        match parse_json_safe::<ProjectAnalysis>(line) {
+            Ok(analysis) => {
+                final_analysis = Some(analysis);
+                continue;
+            }
+            Err(e) => last_parse_error = Some((line.to_string(), e.to_string())),
+        }
+
+        if let Ok(frame) = parse_json_safe::<serde_json::Value>(line) {
+            if let Some(percent) = frame.get("percent").and_then(|p| p.as_f64()) {
+                let current_step = frame
+                    .get("phase")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or("working")
+                    .to_string();
+                let _ = app.emit(
+                    "project-analysis-progress",
+                    ProjectAnalysisProgress {
+                        path: display_path.to_string(),
+                        completion_percent: percent,
+                        current_step,
+                    },
+                );
             }
         }
     }
-    
-    Err(last_error)
+
+    let status = child.wait().await.map_err(|e| AnalysisError::CliNotFound {
+        detail: e.to_string(),
+    })?;
+    let stderr_text = stderr_task.await.unwrap_or_default();
+
+    if !status.success() {
+        return Err(AnalysisError::NonZeroExit {
+            code: status.code(),
+            stderr: stderr_text,
+        });
+    }
+
+    final_analysis.ok_or_else(|| {
+        let (snippet, source) = last_parse_error
+            .unwrap_or_else(|| (String::new(), "no terminal frame received".to_string()));
+        AnalysisError::ParseFailed {
+            snippet: AnalysisError::snippet(&snippet),
+            source,
+        }
+    })
 }
 
 /// RFC-079: Check if a path is a monorepo and get sub-projects.
 #[tauri::command]
-pub async fn analyze_monorepo(path: String) -> Result<MonorepoAnalysis, String> {
+pub async fn analyze_monorepo(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<MonorepoAnalysis, AnalysisError> {
     let project_path = PathBuf::from(&path);
-    
+
     if !project_path.exists() {
-        return Err(format!("Path does not exist: {}", path));
+        return Err(AnalysisError::PathNotFound { path });
+    }
+
+    if let Some(value) = state
+        .analysis_daemon
+        .request("project/monorepo", serde_json::json!({ "path": path }))
+        .await
+    {
+        if let Ok(analysis) = serde_json::from_value::<MonorepoAnalysis>(value) {
+            return Ok(analysis);
+        }
     }
-    
+
     let output = sunwell_command()
         .args(["project", "monorepo", "--json"])
         .current_dir(&project_path)
         .output()
-        .map_err(|e| format!("Failed to run sunwell project monorepo: {}", e))?;
-    
+        .map_err(|e| AnalysisError::CliNotFound {
+            detail: e.to_string(),
+        })?;
+
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Monorepo analysis failed: {}", stderr));
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(AnalysisError::NonZeroExit {
+            code: output.status.code(),
+            stderr,
+        });
     }
-    
+
     let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&stdout)
-        .map_err(|e| format!("Failed to parse monorepo result: {}", e))
+    parse_json_safe(&stdout).map_err(|e| AnalysisError::ParseFailed {
+        snippet: AnalysisError::snippet(&stdout),
+        source: e.to_string(),
+    })
 }
 
 /// RFC-079: Get raw project signals (for debugging).
 #[tauri::command]
-pub async fn get_project_signals(path: String) -> Result<serde_json::Value, String> {
+pub async fn get_project_signals(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<serde_json::Value, AnalysisError> {
     let project_path = PathBuf::from(&path);
-    
+
     if !project_path.exists() {
-        return Err(format!("Path does not exist: {}", path));
+        return Err(AnalysisError::PathNotFound { path });
+    }
+
+    if let Some(value) = state
+        .analysis_daemon
+        .request("project/signals", serde_json::json!({ "path": path }))
+        .await
+    {
+        return Ok(value);
     }
-    
+
     let output = sunwell_command()
         .args(["project", "signals", "--json"])
         .current_dir(&project_path)
         .output()
-        .map_err(|e| format!("Failed to run sunwell project signals: {}", e))?;
-    
+        .map_err(|e| AnalysisError::CliNotFound {
+            detail: e.to_string(),
+        })?;
+
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Signals analysis failed: {}", stderr));
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(AnalysisError::NonZeroExit {
+            code: output.status.code(),
+            stderr,
+        });
     }
-    
+
     let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&stdout)
-        .map_err(|e| format!("Failed to parse signals result: {}", e))
+    parse_json_safe(&stdout).map_err(|e| AnalysisError::ParseFailed {
+        snippet: AnalysisError::snippet(&stdout),
+        source: e.to_string(),
+    })
 }
@@ -3,8 +3,404 @@
 //! This module provides Tauri commands for the ATC (Air Traffic Control) view
 //! in Studio, enabling visualization and control of parallel agent execution.
 
+use crate::error::{ErrorCode, SunwellError};
+use crate::sunwell_err;
+use crate::util::sunwell_command;
 use serde::{Deserialize, Serialize};
-use std::process::Command;
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, ChildStdin, Command as TokioCommand};
+use tokio::sync::Notify;
+
+/// Oldest/newest `workers` protocol major version this build of Studio
+/// understands. Bump when a breaking change lands on either side of the
+/// CLI/Studio bridge (RFC-100).
+const SUPPORTED_PROTOCOL_MAJOR: std::ops::RangeInclusive<u32> = 1..=1;
+
+/// Parsed `sunwell --protocol-version` handshake payload.
+#[derive(Debug, Clone, Deserialize)]
+struct ProtocolInfo {
+    cli_version: String,
+    protocol_version: (u32, u32),
+    #[serde(default)]
+    capabilities: Vec<String>,
+}
+
+/// Negotiated protocol info per project path, cached so repeated calls
+/// (one per ATC poll) don't re-spawn the CLI just to re-read its version.
+/// Keyed by project path since each project may resolve a different
+/// `sunwell` install (e.g. a project-local virtualenv).
+static NEGOTIATED: OnceLock<Mutex<HashMap<String, Result<ProtocolInfo, String>>>> = OnceLock::new();
+
+fn negotiated_cache() -> &'static Mutex<HashMap<String, Result<ProtocolInfo, String>>> {
+    NEGOTIATED.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Run the version/protocol handshake against the `sunwell` CLI resolved
+/// for `project_path`, parse its `{cli_version, protocol_version, capabilities}`
+/// payload, and check `protocol_version`'s major component against
+/// `SUPPORTED_PROTOCOL_MAJOR`. The result is cached per project path so
+/// callers can invoke this before every coordinator command without
+/// re-spawning the CLI each time.
+fn negotiate_cli_version(project_path: &str) -> Result<ProtocolInfo, SunwellError> {
+    let mut cache = negotiated_cache().lock().unwrap();
+    let cached = cache
+        .entry(project_path.to_string())
+        .or_insert_with(|| probe_protocol(project_path).map_err(|e| e.to_json()));
+
+    match cached {
+        Ok(info) => {
+            let (major, minor) = info.protocol_version;
+            if !SUPPORTED_PROTOCOL_MAJOR.contains(&major) {
+                return Err(sunwell_err!(
+                    RuntimeProtocolMismatch,
+                    "sunwell CLI {} reports protocol v{}.{}; Studio supports protocol major {:?}",
+                    info.cli_version,
+                    major,
+                    minor,
+                    SUPPORTED_PROTOCOL_MAJOR
+                ));
+            }
+            Ok(info.clone())
+        }
+        Err(message) => Err(sunwell_err!(RuntimeProtocolMismatch, "sunwell CLI version check failed: {}", message)),
+    }
+}
+
+fn probe_protocol(project_path: &str) -> Result<ProtocolInfo, SunwellError> {
+    let output = sunwell_command()
+        .current_dir(project_path)
+        .args(["--protocol-version"])
+        .output()
+        .map_err(SunwellError::from)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(sunwell_err!(RuntimeProtocolMismatch, "Failed to query sunwell CLI protocol version: {}", stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout).map_err(SunwellError::from)
+}
+
+/// Require that `info.capabilities` lists `capability`, for commands that
+/// depend on CLI support the version check alone can't guarantee (e.g. an
+/// older CLI on a compatible major protocol version that simply hasn't
+/// shipped a given worker verb yet).
+fn require_capability(info: &ProtocolInfo, capability: &str) -> Result<(), SunwellError> {
+    if info.capabilities.iter().any(|c| c == capability) {
+        Ok(())
+    } else {
+        Err(sunwell_err!(
+            RuntimeCapabilityUnsupported,
+            "sunwell CLI {} does not report the '{}' capability",
+            info.cli_version,
+            capability
+        ))
+    }
+}
+
+// =============================================================================
+// Retry with backoff (run_with_retry)
+// =============================================================================
+//
+// `ErrorCode::is_recoverable()` and hints like "Wait before retrying" encode
+// retry intent, but until now nothing acted on it: every coordinator command
+// failed on the CLI's first non-zero exit. `run_with_retry` wraps a
+// subprocess-invocation closure, classifies a failure through
+// `parse_error_string`, and retries with exponential backoff as long as the
+// classified error is recoverable.
+
+/// Exponential-backoff retry policy for a coordinator subprocess call.
+/// Mirrors `agent::RetryPolicy`'s shape; kept separate because it drives a
+/// blocking invoke-and-classify loop around a single command rather than an
+/// auto-resuming session.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoordinatorRetryPolicy {
+    pub initial_interval_ms: u64,
+    pub backoff_coefficient: f64,
+    pub max_interval_ms: u64,
+    pub max_attempts: u32,
+    /// Floor applied to the computed delay when the failure is
+    /// `ModelRateLimited` or `NetworkTimeout` — these represent an external
+    /// provider/network backing off, not a transient local hiccup, so a
+    /// short exponential delay isn't worth retrying against.
+    pub rate_limit_cooldown_ms: u64,
+}
+
+impl Default for CoordinatorRetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_interval_ms: 500,
+            backoff_coefficient: 2.0,
+            max_interval_ms: 10_000,
+            max_attempts: 3,
+            rate_limit_cooldown_ms: 5_000,
+        }
+    }
+}
+
+impl CoordinatorRetryPolicy {
+    fn delay_for_attempt(&self, attempt: u32, code: u16) -> Duration {
+        let scaled = (self.initial_interval_ms as f64 / 1000.0) * self.backoff_coefficient.powi(attempt as i32);
+        let capped = Duration::from_secs_f64(scaled.min(self.max_interval_ms as f64 / 1000.0) * (1.0 + jitter_fraction()));
+
+        if code == ErrorCode::ModelRateLimited as u16 || code == ErrorCode::NetworkTimeout as u16 {
+            capped.max(Duration::from_millis(self.rate_limit_cooldown_ms))
+        } else {
+            capped
+        }
+    }
+}
+
+/// A pseudo-random fraction in `[0.0, 0.25)`, good enough to stagger retry
+/// delays without pulling in a `rand` dependency for one call site (see
+/// `agent::jitter_fraction`, which this mirrors).
+fn jitter_fraction() -> f64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+    (nanos % 1000) as f64 / 1000.0 * 0.25
+}
+
+/// Run `invoke` under `policy`, retrying with exponential backoff whenever
+/// the failure — classified via `crate::error::parse_error_string` — is
+/// recoverable. `invoke` should return the subprocess's stdout on success,
+/// or its stderr (or another diagnostic string) on failure.
+///
+/// On exhaustion, or on a non-recoverable failure, returns the classified
+/// `SunwellError` with `context` populated with the attempt count so
+/// callers can tell a single hard failure apart from an exhausted retry run.
+async fn run_with_retry(
+    policy: CoordinatorRetryPolicy,
+    mut invoke: impl FnMut() -> Result<String, String>,
+) -> Result<String, SunwellError> {
+    let max_attempts = policy.max_attempts.max(1);
+
+    for attempt in 0..max_attempts {
+        match invoke() {
+            Ok(output) => return Ok(output),
+            Err(message) => {
+                let mut err = crate::error::parse_error_string(&message);
+                let attempts_made = attempt + 1;
+
+                if !err.recoverable || attempts_made >= max_attempts {
+                    err.context = serde_json::json!({ "attempts": attempts_made });
+                    return Err(err);
+                }
+
+                let delay = policy.delay_for_attempt(attempt, err.code);
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    unreachable!("loop always returns on its last iteration")
+}
+
+// =============================================================================
+// Coordinator Stream (start_coordinator_stream / stop_coordinator_stream)
+// =============================================================================
+//
+// A long-lived `sunwell workers stream --project <path>` child replaces
+// polling `get_coordinator_state` on a timer: its stdout is a
+// line-delimited stream of `CoordinatorStreamEvent`s, each forwarded to
+// the frontend as a `coordinator://update` event as soon as it's parsed,
+// and its stdin accepts the same pause/resume/start commands those
+// one-shot Tauri commands issue as fresh subprocesses, so a running
+// stream can absorb them without spawning another `sunwell` process.
+
+/// One line of `sunwell workers stream`'s stdout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CoordinatorStreamEvent {
+    State(CoordinatorState),
+    Worker(WorkerStatus),
+    Conflict(FileConflict),
+    Error { message: String },
+}
+
+/// A command written to a running stream's stdin instead of spawning a
+/// fresh `sunwell workers <verb>` process.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum CoordinatorStreamCommand {
+    Pause { worker_id: u32 },
+    Resume { worker_id: u32 },
+    Start { num_workers: u32, dry_run: bool },
+}
+
+/// A running `sunwell workers stream` session for one project.
+struct CoordinatorStreamHandle {
+    child: Arc<tokio::sync::Mutex<Option<Child>>>,
+    stdin: Arc<tokio::sync::Mutex<Option<ChildStdin>>>,
+    cancel: Arc<Notify>,
+}
+
+/// Tracks at most one live coordinator stream per project path. Held in
+/// `AppState` behind an `Arc` (rather than the plain `Mutex<HashMap<..>>>`
+/// most managers in this codebase use) because the read loop started by
+/// `start` runs detached via `tokio::spawn` and needs to remove its own
+/// entry on natural process exit, independent of the command invocation
+/// that started it.
+#[derive(Clone, Default)]
+pub struct CoordinatorStreamManager {
+    handles: Arc<Mutex<HashMap<String, CoordinatorStreamHandle>>>,
+}
+
+impl CoordinatorStreamManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start streaming for `project_path`, or do nothing if already running.
+    pub async fn start(&self, project_path: String, app: AppHandle) -> Result<(), SunwellError> {
+        if self.handles.lock().unwrap().contains_key(&project_path) {
+            return Ok(());
+        }
+
+        let mut child = TokioCommand::new("sunwell")
+            .current_dir(&project_path)
+            .args(["workers", "stream", "--project", &project_path])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take().ok_or_else(|| {
+            SunwellError::new(ErrorCode::RuntimeStateInvalid, "Coordinator stream process has no stdout")
+        })?;
+
+        let cancel = Arc::new(Notify::new());
+        self.handles.lock().unwrap().insert(
+            project_path.clone(),
+            CoordinatorStreamHandle {
+                child: Arc::new(tokio::sync::Mutex::new(Some(child))),
+                stdin: Arc::new(tokio::sync::Mutex::new(stdin)),
+                cancel: cancel.clone(),
+            },
+        );
+
+        let handles = self.handles.clone();
+        tokio::spawn(async move {
+            run_stream_loop(project_path.clone(), stdout, cancel, app).await;
+            handles.lock().unwrap().remove(&project_path);
+        });
+
+        Ok(())
+    }
+
+    /// Stop the stream for `project_path`, if running: wakes the read loop
+    /// and kills the child.
+    pub async fn stop(&self, project_path: &str) {
+        let handle = self.handles.lock().unwrap().remove(project_path);
+        if let Some(handle) = handle {
+            handle.cancel.notify_one();
+            if let Some(mut child) = handle.child.lock().await.take() {
+                let _ = child.kill().await;
+            }
+        }
+    }
+
+    /// Write `command` to the running stream's stdin for `project_path`.
+    /// Returns `Ok(false)` (rather than an error) when no stream is
+    /// running, so callers can fall back to a one-shot subprocess call.
+    async fn send(&self, project_path: &str, command: &CoordinatorStreamCommand) -> Result<bool, SunwellError> {
+        let stdin_slot = match self.handles.lock().unwrap().get(project_path) {
+            Some(handle) => handle.stdin.clone(),
+            None => return Ok(false),
+        };
+
+        let mut guard = stdin_slot.lock().await;
+        let Some(stdin) = guard.as_mut() else { return Ok(false) };
+
+        let mut line = serde_json::to_string(command)
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to encode coordinator stream command: {}", e))?;
+        line.push('\n');
+        stdin.write_all(line.as_bytes()).await.map_err(SunwellError::from)?;
+        Ok(true)
+    }
+}
+
+/// Read `stdout` to completion, framing on `\n`, decoding each line as a
+/// `CoordinatorStreamEvent` and forwarding it to the frontend as
+/// `coordinator://update`. A line that fails to parse as a
+/// `CoordinatorStreamEvent` is run back through `parse_error_string` so
+/// malformed CLI output still reaches the frontend as a structured error
+/// instead of being silently dropped.
+async fn run_stream_loop(project_path: String, mut stdout: tokio::process::ChildStdout, cancel: Arc<Notify>, app: AppHandle) {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    loop {
+        tokio::select! {
+            read_result = stdout.read(&mut chunk) => {
+                let bytes_read = match read_result {
+                    Ok(0) => break,
+                    Ok(n) => n,
+                    Err(_) => break,
+                };
+                buffer.extend_from_slice(&chunk[..bytes_read]);
+
+                while let Some(newline_pos) = buffer.iter().position(|&b| b == b'\n') {
+                    let frame: Vec<u8> = buffer.drain(..=newline_pos).collect();
+                    let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+                    if !line.trim().is_empty() {
+                        emit_stream_line(&app, &line);
+                    }
+                }
+            }
+            _ = cancel.notified() => break,
+        }
+    }
+
+    let _ = app.emit(
+        "coordinator://update",
+        serde_json::json!({ "type": "stream_closed", "project_path": project_path }),
+    );
+}
+
+fn emit_stream_line(app: &AppHandle, line: &str) {
+    match serde_json::from_str::<CoordinatorStreamEvent>(line) {
+        Ok(event) => {
+            let _ = app.emit("coordinator://update", &event);
+        }
+        Err(_) => {
+            let err = crate::error::parse_error_string(line);
+            let _ = app.emit("coordinator://update", serde_json::json!({ "type": "error", "message": err.message }));
+        }
+    }
+}
+
+/// Start a persistent `sunwell workers stream` session for `project_path`,
+/// forwarding `CoordinatorState`/`WorkerStatus`/`FileConflict` deltas to the
+/// frontend as `coordinator://update` events until `stop_coordinator_stream`
+/// is called. A no-op if a stream for this project is already running.
+#[tauri::command]
+pub async fn start_coordinator_stream(
+    project_path: String,
+    app: AppHandle,
+    state: State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    let info = negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
+    require_capability(&info, "workers").map_err(|e| e.to_json())?;
+    state.coordinator_streams.start(project_path, app).await.map_err(|e| e.to_json())
+}
+
+/// Stop the coordinator stream for `project_path`, if one is running.
+#[tauri::command]
+pub async fn stop_coordinator_stream(
+    project_path: String,
+    state: State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.coordinator_streams.stop(&project_path).await;
+    Ok(())
+}
 
 /// Status of a single worker process.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,26 +457,50 @@ impl Default for CoordinatorState {
 
 /// Get the current coordinator state for a project.
 ///
-/// Calls `sunwell workers ui-state --project <path>` and parses the JSON output.
+/// Calls `sunwell workers ui-state --project <path>` and parses the JSON
+/// output, retrying recoverable failures (e.g. a transient provider
+/// hiccup surfaced through `workers ui-state`) under `retry_policy`
+/// (defaulted when omitted — see `CoordinatorRetryPolicy`).
 #[tauri::command]
-pub async fn get_coordinator_state(project_path: String) -> Result<CoordinatorState, String> {
-    let output = Command::new("sunwell")
-        .args(["workers", "ui-state", "--project", &project_path])
-        .output()
-        .map_err(|e| format!("Failed to run sunwell: {}", e))?;
+pub async fn get_coordinator_state(
+    project_path: String,
+    retry_policy: Option<CoordinatorRetryPolicy>,
+) -> Result<CoordinatorState, String> {
+    negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Command failed: {}", stderr));
-    }
+    let stdout = run_with_retry(retry_policy.unwrap_or_default(), || {
+        let output = Command::new("sunwell")
+            .args(["workers", "ui-state", "--project", &project_path])
+            .output()
+            .map_err(|e| e.to_string())?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    })
+    .await
+    .map_err(|e| e.to_json())?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
     serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse JSON: {}", e))
 }
 
-/// Pause a specific worker.
+/// Pause a specific worker: written to the project's running coordinator
+/// stream if one is active, otherwise run as a one-shot subprocess.
 #[tauri::command]
-pub async fn pause_worker(project_path: String, worker_id: u32) -> Result<(), String> {
+pub async fn pause_worker(
+    project_path: String,
+    worker_id: u32,
+    state: State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    let info = negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
+    require_capability(&info, "workers").map_err(|e| e.to_json())?;
+
+    let command = CoordinatorStreamCommand::Pause { worker_id };
+    if state.coordinator_streams.send(&project_path, &command).await.map_err(|e| e.to_json())? {
+        return Ok(());
+    }
+
     let output = Command::new("sunwell")
         .current_dir(&project_path)
         .args(["workers", "pause", &worker_id.to_string()])
@@ -95,9 +515,22 @@ pub async fn pause_worker(project_path: String, worker_id: u32) -> Result<(), St
     Ok(())
 }
 
-/// Resume a paused worker.
+/// Resume a paused worker: written to the project's running coordinator
+/// stream if one is active, otherwise run as a one-shot subprocess.
 #[tauri::command]
-pub async fn resume_worker(project_path: String, worker_id: u32) -> Result<(), String> {
+pub async fn resume_worker(
+    project_path: String,
+    worker_id: u32,
+    state: State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    let info = negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
+    require_capability(&info, "workers").map_err(|e| e.to_json())?;
+
+    let command = CoordinatorStreamCommand::Resume { worker_id };
+    if state.coordinator_streams.send(&project_path, &command).await.map_err(|e| e.to_json())? {
+        return Ok(());
+    }
+
     let output = Command::new("sunwell")
         .current_dir(&project_path)
         .args(["workers", "resume", &worker_id.to_string()])
@@ -112,29 +545,48 @@ pub async fn resume_worker(project_path: String, worker_id: u32) -> Result<(), S
     Ok(())
 }
 
-/// Start parallel execution with multiple workers.
+/// Start parallel execution with multiple workers: written to the
+/// project's running coordinator stream if one is active, otherwise run
+/// as a one-shot subprocess, retrying recoverable failures under
+/// `retry_policy` (defaulted when omitted) so a transient provider/network
+/// blip at the moment workers spin up doesn't abort the whole ATC session.
 #[tauri::command]
 pub async fn start_workers(
     project_path: String,
     num_workers: u32,
     dry_run: bool,
+    retry_policy: Option<CoordinatorRetryPolicy>,
+    state: State<'_, crate::commands::AppState>,
 ) -> Result<(), String> {
-    let num_workers_str = num_workers.to_string();
-    let mut args = vec!["workers", "start", "-n", &num_workers_str];
-    if dry_run {
-        args.push("--dry-run");
+    let info = negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
+    require_capability(&info, "workers").map_err(|e| e.to_json())?;
+
+    let command = CoordinatorStreamCommand::Start { num_workers, dry_run };
+    if state.coordinator_streams.send(&project_path, &command).await.map_err(|e| e.to_json())? {
+        return Ok(());
     }
 
-    let output = Command::new("sunwell")
-        .current_dir(&project_path)
-        .args(&args)
-        .output()
-        .map_err(|e| format!("Failed to run sunwell: {}", e))?;
+    let num_workers_str = num_workers.to_string();
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Command failed: {}", stderr));
-    }
+    run_with_retry(retry_policy.unwrap_or_default(), || {
+        let mut args = vec!["workers", "start", "-n", &num_workers_str];
+        if dry_run {
+            args.push("--dry-run");
+        }
+
+        let output = Command::new("sunwell")
+            .current_dir(&project_path)
+            .args(&args)
+            .output()
+            .map_err(|e| e.to_string())?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    })
+    .await
+    .map_err(|e| e.to_json())?;
 
     Ok(())
 }
@@ -142,6 +594,8 @@ pub async fn start_workers(
 /// Get the scan/state DAG for a project (RFC-100 Phase 0).
 #[tauri::command]
 pub async fn get_state_dag(project_path: String) -> Result<serde_json::Value, String> {
+    negotiate_cli_version(&project_path).map_err(|e| e.to_json())?;
+
     let output = Command::new("sunwell")
         .args(["scan", &project_path, "--json"])
         .output()
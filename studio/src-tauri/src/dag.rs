@@ -8,16 +8,17 @@
 //! - Load fast index for quick project switching (<10ms target)
 //! - Lazy load goal details on demand
 //! - Append goals to cumulative history
-//! - Execute a specific node from the DAG
+//! - Execute a specific node from the DAG, tracked as a resumable `DagJob`
 
 use crate::sunwell_err;
 use crate::util::{parse_json_safe, sunwell_command};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
+use tauri::Emitter;
 
 // =============================================================================
 // Public Types (match TypeScript DagGraph)
@@ -45,6 +46,17 @@ pub struct DagNode {
     // RFC-067: What this node produces (for edge labeling)
     #[serde(default)]
     pub produces: Vec<String>,
+
+    /// RFC-105 addendum: which parallel execution wave this node falls
+    /// into (see `compute_execution_waves`), so the frontend can render
+    /// "everything in wave N can run in parallel." `None` until annotated.
+    #[serde(default)]
+    pub wave: Option<u32>,
+
+    /// RFC-105 addendum: whether this node sits on the longest weighted
+    /// chain gating goal completion (see `compute_critical_path`).
+    #[serde(default)]
+    pub on_critical_path: bool,
 }
 
 fn default_task_type() -> String {
@@ -81,6 +93,10 @@ pub struct DagGraph {
     pub edges: Vec<DagEdge>,
     pub goal: Option<String>,
     pub total_progress: u8,
+    /// Dependency cycles found while assembling the graph, each a list of
+    /// node ids walking the loop back to its start. Empty when acyclic.
+    #[serde(default)]
+    pub cycles: Vec<Vec<String>>,
 }
 
 // =============================================================================
@@ -104,6 +120,39 @@ pub struct DagIndex {
     pub goals: Vec<GoalSummary>,
     /// Recent artifacts for quick reference
     pub recent_artifacts: Vec<ArtifactSummary>,
+    /// Per-plan-file fingerprint and derivation from the last build,
+    /// keyed by plan file path — lets `build_dag_index` skip re-parsing
+    /// files whose `(mtime, size)` haven't changed. A `BTreeMap` (not a
+    /// `HashMap`) so the index serializes deterministically.
+    #[serde(default)]
+    plan_cache: BTreeMap<String, PlanCacheEntry>,
+}
+
+/// A single plan file's fingerprint at last build time, used to decide
+/// whether the file needs re-parsing on the next incremental rebuild.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct PlanFingerprint {
+    mtime_secs: u64,
+    size: u64,
+}
+
+/// A plan file's derived contribution to the index, cached alongside its
+/// fingerprint so an unchanged file can be skipped on rebuild.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedPlanEntry {
+    goal_summary: GoalSummary,
+    artifact_ids: Vec<String>,
+}
+
+/// A plan-file cache slot: its fingerprint plus the derivation that
+/// fingerprint produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlanCacheEntry {
+    fingerprint: PlanFingerprint,
+    entry: CachedPlanEntry,
 }
 
 /// Summary statistics for the index
@@ -323,6 +372,10 @@ struct PlanTask {
     // RFC-067: Task type discrimination
     #[serde(default)]
     task_type: Option<String>, // "create", "wire", "verify", "refactor"
+    // RFC-105 addendum: input-hash fingerprint recorded when the task last
+    // completed, used to detect staleness against changed upstream inputs.
+    #[serde(default)]
+    content_hash: Option<String>,
 }
 
 /// Artifact from plans/<hash>.json (legacy format with graph.artifacts)
@@ -396,11 +449,360 @@ pub async fn get_project_dag(path: String) -> Result<DagGraph, String> {
     let execution = read_latest_execution(&plans_dir);
 
     // 3. Merge into DagGraph
-    let graph = merge_to_dag(backlog, execution);
+    let mut graph = merge_to_dag(backlog, execution);
+
+    // RFC-105 addendum: annotate each node with its parallel execution
+    // wave so the frontend can render "everything in wave N can run in
+    // parallel" without recomputing the schedule itself.
+    for (wave_index, wave) in compute_execution_waves(&graph).iter().enumerate() {
+        for id in wave {
+            if let Some(node) = graph.nodes.iter_mut().find(|n| &n.id == id) {
+                node.wave = Some(wave_index as u32);
+            }
+        }
+    }
+
+    // RFC-105 addendum: annotate the critical path so users can see which
+    // chain of tasks actually gates goal completion.
+    let (critical_path, _) = compute_critical_path(&graph);
+    let critical_set: HashSet<&str> = critical_path.iter().map(|id| id.as_str()).collect();
+    for node in graph.nodes.iter_mut() {
+        node.on_critical_path = critical_set.contains(node.id.as_str());
+    }
 
     Ok(graph)
 }
 
+// =============================================================================
+// RFC-105 addendum: Topological Scheduling
+// =============================================================================
+
+/// Result of topologically scheduling a `DagGraph` via `schedule_dag`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DagSchedule {
+    /// Execution waves in topological order — each wave's nodes have no
+    /// remaining incomplete dependency once every earlier wave finishes.
+    pub waves: Vec<Vec<String>>,
+    /// The next wave: node ids with no incomplete dependency right now.
+    pub runnable: Vec<String>,
+    /// Node ids that can never run because a dependency (directly or
+    /// transitively) failed.
+    pub blocked: Vec<String>,
+    /// Node ids left in the residual graph — on a circular dependency or
+    /// downstream of one, surfaced so callers can report it instead of
+    /// silently stalling.
+    pub cycle: Vec<String>,
+}
+
+/// Compute topologically ordered execution waves plus the currently
+/// runnable set over a `DagGraph`, via Kahn's algorithm.
+///
+/// Nodes already `complete` or `failed` are terminal and excluded from
+/// scheduling. Edges are taken from `graph.edges` (status is trusted
+/// from disk, per node `depends_on`/`status`, not recomputed here).
+/// A node whose dependency failed — directly or transitively through
+/// another blocked node — is reported in `blocked` rather than ever
+/// appearing in a wave. Any node that still has unresolved incoming
+/// edges once the queue runs dry sits on or behind a cycle and is
+/// reported in `cycle` instead of a wave.
+pub fn schedule_dag(graph: &DagGraph) -> DagSchedule {
+    let nodes: HashMap<&str, &DagNode> = graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+    let mut pending: HashMap<String, &DagNode> = nodes
+        .iter()
+        .filter(|(_, n)| n.status != "complete" && n.status != "failed")
+        .map(|(id, n)| (id.to_string(), *n))
+        .collect();
+
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    let mut in_degree: HashMap<String, usize> = pending.keys().map(|id| (id.clone(), 0)).collect();
+    for edge in &graph.edges {
+        if pending.contains_key(&edge.source) && pending.contains_key(&edge.target) {
+            adjacency.entry(edge.source.clone()).or_default().push(edge.target.clone());
+            *in_degree.entry(edge.target.clone()).or_insert(0) += 1;
+        }
+    }
+
+    // Dependencies outside `pending` are either already complete (so
+    // they're satisfied) or already failed (seed the blocked-propagation
+    // set below).
+    let mut unrunnable: HashSet<String> =
+        nodes.values().filter(|n| n.status == "failed").map(|n| n.id.clone()).collect();
+    let is_dep_satisfied = |dep: &str| nodes.get(dep).map_or(true, |n| n.status == "complete");
+
+    let mut waves: Vec<Vec<String>> = Vec::new();
+    let mut blocked: Vec<String> = Vec::new();
+
+    let mut queue: Vec<String> = pending
+        .values()
+        // Nodes with a failed dependency are queued too, so the loop marks
+        // them blocked and releases their successors to be blocked in turn.
+        .filter(|n| {
+            in_degree.get(&n.id).copied().unwrap_or(0) == 0
+                && n.depends_on.iter().all(|d| is_dep_satisfied(d) || unrunnable.contains(d))
+        })
+        .map(|n| n.id.clone())
+        .collect();
+    queue.sort();
+
+    while !queue.is_empty() {
+        let mut runnable_wave: Vec<String> = Vec::new();
+
+        for id in &queue {
+            pending.remove(id);
+            let is_blocked = nodes.get(id.as_str()).is_some_and(|n| n.depends_on.iter().any(|d| unrunnable.contains(d)));
+            if is_blocked {
+                unrunnable.insert(id.clone());
+                blocked.push(id.clone());
+            } else {
+                runnable_wave.push(id.clone());
+            }
+        }
+
+        let mut next: Vec<String> = Vec::new();
+        for id in &queue {
+            for succ in adjacency.get(id).into_iter().flatten() {
+                if let Some(count) = in_degree.get_mut(succ) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 && pending.contains_key(succ) {
+                        next.push(succ.clone());
+                    }
+                }
+            }
+        }
+
+        if !runnable_wave.is_empty() {
+            waves.push(runnable_wave);
+        }
+
+        next.sort();
+        next.dedup();
+        queue = next;
+    }
+
+    // Anything left with a positive in-degree never became schedulable —
+    // a circular dependency among the residual nodes.
+    let mut cycle: Vec<String> =
+        pending.keys().filter(|id| in_degree.get(*id).copied().unwrap_or(0) > 0).cloned().collect();
+    cycle.sort();
+
+    let cycle_set: HashSet<&String> = cycle.iter().collect();
+    for id in pending.keys() {
+        if !cycle_set.contains(id) && !blocked.contains(id) {
+            blocked.push(id.clone());
+        }
+    }
+    blocked.sort();
+
+    let runnable = waves.first().cloned().unwrap_or_default();
+
+    DagSchedule { waves, runnable, blocked, cycle }
+}
+
+/// Compute the topological schedule for a project's current DAG (RFC-105
+/// addendum), so the UI can highlight the next-runnable wave and flag
+/// blocked or circular nodes instead of trusting on-disk status alone.
+#[tauri::command]
+pub async fn get_dag_schedule(path: String) -> Result<DagSchedule, String> {
+    let graph = get_project_dag(path).await?;
+    Ok(schedule_dag(&graph))
+}
+
+/// Compute dependency-respecting parallel execution waves over a
+/// `DagGraph` — the same Kahn's-algorithm layering `schedule_dag` already
+/// performs, returned as bare node-id batches for callers (like
+/// `get_project_dag`'s per-node `wave` annotation) that don't need the
+/// full `runnable`/`blocked`/`cycle` breakdown. Nodes left out of every
+/// wave are already complete or failed, blocked by a failed dependency,
+/// or on or behind a cycle — see `find_cycles` for recovering one.
+pub fn compute_execution_waves(graph: &DagGraph) -> Vec<Vec<String>> {
+    schedule_dag(graph).waves
+}
+
+/// Compute the downstream blast radius of a node (RFC-105 addendum) — the
+/// project's current DAG loaded, then a BFS over a reverse-adjacency map
+/// built from `DagGraph.edges` (source -> targets) from `node_id`. Unlike
+/// `get_artifact_impact` (RFC-074, which shells out to the CLI's content-
+/// hash-aware cache), this walks the already-loaded graph directly, so it
+/// works for any node regardless of cache state. The natural complement
+/// to forward `depends_on` resolution for "if I change X, what breaks?",
+/// and for cascading a `failed` status to everything it blocks.
+#[tauri::command]
+pub async fn get_downstream_impact(path: String, node_id: String) -> Result<Vec<String>, String> {
+    let graph = get_project_dag(path).await?;
+    Ok(compute_downstream_impact(&graph, &node_id))
+}
+
+/// BFS from `node_id` over `graph.edges`'s reverse adjacency (source ->
+/// targets), returning every transitively reachable successor ordered by
+/// distance — first-order impact before deeper impact.
+fn compute_downstream_impact(graph: &DagGraph, node_id: &str) -> Vec<String> {
+    let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &graph.edges {
+        successors.entry(edge.source.as_str()).or_default().push(edge.target.as_str());
+    }
+
+    let mut visited: HashSet<&str> = HashSet::new();
+    visited.insert(node_id);
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(node_id);
+    let mut impacted: Vec<String> = Vec::new();
+
+    while let Some(id) = queue.pop_front() {
+        for &succ in successors.get(id).into_iter().flatten() {
+            if visited.insert(succ) {
+                impacted.push(succ.to_string());
+                queue.push_back(succ);
+            }
+        }
+    }
+
+    impacted
+}
+
+/// Map a `DagNode::effort` bucket to a relative scheduling weight, so
+/// `compute_critical_path` can sum them into a duration estimate. Falls
+/// back to the "medium" weight for anything unrecognized.
+fn effort_weight(effort: &str) -> u64 {
+    match effort {
+        "small" => 1,
+        "large" => 8,
+        _ => 3,
+    }
+}
+
+/// Find the longest weighted path through the DAG — the chain of tasks
+/// that actually gates goal completion. Processes nodes in topological
+/// order (`schedule_dag`'s waves flattened, which already exclude
+/// `complete`/`failed` nodes and leave cyclic nodes out entirely),
+/// keeping `longest[id] = weight[id] + max(longest[pred])` over incoming
+/// edges and the predecessor that achieved that max, then backtracks
+/// from the global maximum to reconstruct the path. Returns the ordered
+/// node ids on the critical path plus its total weight.
+pub fn compute_critical_path(graph: &DagGraph) -> (Vec<String>, u64) {
+    let weight: HashMap<&str, u64> =
+        graph.nodes.iter().map(|n| (n.id.as_str(), effort_weight(&n.effort))).collect();
+    let topo_order: Vec<String> = schedule_dag(graph).waves.into_iter().flatten().collect();
+
+    let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &graph.edges {
+        predecessors.entry(edge.target.as_str()).or_default().push(edge.source.as_str());
+    }
+
+    let mut longest: HashMap<String, u64> = HashMap::new();
+    let mut best_pred: HashMap<String, Option<String>> = HashMap::new();
+
+    for id in &topo_order {
+        let own_weight = weight.get(id.as_str()).copied().unwrap_or(0);
+        let mut best_weight = own_weight;
+        let mut best_predecessor: Option<String> = None;
+
+        for &pred in predecessors.get(id.as_str()).into_iter().flatten() {
+            if let Some(&pred_longest) = longest.get(pred) {
+                let candidate = pred_longest + own_weight;
+                if candidate > best_weight {
+                    best_weight = candidate;
+                    best_predecessor = Some(pred.to_string());
+                }
+            }
+        }
+
+        longest.insert(id.clone(), best_weight);
+        best_pred.insert(id.clone(), best_predecessor);
+    }
+
+    let (end, total_weight) = match longest.iter().max_by_key(|(_, &w)| w) {
+        Some((id, &w)) => (id.clone(), w),
+        None => return (Vec::new(), 0),
+    };
+
+    let mut path = vec![end.clone()];
+    let mut current = end;
+    while let Some(Some(pred)) = best_pred.get(&current) {
+        path.push(pred.clone());
+        current = pred.clone();
+    }
+    path.reverse();
+
+    (path, total_weight)
+}
+
+/// Validate that a task set (id, `depends_on`) is acyclic and every
+/// dependency refers to a known task id, via Kahn's algorithm. Returns
+/// `Err` naming the unknown task id, or `Err("dependency cycle: a -> b
+/// -> c -> a")` with one concrete offending cycle recovered by walking
+/// `depends_on` edges from a stuck node until one repeats.
+fn validate_acyclic(nodes: &[(String, Vec<String>)]) -> Result<(), String> {
+    let ids: HashSet<&str> = nodes.iter().map(|(id, _)| id.as_str()).collect();
+    for (id, deps) in nodes {
+        for dep in deps {
+            if !ids.contains(dep.as_str()) {
+                return Err(format!("task '{}' depends on unknown task '{}'", id, dep));
+            }
+        }
+    }
+
+    let mut in_degree: HashMap<&str, usize> = nodes.iter().map(|(id, _)| (id.as_str(), 0)).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (id, deps) in nodes {
+        for dep in deps {
+            *in_degree.get_mut(id.as_str()).unwrap() += 1;
+            dependents.entry(dep.as_str()).or_default().push(id.as_str());
+        }
+    }
+
+    let mut queue: Vec<&str> = in_degree.iter().filter(|(_, &d)| d == 0).map(|(&id, _)| id).collect();
+    queue.sort();
+    let mut popped = 0usize;
+    let mut i = 0;
+    while i < queue.len() {
+        let id = queue[i];
+        i += 1;
+        popped += 1;
+        let mut next: Vec<&str> = Vec::new();
+        for &dependent in dependents.get(id).into_iter().flatten() {
+            let count = in_degree.get_mut(dependent).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                next.push(dependent);
+            }
+        }
+        next.sort();
+        queue.extend(next);
+    }
+
+    if popped == nodes.len() {
+        return Ok(());
+    }
+
+    // A cycle exists among whatever still has positive in-degree. Recover
+    // one concrete cycle by walking `depends_on` edges — staying within
+    // the still-unresolved set — from any stuck node until one repeats.
+    let remaining: HashSet<&str> = in_degree.iter().filter(|(_, &d)| d > 0).map(|(&id, _)| id).collect();
+    let depends_on: HashMap<&str, &Vec<String>> = nodes.iter().map(|(id, deps)| (id.as_str(), deps)).collect();
+
+    let start = *remaining.iter().min().unwrap();
+    let mut path = vec![start];
+    let mut first_seen_at: HashMap<&str, usize> = HashMap::from([(start, 0)]);
+    let mut current = start;
+    loop {
+        let next = depends_on
+            .get(current)
+            .into_iter()
+            .flatten()
+            .map(|d| d.as_str())
+            .find(|d| remaining.contains(d))
+            .unwrap_or(start);
+        path.push(next);
+        if let Some(&first_idx) = first_seen_at.get(next) {
+            return Err(format!("dependency cycle: {}", path[first_idx..].join(" -> ")));
+        }
+        first_seen_at.insert(next, path.len() - 1);
+        current = next;
+    }
+}
+
 // =============================================================================
 // RFC-105: Hierarchical DAG Commands
 // =============================================================================
@@ -456,30 +858,41 @@ pub async fn get_goal_details(path: String, goal_id: String) -> Result<GoalNode,
 /// 1. Write goal file to dag/goals/<hash>.json
 /// 2. Append edges to dag/edges.jsonl
 /// 3. Update dag/index.json
+/// 4. Mirror the goal into the SQLite query index (best-effort)
 #[tauri::command]
 pub async fn append_goal_to_dag(path: String, goal: GoalNode) -> Result<(), String> {
     let project_path = PathBuf::from(&path);
+    append_goal_to_dag_sync(&project_path, &goal)
+}
+
+/// Synchronous core of `append_goal_to_dag` — nothing here actually
+/// awaits, so the DAG job tracker (a plain OS thread, not the async
+/// runtime) can call it directly once a node's execution completes.
+fn append_goal_to_dag_sync(project_path: &Path, goal: &GoalNode) -> Result<(), String> {
     let dag_dir = project_path.join(".sunwell/dag");
     let goals_dir = dag_dir.join("goals");
-    
+
     // Ensure directories exist
     fs::create_dir_all(&goals_dir)
         .map_err(|e| format!("Failed to create dag/goals directory: {}", e))?;
-    
+
     // 1. Write goal file
     let goal_path = goals_dir.join(format!("{}.json", goal.id));
     let goal_json = serde_json::to_string_pretty(&goal)
         .map_err(|e| format!("Failed to serialize goal: {}", e))?;
     fs::write(&goal_path, goal_json)
         .map_err(|e| format!("Failed to write goal file: {}", e))?;
-    
+
     // 2. Append edges to edges.jsonl
     let edges_path = dag_dir.join("edges.jsonl");
-    append_goal_edges(&edges_path, &goal)?;
-    
+    let edges = append_goal_edges(&edges_path, goal)?;
+
     // 3. Update index
-    update_dag_index(&project_path, &goal).await?;
-    
+    update_dag_index_sync(project_path, goal)?;
+
+    // 4. Mirror into the SQLite query index — never fails the append itself
+    crate::dag_store::sync_goal(project_path, goal, &edges);
+
     Ok(())
 }
 
@@ -505,14 +918,45 @@ pub async fn get_workspace_dag(path: String) -> Result<WorkspaceDagIndex, String
     }
     
     // Build workspace index by scanning project directories
-    build_workspace_index(&workspace_path).await
+    build_workspace_index(&workspace_path, false, |_, _, _, _| {}).await
 }
 
 /// Refresh workspace index by re-scanning all projects (RFC-105)
 #[tauri::command]
 pub async fn refresh_workspace_index(path: String) -> Result<WorkspaceDagIndex, String> {
     let workspace_path = PathBuf::from(&path);
-    build_workspace_index(&workspace_path).await
+    build_workspace_index(&workspace_path, true, |_, _, _, _| {}).await
+}
+
+/// Progress event emitted per project during `scan_workspace` (RFC-105
+/// addendum), so the frontend can render a live scan progress bar.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceScanProgressEvent {
+    project_name: String,
+    completed: usize,
+    total: usize,
+    skipped: bool,
+}
+
+/// Scan a workspace in parallel with live progress events, reusing the
+/// on-disk scan manifest to skip projects unchanged since the last scan
+/// (RFC-105 addendum). Set `force` to bypass the manifest and re-index
+/// every project regardless of its recorded mtime.
+#[tauri::command]
+pub async fn scan_workspace(
+    app: tauri::AppHandle,
+    path: String,
+    force: Option<bool>,
+) -> Result<WorkspaceDagIndex, String> {
+    let workspace_path = PathBuf::from(&path);
+    build_workspace_index(&workspace_path, force.unwrap_or(false), move |project_name, completed, total, skipped| {
+        let _ = app.emit(
+            "workspace-scan-progress",
+            WorkspaceScanProgressEvent { project_name: project_name.to_string(), completed, total, skipped },
+        );
+    })
+    .await
 }
 
 /// Get environment-level DAG overview (RFC-105 Phase 4)
@@ -537,11 +981,111 @@ pub async fn get_environment_dag() -> Result<EnvironmentDag, String> {
 // RFC-105: Index Building and Management
 // =============================================================================
 
+/// Fingerprint a plan file's `(mtime, size)` so `build_dag_index` can
+/// tell whether it needs re-parsing.
+fn plan_fingerprint(entry: &fs::DirEntry) -> Option<PlanFingerprint> {
+    let meta = entry.metadata().ok()?;
+    let mtime_secs = meta.modified().ok()?.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs();
+    Some(PlanFingerprint { mtime_secs, size: meta.len() })
+}
+
+/// Parse one plan file into its index contribution. Mirrors the per-file
+/// derivation `build_dag_index` always performed, extracted so it can run
+/// off the async runtime (on a plain thread, see `parse_plan_files`) and
+/// be cached per fingerprint.
+fn parse_plan_file(path: &Path) -> Option<CachedPlanEntry> {
+    let content = fs::read_to_string(path).ok()?;
+    let execution = parse_json_safe::<SavedExecution>(&content).ok()?;
+
+    let goal_id = generate_hash(&execution.goal);
+    let task_count = if !execution.tasks.is_empty() {
+        execution.tasks.len() as u32
+    } else {
+        execution.graph.artifacts.len() as u32
+    };
+
+    let completed_count = if !execution.tasks.is_empty() {
+        execution
+            .tasks
+            .iter()
+            .filter(|t| t.status.as_deref() == Some("completed") || t.status.as_deref() == Some("complete"))
+            .count() as u32
+    } else {
+        execution.completed.len() as u32
+    };
+
+    let status = if completed_count == task_count && task_count > 0 {
+        "complete".to_string()
+    } else if completed_count > 0 {
+        "in_progress".to_string()
+    } else {
+        "pending".to_string()
+    };
+
+    let file_time =
+        fs::metadata(path).ok().and_then(|m| m.modified().ok()).map(format_system_time).unwrap_or_else(iso_now);
+
+    let goal_summary = GoalSummary {
+        id: goal_id.clone(),
+        title: truncate_title(&execution.goal),
+        status,
+        completed_at: if completed_count == task_count && task_count > 0 { Some(file_time.clone()) } else { None },
+        created_at: file_time,
+        task_count,
+    };
+
+    let artifact_ids: Vec<String> = if !execution.tasks.is_empty() {
+        execution.tasks.iter().flat_map(|t| t.produces.clone()).collect()
+    } else {
+        execution.graph.artifacts.iter().map(|a| a.id.clone()).collect()
+    };
+
+    Some(CachedPlanEntry { goal_summary, artifact_ids })
+}
+
+/// Parse a batch of (changed) plan files spread across a small thread
+/// pool, bounded by available parallelism, so a cold index build over
+/// hundreds of plans is bounded by IO rather than single-threaded
+/// parsing. Results come back in the same order as `paths`.
+fn parse_plan_files(paths: &[PathBuf]) -> Vec<Option<CachedPlanEntry>> {
+    let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(paths.len().max(1));
+    if workers <= 1 {
+        return paths.iter().map(|p| parse_plan_file(p)).collect();
+    }
+
+    let chunk_size = paths.len().div_ceil(workers);
+    std::thread::scope(|scope| {
+        paths
+            .chunks(chunk_size.max(1))
+            .map(|chunk| scope.spawn(move || chunk.iter().map(|p| parse_plan_file(p)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    })
+}
+
 /// Build DAG index from existing plans/ directory (migration path)
+///
+/// Incremental: reuses each plan file's cached derivation from the
+/// previous `index.json` when its `(mtime, size)` fingerprint is
+/// unchanged, and only (re)parses new/changed files — across a small
+/// thread pool, since each file's derivation is independent. Plan files
+/// are always reassembled in the same mtime-sorted order regardless of
+/// which ones were reparsed, so the plan-derived goals, summary and
+/// artifacts come out the same whether built incrementally or from
+/// scratch (`last_updated` and backlog-only goals are rebuilt each time).
 async fn build_dag_index(project_path: &Path) -> Result<DagIndex, String> {
     let plans_dir = project_path.join(".sunwell/plans");
     let backlog_path = project_path.join(".sunwell/backlog/current.json");
-    
+    let index_path = project_path.join(".sunwell/dag/index.json");
+
+    let previous_cache: BTreeMap<String, PlanCacheEntry> = fs::read_to_string(&index_path)
+        .ok()
+        .and_then(|content| parse_json_safe::<DagIndex>(&content).ok())
+        .map(|prev| prev.plan_cache)
+        .unwrap_or_default();
+
     let mut index = DagIndex {
         version: 1,
         project_id: generate_project_id(project_path),
@@ -549,11 +1093,12 @@ async fn build_dag_index(project_path: &Path) -> Result<DagIndex, String> {
         summary: DagSummary::default(),
         goals: Vec::new(),
         recent_artifacts: Vec::new(),
+        plan_cache: BTreeMap::new(),
     };
-    
+
     // Read backlog for goal metadata
     let backlog = read_backlog(&backlog_path);
-    
+
     // Scan plans directory for execution files
     if plans_dir.exists() {
         let mut plan_files: Vec<_> = fs::read_dir(&plans_dir)
@@ -565,88 +1110,65 @@ async fn build_dag_index(project_path: &Path) -> Result<DagIndex, String> {
                     && !path.to_string_lossy().contains(".trace")
             })
             .collect();
-        
+
         // Sort by modification time (oldest first for chronological order)
         plan_files.sort_by_key(|e| e.metadata().ok().and_then(|m| m.modified().ok()));
-        
-        for entry in plan_files {
-            if let Ok(content) = fs::read_to_string(entry.path()) {
-                if let Ok(execution) = parse_json_safe::<SavedExecution>(&content) {
-                    // Create goal summary from execution
-                    let goal_id = generate_hash(&execution.goal);
-                    let task_count = if !execution.tasks.is_empty() {
-                        execution.tasks.len() as u32
-                    } else {
-                        execution.graph.artifacts.len() as u32
-                    };
-                    
-                    let completed_count = if !execution.tasks.is_empty() {
-                        execution.tasks.iter()
-                            .filter(|t| t.status.as_deref() == Some("completed") || t.status.as_deref() == Some("complete"))
-                            .count() as u32
-                    } else {
-                        execution.completed.len() as u32
-                    };
-                    
-                    let status = if completed_count == task_count && task_count > 0 {
-                        "complete".to_string()
-                    } else if completed_count > 0 {
-                        "in_progress".to_string()
-                    } else {
-                        "pending".to_string()
-                    };
-                    
-                    // Get file modification time for timestamps
-                    let file_time = entry.metadata()
-                        .ok()
-                        .and_then(|m| m.modified().ok())
-                        .map(|t| format_system_time(t))
-                        .unwrap_or_else(iso_now);
-                    
-                    let goal_summary = GoalSummary {
-                        id: goal_id.clone(),
-                        title: truncate_title(&execution.goal),
-                        status,
-                        completed_at: if completed_count == task_count && task_count > 0 {
-                            Some(file_time.clone())
-                        } else {
-                            None
-                        },
-                        created_at: file_time,
-                        task_count,
-                    };
-                    
-                    // Check for duplicate goal (same title)
-                    if !index.goals.iter().any(|g| g.title == goal_summary.title) {
-                        index.goals.push(goal_summary);
-                        index.summary.total_goals += 1;
-                        if completed_count == task_count && task_count > 0 {
-                            index.summary.completed_goals += 1;
-                        }
-                        index.summary.total_artifacts += task_count;
-                    }
-                    
-                    // Add recent artifacts (last 10)
-                    let artifacts: Vec<String> = if !execution.tasks.is_empty() {
-                        execution.tasks.iter().flat_map(|t| t.produces.clone()).collect()
-                    } else {
-                        execution.graph.artifacts.iter().map(|a| a.id.clone()).collect()
-                    };
-                    
-                    for artifact_id in artifacts.iter().take(10) {
-                        if index.recent_artifacts.len() < 10 {
-                            index.recent_artifacts.push(ArtifactSummary {
-                                id: artifact_id.clone(),
-                                path: None,
-                                goal_id: goal_id.clone(),
-                            });
-                        }
+
+        // Reuse cached derivations for files whose fingerprint hasn't
+        // changed; collect the rest for (re)parsing.
+        let mut derived: Vec<Option<CachedPlanEntry>> = vec![None; plan_files.len()];
+        let mut stale: Vec<usize> = Vec::new();
+        for (i, entry) in plan_files.iter().enumerate() {
+            let path_str = entry.path().to_string_lossy().to_string();
+            let reused = plan_fingerprint(entry).and_then(|fp| {
+                previous_cache.get(&path_str).filter(|cached| cached.fingerprint == fp).map(|cached| cached.entry.clone())
+            });
+            match reused {
+                Some(cached) => derived[i] = Some(cached),
+                None => stale.push(i),
+            }
+        }
+
+        if !stale.is_empty() {
+            let stale_paths: Vec<PathBuf> = stale.iter().map(|&i| plan_files[i].path()).collect();
+            for (&i, result) in stale.iter().zip(parse_plan_files(&stale_paths)) {
+                derived[i] = result;
+            }
+        }
+
+        for (i, entry) in plan_files.iter().enumerate() {
+            let Some(cached) = derived[i].take() else { continue };
+
+            // Check for duplicate goal (same title)
+            if !index.goals.iter().any(|g| g.title == cached.goal_summary.title) {
+                index.summary.total_goals += 1;
+                if cached.goal_summary.completed_at.is_some() {
+                    index.summary.completed_goals += 1;
+                }
+                index.summary.total_artifacts += cached.goal_summary.task_count;
+                let goal_id = cached.goal_summary.id.clone();
+
+                // Add artifacts until 10 are recorded, oldest plan first
+                for artifact_id in cached.artifact_ids.iter().take(10) {
+                    if index.recent_artifacts.len() < 10 {
+                        index.recent_artifacts.push(ArtifactSummary {
+                            id: artifact_id.clone(),
+                            path: None,
+                            goal_id: goal_id.clone(),
+                        });
                     }
                 }
+
+                index.goals.push(cached.goal_summary.clone());
+            }
+
+            if let Some(fingerprint) = plan_fingerprint(entry) {
+                let path_str = entry.path().to_string_lossy().to_string();
+                index.plan_cache.insert(path_str, PlanCacheEntry { fingerprint, entry: cached });
             }
         }
     }
-    
+
     // Also include backlog goals not yet executed
     for (goal_id, goal) in &backlog.goals {
         if !index.goals.iter().any(|g| g.id == *goal_id || g.title == goal.title) {
@@ -686,12 +1208,20 @@ async fn build_dag_index(project_path: &Path) -> Result<DagIndex, String> {
 
 /// Build goal details from plans/ (migration path)
 async fn build_goal_from_plans(project_path: &Path, goal_id: &str) -> Result<GoalNode, String> {
+    build_goal_from_plans_sync(project_path, goal_id)
+}
+
+/// Synchronous core of `build_goal_from_plans` — no part of this actually
+/// awaits anything, so the DAG job tracker (which runs on a plain OS
+/// thread, not the async runtime) calls this directly instead of the
+/// `async fn` wrapper kept above for existing callers.
+fn build_goal_from_plans_sync(project_path: &Path, goal_id: &str) -> Result<GoalNode, String> {
     let plans_dir = project_path.join(".sunwell/plans");
-    
+
     if !plans_dir.exists() {
         return Err(format!("Goal {} not found", goal_id));
     }
-    
+
     // Find the plan file that matches this goal
     let entries: Vec<_> = fs::read_dir(&plans_dir)
         .map_err(|e| format!("Failed to read plans directory: {}", e))?
@@ -702,39 +1232,107 @@ async fn build_goal_from_plans(project_path: &Path, goal_id: &str) -> Result<Goa
                 && !path.to_string_lossy().contains(".trace")
         })
         .collect();
-    
+
     for entry in entries {
         if let Ok(content) = fs::read_to_string(entry.path()) {
             if let Ok(execution) = parse_json_safe::<SavedExecution>(&content) {
                 let computed_id = generate_hash(&execution.goal);
                 if computed_id == goal_id || entry.path().file_stem().map_or(false, |s| s.to_string_lossy() == goal_id) {
-                    return execution_to_goal_node(execution, entry.path());
+                    return execution_to_goal_node(execution, entry.path(), project_path);
                 }
             }
         }
     }
-    
+
     Err(format!("Goal {} not found", goal_id))
 }
 
+/// Look up the goal previously recorded for `goal_id` via
+/// `append_goal_to_dag_sync`, if this goal has run before — the
+/// baseline a re-run's content hashes are compared against.
+fn load_previous_goal(project_path: &Path, goal_id: &str) -> Option<GoalNode> {
+    if let Some(goal) = crate::dag_store::load_goal(project_path, goal_id) {
+        return Some(goal);
+    }
+    let path = project_path.join(".sunwell/dag/goals").join(format!("{}.json", goal_id));
+    let content = fs::read_to_string(path).ok()?;
+    parse_json_safe::<GoalNode>(&content).ok()
+}
+
+/// Compute a task's up-to-date hash: its own description plus the
+/// content hash of every artifact it `requires` from an upstream
+/// producer. Two runs land on the same composite hash iff the
+/// description and every required input are unchanged, which is exactly
+/// what lets a re-run skip a task instead of redoing it.
+///
+/// Returns `None` if any required input has no known hash yet (a new or
+/// never-hashed upstream producer), since that means the task can't be
+/// proven up to date.
+fn task_composite_hash(description: &str, requires: &[String], produced_hashes: &HashMap<String, String>) -> Option<String> {
+    let mut composite = description.to_string();
+    for req in requires {
+        composite.push('|');
+        composite.push_str(produced_hashes.get(req)?);
+    }
+    Some(generate_hash(&composite))
+}
+
 /// Convert SavedExecution to GoalNode
-fn execution_to_goal_node(exec: SavedExecution, file_path: PathBuf) -> Result<GoalNode, String> {
+fn execution_to_goal_node(exec: SavedExecution, file_path: PathBuf, project_path: &Path) -> Result<GoalNode, String> {
     let file_time = fs::metadata(&file_path)
         .ok()
         .and_then(|m| m.modified().ok())
         .map(|t| format_system_time(t))
         .unwrap_or_else(iso_now);
-    
+
+    let goal_id = generate_hash(&exec.goal);
+    let previous_goal = load_previous_goal(project_path, &goal_id);
+    let previous_hashes: HashMap<String, String> = previous_goal
+        .as_ref()
+        .map(|g| g.tasks.iter().filter_map(|t| t.content_hash.clone().map(|h| (t.id.clone(), h))).collect())
+        .unwrap_or_default();
+
+    let mut tasks_skipped = 0u32;
+
     let tasks: Vec<TaskNode> = if !exec.tasks.is_empty() {
-        exec.tasks.iter().map(|t| TaskNode {
-            id: t.id.clone(),
-            description: t.description.clone(),
-            status: t.status.clone().unwrap_or_else(|| "pending".to_string()),
-            produces: t.produces.clone(),
-            requires: t.requires.clone(),
-            depends_on: t.depends_on.clone(),
-            content_hash: None,
-        }).collect()
+        // Produced-artifact hashes seen so far this run, fed by each
+        // task's own composite hash as it's derived below — tasks are
+        // already in topological (producer-before-consumer) order.
+        let mut produced_hashes: HashMap<String, String> = HashMap::new();
+
+        exec.tasks
+            .iter()
+            .map(|t| {
+                let mut status = t.status.clone().unwrap_or_else(|| "pending".to_string());
+                let composite = task_composite_hash(&t.description, &t.requires, &produced_hashes);
+                let is_up_to_date = match (&composite, previous_hashes.get(&t.id)) {
+                    (Some(current), Some(previous)) => current == previous,
+                    _ => false,
+                };
+
+                if is_up_to_date && status != "complete" && status != "completed" {
+                    status = "complete".to_string();
+                    tasks_skipped += 1;
+                }
+
+                let content_hash = composite.or_else(|| previous_hashes.get(&t.id).cloned());
+                if let Some(hash) = &content_hash {
+                    for produced in &t.produces {
+                        produced_hashes.insert(produced.clone(), hash.clone());
+                    }
+                }
+
+                TaskNode {
+                    id: t.id.clone(),
+                    description: t.description.clone(),
+                    status,
+                    produces: t.produces.clone(),
+                    requires: t.requires.clone(),
+                    depends_on: t.depends_on.clone(),
+                    content_hash,
+                }
+            })
+            .collect()
     } else {
         exec.graph.artifacts.iter().map(|a| TaskNode {
             id: a.id.clone(),
@@ -765,7 +1363,7 @@ fn execution_to_goal_node(exec: SavedExecution, file_path: PathBuf) -> Result<Go
     };
     
     Ok(GoalNode {
-        id: generate_hash(&exec.goal),
+        id: goal_id,
         title: truncate_title(&exec.goal),
         description: exec.goal.clone(),
         status: status.to_string(),
@@ -776,21 +1374,22 @@ fn execution_to_goal_node(exec: SavedExecution, file_path: PathBuf) -> Result<Go
         metrics: Some(GoalMetrics {
             duration_seconds: None,
             tasks_completed: completed_count,
-            tasks_skipped: 0,
+            tasks_skipped,
         }),
     })
 }
 
 /// Append edges from a goal to the edge log
-fn append_goal_edges(edges_path: &Path, goal: &GoalNode) -> Result<(), String> {
+fn append_goal_edges(edges_path: &Path, goal: &GoalNode) -> Result<Vec<EdgeLogEntry>, String> {
     let mut file = fs::OpenOptions::new()
         .create(true)
         .append(true)
         .open(edges_path)
         .map_err(|e| format!("Failed to open edges file: {}", e))?;
-    
+
     let ts = iso_now();
-    
+    let mut written = Vec::new();
+
     // Build edges from task dependencies
     for task in &goal.tasks {
         for dep in &task.depends_on {
@@ -806,8 +1405,9 @@ fn append_goal_edges(edges_path: &Path, goal: &GoalNode) -> Result<(), String> {
                 .map_err(|e| format!("Failed to serialize edge: {}", e))?;
             writeln!(file, "{}", line)
                 .map_err(|e| format!("Failed to write edge: {}", e))?;
+            written.push(edge);
         }
-        
+
         // Add produces edges
         for artifact in &task.produces {
             let edge = EdgeLogEntry {
@@ -822,17 +1422,23 @@ fn append_goal_edges(edges_path: &Path, goal: &GoalNode) -> Result<(), String> {
                 .map_err(|e| format!("Failed to serialize edge: {}", e))?;
             writeln!(file, "{}", line)
                 .map_err(|e| format!("Failed to write edge: {}", e))?;
+            written.push(edge);
         }
     }
-    
-    Ok(())
+
+    Ok(written)
 }
 
-/// Update the DAG index with a new goal
-async fn update_dag_index(project_path: &Path, goal: &GoalNode) -> Result<(), String> {
+/// Update the DAG index with a new goal (see `append_goal_to_dag_sync` for
+/// why this stays synchronous rather than an `async fn`).
+fn update_dag_index_sync(project_path: &Path, goal: &GoalNode) -> Result<(), String> {
+    let task_deps: Vec<(String, Vec<String>)> =
+        goal.tasks.iter().map(|t| (t.id.clone(), t.depends_on.clone())).collect();
+    validate_acyclic(&task_deps).map_err(|e| format!("Refusing to record goal '{}': {}", goal.id, e))?;
+
     let dag_dir = project_path.join(".sunwell/dag");
     let index_path = dag_dir.join("index.json");
-    
+
     // Read existing index or create new
     let mut index = if index_path.exists() {
         let content = fs::read_to_string(&index_path)
@@ -888,11 +1494,76 @@ fn build_empty_index(project_path: &Path) -> DagIndex {
         summary: DagSummary::default(),
         goals: Vec::new(),
         recent_artifacts: Vec::new(),
+        plan_cache: BTreeMap::new(),
+    }
+}
+
+/// One project's scan result, cached in the scan manifest and reused on
+/// the next scan when `.sunwell/` is unchanged (see `build_workspace_index`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorkspaceScanManifestEntry {
+    last_indexed_mtime: u64,
+    summary: ProjectSummary,
+    #[serde(default)]
+    tasks: Vec<TaskNode>,
+}
+
+/// On-disk checkpoint for `build_workspace_index`, keyed by each
+/// project's path relative to the workspace root.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct WorkspaceScanManifest {
+    #[serde(default)]
+    entries: HashMap<String, WorkspaceScanManifestEntry>,
+}
+
+fn scan_manifest_path(workspace_path: &Path) -> PathBuf {
+    workspace_path.join(".sunwell/dag/workspace-scan-manifest.json")
+}
+
+fn load_scan_manifest(workspace_path: &Path) -> WorkspaceScanManifest {
+    fs::read_to_string(scan_manifest_path(workspace_path))
+        .ok()
+        .and_then(|content| parse_json_safe(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_scan_manifest(workspace_path: &Path, manifest: &WorkspaceScanManifest) {
+    if let Some(dir) = scan_manifest_path(workspace_path).parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(manifest) {
+        let _ = fs::write(scan_manifest_path(workspace_path), json);
     }
 }
 
-/// Build workspace index from project directories
-async fn build_workspace_index(workspace_path: &Path) -> Result<WorkspaceDagIndex, String> {
+/// Last-modified time of `path`, in whole seconds since the epoch, used
+/// to detect whether a project's `.sunwell/` directory changed since it
+/// was last indexed.
+fn dir_mtime_secs(path: &Path) -> Option<u64> {
+    fs::metadata(path).ok()?.modified().ok()?.duration_since(std::time::UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// Build workspace index from project directories.
+///
+/// Projects are indexed concurrently through a `tokio::task::JoinSet`
+/// bounded by a semaphore at `available_parallelism`, instead of
+/// serially, so a large monorepo scans in roughly `projects / cores`
+/// wall-clock instead of `projects * avg_project_time`. `on_progress` is
+/// invoked after each project finishes (scanned or skipped) with
+/// `(project_name, completed, total, skipped)`.
+///
+/// A scan manifest at `.sunwell/dag/workspace-scan-manifest.json`
+/// checkpoints each project's `.sunwell/` mtime alongside its summary
+/// and task list; a project whose `.sunwell/` is unchanged since that
+/// recorded mtime is skipped and its cached data reused unless `force`
+/// is set. The manifest is rewritten after every scan, so an
+/// interrupted scan resumes from the last checkpoint rather than
+/// restarting from scratch.
+async fn build_workspace_index(
+    workspace_path: &Path,
+    force: bool,
+    on_progress: impl Fn(&str, usize, usize, bool) + Send + Sync + 'static,
+) -> Result<WorkspaceDagIndex, String> {
     let mut index = WorkspaceDagIndex {
         workspace_id: generate_project_id(workspace_path),
         last_updated: iso_now(),
@@ -900,36 +1571,156 @@ async fn build_workspace_index(workspace_path: &Path) -> Result<WorkspaceDagInde
         cross_project_dependencies: Vec::new(),
         shared_patterns: Vec::new(),
     };
-    
-    // Scan for projects (directories with .sunwell/)
+
+    let manifest = load_scan_manifest(workspace_path);
+
+    let mut project_dirs: Vec<PathBuf> = Vec::new();
     if let Ok(entries) = fs::read_dir(workspace_path) {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
             if path.is_dir() && path.join(".sunwell").exists() {
-                // Get project index
-                if let Ok(project_index) = get_project_dag_index(path.to_string_lossy().to_string()).await {
-                    let project_name = path.file_name()
-                        .map(|n| n.to_string_lossy().to_string())
-                        .unwrap_or_default();
-                    
-                    index.projects.push(ProjectSummary {
-                        id: project_index.project_id,
-                        name: project_name,
-                        path: path.strip_prefix(workspace_path)
-                            .map(|p| p.to_string_lossy().to_string())
-                            .unwrap_or_else(|_| path.to_string_lossy().to_string()),
-                        summary: project_index.summary,
-                        tech_stack: detect_tech_stack(&path),
-                        last_activity: project_index.goals.iter()
-                            .filter_map(|g| g.completed_at.as_ref())
-                            .max()
-                            .cloned(),
-                    });
+                project_dirs.push(path);
+            }
+        }
+    }
+    let total = project_dirs.len();
+
+    let on_progress = std::sync::Arc::new(on_progress);
+    let permits = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(permits));
+    let completed_count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let mut join_set = tokio::task::JoinSet::new();
+    for path in project_dirs {
+        let rel_key = path
+            .strip_prefix(workspace_path)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| path.to_string_lossy().to_string());
+        let dot_sunwell_mtime = dir_mtime_secs(&path.join(".sunwell"));
+        let cached_entry = manifest.entries.get(&rel_key).cloned();
+        let can_skip = !force
+            && cached_entry
+                .as_ref()
+                .zip(dot_sunwell_mtime)
+                .is_some_and(|(entry, mtime)| entry.last_indexed_mtime == mtime);
+
+        let semaphore = semaphore.clone();
+        let on_progress = on_progress.clone();
+        let completed_count = completed_count.clone();
+        let workspace_path_owned = workspace_path.to_path_buf();
+
+        join_set.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.ok();
+            let project_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+            let scanned = if can_skip {
+                cached_entry.map(|entry| (entry.summary, entry.tasks))
+            } else {
+                None
+            };
+            let skipped = scanned.is_some();
+
+            let scanned = match scanned {
+                Some(result) => Some(result),
+                None => match get_project_dag_index(path.to_string_lossy().to_string()).await {
+                    Ok(project_index) => {
+                        let summary = ProjectSummary {
+                            id: project_index.project_id.clone(),
+                            name: project_name.clone(),
+                            path: path
+                                .strip_prefix(&workspace_path_owned)
+                                .map(|p| p.to_string_lossy().to_string())
+                                .unwrap_or_else(|_| path.to_string_lossy().to_string()),
+                            summary: project_index.summary,
+                            tech_stack: detect_tech_stack(&path),
+                            last_activity: project_index
+                                .goals
+                                .iter()
+                                .filter_map(|g| g.completed_at.as_ref())
+                                .max()
+                                .cloned(),
+                        };
+                        Some((summary, load_project_tasks(&path)))
+                    }
+                    Err(_) => None,
+                },
+            };
+
+            let done = completed_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            on_progress(&project_name, done, total, skipped);
+
+            (rel_key, dot_sunwell_mtime, scanned)
+        });
+    }
+
+    let mut new_manifest = WorkspaceScanManifest::default();
+    // producer[artifact_id] = project ids that produce it, for cross-project
+    // resolution once every project has been scanned.
+    let mut producers: HashMap<String, Vec<String>> = HashMap::new();
+    // producer_by_hash[content_hash] = (project_id, artifact_id) of every
+    // task whose produced content hashes identically — a stronger signal
+    // of reuse than matching artifact id strings alone.
+    let mut producers_by_hash: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    // Every task across every project, kept for requires-resolution and
+    // description-overlap clustering below.
+    let mut project_tasks: Vec<(String, Vec<TaskNode>)> = Vec::new();
+
+    while let Some(joined) = join_set.join_next().await {
+        let Ok((rel_key, mtime, scanned)) = joined else { continue };
+        let Some((summary, tasks)) = scanned else { continue };
+
+        if let Some(mtime) = mtime {
+            new_manifest
+                .entries
+                .insert(rel_key, WorkspaceScanManifestEntry { last_indexed_mtime: mtime, summary: summary.clone(), tasks: tasks.clone() });
+        }
+
+        let project_id = summary.id.clone();
+        for task in &tasks {
+            for artifact_id in &task.produces {
+                producers.entry(artifact_id.clone()).or_default().push(project_id.clone());
+            }
+            if let Some(hash) = &task.content_hash {
+                for artifact_id in &task.produces {
+                    producers_by_hash.entry(hash.clone()).or_default().push((project_id.clone(), artifact_id.clone()));
                 }
             }
         }
+        project_tasks.push((project_id, tasks));
+        index.projects.push(summary);
     }
-    
+    index.projects.sort_by(|a, b| a.path.cmp(&b.path));
+
+    save_scan_manifest(workspace_path, &new_manifest);
+
+    // Resolve cross-project edges: a task `requires` an artifact produced by
+    // a *different* project's goal.
+    let mut seen_edges = std::collections::HashSet::new();
+    for (project_id, tasks) in &project_tasks {
+        for task in tasks {
+            for artifact_id in &task.requires {
+                let Some(producing_projects) = producers.get(artifact_id) else { continue };
+                for producer_id in producing_projects {
+                    if producer_id == project_id {
+                        continue;
+                    }
+                    let key = (producer_id.clone(), project_id.clone(), artifact_id.clone());
+                    if seen_edges.insert(key) {
+                        index.cross_project_dependencies.push(CrossProjectEdge {
+                            source_project: producer_id.clone(),
+                            target_project: project_id.clone(),
+                            source_artifact: artifact_id.clone(),
+                            target_artifact: artifact_id.clone(),
+                            edge_type: "requires".to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    index.shared_patterns = find_shared_patterns(&producers, &producers_by_hash, &project_tasks);
+
     // Save workspace index
     let dag_dir = workspace_path.join(".sunwell/dag");
     if let Err(e) = fs::create_dir_all(&dag_dir) {
@@ -940,10 +1731,112 @@ async fn build_workspace_index(workspace_path: &Path) -> Result<WorkspaceDagInde
             let _ = fs::write(index_path, json);
         }
     }
-    
+
     Ok(index)
 }
 
+/// Every task across a project's `dag/goals/*.json` files (the
+/// lightweight `DagIndex` doesn't carry per-task `requires`/`produces`).
+fn load_project_tasks(project_path: &Path) -> Vec<TaskNode> {
+    let goals_dir = project_path.join(".sunwell/dag/goals");
+    let Ok(entries) = fs::read_dir(&goals_dir) else { return Vec::new() };
+
+    let mut tasks = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let Ok(content) = fs::read_to_string(entry.path()) else { continue };
+        let Ok(goal) = parse_json_safe::<GoalNode>(&content) else { continue };
+        tasks.extend(goal.tasks);
+    }
+    tasks
+}
+
+/// Normalize a task description into a token set (lowercase words of at
+/// least 4 characters) for shingle-overlap comparison.
+fn description_tokens(description: &str) -> HashSet<String> {
+    description
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|tok| tok.to_lowercase())
+        .filter(|tok| tok.len() >= 4)
+        .collect()
+}
+
+/// Jaccard overlap between two token sets.
+fn token_overlap(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count() as f32;
+    let union = a.union(b).count() as f32;
+    intersection / union
+}
+
+/// Find reusable patterns across projects: artifacts produced by two or
+/// more projects (by id or, more strongly, by identical content hash),
+/// plus clusters of tasks in different projects whose descriptions
+/// overlap above a simple shingle threshold. Each cluster is rendered as
+/// one string naming the participating project ids, since
+/// `WorkspaceDagIndex::shared_patterns` is a flat `Vec<String>`.
+fn find_shared_patterns(
+    producers: &HashMap<String, Vec<String>>,
+    producers_by_hash: &HashMap<String, Vec<(String, String)>>,
+    project_tasks: &[(String, Vec<TaskNode>)],
+) -> Vec<String> {
+    const OVERLAP_THRESHOLD: f32 = 0.5;
+    let mut patterns = std::collections::BTreeSet::new();
+
+    for (artifact_id, projects) in producers {
+        if projects.len() >= 2 {
+            let mut ids: Vec<&String> = projects.iter().collect();
+            ids.sort();
+            ids.dedup();
+            if ids.len() >= 2 {
+                patterns.insert(format!("artifact:{} (projects: {})", artifact_id, join_ids(&ids)));
+            }
+        }
+    }
+
+    for (hash, producers) in producers_by_hash {
+        let mut ids: Vec<&String> = producers.iter().map(|(p, _)| p).collect();
+        ids.sort();
+        ids.dedup();
+        if ids.len() >= 2 {
+            patterns.insert(format!("content:{} (projects: {})", &hash[..hash.len().min(12)], join_ids(&ids)));
+        }
+    }
+
+    let mut flattened: Vec<(&str, &TaskNode, HashSet<String>)> = Vec::new();
+    for (project_id, tasks) in project_tasks {
+        for task in tasks {
+            flattened.push((project_id.as_str(), task, description_tokens(&task.description)));
+        }
+    }
+
+    for i in 0..flattened.len() {
+        for j in (i + 1)..flattened.len() {
+            let (project_a, task_a, tokens_a) = &flattened[i];
+            let (project_b, _, tokens_b) = &flattened[j];
+            if project_a == project_b {
+                continue;
+            }
+            if token_overlap(tokens_a, tokens_b) >= OVERLAP_THRESHOLD {
+                let mut ids = [*project_a, *project_b];
+                ids.sort();
+                patterns.insert(format!(
+                    "pattern:{} (projects: {})",
+                    truncate_title(&task_a.description),
+                    ids.join(", ")
+                ));
+            }
+        }
+    }
+
+    patterns.into_iter().collect()
+}
+
+fn join_ids(ids: &[&String]) -> String {
+    ids.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ")
+}
+
 /// Detect tech stack from project files
 fn detect_tech_stack(project_path: &Path) -> Vec<String> {
     let mut stack = Vec::new();
@@ -1032,10 +1925,259 @@ fn format_system_time(time: SystemTime) -> String {
     datetime.to_rfc3339()
 }
 
+// =============================================================================
+// DAG Job Subsystem — resumable node execution with checkpointed state
+// =============================================================================
+//
+// `execute_dag_node` used to fire off an agent session and hand back
+// immediately, with nothing durable recording that a node was mid-flight.
+// A `DagJob` tracks that lifecycle explicitly (`Pending → Running →
+// {Complete, Failed, Paused}`), checkpointed to
+// `.sunwell/dag/jobs/<id>.json` after every transition so a crash or app
+// restart can resume from the last committed task instead of restarting
+// the whole goal.
+
+/// Lifecycle state of a DAG node execution job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DagJobState {
+    Pending,
+    Running,
+    Complete,
+    Failed,
+    Paused,
+}
+
+/// Checkpointed state for one DAG node execution job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DagJob {
+    pub id: String,
+    pub node_id: String,
+    pub project_path: String,
+    pub session_id: String,
+    pub state: DagJobState,
+    pub progress: u8,
+    pub current_action: Option<String>,
+    /// Ids of tasks the underlying agent session has reported complete so
+    /// far — enough for `resume_dag_job` to know what not to redo.
+    pub completed_task_ids: Vec<String>,
+    pub current_task_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl DagJob {
+    fn jobs_dir(project_path: &Path) -> PathBuf {
+        project_path.join(".sunwell/dag/jobs")
+    }
+
+    fn checkpoint_path(project_path: &Path, job_id: &str) -> PathBuf {
+        Self::jobs_dir(project_path).join(format!("{}.json", job_id))
+    }
+
+    fn new(project_path: &Path, node_id: &str, session_id: &str) -> Self {
+        let now = iso_now();
+        Self {
+            id: format!("job-{}", session_id),
+            node_id: node_id.to_string(),
+            project_path: project_path.to_string_lossy().to_string(),
+            session_id: session_id.to_string(),
+            state: DagJobState::Pending,
+            progress: 0,
+            current_action: None,
+            completed_task_ids: Vec::new(),
+            current_task_id: None,
+            error: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Load a job's checkpoint by id.
+    pub fn load(project_path: &Path, job_id: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(Self::checkpoint_path(project_path, job_id))
+            .map_err(|e| format!("Failed to read job checkpoint {}: {}", job_id, e))?;
+        parse_json_safe(&content).map_err(|e| format!("Failed to parse job checkpoint {}: {}", job_id, e))
+    }
+
+    /// Persist this job's current state, after every transition.
+    fn save(&self) -> Result<(), String> {
+        let project_path = PathBuf::from(&self.project_path);
+        let dir = Self::jobs_dir(&project_path);
+        fs::create_dir_all(&dir).map_err(|e| format!("Failed to create dag/jobs directory: {}", e))?;
+        let json = serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize job: {}", e))?;
+        fs::write(Self::checkpoint_path(&project_path, &self.id), json)
+            .map_err(|e| format!("Failed to write job checkpoint: {}", e))
+    }
+
+    fn transition(&mut self, state: DagJobState) {
+        self.state = state;
+        self.updated_at = iso_now();
+    }
+}
+
+/// Payload for the `dag-job-progress` Tauri event.
+#[derive(Debug, Clone, Serialize)]
+struct DagJobProgressEvent {
+    job: DagJob,
+}
+
+/// Fold one agent event into a job's checkpoint — progress, the action
+/// description shown in the UI, and the completed/current task ids.
+fn apply_agent_event_to_job(job: &mut DagJob, event: &crate::agent::AgentEvent) {
+    let task_id = event.data.get("id").and_then(|v| v.as_str()).map(str::to_string);
+    let description = event.data.get("description").and_then(|v| v.as_str()).map(str::to_string);
+
+    match event.event_type.as_str() {
+        "task_start" => {
+            job.current_task_id = task_id;
+            job.current_action = description.or_else(|| Some("Running task...".to_string()));
+        }
+        "task_complete" => {
+            if let Some(id) = task_id {
+                if !job.completed_task_ids.contains(&id) {
+                    job.completed_task_ids.push(id);
+                }
+            }
+            job.current_task_id = None;
+        }
+        "task_failed" => {
+            job.current_task_id = None;
+        }
+        _ => {}
+    }
+
+    if let Some(hints) = crate::agent::UIHints::from_event(event).progress {
+        job.progress = (hints * 100.0).clamp(0.0, 100.0) as u8;
+    }
+}
+
+/// Tail a session's NDJSON event journal (written live by
+/// `AgentBridge::spawn_and_stream`) on a background thread, folding each
+/// new event into the job's checkpoint and re-emitting a
+/// `dag-job-progress` event so the UI can render a live progress bar.
+/// On a terminal event, the job transitions to `Complete`/`Failed` and,
+/// on success, its goal is appended to the DAG via the usual
+/// `append_goal_to_dag` path.
+fn spawn_job_tracker(app: tauri::AppHandle, mut job: DagJob) {
+    std::thread::spawn(move || {
+        job.transition(DagJobState::Running);
+        let _ = job.save();
+        let _ = app.emit("dag-job-progress", DagJobProgressEvent { job: job.clone() });
+
+        let project_path = PathBuf::from(&job.project_path);
+        let journal_path =
+            project_path.join(".sunwell/sessions").join(format!("{}.ndjson", job.session_id));
+
+        // The journal is created lazily by the agent's own stream thread;
+        // give it a few seconds to show up before giving up on tracking.
+        let mut waited_ms = 0;
+        while !journal_path.exists() && waited_ms < 10_000 {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            waited_ms += 200;
+        }
+        if !journal_path.exists() {
+            return;
+        }
+
+        let mut offset: usize = 0;
+        loop {
+            std::thread::sleep(std::time::Duration::from_millis(400));
+            let Ok(content) = fs::read_to_string(&journal_path) else { continue };
+            if content.len() <= offset {
+                continue;
+            }
+            let new_lines = content[offset..].to_string();
+            offset = content.len();
+
+            let mut succeeded = None;
+            for line in new_lines.lines() {
+                if line.is_empty() {
+                    continue;
+                }
+                let Ok(event) = parse_json_safe::<crate::agent::AgentEvent>(line) else { continue };
+                apply_agent_event_to_job(&mut job, &event);
+                match event.event_type.as_str() {
+                    "complete" => succeeded = Some(true),
+                    "error" => succeeded = Some(false),
+                    _ => {}
+                }
+            }
+
+            let _ = job.save();
+            let _ = app.emit("dag-job-progress", DagJobProgressEvent { job: job.clone() });
+
+            if let Some(ok) = succeeded {
+                job.transition(if ok { DagJobState::Complete } else { DagJobState::Failed });
+                if ok {
+                    job.progress = 100;
+                } else {
+                    job.error = Some("Agent session reported an error".to_string());
+                }
+                let _ = job.save();
+                let _ = app.emit("dag-job-progress", DagJobProgressEvent { job: job.clone() });
+
+                if ok {
+                    if let Ok(goal) = build_goal_from_plans_sync(&project_path, &job.node_id) {
+                        let _ = append_goal_to_dag_sync(&project_path, &goal);
+                    }
+                }
+                return;
+            }
+        }
+    });
+}
+
+/// Resume a paused or interrupted DAG job: reload its checkpoint, re-issue
+/// `agent resume` under the same session id (RFC-Cloud-Model-Parity's
+/// resume path already reconstructs the interrupted `SavedExecution` from
+/// `.sunwell/plans/`), and resume tracking from the last committed task.
+#[tauri::command]
+pub async fn resume_dag_job(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+    path: String,
+    job_id: String,
+) -> Result<DagJob, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let mut job = DagJob::load(&project_path, &job_id)?;
+
+    // Resuming re-issues the same agent run that originally created this
+    // job, so it needs the same capabilities `execute_dag_node` requires.
+    crate::runtime_acl::require(&job.node_id, "filesystem_write").map_err(|e| e.to_json())?;
+    crate::runtime_acl::require(&job.node_id, "shell").map_err(|e| e.to_json())?;
+
+    if job.state == DagJobState::Complete {
+        return Ok(job);
+    }
+
+    let agent = state
+        .agent
+        .lock()
+        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to acquire agent lock: {}", e).to_json())?;
+    state.job_manager.start(job.session_id.clone(), &project_path, &job.node_id, None, None);
+    agent
+        .resume_goal(job.session_id.clone(), app.clone(), &project_path, None, None, state.job_manager.clone())
+        .map_err(|e| e.to_json())?;
+    drop(agent);
+
+    job.transition(DagJobState::Running);
+    job.save()?;
+    spawn_job_tracker(app, job.clone());
+
+    Ok(job)
+}
+
 /// Execute a specific node from the DAG (RFC-056)
-/// 
+///
 /// For backlog goals, uses `sunwell backlog run <id>` to preserve goal metadata.
 /// For execution artifacts, uses `sunwell agent run <description>`.
+/// The backlog path is tracked as a `DagJob`, checkpointed to
+/// `.sunwell/dag/jobs/<id>.json` so it can be resumed with
+/// `resume_dag_job` after a crash or app restart; the `agent run` path
+/// is started through `run_goal` and gets no `DagJob`.
 #[tauri::command]
 pub async fn execute_dag_node(
     app: tauri::AppHandle,
@@ -1044,14 +2186,20 @@ pub async fn execute_dag_node(
     node_id: String,
 ) -> Result<crate::commands::RunGoalResult, String> {
     let project_path = std::path::PathBuf::from(&path);
-    
+
+    // A DAG node's `backlog run`/`agent run` writes artifacts and shells out
+    // the same way a cascade fix does (`weakness::execute_cascade_fix`), so
+    // it's gated behind the same approved capabilities.
+    crate::runtime_acl::require(&node_id, "filesystem_write").map_err(|e| e.to_json())?;
+    crate::runtime_acl::require(&node_id, "shell").map_err(|e| e.to_json())?;
+
     // Check if this is a backlog goal
     let backlog_path = project_path.join(".sunwell/backlog/current.json");
     let is_backlog_goal = if backlog_path.exists() {
         match std::fs::read_to_string(&backlog_path) {
             Ok(content) => {
                 // Check if node_id exists in the backlog goals
-                content.contains(&format!("\"{}\"", node_id)) || 
+                content.contains(&format!("\"{}\"", node_id)) ||
                 content.contains(&format!("\"{}", node_id))
             }
             Err(_) => false,
@@ -1062,15 +2210,21 @@ pub async fn execute_dag_node(
 
     if is_backlog_goal {
         // Use backlog run command for backlog goals
-        let mut agent = state.agent.lock()
+        let session_id = crate::agent::new_session_id();
+        let agent = state.agent.lock()
             .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to acquire agent lock: {}", e).to_json())?;
-        agent.run_backlog_goal(app, &node_id, &project_path, None)
+        state.job_manager.start(session_id.clone(), &project_path, &node_id, None, None);
+        agent.run_backlog_goal(session_id.clone(), app.clone(), &node_id, &project_path, None, state.job_manager.clone())
             .map_err(|e| e.to_json())?;
-        
+        drop(agent);
+
+        spawn_job_tracker(app, DagJob::new(&project_path, &node_id, &session_id));
+
         Ok(crate::commands::RunGoalResult {
             success: true,
             message: format!("Backlog goal {} started", node_id),
             workspace_path: crate::workspace::shorten_path(&project_path),
+            session_id,
         })
     } else {
         // Fall back to regular agent run for execution artifacts
@@ -1190,9 +2344,13 @@ pub async fn load_plan_file(plan_path: String) -> Result<DagGraph, String> {
 
 /// Convert a CliPlanFile to DagGraph format (RFC-090)
 fn cli_plan_to_dag_graph(plan: CliPlanFile) -> Result<DagGraph, String> {
+    let task_deps: Vec<(String, Vec<String>)> =
+        plan.task_list.iter().map(|t| (t.id.clone(), t.depends_on.clone())).collect();
+    validate_acyclic(&task_deps)?;
+
     let mut nodes: Vec<DagNode> = Vec::new();
     let mut edges: Vec<DagEdge> = Vec::new();
-    
+
     // Convert tasks to nodes
     for task in &plan.task_list {
         nodes.push(DagNode {
@@ -1209,8 +2367,10 @@ fn cli_plan_to_dag_graph(plan: CliPlanFile) -> Result<DagGraph, String> {
             current_action: None,
             task_type: "create".to_string(),
             produces: task.produces.clone(),
+            wave: None,
+            on_critical_path: false,
         });
-        
+
         // Build edges from dependencies
         for dep in &task.depends_on {
             edges.push(DagEdge {
@@ -1230,6 +2390,8 @@ fn cli_plan_to_dag_graph(plan: CliPlanFile) -> Result<DagGraph, String> {
         edges,
         goal: plan.goal,
         total_progress: 0,
+        // `validate_acyclic` above already rejects a cyclic task list.
+        cycles: Vec::new(),
     })
 }
 
@@ -1417,6 +2579,68 @@ fn read_latest_execution(plans_dir: &Path) -> Option<SavedExecution> {
 // Merge Logic
 // =============================================================================
 
+/// Compute a completed task's input-hash fingerprint: its description plus
+/// its sorted `requires` list plus the sorted content hashes of the tasks
+/// that produce each required artifact. Comparing this against the hash
+/// stored when the task last completed reveals whether an upstream
+/// artifact changed underneath it without the task itself being redone.
+fn task_input_hash(
+    description: &str,
+    requires: &[String],
+    producers: &HashMap<String, String>,
+    content_hashes: &HashMap<String, String>,
+) -> String {
+    let mut sorted_requires: Vec<&String> = requires.iter().collect();
+    sorted_requires.sort();
+
+    let mut upstream_hashes: Vec<&str> = requires
+        .iter()
+        .filter_map(|req| producers.get(req))
+        .filter_map(|task_id| content_hashes.get(task_id))
+        .map(|h| h.as_str())
+        .collect();
+    upstream_hashes.sort();
+
+    let mut composite = description.to_string();
+    for req in sorted_requires {
+        composite.push('|');
+        composite.push_str(req);
+    }
+    for hash in upstream_hashes {
+        composite.push('|');
+        composite.push_str(hash);
+    }
+    generate_hash(&composite)
+}
+
+/// Flip every node in `stale_ids`, plus every `complete` descendant
+/// reachable from one via a dependency edge, to `"stale"` status with
+/// `progress` reset to 0 — so a finished task built on a now-changed
+/// upstream output loses its green checkmark, and so does anything built
+/// on top of it.
+fn propagate_staleness(nodes: &mut [DagNode], edges: &[DagEdge], mut stale_ids: HashSet<String>) {
+    let mut frontier: Vec<String> = stale_ids.iter().cloned().collect();
+    while let Some(id) = frontier.pop() {
+        for edge in edges {
+            if edge.source == id && !stale_ids.contains(&edge.target) {
+                let target_is_complete =
+                    nodes.iter().any(|n| n.id == edge.target && (n.status == "complete" || n.status == "completed"));
+                if target_is_complete {
+                    stale_ids.insert(edge.target.clone());
+                    frontier.push(edge.target.clone());
+                }
+            }
+        }
+    }
+
+    for node in nodes.iter_mut() {
+        if stale_ids.contains(&node.id) {
+            node.status = "stale".to_string();
+            node.progress = 0;
+        }
+    }
+}
+
 fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph {
     let mut nodes = Vec::new();
     let mut edges = Vec::new();
@@ -1494,6 +2718,8 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
                     // RFC-067 fields
                     task_type,
                     produces: task.produces.clone(),
+                    wave: None,
+                    on_critical_path: false,
                 });
 
                 seen_ids.insert(task.id.clone());
@@ -1514,6 +2740,25 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
                     });
                 }
             }
+
+            // RFC-105 addendum: a task marked complete may have been
+            // completed against upstream inputs that have since changed.
+            let content_hashes: HashMap<String, String> = exec
+                .tasks
+                .iter()
+                .filter_map(|t| t.content_hash.clone().map(|h| (t.id.clone(), h)))
+                .collect();
+            let stale_ids: HashSet<String> = exec
+                .tasks
+                .iter()
+                .filter(|t| matches!(t.status.as_deref(), Some("completed") | Some("complete")))
+                .filter_map(|t| {
+                    let stored = t.content_hash.as_ref()?;
+                    let current = task_input_hash(&t.description, &t.requires, &producers, &content_hashes);
+                    (&current != stored).then(|| t.id.clone())
+                })
+                .collect();
+            propagate_staleness(&mut nodes, &edges, stale_ids);
         }
         // Legacy format: graph.artifacts with separate completed map
         else {
@@ -1553,6 +2798,8 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
                     // RFC-067 fields (default for legacy format)
                     task_type: "create".to_string(),
                     produces: vec![artifact.id.clone()],
+                    wave: None,
+                    on_critical_path: false,
                 });
 
                 seen_ids.insert(artifact.id.clone());
@@ -1630,6 +2877,8 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
             // RFC-067 fields (goals are typically "create" type)
             task_type: "create".to_string(),
             produces: vec![],
+            wave: None,
+            on_critical_path: false,
         });
 
         // Create edges for goal dependencies
@@ -1647,6 +2896,16 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
         }
     }
 
+    // RFC-105 addendum: detect dependency cycles across the assembled graph
+    // and surface them as a "cycle" status instead of misreporting "blocked".
+    let cycles = find_cycles(&nodes);
+    let cycle_ids: HashSet<&str> = cycles.iter().flatten().map(|id| id.as_str()).collect();
+    for node in &mut nodes {
+        if cycle_ids.contains(node.id.as_str()) {
+            node.status = "cycle".to_string();
+        }
+    }
+
     // Calculate total progress
     let total = nodes.len();
     let progress = if total > 0 {
@@ -1660,9 +2919,75 @@ fn merge_to_dag(backlog: Backlog, execution: Option<SavedExecution>) -> DagGraph
         edges,
         goal: execution.map(|e| e.goal),
         total_progress: progress,
+        cycles,
     }
 }
 
+/// DFS visitation state for `find_cycles`: White = unvisited, Gray = on the
+/// current path, Black = fully explored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DfsColor {
+    White,
+    Gray,
+    Black,
+}
+
+/// Detect dependency cycles across `nodes` via iterative (non-recursive)
+/// three-color DFS over `depends_on` edges. Running the DFS from every
+/// White node covers disconnected components; a Gray successor is a
+/// back-edge, and the offending cycle is recovered by walking the live
+/// DFS path stack back to that successor. Returns one cycle (a sequence
+/// of node ids that loops back to its start) per back-edge found.
+fn find_cycles(nodes: &[DagNode]) -> Vec<Vec<String>> {
+    let deps_by_id: HashMap<&str, &[String]> =
+        nodes.iter().map(|n| (n.id.as_str(), n.depends_on.as_slice())).collect();
+    let mut color: HashMap<String, DfsColor> =
+        nodes.iter().map(|n| (n.id.clone(), DfsColor::White)).collect();
+    let mut cycles: Vec<Vec<String>> = Vec::new();
+
+    for start in nodes {
+        if color.get(start.id.as_str()) != Some(&DfsColor::White) {
+            continue;
+        }
+
+        // Each frame is (node id, index into its dependency list to visit next).
+        let mut stack: Vec<(String, usize)> = vec![(start.id.clone(), 0)];
+        color.insert(start.id.clone(), DfsColor::Gray);
+
+        while let Some((id, idx)) = stack.pop() {
+            let deps = deps_by_id.get(id.as_str()).copied().unwrap_or(&[]);
+            if idx >= deps.len() {
+                color.insert(id, DfsColor::Black);
+                continue;
+            }
+
+            let dep = &deps[idx];
+            stack.push((id, idx + 1));
+
+            match color.get(dep.as_str()) {
+                Some(DfsColor::Gray) => {
+                    // Back-edge: the live path (root..=id) is still on `stack`;
+                    // walk it from `dep` back to the top to recover the cycle.
+                    let mut cycle: Vec<String> = stack
+                        .iter()
+                        .map(|(node_id, _)| node_id.clone())
+                        .skip_while(|node_id| node_id != dep)
+                        .collect();
+                    cycle.push(dep.clone());
+                    cycles.push(cycle);
+                }
+                Some(DfsColor::White) => {
+                    color.insert(dep.clone(), DfsColor::Gray);
+                    stack.push((dep.clone(), 0));
+                }
+                _ => {} // Black, or a dependency id with no matching node: nothing to do.
+            }
+        }
+    }
+
+    cycles
+}
+
 fn is_ready(
     _id: &str,
     deps: &[String],
@@ -0,0 +1,448 @@
+//! DAG Query/Filter Mini-Language (RFC-105 addendum)
+//!
+//! `get_project_dag` returns every node flat, which doesn't scale once a
+//! project's DAG grows into the hundreds of nodes. `query_project_dag`
+//! adds a filtering layer on top: a small expression language — field
+//! predicates over `DagNode` (`status`, `source`, `category`, `effort`,
+//! `priority`), comparison operators, boolean `and`/`or`/`not`, and two
+//! graph-aware predicates (`has_incomplete_deps`, `is_leaf`) — parsed into
+//! an `Expr` AST and evaluated per node. Matching nodes keep their edges
+//! only where both endpoints survived the filter, so the result is a
+//! genuine subgraph rather than a node list with dangling edges.
+//!
+//! The parser is a hand-rolled recursive-descent one over a
+//! `Peekable<Chars>`, the same shape `run_analysis::parse_condition` uses
+//! for its `cfg(...)`-style guards — `None`/parse-error here just means
+//! "this query doesn't parse," not a panic.
+
+use crate::dag::{DagGraph, DagNode};
+
+/// A parsed query expression, evaluated against one `DagNode` at a time
+/// (graph-aware variants also consult the whole `DagGraph` for
+/// dependency/edge lookups).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A field predicate, e.g. `priority > 0.7`, `status == blocked`.
+    Compare {
+        field: String,
+        op: CompareOp,
+        value: Value,
+    },
+    /// True if any entry in `depends_on` refers to a node that isn't
+    /// `complete` (including a dependency id with no matching node at
+    /// all, which can't be confirmed complete either).
+    HasIncompleteDeps,
+    /// True if no edge in the graph has this node as its source.
+    IsLeaf,
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+}
+
+/// A comparison's right-hand side. Parsed as `Num` when it looks like a
+/// float literal, `Str` otherwise (covers both barewords like `blocked`
+/// and quoted strings like `"ai"`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    Num(f32),
+}
+
+impl Expr {
+    /// Evaluate this expression against `node` within `graph`.
+    pub fn evaluate(&self, node: &DagNode, graph: &DagGraph) -> bool {
+        match self {
+            Expr::Compare { field, op, value } => evaluate_compare(node, field, *op, value),
+            Expr::HasIncompleteDeps => node.depends_on.iter().any(|dep_id| {
+                graph
+                    .nodes
+                    .iter()
+                    .find(|n| &n.id == dep_id)
+                    .map_or(true, |dep| dep.status != "complete")
+            }),
+            Expr::IsLeaf => !graph.edges.iter().any(|e| e.source == node.id),
+            Expr::And(lhs, rhs) => lhs.evaluate(node, graph) && rhs.evaluate(node, graph),
+            Expr::Or(lhs, rhs) => lhs.evaluate(node, graph) || rhs.evaluate(node, graph),
+            Expr::Not(inner) => !inner.evaluate(node, graph),
+        }
+    }
+}
+
+/// Compare `node`'s named field against `value` with `op`. An unknown
+/// field or a type mismatch (e.g. `priority == blocked`) evaluates to
+/// `false` rather than erroring — the query parsed fine, it just can't
+/// match anything for this node.
+fn evaluate_compare(node: &DagNode, field: &str, op: CompareOp, value: &Value) -> bool {
+    match field {
+        "status" => compare_str(&node.status, op, value),
+        "source" => compare_str(&node.source, op, value),
+        "effort" => compare_str(&node.effort, op, value),
+        "category" => compare_str(node.category.as_deref().unwrap_or(""), op, value),
+        "priority" => compare_num(node.priority, op, value),
+        _ => false,
+    }
+}
+
+fn compare_str(field: &str, op: CompareOp, value: &Value) -> bool {
+    let Value::Str(s) = value else { return false };
+    match op {
+        CompareOp::Eq => field == s,
+        CompareOp::Ne => field != s,
+        // Ordering on strings isn't part of this language — only `==`/`!=`
+        // are meaningful for the string fields it covers.
+        _ => false,
+    }
+}
+
+fn compare_num(field: f32, op: CompareOp, value: &Value) -> bool {
+    let Value::Num(n) = value else { return false };
+    match op {
+        CompareOp::Eq => field == *n,
+        CompareOp::Ne => field != *n,
+        CompareOp::Gt => field > *n,
+        CompareOp::Lt => field < *n,
+        CompareOp::Ge => field >= *n,
+        CompareOp::Le => field <= *n,
+    }
+}
+
+/// Parse a query string into an `Expr`. Returns `None` on anything that
+/// doesn't parse cleanly, including trailing garbage after a complete
+/// expression — callers should surface that as a user-facing error rather
+/// than guessing at what was meant.
+pub fn parse_query(input: &str) -> Option<Expr> {
+    let mut parser = QueryParser {
+        chars: input.chars().peekable(),
+    };
+    let expr = parser.parse_or()?;
+    parser.skip_ws();
+    if parser.chars.next().is_some() {
+        return None; // trailing garbage after a complete expression
+    }
+    Some(expr)
+}
+
+struct QueryParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> QueryParser<'a> {
+    fn skip_ws(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        let rest: String = self.chars.clone().collect();
+        let rest = rest.trim_start();
+        rest == keyword
+            || rest
+                .strip_prefix(keyword)
+                .is_some_and(|after| after.starts_with(|c: char| c.is_whitespace() || c == '('))
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) {
+        self.skip_ws();
+        for _ in 0..keyword.chars().count() {
+            self.chars.next();
+        }
+    }
+
+    fn parse_ident(&mut self) -> Option<String> {
+        self.skip_ws();
+        let mut ident = String::new();
+        while matches!(self.chars.peek(), Some(c) if c.is_alphanumeric() || *c == '_') {
+            ident.push(self.chars.next().unwrap());
+        }
+        if ident.is_empty() {
+            None
+        } else {
+            Some(ident)
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.chars.next(); // opening quote, already peeked by the caller
+        let mut value = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => break,
+                c => value.push(c),
+            }
+        }
+        Some(value)
+    }
+
+    /// `or_expr := and_expr ("or" and_expr)*`
+    fn parse_or(&mut self) -> Option<Expr> {
+        let mut expr = self.parse_and()?;
+        loop {
+            self.skip_ws();
+            if self.peek_keyword("or") {
+                self.consume_keyword("or");
+                let rhs = self.parse_and()?;
+                expr = Expr::Or(Box::new(expr), Box::new(rhs));
+            } else {
+                return Some(expr);
+            }
+        }
+    }
+
+    /// `and_expr := unary ("and" unary)*`
+    fn parse_and(&mut self) -> Option<Expr> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            self.skip_ws();
+            if self.peek_keyword("and") {
+                self.consume_keyword("and");
+                let rhs = self.parse_unary()?;
+                expr = Expr::And(Box::new(expr), Box::new(rhs));
+            } else {
+                return Some(expr);
+            }
+        }
+    }
+
+    /// `unary := "not" unary | atom`
+    fn parse_unary(&mut self) -> Option<Expr> {
+        self.skip_ws();
+        if self.peek_keyword("not") {
+            self.consume_keyword("not");
+            let inner = self.parse_unary()?;
+            return Some(Expr::Not(Box::new(inner)));
+        }
+        self.parse_atom()
+    }
+
+    /// `atom := "(" or_expr ")" | "has_incomplete_deps" | "is_leaf" | field cmp value`
+    fn parse_atom(&mut self) -> Option<Expr> {
+        self.skip_ws();
+        if self.chars.peek() == Some(&'(') {
+            self.chars.next();
+            let expr = self.parse_or()?;
+            self.skip_ws();
+            if self.chars.next() != Some(')') {
+                return None;
+            }
+            return Some(expr);
+        }
+
+        let field = self.parse_ident()?;
+        match field.as_str() {
+            "has_incomplete_deps" => return Some(Expr::HasIncompleteDeps),
+            "is_leaf" => return Some(Expr::IsLeaf),
+            _ => {}
+        }
+
+        let op = self.parse_compare_op()?;
+        let value = self.parse_value()?;
+        Some(Expr::Compare { field, op, value })
+    }
+
+    fn parse_compare_op(&mut self) -> Option<CompareOp> {
+        self.skip_ws();
+        let op = match (self.chars.next()?, self.chars.peek()) {
+            ('=', Some('=')) => CompareOp::Eq,
+            ('!', Some('=')) => CompareOp::Ne,
+            ('>', Some('=')) => CompareOp::Ge,
+            ('<', Some('=')) => CompareOp::Le,
+            ('>', _) => return Some(CompareOp::Gt),
+            ('<', _) => return Some(CompareOp::Lt),
+            _ => return None,
+        };
+        self.chars.next(); // consume the second half of a two-char operator
+        Some(op)
+    }
+
+    fn parse_value(&mut self) -> Option<Value> {
+        self.skip_ws();
+        if self.chars.peek() == Some(&'"') {
+            return self.parse_string().map(Value::Str);
+        }
+
+        let mut raw = String::new();
+        while matches!(self.chars.peek(), Some(c) if c.is_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            raw.push(self.chars.next().unwrap());
+        }
+        if raw.is_empty() {
+            return None;
+        }
+        match raw.parse::<f32>() {
+            Ok(n) => Some(Value::Num(n)),
+            Err(_) => Some(Value::Str(raw)),
+        }
+    }
+}
+
+/// Filter `graph` down to nodes matching `query`, with edges pruned to
+/// only those whose source and target both survived the filter.
+/// `cycles` is filtered the same way — a cycle referencing a node that
+/// didn't survive isn't a cycle in the returned subgraph.
+pub fn filter_dag(graph: &DagGraph, query: &str) -> Result<DagGraph, String> {
+    let expr = parse_query(query).ok_or_else(|| format!("Invalid query: {}", query))?;
+
+    let nodes: Vec<DagNode> = graph
+        .nodes
+        .iter()
+        .filter(|n| expr.evaluate(n, graph))
+        .cloned()
+        .collect();
+    let surviving_ids: std::collections::HashSet<&str> =
+        nodes.iter().map(|n| n.id.as_str()).collect();
+
+    let edges = graph
+        .edges
+        .iter()
+        .filter(|e| {
+            surviving_ids.contains(e.source.as_str()) && surviving_ids.contains(e.target.as_str())
+        })
+        .cloned()
+        .collect();
+    let cycles = graph
+        .cycles
+        .iter()
+        .filter(|cycle| cycle.iter().all(|id| surviving_ids.contains(id.as_str())))
+        .cloned()
+        .collect();
+
+    Ok(DagGraph {
+        nodes,
+        edges,
+        goal: graph.goal.clone(),
+        total_progress: graph.total_progress,
+        cycles,
+    })
+}
+
+/// Load a project's DAG and filter it down to nodes matching `query` — see
+/// the module doc comment for the query grammar. Lets the UI ask for, say,
+/// "ready human-sourced tasks with priority > 0.8 and no incomplete deps"
+/// instead of filtering hundreds of flat nodes client-side.
+#[tauri::command]
+pub async fn query_project_dag(path: String, query: String) -> Result<DagGraph, String> {
+    let graph = crate::dag::get_project_dag(path).await?;
+    filter_dag(&graph, &query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag::{DagEdge, DagGraph, DagNode};
+
+    fn node(id: &str, status: &str, source: &str, priority: f32, depends_on: &[&str]) -> DagNode {
+        DagNode {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: String::new(),
+            status: status.to_string(),
+            source: source.to_string(),
+            progress: 0,
+            priority,
+            effort: "medium".to_string(),
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            category: None,
+            current_action: None,
+            task_type: "create".to_string(),
+            produces: Vec::new(),
+            wave: None,
+            on_critical_path: false,
+        }
+    }
+
+    #[test]
+    fn test_parses_simple_comparison() {
+        let expr = parse_query("priority > 0.7").unwrap();
+        assert_eq!(
+            expr,
+            Expr::Compare {
+                field: "priority".to_string(),
+                op: CompareOp::Gt,
+                value: Value::Num(0.7),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_and_or_not() {
+        assert!(parse_query("status == blocked and priority > 0.5").is_some());
+        assert!(parse_query("status == blocked or is_leaf").is_some());
+        assert!(parse_query("not has_incomplete_deps").is_some());
+    }
+
+    #[test]
+    fn test_rejects_trailing_garbage() {
+        assert!(parse_query("status == blocked extra").is_none());
+    }
+
+    #[test]
+    fn test_evaluate_field_and_graph_predicates() {
+        let a = node("a", "complete", "human", 0.9, &[]);
+        let b = node("b", "pending", "ai", 0.3, &["a"]);
+        let graph = DagGraph {
+            nodes: vec![a, b],
+            edges: vec![DagEdge {
+                id: "e1".to_string(),
+                source: "a".to_string(),
+                target: "b".to_string(),
+                artifact: None,
+                edge_type: "dependency".to_string(),
+                verification_status: None,
+                integration_type: None,
+            }],
+            goal: None,
+            total_progress: 0,
+            cycles: Vec::new(),
+        };
+
+        let ready_human = parse_query("source == human and priority > 0.8").unwrap();
+        assert!(ready_human.evaluate(&graph.nodes[0], &graph));
+        assert!(!ready_human.evaluate(&graph.nodes[1], &graph));
+
+        let no_incomplete_deps = parse_query("not has_incomplete_deps").unwrap();
+        assert!(no_incomplete_deps.evaluate(&graph.nodes[0], &graph));
+        // b's only dependency, a, is complete.
+        assert!(no_incomplete_deps.evaluate(&graph.nodes[1], &graph));
+
+        let leaf = parse_query("is_leaf").unwrap();
+        assert!(!leaf.evaluate(&graph.nodes[0], &graph));
+        assert!(leaf.evaluate(&graph.nodes[1], &graph));
+    }
+
+    #[test]
+    fn test_filter_dag_prunes_edges_to_surviving_nodes() {
+        let a = node("a", "complete", "human", 0.9, &[]);
+        let b = node("b", "pending", "ai", 0.3, &["a"]);
+        let graph = DagGraph {
+            nodes: vec![a, b],
+            edges: vec![DagEdge {
+                id: "e1".to_string(),
+                source: "a".to_string(),
+                target: "b".to_string(),
+                artifact: None,
+                edge_type: "dependency".to_string(),
+                verification_status: None,
+                integration_type: None,
+            }],
+            goal: None,
+            total_progress: 0,
+            cycles: Vec::new(),
+        };
+
+        let filtered = filter_dag(&graph, "source == human").unwrap();
+        assert_eq!(filtered.nodes.len(), 1);
+        assert_eq!(filtered.nodes[0].id, "a");
+        assert!(filtered.edges.is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+//! SQLite-backed DAG store — a rebuildable, queryable mirror of the goal
+//! history (RFC-105 addendum).
+//!
+//! `dag/goals/*.json` and the append-only `dag/edges.jsonl` remain the
+//! source of truth; this module mirrors them into `dag/index.sqlite` so
+//! both cross-cutting queries ("every goal that produced artifact X",
+//! "all integration edges still unverified") and single-goal lookups by
+//! id run as indexed SQL instead of an O(files) directory scan.
+//! `append_goal_to_dag` updates the database transactionally alongside
+//! the JSON write (best-effort — a DB write failure never fails the
+//! goal append), and the `rebuild_dag_db` command repopulates it from
+//! disk when invoked — after a schema version change, which leaves the
+//! tables empty, or when the cache is suspected stale — this doubles as the one-time migration path for projects whose history
+//! predates this store. `.sunwell/dag/plans/` (the incremental-execution
+//! plan cache read by `read_latest_execution`) is out of scope here: it
+//! holds transient CLI-authored snapshots, not goal history, so it's
+//! left as plain JSON.
+//!
+//! Every read here falls back to the JSON files on any miss or error —
+//! callers should never treat an empty/`None` result as authoritative
+//! without that fallback.
+
+use crate::dag::{EdgeLogEntry, GoalNode};
+use crate::util::parse_json_safe;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Bumped whenever the schema changes; `ensure_schema` drops and recreates
+/// every table when the stored version doesn't match.
+///
+/// v2 added `goals.json`, the full serialized `GoalNode`, so a goal
+/// lookup by id can be answered from the database alone instead of
+/// falling back to `dag/goals/<id>.json` — see `load_goal`.
+const SCHEMA_VERSION: i64 = 2;
+
+fn db_path(project_path: &Path) -> std::path::PathBuf {
+    project_path.join(".sunwell/dag/index.sqlite")
+}
+
+fn open(project_path: &Path) -> Result<Connection, String> {
+    let path = db_path(project_path);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create dag directory: {}", e))?;
+    }
+    let conn = Connection::open(&path).map_err(|e| format!("Failed to open DAG store: {}", e))?;
+    ensure_schema(&conn)?;
+    Ok(conn)
+}
+
+/// Create the schema if missing, or wipe and recreate it if the stored
+/// `schema_version` doesn't match `SCHEMA_VERSION`. Callers that rebuild
+/// after a schema bump should follow with a full `rebuild_dag_db`.
+fn ensure_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute("CREATE TABLE IF NOT EXISTS meta (key TEXT PRIMARY KEY, value TEXT NOT NULL)", [])
+        .map_err(|e| format!("Failed to create meta table: {}", e))?;
+
+    let stored_version: Option<i64> = conn
+        .query_row("SELECT value FROM meta WHERE key = 'schema_version'", [], |row| row.get::<_, String>(0))
+        .ok()
+        .and_then(|v| v.parse().ok());
+
+    if stored_version != Some(SCHEMA_VERSION) {
+        conn.execute_batch(
+            "DROP TABLE IF EXISTS goals;
+             DROP TABLE IF EXISTS tasks;
+             DROP TABLE IF EXISTS artifacts;
+             DROP TABLE IF EXISTS edges;",
+        )
+        .map_err(|e| format!("Failed to reset DAG store schema: {}", e))?;
+        conn.execute(
+            "INSERT INTO meta (key, value) VALUES ('schema_version', ?1)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            [SCHEMA_VERSION.to_string()],
+        )
+        .map_err(|e| format!("Failed to record schema version: {}", e))?;
+    }
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS goals (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            description TEXT NOT NULL,
+            status TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            completed_at TEXT,
+            json TEXT NOT NULL
+         );
+         CREATE TABLE IF NOT EXISTS tasks (
+            goal_id TEXT NOT NULL,
+            id TEXT NOT NULL,
+            description TEXT NOT NULL,
+            status TEXT NOT NULL,
+            content_hash TEXT,
+            PRIMARY KEY (goal_id, id)
+         );
+         CREATE TABLE IF NOT EXISTS artifacts (
+            goal_id TEXT NOT NULL,
+            task_id TEXT NOT NULL,
+            artifact_id TEXT NOT NULL,
+            PRIMARY KEY (goal_id, task_id, artifact_id)
+         );
+         CREATE INDEX IF NOT EXISTS idx_artifacts_artifact_id ON artifacts(artifact_id);
+         CREATE TABLE IF NOT EXISTS edges (
+            id TEXT NOT NULL,
+            goal_id TEXT NOT NULL,
+            source TEXT NOT NULL,
+            target TEXT NOT NULL,
+            edge_type TEXT NOT NULL,
+            ts TEXT NOT NULL,
+            kind TEXT,
+            PRIMARY KEY (goal_id, id)
+         );
+         CREATE INDEX IF NOT EXISTS idx_edges_type ON edges(edge_type);",
+    )
+    .map_err(|e| format!("Failed to create DAG store tables: {}", e))?;
+
+    Ok(())
+}
+
+/// Upsert one completed/updated goal — its row, tasks, produced
+/// artifacts, and derived edges — inside a single transaction.
+fn upsert_goal(conn: &mut Connection, goal: &GoalNode, edges: &[EdgeLogEntry]) -> Result<(), String> {
+    let json = serde_json::to_string(goal).map_err(|e| format!("Failed to serialize goal: {}", e))?;
+    let tx = conn.transaction().map_err(|e| format!("Failed to start DAG store transaction: {}", e))?;
+
+    tx.execute(
+        "INSERT INTO goals (id, title, description, status, created_at, completed_at, json)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(id) DO UPDATE SET
+            title = excluded.title,
+            description = excluded.description,
+            status = excluded.status,
+            created_at = excluded.created_at,
+            completed_at = excluded.completed_at,
+            json = excluded.json",
+        rusqlite::params![goal.id, goal.title, goal.description, goal.status, goal.created_at, goal.completed_at, json],
+    )
+    .map_err(|e| format!("Failed to upsert goal row: {}", e))?;
+
+    tx.execute("DELETE FROM tasks WHERE goal_id = ?1", [&goal.id]).map_err(|e| format!("Failed to clear tasks: {}", e))?;
+    tx.execute("DELETE FROM artifacts WHERE goal_id = ?1", [&goal.id])
+        .map_err(|e| format!("Failed to clear artifacts: {}", e))?;
+    tx.execute("DELETE FROM edges WHERE goal_id = ?1", [&goal.id]).map_err(|e| format!("Failed to clear edges: {}", e))?;
+
+    for task in &goal.tasks {
+        tx.execute(
+            "INSERT INTO tasks (goal_id, id, description, status, content_hash) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![goal.id, task.id, task.description, task.status, task.content_hash],
+        )
+        .map_err(|e| format!("Failed to insert task row: {}", e))?;
+
+        for artifact_id in &task.produces {
+            tx.execute(
+                "INSERT INTO artifacts (goal_id, task_id, artifact_id) VALUES (?1, ?2, ?3)",
+                rusqlite::params![goal.id, task.id, artifact_id],
+            )
+            .map_err(|e| format!("Failed to insert artifact row: {}", e))?;
+        }
+    }
+
+    for edge in edges {
+        tx.execute(
+            "INSERT INTO edges (id, goal_id, source, target, edge_type, ts, kind)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![edge.id, goal.id, edge.source, edge.target, edge.edge_type, edge.ts, edge.kind],
+        )
+        .map_err(|e| format!("Failed to insert edge row: {}", e))?;
+    }
+
+    tx.commit().map_err(|e| format!("Failed to commit DAG store transaction: {}", e))
+}
+
+/// Update the DAG store for a just-appended goal. Best-effort: the JSON
+/// files under `dag/` remain the source of truth, so a store failure is
+/// logged and swallowed rather than propagated to the caller.
+pub(crate) fn sync_goal(project_path: &Path, goal: &GoalNode, edges: &[EdgeLogEntry]) {
+    let result = (|| -> Result<(), String> {
+        let mut conn = open(project_path)?;
+        upsert_goal(&mut conn, goal, edges)
+    })();
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to update DAG store for goal {}: {}", goal.id, e);
+    }
+}
+
+/// Look up a full goal by id as an indexed query instead of an O(n)
+/// directory scan. Returns `None` on any error or miss — callers should
+/// fall back to reading `dag/goals/<id>.json` directly, since the JSON
+/// files remain the source of truth and the store may be stale, absent,
+/// or mid-migration for an older project.
+pub(crate) fn load_goal(project_path: &Path, goal_id: &str) -> Option<GoalNode> {
+    let conn = open(project_path).ok()?;
+    let json: String = conn
+        .query_row("SELECT json FROM goals WHERE id = ?1", [goal_id], |row| row.get(0))
+        .ok()?;
+    parse_json_safe::<GoalNode>(&json).ok()
+}
+
+/// Goal ids (with titles) that produced a given artifact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalRef {
+    pub id: String,
+    pub title: String,
+}
+
+/// An edge row from the store, as returned by `query_unverified_edges`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeRow {
+    pub id: String,
+    pub goal_id: String,
+    pub source: String,
+    pub target: String,
+    pub edge_type: String,
+}
+
+/// A goal's place in the timeline, as returned by `query_goal_timeline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntry {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// Every goal that produced `artifact_id`, via the indexed `artifacts`
+/// table rather than scanning every goal file.
+#[tauri::command]
+pub async fn query_goals_by_artifact(path: String, artifact_id: String) -> Result<Vec<GoalRef>, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let conn = open(&project_path)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT DISTINCT g.id, g.title FROM goals g
+             JOIN artifacts a ON a.goal_id = g.id
+             WHERE a.artifact_id = ?1
+             ORDER BY g.created_at",
+        )
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let rows = stmt
+        .query_map([artifact_id], |row| Ok(GoalRef { id: row.get(0)?, title: row.get(1)? }))
+        .map_err(|e| format!("Failed to run query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Failed to read query results: {}", e))
+}
+
+/// Integration edges (RFC-067 "wire" tasks) — these start unverified in
+/// the live graph and nothing in the journal ever marks one verified,
+/// so every logged `integration` edge here is still pending review.
+/// `dag::append_goal_edges` logs only `dependency` and `produces` edges,
+/// so none of the edges it writes match this query.
+#[tauri::command]
+pub async fn query_unverified_edges(path: String) -> Result<Vec<EdgeRow>, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let conn = open(&project_path)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, goal_id, source, target, edge_type FROM edges
+             WHERE edge_type = 'integration'
+             ORDER BY ts",
+        )
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(EdgeRow { id: row.get(0)?, goal_id: row.get(1)?, source: row.get(2)?, target: row.get(3)?, edge_type: row.get(4)? })
+        })
+        .map_err(|e| format!("Failed to run query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Failed to read query results: {}", e))
+}
+
+/// Every goal in chronological order, for a history/timeline view.
+#[tauri::command]
+pub async fn query_goal_timeline(path: String) -> Result<Vec<TimelineEntry>, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let conn = open(&project_path)?;
+    let mut stmt = conn
+        .prepare("SELECT id, title, status, created_at, completed_at FROM goals ORDER BY created_at")
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(TimelineEntry {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                status: row.get(2)?,
+                created_at: row.get(3)?,
+                completed_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| format!("Failed to run query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Failed to read query results: {}", e))
+}
+
+/// Repopulate the DAG store from the on-disk `dag/goals/*.json` and
+/// `dag/edges.jsonl`, for use after a schema bump or if the cache is
+/// suspected stale. The JSON files are always authoritative, so this is
+/// always safe to run.
+#[tauri::command]
+pub async fn rebuild_dag_db(path: String) -> Result<String, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let db = db_path(&project_path);
+    if db.exists() {
+        fs::remove_file(&db).map_err(|e| format!("Failed to remove stale DAG store: {}", e))?;
+    }
+
+    let mut conn = open(&project_path)?;
+
+    let goals_dir = project_path.join(".sunwell/dag/goals");
+    let edges_path = project_path.join(".sunwell/dag/edges.jsonl");
+
+    let all_edges: Vec<EdgeLogEntry> = fs::read_to_string(&edges_path)
+        .map(|content| content.lines().filter_map(|line| parse_json_safe::<EdgeLogEntry>(line).ok()).collect())
+        .unwrap_or_default();
+
+    let mut rebuilt = 0usize;
+    if goals_dir.exists() {
+        let entries = fs::read_dir(&goals_dir).map_err(|e| format!("Failed to read dag/goals directory: {}", e))?;
+        for entry in entries.filter_map(|e| e.ok()) {
+            let Ok(content) = fs::read_to_string(entry.path()) else { continue };
+            let Ok(goal) = parse_json_safe::<GoalNode>(&content) else { continue };
+            let goal_edges: Vec<EdgeLogEntry> = all_edges.iter().filter(|e| goal_references_edge(&goal, e)).cloned().collect();
+            upsert_goal(&mut conn, &goal, &goal_edges)?;
+            rebuilt += 1;
+        }
+    }
+
+    Ok(format!("Rebuilt DAG store from {} goal(s)", rebuilt))
+}
+
+/// Whether a logged edge belongs to this goal — its source or target is
+/// one of the goal's own task ids, or its target is an artifact one of
+/// those tasks produces.
+fn goal_references_edge(goal: &GoalNode, edge: &EdgeLogEntry) -> bool {
+    goal.tasks.iter().any(|t| t.id == edge.source || t.id == edge.target || t.produces.iter().any(|p| p == &edge.target))
+}
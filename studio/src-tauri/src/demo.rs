@@ -3,12 +3,16 @@
 //! Tauri commands for running the real demo comparison.
 //! Calls `sunwell demo --json` and streams results to frontend.
 
+use crate::commands::AppState;
 use crate::error::{ErrorCode, SunwellError};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::process::Stdio;
-use tauri::{Emitter, Window};
-use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, State, Window};
+use tokio::io::AsyncReadExt;
+use tokio::process::{Child, Command};
+use tokio::sync::{Notify, Semaphore};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // TYPES — Match Python `sunwell demo --json` output exactly
@@ -179,6 +183,85 @@ fn default_verbose() -> bool {
     true // Always request code by default
 }
 
+/// Result of a finished `run_demo_streaming` call: the token the run was
+/// registered under (also sent in the `demo-started` event when the run
+/// begins), plus the comparison. A cancelled run returns an error instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoRunResult {
+    pub token: String,
+    pub comparison: DemoComparison,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// CANCELLATION — active demo runs, keyed by run token
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Handle to an in-flight demo run, shared between `run_demo_streaming`
+/// (which owns the read loop) and `cancel_demo` (which kills the child).
+struct DemoHandle {
+    child: Arc<Mutex<Option<Child>>>,
+    cancel: Arc<Notify>,
+}
+
+/// Tauri-managed state tracking active demo runs by token, so a run can be
+/// cancelled from a separate command invocation after it started. Mirrors
+/// the session-map pattern used for agent runs in `agent::AgentBridge`.
+#[derive(Default)]
+pub struct DemoManager {
+    handles: Mutex<HashMap<String, DemoHandle>>,
+}
+
+impl DemoManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn register(&self, token: String, child: Arc<Mutex<Option<Child>>>, cancel: Arc<Notify>) {
+        if let Ok(mut handles) = self.handles.lock() {
+            handles.insert(token, DemoHandle { child, cancel });
+        }
+    }
+
+    fn unregister(&self, token: &str) {
+        if let Ok(mut handles) = self.handles.lock() {
+            handles.remove(token);
+        }
+    }
+
+    /// Cancel the demo run registered under `token`: wake its read loop via
+    /// the cancellation `Notify` and kill the child process.
+    pub async fn cancel(&self, token: &str) -> Result<(), SunwellError> {
+        let handle = self
+            .handles
+            .lock()
+            .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo handle map lock poisoned"))?
+            .get(token)
+            .map(|h| (h.child.clone(), h.cancel.clone()));
+
+        let (child_slot, cancel) = handle
+            .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, format!("No such demo run: {}", token)))?;
+
+        cancel.notify_one();
+
+        let child = child_slot.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = child {
+            let _ = child.kill().await;
+        }
+
+        Ok(())
+    }
+}
+
+/// Generate a unique token identifying a demo run, so `cancel_demo` can
+/// address it while `run_demo_streaming` is still in flight.
+fn new_demo_token() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos =
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    format!("demo-{:x}-{:x}", nanos, seq)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // COMMANDS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -191,25 +274,28 @@ fn default_verbose() -> bool {
 pub async fn run_demo_streaming(
     window: Window,
     input: DemoInput,
-) -> Result<DemoComparison, SunwellError> {
+    state: State<'_, AppState>,
+) -> Result<DemoRunResult, SunwellError> {
+    let token = new_demo_token();
+
     // Build command arguments - use --stream for NDJSON output
     let mut args = vec!["demo".to_string(), "--stream".to_string()];
-    
+
     if let Some(task) = &input.task {
         args.push("--task".to_string());
         args.push(task.clone());
     }
-    
+
     if let Some(model) = &input.model {
         args.push("--model".to_string());
         args.push(model.clone());
     }
-    
+
     if let Some(provider) = &input.provider {
         args.push("--provider".to_string());
         args.push(provider.clone());
     }
-    
+
     // Start subprocess with streaming
     let mut child = Command::new("sunwell")
         .args(&args)
@@ -217,84 +303,167 @@ pub async fn run_demo_streaming(
         .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
-    
+    let stdout = child.stdout.take();
+
+    // Register the child and a cancellation signal so `cancel_demo` can
+    // address this run by token while it's still in flight.
+    let child_slot = Arc::new(Mutex::new(Some(child)));
+    let cancel = Arc::new(Notify::new());
+    state.demos.register(token.clone(), child_slot.clone(), cancel.clone());
+    // The return value only arrives once the run is over, so hand the
+    // token to the frontend now.
+    let _ = window.emit("demo-started", serde_json::json!({ "token": token }));
+
     // Emit starting progress
     let _ = window.emit("demo-progress", DemoProgress {
         phase: "starting".to_string(),
         message: "Starting parallel demo...".to_string(),
         progress: 0.0,
     });
-    
+
     let mut final_result: Option<DemoComparison> = None;
-    
-    // Read stdout line by line (NDJSON)
-    if let Some(stdout) = child.stdout.take() {
-        let reader = BufReader::new(stdout);
-        let mut lines = reader.lines();
-        
-        while let Ok(Some(line)) = lines.next_line().await {
-            // Skip empty lines
-            if line.trim().is_empty() {
-                continue;
-            }
-            
-            // Parse NDJSON event
-            match serde_json::from_str::<DemoStreamEvent>(&line) {
-                Ok(event) => {
-                    match &event {
-                        DemoStreamEvent::Start { model, task } => {
-                            let _ = window.emit("demo-start", serde_json::json!({
-                                "model": model,
-                                "task": task,
-                            }));
-                        }
-                        DemoStreamEvent::Chunk { method, content } => {
-                            let _ = window.emit("demo-chunk", serde_json::json!({
-                                "method": method,
-                                "content": content,
-                            }));
-                        }
-                        DemoStreamEvent::Phase { method, phase } => {
-                            let _ = window.emit("demo-phase", serde_json::json!({
-                                "method": method,
-                                "phase": phase,
-                            }));
-                        }
-                        DemoStreamEvent::Complete(comparison) => {
-                            final_result = Some(*comparison.clone());
-                            let _ = window.emit("demo-complete", comparison.as_ref());
-                        }
-                        DemoStreamEvent::Error { message } => {
-                            let _ = window.emit("demo-error", serde_json::json!({
-                                "message": message,
-                            }));
+    let mut cancelled = false;
+
+    // Read stdout as raw bytes and frame on `\n` ourselves instead of using
+    // `BufReader::lines()`, which bails out (and silently drops the rest of
+    // the stream, including the terminal `Complete` event) the moment a
+    // single read contains invalid UTF-8 or a line split across OS read
+    // boundaries arrives incomplete. Drive it inside a `select!` against
+    // the cancellation signal so `cancel_demo` can interrupt it mid-read.
+    if let Some(mut stdout) = stdout {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            tokio::select! {
+                read_result = stdout.read(&mut chunk) => {
+                    let bytes_read = match read_result {
+                        Ok(0) => break,
+                        Ok(n) => n,
+                        Err(_) => break,
+                    };
+                    buffer.extend_from_slice(&chunk[..bytes_read]);
+
+                    while let Some(newline_pos) = buffer.iter().position(|&b| b == b'\n') {
+                        let frame: Vec<u8> = buffer.drain(..=newline_pos).collect();
+                        // Drop the trailing newline before decoding.
+                        let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+
+                        if line.trim().is_empty() {
+                            continue;
                         }
+
+                        handle_demo_frame(&window, &line, &mut final_result);
                     }
                 }
-                Err(e) => {
-                    // Log parse error but continue
-                    eprintln!("Failed to parse NDJSON line: {} - {}", e, line);
+                _ = cancel.notified() => {
+                    cancelled = true;
+                    break;
                 }
             }
         }
+
+        // The stream may end without a trailing newline; process whatever
+        // is left in the buffer as a final frame.
+        if !cancelled && !buffer.is_empty() {
+            let line = String::from_utf8_lossy(&buffer).into_owned();
+            if !line.trim().is_empty() {
+                handle_demo_frame(&window, &line, &mut final_result);
+            }
+        }
+    }
+
+    state.demos.unregister(&token);
+
+    if cancelled {
+        // `DemoManager::cancel` already tries to kill the child, but guard
+        // against a race where this loop broke before that landed.
+        let remaining_child = child_slot.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = remaining_child {
+            let _ = child.kill().await;
+        }
+        let _ = window.emit("demo-cancelled", serde_json::json!({ "token": token }));
+        return Err(SunwellError::new(ErrorCode::RuntimeCancelled, "Demo run was cancelled"));
     }
-    
+
+    let taken_child = child_slot
+        .lock()
+        .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo child lock poisoned"))?
+        .take();
+    let mut child = taken_child
+        .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo child handle missing"))?;
+
     // Wait for completion
     let status = child.wait().await
         .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
-    
+
     if !status.success() {
         return Err(SunwellError::new(
             ErrorCode::RuntimeProcessFailed,
             "Demo execution failed",
         ));
     }
-    
+
     // Return final result
-    final_result.ok_or_else(|| SunwellError::new(
+    let comparison = final_result.ok_or_else(|| SunwellError::new(
         ErrorCode::ConfigInvalid,
         "No complete event received from demo stream",
-    ))
+    ))?;
+
+    Ok(DemoRunResult { token, comparison })
+}
+
+/// Cancel an in-flight demo run registered under `token` (see
+/// `run_demo_streaming`): wakes its read loop and kills the child process.
+#[tauri::command]
+pub async fn cancel_demo(token: String, state: State<'_, AppState>) -> Result<(), SunwellError> {
+    state.demos.cancel(&token).await
+}
+
+/// Parse and dispatch a single decoded NDJSON frame from `sunwell demo
+/// --stream`, emitting a `demo-error` event (rather than just logging)
+/// when the frame fails to parse as a `DemoStreamEvent`.
+fn handle_demo_frame(window: &Window, line: &str, final_result: &mut Option<DemoComparison>) {
+    match serde_json::from_str::<DemoStreamEvent>(line) {
+        Ok(event) => match &event {
+            DemoStreamEvent::Start { model, task } => {
+                let _ = window.emit("demo-start", serde_json::json!({
+                    "model": model,
+                    "task": task,
+                }));
+            }
+            DemoStreamEvent::Chunk { method, content } => {
+                let _ = window.emit("demo-chunk", serde_json::json!({
+                    "method": method,
+                    "content": content,
+                }));
+            }
+            DemoStreamEvent::Phase { method, phase } => {
+                let _ = window.emit("demo-phase", serde_json::json!({
+                    "method": method,
+                    "phase": phase,
+                }));
+            }
+            DemoStreamEvent::Complete(comparison) => {
+                *final_result = Some(*comparison.clone());
+                let _ = window.emit("demo-complete", comparison.as_ref());
+            }
+            DemoStreamEvent::Error { message } => {
+                let _ = window.emit("demo-error", serde_json::json!({
+                    "message": message,
+                }));
+            }
+        },
+        Err(e) => {
+            // A frame that fails to parse still needs to reach the
+            // frontend as a `demo-error` rather than only `eprintln!`,
+            // so the UI doesn't go silent on a malformed/non-UTF-8 frame.
+            let _ = window.emit("demo-error", serde_json::json!({
+                "message": format!("Failed to parse NDJSON frame: {}", e),
+            }));
+            eprintln!("Failed to parse NDJSON line: {} - {}", e, line);
+        }
+    }
 }
 
 /// List available demo tasks.
@@ -354,3 +523,411 @@ pub async fn list_demo_tasks() -> Result<Vec<DemoTask>, SunwellError> {
         },
     ])
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// WORKLOADS — Batch demo runs for aggregated benchmark reports
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One entry in a demo workload file: which task to compare, against which
+/// model/provider, and how many times to repeat it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoWorkloadEntry {
+    pub task: Option<String>,
+    pub model: Option<String>,
+    pub provider: Option<String>,
+    #[serde(default = "default_workload_runs")]
+    pub runs: u32,
+}
+
+fn default_workload_runs() -> u32 {
+    1
+}
+
+/// A demo workload file: a named batch of task/model combinations, modeled
+/// on Meilisearch's `xtask bench` workload files. Meant to be committed to
+/// a repo so teams can re-run the same benchmark suite across models.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoWorkload {
+    pub name: String,
+    pub entries: Vec<DemoWorkloadEntry>,
+    /// When set, the aggregated report is POSTed here as JSON so teams can
+    /// track the Prism Principle's improvement over time.
+    pub report_url: Option<String>,
+}
+
+/// Progress event emitted once per run as `run_demo_workload` works
+/// through the list; each repeat of an entry counts as its own run, and
+/// `index`/`total` count runs, not entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoWorkloadProgress {
+    pub index: u32,
+    pub total: u32,
+    pub task: Option<String>,
+    pub model: Option<String>,
+}
+
+/// Aggregate stats for one task/model entry across its repeated runs.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WorkloadEntryStats {
+    pub runs: u32,
+    pub avg_improvement: f64,
+    /// Variance of `improvement_percent` across this entry's repeats —
+    /// high variance means a single run isn't trustworthy on its own.
+    pub improvement_variance: f64,
+    pub sunwell_win_rate: f64,
+    pub avg_sunwell_time_ms: f64,
+    pub avg_single_shot_time_ms: f64,
+    pub avg_sunwell_tokens: f64,
+    pub avg_single_shot_tokens: f64,
+}
+
+/// Aggregate stats across an entire workload run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoWorkloadStats {
+    pub total_runs: u32,
+    pub avg_improvement: f64,
+    pub sunwell_wins: u32,
+    pub single_shot_wins: u32,
+    pub ties: u32,
+    /// Keyed by `"{task}@{model}"`.
+    #[serde(default)]
+    pub by_entry: HashMap<String, WorkloadEntryStats>,
+}
+
+/// Result of running an entire demo workload: every individual comparison
+/// plus the aggregate stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemoWorkloadReport {
+    pub name: String,
+    pub comparisons: Vec<DemoComparison>,
+    pub stats: DemoWorkloadStats,
+}
+
+/// Run every entry in `workload` (repeated `entry.runs` times each) via the
+/// existing `run_demo_streaming` path, aggregate the results into
+/// per-entry and overall stats — mean improvement, Sunwell win-rate, mean
+/// time/tokens, and variance across repeats — and, when `report_url` is
+/// set, POST the aggregated report to that server. Emits a
+/// `demo-workload-progress` event before each run starts.
+#[tauri::command]
+pub async fn run_demo_workload(
+    window: Window,
+    workload: DemoWorkload,
+    state: State<'_, AppState>,
+) -> Result<DemoWorkloadReport, SunwellError> {
+    let mut comparisons: Vec<DemoComparison> = Vec::new();
+    let total: u32 = workload.entries.iter().map(|e| e.runs.max(1)).sum();
+    let mut index = 0u32;
+
+    for entry in &workload.entries {
+        for _ in 0..entry.runs.max(1) {
+            index += 1;
+            let _ = window.emit("demo-workload-progress", DemoWorkloadProgress {
+                index,
+                total,
+                task: entry.task.clone(),
+                model: entry.model.clone(),
+            });
+
+            let input = DemoInput {
+                task: entry.task.clone(),
+                model: entry.model.clone(),
+                provider: entry.provider.clone(),
+                verbose: false,
+            };
+
+            match run_demo_streaming(window.clone(), input, state.clone()).await {
+                Ok(result) => comparisons.push(result.comparison),
+                Err(e) => {
+                    eprintln!("Workload entry {:?}/{:?} failed: {}", entry.task, entry.model, e);
+                }
+            }
+        }
+    }
+
+    let stats = aggregate_workload_stats(&comparisons);
+    let report = DemoWorkloadReport { name: workload.name.clone(), comparisons, stats };
+
+    if let Some(report_url) = &workload.report_url {
+        post_workload_report(report_url, &report).await?;
+    }
+
+    Ok(report)
+}
+
+/// Fold comparisons into per-entry (`task@model`) and overall stats.
+fn aggregate_workload_stats(comparisons: &[DemoComparison]) -> DemoWorkloadStats {
+    let mut by_entry: HashMap<String, Vec<&DemoComparison>> = HashMap::new();
+    let mut sunwell_wins = 0;
+    let mut single_shot_wins = 0;
+    let mut ties = 0;
+    let mut improvement_total = 0.0;
+
+    for comparison in comparisons {
+        improvement_total += comparison.improvement_percent;
+        if comparison.sunwell.score > comparison.single_shot.score {
+            sunwell_wins += 1;
+        } else if comparison.sunwell.score < comparison.single_shot.score {
+            single_shot_wins += 1;
+        } else {
+            ties += 1;
+        }
+
+        let key = format!("{}@{}", comparison.task.name, comparison.model);
+        by_entry.entry(key).or_default().push(comparison);
+    }
+
+    let by_entry = by_entry.into_iter().map(|(key, runs)| (key, entry_stats_from_runs(&runs))).collect();
+
+    DemoWorkloadStats {
+        total_runs: comparisons.len() as u32,
+        avg_improvement: if comparisons.is_empty() { 0.0 } else { improvement_total / comparisons.len() as f64 },
+        sunwell_wins,
+        single_shot_wins,
+        ties,
+        by_entry,
+    }
+}
+
+/// Compute mean improvement/time/tokens and the variance of
+/// `improvement_percent` across a single entry's repeated runs.
+fn entry_stats_from_runs(runs: &[&DemoComparison]) -> WorkloadEntryStats {
+    let n = runs.len() as f64;
+    let avg_improvement = runs.iter().map(|r| r.improvement_percent).sum::<f64>() / n;
+    let improvement_variance = runs
+        .iter()
+        .map(|r| {
+            let diff = r.improvement_percent - avg_improvement;
+            diff * diff
+        })
+        .sum::<f64>()
+        / n;
+    let sunwell_win_rate = runs.iter().filter(|r| r.sunwell.score > r.single_shot.score).count() as f64 / n;
+    let avg_sunwell_time_ms = runs.iter().map(|r| r.sunwell.time_ms as f64).sum::<f64>() / n;
+    let avg_single_shot_time_ms = runs.iter().map(|r| r.single_shot.time_ms as f64).sum::<f64>() / n;
+    let avg_sunwell_tokens =
+        runs.iter().map(|r| r.sunwell.tokens.as_ref().map(|t| t.total as f64).unwrap_or(0.0)).sum::<f64>() / n;
+    let avg_single_shot_tokens =
+        runs.iter().map(|r| r.single_shot.tokens.as_ref().map(|t| t.total as f64).unwrap_or(0.0)).sum::<f64>() / n;
+
+    WorkloadEntryStats {
+        runs: runs.len() as u32,
+        avg_improvement,
+        improvement_variance,
+        sunwell_win_rate,
+        avg_sunwell_time_ms,
+        avg_single_shot_time_ms,
+        avg_sunwell_tokens,
+        avg_single_shot_tokens,
+    }
+}
+
+/// POST the aggregated workload report as JSON to `report_url`.
+async fn post_workload_report(report_url: &str, report: &DemoWorkloadReport) -> Result<(), SunwellError> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(report_url)
+        .json(report)
+        .send()
+        .await
+        .map_err(|e| SunwellError::new(ErrorCode::NetworkUnreachable, format!("Failed to reach report server: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::NetworkUnreachable,
+            format!("Report server returned status {}", response.status()),
+        ));
+    }
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// PARALLEL EXECUTION — bounded-concurrency fan-out across multiple tasks
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One slot's result within a `run_demos_parallel` batch, keyed by the
+/// stable `index` the frontend used to route that slot's
+/// `demo-progress`/`demo-chunk`/`demo-complete` events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedDemoResult {
+    pub index: usize,
+    pub comparison: Option<DemoComparison>,
+    pub error: Option<String>,
+}
+
+/// Run several `DemoTask`s concurrently, bounded by `concurrency` (defaults
+/// to the number of logical CPUs) via a `Semaphore` so at most
+/// `concurrency` `sunwell` children run at once. Every emitted
+/// `demo-progress`/`demo-chunk`/`demo-complete`/`demo-error` event carries
+/// the originating input's `index` (its position in `inputs`) so the
+/// frontend can render a grid of simultaneous comparisons instead of one
+/// stream at a time.
+#[tauri::command]
+pub async fn run_demos_parallel(
+    window: Window,
+    inputs: Vec<DemoInput>,
+    concurrency: Option<usize>,
+) -> Result<Vec<IndexedDemoResult>, SunwellError> {
+    let limit = concurrency.unwrap_or_else(num_cpus::get).max(1);
+    let semaphore = Arc::new(Semaphore::new(limit));
+
+    let mut handles = Vec::with_capacity(inputs.len());
+    for (index, input) in inputs.into_iter().enumerate() {
+        let window = window.clone();
+        let semaphore = semaphore.clone();
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await.ok();
+            run_single_demo_indexed(&window, input, index).await
+        }));
+    }
+
+    let mut results = Vec::with_capacity(handles.len());
+    for (index, handle) in handles.into_iter().enumerate() {
+        match handle.await {
+            Ok(Ok(comparison)) => {
+                results.push(IndexedDemoResult { index, comparison: Some(comparison), error: None })
+            }
+            Ok(Err(e)) => results.push(IndexedDemoResult { index, comparison: None, error: Some(e.to_string()) }),
+            Err(e) => {
+                results.push(IndexedDemoResult { index, comparison: None, error: Some(format!("Task panicked: {}", e)) })
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Run a single demo comparison for one slot of a `run_demos_parallel`
+/// batch. Mirrors `run_demo_streaming`'s byte-framed read loop but isn't
+/// registered with `DemoManager` — parallel batches aren't individually
+/// cancellable yet — and tags every emitted event with `index`.
+async fn run_single_demo_indexed(
+    window: &Window,
+    input: DemoInput,
+    index: usize,
+) -> Result<DemoComparison, SunwellError> {
+    let mut args = vec!["demo".to_string(), "--stream".to_string()];
+
+    if let Some(task) = &input.task {
+        args.push("--task".to_string());
+        args.push(task.clone());
+    }
+    if let Some(model) = &input.model {
+        args.push("--model".to_string());
+        args.push(model.clone());
+    }
+    if let Some(provider) = &input.provider {
+        args.push("--provider".to_string());
+        args.push(provider.clone());
+    }
+
+    let mut child = Command::new("sunwell")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+
+    let _ = window.emit("demo-progress", serde_json::json!({
+        "index": index,
+        "phase": "starting",
+        "message": "Starting demo...",
+        "progress": 0.0,
+    }));
+
+    let mut final_result: Option<DemoComparison> = None;
+
+    if let Some(mut stdout) = child.stdout.take() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            let bytes_read = match stdout.read(&mut chunk).await {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(_) => break,
+            };
+            buffer.extend_from_slice(&chunk[..bytes_read]);
+
+            while let Some(newline_pos) = buffer.iter().position(|&b| b == b'\n') {
+                let frame: Vec<u8> = buffer.drain(..=newline_pos).collect();
+                let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+                if line.trim().is_empty() {
+                    continue;
+                }
+                handle_demo_frame_indexed(window, &line, &mut final_result, index);
+            }
+        }
+
+        if !buffer.is_empty() {
+            let line = String::from_utf8_lossy(&buffer).into_owned();
+            if !line.trim().is_empty() {
+                handle_demo_frame_indexed(window, &line, &mut final_result, index);
+            }
+        }
+    }
+
+    let status =
+        child.wait().await.map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+    if !status.success() {
+        return Err(SunwellError::new(ErrorCode::RuntimeProcessFailed, "Demo execution failed"));
+    }
+
+    final_result.ok_or_else(|| {
+        SunwellError::new(ErrorCode::ConfigInvalid, "No complete event received from demo stream")
+    })
+}
+
+/// Parse and dispatch a single decoded NDJSON frame for one slot of a
+/// `run_demos_parallel` batch — same event names as `handle_demo_frame`,
+/// but every payload carries `index` so the frontend can route it to the
+/// right grid cell.
+fn handle_demo_frame_indexed(window: &Window, line: &str, final_result: &mut Option<DemoComparison>, index: usize) {
+    match serde_json::from_str::<DemoStreamEvent>(line) {
+        Ok(event) => match &event {
+            DemoStreamEvent::Start { model, task } => {
+                let _ = window.emit("demo-progress", serde_json::json!({
+                    "index": index,
+                    "phase": "start",
+                    "model": model,
+                    "task": task,
+                }));
+            }
+            DemoStreamEvent::Chunk { method, content } => {
+                let _ = window.emit("demo-chunk", serde_json::json!({
+                    "index": index,
+                    "method": method,
+                    "content": content,
+                }));
+            }
+            DemoStreamEvent::Phase { method, phase } => {
+                let _ = window.emit("demo-progress", serde_json::json!({
+                    "index": index,
+                    "phase": phase,
+                    "method": method,
+                }));
+            }
+            DemoStreamEvent::Complete(comparison) => {
+                *final_result = Some(*comparison.clone());
+                let _ = window.emit("demo-complete", serde_json::json!({
+                    "index": index,
+                    "comparison": comparison.as_ref(),
+                }));
+            }
+            DemoStreamEvent::Error { message } => {
+                let _ = window.emit("demo-error", serde_json::json!({
+                    "index": index,
+                    "message": message,
+                }));
+            }
+        },
+        Err(e) => {
+            let _ = window.emit("demo-error", serde_json::json!({
+                "index": index,
+                "message": format!("Failed to parse NDJSON frame: {}", e),
+            }));
+            eprintln!("Failed to parse NDJSON line (index {}): {} - {}", index, e, line);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! Long-lived demo session transport — seq-keyed request/response channel
+//! with capability negotiation (RFC-095 addendum).
+//!
+//! `run_demo_streaming`/`run_demos_parallel` shell out to `sunwell demo`
+//! once and read its output to completion. This module instead keeps a
+//! `sunwell demo --session` child alive and talks to it over a framed
+//! NDJSON request/response protocol, so the frontend can issue mid-run
+//! commands (pause, snapshot intermediate code, retune the judge
+//! threshold) against a process that's still refining. Modeled on the DAP
+//! client's seq-keyed transport and `initialize`/`Capabilities` handshake
+//! (as in helix-dap).
+
+use crate::commands::AppState;
+use crate::demo::{DemoInput, DemoStreamEvent};
+use crate::error::{ErrorCode, SunwellError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, State, Window};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, ChildStdout, Command};
+use tokio::sync::oneshot;
+
+/// One frame of the session wire protocol. `Request`s are sent by us,
+/// `Response`s answer a specific request by `request_seq`, and `Event`s
+/// are unsolicited — the same `DemoStreamEvent`s `run_demo_streaming`
+/// consumes, just delivered over the persistent channel instead of a
+/// one-shot stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum TransportMessage {
+    Request {
+        seq: u64,
+        command: String,
+        #[serde(default)]
+        arguments: serde_json::Value,
+    },
+    Response {
+        seq: u64,
+        request_seq: u64,
+        command: String,
+        success: bool,
+        #[serde(default)]
+        body: Option<serde_json::Value>,
+        #[serde(default)]
+        message: Option<String>,
+    },
+    Event {
+        seq: u64,
+        event: DemoStreamEvent,
+    },
+}
+
+/// The resolved outcome of a request, handed back through its `oneshot`.
+struct TransportResponse {
+    success: bool,
+    body: Option<serde_json::Value>,
+    message: Option<String>,
+}
+
+/// What the running `sunwell demo --session` process supports. The
+/// frontend enables only the controls a given session actually implements
+/// instead of guessing from the Sunwell version.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Capabilities {
+    #[serde(default)]
+    pub supports_pause: bool,
+    #[serde(default)]
+    pub supports_intermediate_code: bool,
+    #[serde(default)]
+    pub supports_threshold_override: bool,
+}
+
+/// A live request/response channel to one `sunwell demo --session`
+/// child: writes monotonically-`seq`'d requests to its stdin and matches
+/// replies read from its stdout against a map of pending `oneshot`
+/// senders, forwarding any unsolicited `Event` frame to the window.
+struct Transport {
+    child: Mutex<Option<Child>>,
+    stdin: tokio::sync::Mutex<tokio::process::ChildStdin>,
+    next_seq: AtomicU64,
+    pending: Mutex<HashMap<u64, oneshot::Sender<TransportResponse>>>,
+}
+
+impl Transport {
+    /// Spawn `sunwell <args>` with piped stdio and start the background
+    /// read loop that dispatches responses/events as they arrive.
+    async fn spawn(window: Window, args: &[String]) -> Result<Arc<Self>, SunwellError> {
+        let mut child = Command::new("sunwell")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo session process has no stdin"))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo session process has no stdout"))?;
+
+        let transport = Arc::new(Transport {
+            child: Mutex::new(Some(child)),
+            stdin: tokio::sync::Mutex::new(stdin),
+            next_seq: AtomicU64::new(1),
+            pending: Mutex::new(HashMap::new()),
+        });
+
+        let reader = transport.clone();
+        tokio::spawn(async move {
+            reader.read_loop(window, stdout).await;
+        });
+
+        Ok(transport)
+    }
+
+    /// Read NDJSON frames from the child's stdout until it closes,
+    /// dispatching each to a pending request or the window.
+    async fn read_loop(&self, window: Window, mut stdout: ChildStdout) {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            let bytes_read = match stdout.read(&mut chunk).await {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(_) => break,
+            };
+            buffer.extend_from_slice(&chunk[..bytes_read]);
+
+            while let Some(newline_pos) = buffer.iter().position(|&b| b == b'\n') {
+                let frame: Vec<u8> = buffer.drain(..=newline_pos).collect();
+                let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+                if line.trim().is_empty() {
+                    continue;
+                }
+                self.dispatch_line(&window, &line);
+            }
+        }
+
+        // Drop every waiting sender so in-flight requests fail with
+        // "closed before responding" instead of waiting forever.
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.clear();
+        }
+    }
+
+    fn dispatch_line(&self, window: &Window, line: &str) {
+        match serde_json::from_str::<TransportMessage>(line) {
+            Ok(TransportMessage::Response { request_seq, success, body, message, .. }) => {
+                let sender = self.pending.lock().ok().and_then(|mut pending| pending.remove(&request_seq));
+                if let Some(sender) = sender {
+                    let _ = sender.send(TransportResponse { success, body, message });
+                }
+            }
+            Ok(TransportMessage::Event { event, .. }) => emit_transport_event(window, &event),
+            Ok(TransportMessage::Request { command, .. }) => {
+                // The session process only answers requests we send; it
+                // never issues its own over this channel.
+                eprintln!("Demo session sent an unexpected request frame: {}", command);
+            }
+            Err(e) => {
+                eprintln!("Failed to parse demo session frame: {} - {}", e, line);
+            }
+        }
+    }
+
+    /// Send `command` with `arguments`, wait for its matching response,
+    /// and resolve to the response body (or an error if `success` was
+    /// false).
+    async fn send_request(&self, command: &str, arguments: serde_json::Value) -> Result<serde_json::Value, SunwellError> {
+        let seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo session pending-request map lock poisoned"))?
+            .insert(seq, tx);
+
+        let request = TransportMessage::Request { seq, command: command.to_string(), arguments };
+        let mut line = serde_json::to_string(&request)
+            .map_err(|e| SunwellError::new(ErrorCode::RuntimeStateInvalid, format!("Failed to encode demo session request: {}", e)))?;
+        line.push('\n');
+
+        {
+            let mut stdin = self.stdin.lock().await;
+            stdin
+                .write_all(line.as_bytes())
+                .await
+                .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+        }
+
+        let response = rx
+            .await
+            .map_err(|_| SunwellError::new(ErrorCode::RuntimeProcessFailed, "Demo session closed before responding"))?;
+
+        if !response.success {
+            return Err(SunwellError::new(
+                ErrorCode::RuntimeProcessFailed,
+                response.message.unwrap_or_else(|| format!("Demo session request '{}' failed", command)),
+            ));
+        }
+
+        Ok(response.body.unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Kill the session's child process, if it hasn't already exited.
+    async fn kill(&self) {
+        let child = self.child.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = child {
+            let _ = child.kill().await;
+        }
+    }
+}
+
+/// Forward an unsolicited session event to the window under the same
+/// event names `run_demo_streaming`'s `handle_demo_frame` uses, so the
+/// frontend doesn't need a separate listener set for session mode.
+fn emit_transport_event(window: &Window, event: &DemoStreamEvent) {
+    match event {
+        DemoStreamEvent::Start { model, task } => {
+            let _ = window.emit("demo-start", serde_json::json!({ "model": model, "task": task }));
+        }
+        DemoStreamEvent::Chunk { method, content } => {
+            let _ = window.emit("demo-chunk", serde_json::json!({ "method": method, "content": content }));
+        }
+        DemoStreamEvent::Phase { method, phase } => {
+            let _ = window.emit("demo-phase", serde_json::json!({ "method": method, "phase": phase }));
+        }
+        DemoStreamEvent::Complete(comparison) => {
+            let _ = window.emit("demo-complete", comparison.as_ref());
+        }
+        DemoStreamEvent::Error { message } => {
+            let _ = window.emit("demo-error", serde_json::json!({ "message": message }));
+        }
+    }
+}
+
+/// One persistent demo session: its transport plus the capabilities it
+/// negotiated on `initialize`.
+pub struct DemoSession {
+    transport: Arc<Transport>,
+    pub capabilities: Capabilities,
+}
+
+/// Tauri-managed state tracking active demo sessions by token, so
+/// follow-up commands (`pause_refinement`, `request_intermediate_code`,
+/// `set_judge_threshold`, `stop_demo_session`) can address a session
+/// started by `start_demo_session`. Mirrors `demo::DemoManager`'s
+/// token-keyed map.
+#[derive(Default)]
+pub struct DemoSessionManager {
+    sessions: Mutex<HashMap<String, Arc<DemoSession>>>,
+}
+
+impl DemoSessionManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn register(&self, token: String, session: Arc<DemoSession>) {
+        if let Ok(mut sessions) = self.sessions.lock() {
+            sessions.insert(token, session);
+        }
+    }
+
+    fn get(&self, token: &str) -> Result<Arc<DemoSession>, SunwellError> {
+        self.sessions
+            .lock()
+            .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Demo session map lock poisoned"))?
+            .get(token)
+            .cloned()
+            .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, format!("No such demo session: {}", token)))
+    }
+
+    async fn stop(&self, token: &str) -> Result<(), SunwellError> {
+        let session = self.get(token)?;
+        session.transport.kill().await;
+        if let Ok(mut sessions) = self.sessions.lock() {
+            sessions.remove(token);
+        }
+        Ok(())
+    }
+}
+
+/// Generate a unique token identifying a demo session.
+fn new_session_token() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos =
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, Ordering::SeqCst);
+    format!("demo-session-{:x}-{:x}", nanos, seq)
+}
+
+/// Result of starting a demo session: the token later commands address it
+/// by, plus the capabilities it negotiated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartSessionResult {
+    pub token: String,
+    pub capabilities: Capabilities,
+}
+
+/// Start a persistent `sunwell demo --session` process for `input` and
+/// negotiate capabilities via an `initialize` request.
+#[tauri::command]
+pub async fn start_demo_session(
+    window: Window,
+    input: DemoInput,
+    state: State<'_, AppState>,
+) -> Result<StartSessionResult, SunwellError> {
+    let mut args = vec!["demo".to_string(), "--session".to_string()];
+
+    if let Some(task) = &input.task {
+        args.push("--task".to_string());
+        args.push(task.clone());
+    }
+    if let Some(model) = &input.model {
+        args.push("--model".to_string());
+        args.push(model.clone());
+    }
+    if let Some(provider) = &input.provider {
+        args.push("--provider".to_string());
+        args.push(provider.clone());
+    }
+
+    let transport = Transport::spawn(window, &args).await?;
+
+    let init_body = transport.send_request("initialize", serde_json::json!({})).await?;
+    let capabilities: Capabilities = serde_json::from_value(init_body).unwrap_or_default();
+
+    let token = new_session_token();
+    state.demo_sessions.register(token.clone(), Arc::new(DemoSession { transport, capabilities: capabilities.clone() }));
+
+    Ok(StartSessionResult { token, capabilities })
+}
+
+/// Ask the session to pause its current refinement loop. Requires
+/// `supports_pause`.
+#[tauri::command]
+pub async fn pause_refinement(token: String, state: State<'_, AppState>) -> Result<(), SunwellError> {
+    let session = state.demo_sessions.get(&token)?;
+    if !session.capabilities.supports_pause {
+        return Err(SunwellError::new(
+            ErrorCode::RuntimeCapabilityUnsupported,
+            "This demo session does not support pausing refinement",
+        ));
+    }
+    session.transport.send_request("pause", serde_json::json!({})).await?;
+    Ok(())
+}
+
+/// Ask the session for a snapshot of the code it has produced so far.
+/// Requires `supports_intermediate_code`.
+#[tauri::command]
+pub async fn request_intermediate_code(token: String, state: State<'_, AppState>) -> Result<String, SunwellError> {
+    let session = state.demo_sessions.get(&token)?;
+    if !session.capabilities.supports_intermediate_code {
+        return Err(SunwellError::new(
+            ErrorCode::RuntimeCapabilityUnsupported,
+            "This demo session does not support intermediate code snapshots",
+        ));
+    }
+    let body = session.transport.send_request("intermediate_code", serde_json::json!({})).await?;
+    body.get("code")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Session response missing 'code' field"))
+}
+
+/// Override the judge's pass/fail threshold mid-run. Requires
+/// `supports_threshold_override`.
+#[tauri::command]
+pub async fn set_judge_threshold(token: String, threshold: f64, state: State<'_, AppState>) -> Result<(), SunwellError> {
+    let session = state.demo_sessions.get(&token)?;
+    if !session.capabilities.supports_threshold_override {
+        return Err(SunwellError::new(
+            ErrorCode::RuntimeCapabilityUnsupported,
+            "This demo session does not support overriding the judge threshold",
+        ));
+    }
+    session.transport.send_request("set_judge_threshold", serde_json::json!({ "threshold": threshold })).await?;
+    Ok(())
+}
+
+/// Stop a demo session: kill its child process and forget its token.
+#[tauri::command]
+pub async fn stop_demo_session(token: String, state: State<'_, AppState>) -> Result<(), SunwellError> {
+    state.demo_sessions.stop(&token).await
+}
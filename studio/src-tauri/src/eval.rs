@@ -80,6 +80,68 @@ pub struct EvaluationRun {
     pub sunwell_score: Option<FullStackScore>,
     #[serde(default)]
     pub improvement_percent: f64,
+    /// Machine/build fingerprint captured when the run started. `None` for
+    /// runs recorded before this field existed.
+    #[serde(default)]
+    pub env: Option<EnvInfo>,
+}
+
+/// Machine and build fingerprint for a single evaluation run, so comparing
+/// `improvement_percent` across runs that used different hardware or a
+/// different `sunwell` CLI build doesn't silently skew the result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvInfo {
+    pub cpu_model: String,
+    pub physical_cores: usize,
+    pub logical_cores: usize,
+    pub total_ram_mb: u64,
+    pub os_name: String,
+    pub os_version: String,
+    pub hostname: String,
+    pub sunwell_version: Option<String>,
+    pub git_commit: Option<String>,
+}
+
+impl EnvInfo {
+    /// Capture a fresh snapshot of the current machine and build. Fields
+    /// that can't be determined never fail the whole evaluation: the
+    /// optional ones (e.g. `git_commit` outside a git repo) are left
+    /// `None`, text fields fall back to `"unknown"` and counts to 0.
+    async fn capture() -> Self {
+        let mut sys = sysinfo::System::new_all();
+        sys.refresh_all();
+
+        let cpu_model =
+            sys.cpus().first().map(|cpu| cpu.brand().trim().to_string()).unwrap_or_else(|| "unknown".to_string());
+
+        let sunwell_version = Command::new("sunwell")
+            .arg("--version")
+            .output()
+            .await
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+        let git_commit = Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .await
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+        Self {
+            cpu_model,
+            physical_cores: sys.physical_core_count().unwrap_or(0),
+            logical_cores: sys.cpus().len(),
+            total_ram_mb: sys.total_memory() / (1024 * 1024),
+            os_name: sysinfo::System::name().unwrap_or_else(|| "unknown".to_string()),
+            os_version: sysinfo::System::os_version().unwrap_or_else(|| "unknown".to_string()),
+            hostname: sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string()),
+            sunwell_version,
+            git_commit,
+        }
+    }
 }
 
 /// Progress event during evaluation.
@@ -153,6 +215,10 @@ pub async fn run_eval_streaming(
     window: Window,
     input: EvalInput,
 ) -> Result<EvaluationRun, SunwellError> {
+    // Capture the machine/build fingerprint before anything else so it
+    // reflects the environment this run actually executed under.
+    let env = EnvInfo::capture().await;
+
     // Build command arguments
     let mut args = vec!["eval".to_string(), "--stream".to_string()];
 
@@ -243,8 +309,10 @@ pub async fn run_eval_streaming(
                         );
                     }
                     EvalStreamEvent::Complete(run) => {
-                        final_result = Some(*run.clone());
-                        let _ = window.emit("eval-complete", run.as_ref());
+                        let mut run = *run.clone();
+                        run.env = Some(env.clone());
+                        let _ = window.emit("eval-complete", &run);
+                        final_result = Some(run);
                     }
                     EvalStreamEvent::Error { message } => {
                         let _ = window.emit(
@@ -366,6 +434,268 @@ pub async fn get_eval_history(limit: Option<u32>) -> Result<Vec<EvaluationRun>,
     Ok(serde_json::from_str(&stdout).unwrap_or_default())
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// WORKLOADS — Batch evaluation runs for reproducible regression suites
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One task spec within a workload file: which task to run, against which
+/// model/provider/lens, and how many times to repeat it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadTaskSpec {
+    pub task_id: String,
+    pub model: String,
+    pub provider: Option<String>,
+    pub lens: Option<String>,
+    #[serde(default = "default_runs")]
+    pub runs: u32,
+}
+
+fn default_runs() -> u32 {
+    1
+}
+
+/// A workload file: a named batch of task specs, modeled on a benchmark
+/// harness suite. Meant to be committed to a repo so teams can re-run the
+/// same regression suite across models or Sunwell versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalWorkload {
+    pub name: String,
+    pub tasks: Vec<WorkloadTaskSpec>,
+    /// When set, the aggregated report is POSTed here as JSON so teams can
+    /// track cognitive-architecture improvement over time.
+    pub report_url: Option<String>,
+}
+
+/// Result of running an entire workload: every individual run plus the
+/// same aggregate shapes `get_eval_stats` returns, so the Studio can reuse
+/// its existing stats UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadReport {
+    pub name: String,
+    pub runs: Vec<EvaluationRun>,
+    pub stats: EvalStats,
+}
+
+/// Run every task spec in `workload` N times via the existing
+/// `sunwell eval --stream` path, aggregate the results into the same
+/// `EvalStats`/`TaskStats` shapes as `get_eval_stats`, and — when
+/// `report_url` is set — POST the aggregated report to that server.
+#[tauri::command]
+pub async fn run_eval_workload(window: Window, workload: EvalWorkload) -> Result<WorkloadReport, SunwellError> {
+    let mut runs: Vec<EvaluationRun> = Vec::new();
+
+    for spec in &workload.tasks {
+        for _ in 0..spec.runs.max(1) {
+            let input = EvalInput {
+                task: Some(spec.task_id.clone()),
+                model: Some(spec.model.clone()),
+                provider: spec.provider.clone(),
+                lens: spec.lens.clone(),
+            };
+            match run_eval_streaming(window.clone(), input).await {
+                Ok(run) => runs.push(run),
+                Err(e) => {
+                    eprintln!("Workload task {} failed: {}", spec.task_id, e);
+                }
+            }
+        }
+    }
+
+    let stats = aggregate_eval_stats(&runs);
+    let report = WorkloadReport { name: workload.name.clone(), runs, stats };
+
+    if let Some(report_url) = &workload.report_url {
+        post_workload_report(report_url, &report).await?;
+    }
+
+    Ok(report)
+}
+
+/// Fold a batch of runs into the same `EvalStats`/`TaskStats` shapes used
+/// by `get_eval_stats`, so workload reports and the stats UI stay
+/// consistent.
+fn aggregate_eval_stats(runs: &[EvaluationRun]) -> EvalStats {
+    let mut by_task: std::collections::HashMap<String, TaskStats> = std::collections::HashMap::new();
+    let mut sunwell_wins = 0;
+    let mut single_shot_wins = 0;
+    let mut ties = 0;
+    let mut improvement_total = 0.0;
+
+    for run in runs {
+        improvement_total += run.improvement_percent;
+        if run.improvement_percent > 0.0 {
+            sunwell_wins += 1;
+        } else if run.improvement_percent < 0.0 {
+            single_shot_wins += 1;
+        } else {
+            ties += 1;
+        }
+
+        let task_stats = by_task.entry(run.task_id.clone()).or_default();
+        let prior_runs = task_stats.runs as f64;
+        task_stats.runs += 1;
+        task_stats.avg_improvement =
+            (task_stats.avg_improvement * prior_runs + run.improvement_percent) / task_stats.runs as f64;
+        task_stats.sunwell_avg_score = (task_stats.sunwell_avg_score * prior_runs
+            + run.sunwell_score.as_ref().map(|s| s.total).unwrap_or(0.0))
+            / task_stats.runs as f64;
+        task_stats.single_shot_avg_score = (task_stats.single_shot_avg_score * prior_runs
+            + run.single_shot_score.as_ref().map(|s| s.total).unwrap_or(0.0))
+            / task_stats.runs as f64;
+    }
+
+    EvalStats {
+        total_runs: runs.len() as u32,
+        avg_improvement: if runs.is_empty() { 0.0 } else { improvement_total / runs.len() as f64 },
+        sunwell_wins,
+        single_shot_wins,
+        ties,
+        by_task,
+    }
+}
+
+/// POST the aggregated workload report as JSON to `report_url`.
+async fn post_workload_report(report_url: &str, report: &WorkloadReport) -> Result<(), SunwellError> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(report_url)
+        .json(report)
+        .send()
+        .await
+        .map_err(|e| SunwellError::new(ErrorCode::NetworkUnreachable, format!("Failed to reach report server: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::NetworkUnreachable,
+            format!("Report server returned status {}", response.status()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Run a task's in-process tool-calling baseline (RFC-098 addendum):
+/// drives a real multi-step session against the model directly, instead
+/// of depending on the Python side to execute `available_tools`, so users
+/// get an apples-to-apples baseline without shelling out.
+#[tauri::command]
+pub async fn run_eval_inprocess_baseline(
+    task: EvalTask,
+    model: String,
+    provider: Option<String>,
+    allow_mutating_tools: Option<bool>,
+    max_steps: Option<u32>,
+    working_dir: String,
+) -> Result<SingleShotResult, SunwellError> {
+    crate::eval_tools::run_tool_calling_session(
+        &task,
+        &model,
+        provider.as_deref(),
+        allow_mutating_tools.unwrap_or(false),
+        max_steps,
+        std::path::Path::new(&working_dir),
+    )
+    .await
+}
+
+/// Convert recent evaluation history into JUnit XML and write it to
+/// `output_path`, so existing CI runners can ingest a Sunwell regression
+/// the same way they ingest any other test report.
+///
+/// A run's `<testcase>` gets a `<failure>` when `improvement_percent`
+/// falls below `min_improvement`, or when `sunwell_score.total` regresses
+/// below that task's own baseline (the mean `sunwell_score.total` across
+/// the fetched history for that task).
+#[tauri::command]
+pub async fn export_eval_junit(
+    limit: Option<u32>,
+    min_improvement: f64,
+    output_path: String,
+) -> Result<(), SunwellError> {
+    let runs = get_eval_history(limit).await?;
+    let xml = build_junit_xml(&runs, min_improvement);
+    std::fs::write(&output_path, xml).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))
+}
+
+/// Render `runs` as JUnit XML: one `<testsuite>` per `task_id`, one
+/// `<testcase>` per run.
+fn build_junit_xml(runs: &[EvaluationRun], min_improvement: f64) -> String {
+    let baselines = task_score_baselines(runs);
+
+    let mut by_task: std::collections::HashMap<&str, Vec<&EvaluationRun>> = std::collections::HashMap::new();
+    for run in runs {
+        by_task.entry(run.task_id.as_str()).or_default().push(run);
+    }
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<testsuites>\n");
+
+    let mut task_ids: Vec<&str> = by_task.keys().copied().collect();
+    task_ids.sort();
+
+    for task_id in task_ids {
+        let task_runs = &by_task[task_id];
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\">\n",
+            xml_escape(task_id),
+            task_runs.len()
+        ));
+
+        for run in task_runs.iter() {
+            let baseline = baselines.get(task_id).copied().unwrap_or(0.0);
+            let current_score = run.sunwell_score.as_ref().map(|s| s.total).unwrap_or(0.0);
+            let regressed = current_score < baseline;
+            let below_min_improvement = run.improvement_percent < min_improvement;
+
+            xml.push_str(&format!("    <testcase name=\"{}\" classname=\"{}\">\n", xml_escape(&run.id), xml_escape(task_id)));
+
+            if below_min_improvement || regressed {
+                let message = format!(
+                    "improvement_percent={:.2} (min {:.2}), sunwell_score.total={:.2} (baseline {:.2}), score breakdown: {:?}",
+                    run.improvement_percent, min_improvement, current_score, baseline, run.sunwell_score
+                );
+                xml.push_str(&format!(
+                    "      <failure message=\"{}\">{}</failure>\n",
+                    xml_escape(&message),
+                    xml_escape(&message)
+                ));
+            }
+
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Per-task baseline: the mean `sunwell_score.total` across `runs` for
+/// that task, used to detect regression against a run's own history.
+fn task_score_baselines(runs: &[EvaluationRun]) -> std::collections::HashMap<&str, f64> {
+    let mut totals: std::collections::HashMap<&str, (f64, u32)> = std::collections::HashMap::new();
+    for run in runs {
+        let score = run.sunwell_score.as_ref().map(|s| s.total).unwrap_or(0.0);
+        let entry = totals.entry(run.task_id.as_str()).or_insert((0.0, 0));
+        entry.0 += score;
+        entry.1 += 1;
+    }
+    totals.into_iter().map(|(task_id, (sum, count))| (task_id, if count == 0 { 0.0 } else { sum / count as f64 })).collect()
+}
+
+/// Escape the handful of characters that are meaningful in XML text/attr
+/// content.
+fn xml_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
 /// Get evaluation statistics.
 #[tauri::command]
 pub async fn get_eval_stats() -> Result<EvalStats, SunwellError> {
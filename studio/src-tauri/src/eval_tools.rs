@@ -0,0 +1,417 @@
+//! In-process tool-calling harness for eval tasks (RFC-098 addendum).
+//!
+//! `EvalTask.available_tools` used to be descriptive only — nothing ever
+//! executed them, so every comparison depended on the Python side. This
+//! module exposes those tools as callable function schemas to a model and
+//! drives a real multi-step session against a sandboxed working directory,
+//! giving `eval` an apples-to-apples in-process baseline without shelling
+//! out to `sunwell`.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::eval::{EvalTask, SingleShotResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Hard ceiling on how many tool-calling rounds a session may run, even if
+/// the caller didn't set one. Keeps a misbehaving model from looping
+/// forever against the sandbox.
+const DEFAULT_MAX_STEPS: u32 = 20;
+
+/// Tool names considered mutating (touch the filesystem or spawn a
+/// process) as opposed to read-only. Run behind `allow_mutating_tools` so
+/// a runaway loop can't delete files by default.
+const MUTATING_TOOLS: &[&str] = &["create_file", "run_command"];
+
+/// A single message in the session's ordered history, mirroring the
+/// OpenAI-style chat-with-tools wire format so it can be sent straight to
+/// the configured model provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCallRequest>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+impl ChatMessage {
+    fn system(content: impl Into<String>) -> Self {
+        Self { role: "system".to_string(), content: Some(content.into()), tool_calls: None, tool_call_id: None }
+    }
+
+    fn user(content: impl Into<String>) -> Self {
+        Self { role: "user".to_string(), content: Some(content.into()), tool_calls: None, tool_call_id: None }
+    }
+
+    fn tool_result(tool_call_id: String, content: String) -> Self {
+        Self { role: "tool".to_string(), content: Some(content), tool_calls: None, tool_call_id: Some(tool_call_id) }
+    }
+}
+
+/// A requested tool invocation, as returned by the model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallRequest {
+    pub id: String,
+    pub function: ToolCallFunction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallFunction {
+    pub name: String,
+    /// Raw JSON-encoded argument object, matching the model API's wire format.
+    pub arguments: String,
+}
+
+/// One round-trip's worth of assistant output: optional text plus zero or
+/// more tool calls to execute before re-querying.
+#[derive(Debug, Clone, Deserialize)]
+struct AssistantTurn {
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ToolCallRequest>,
+    #[serde(default)]
+    usage: TurnUsage,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct TurnUsage {
+    #[serde(default)]
+    prompt_tokens: u32,
+    #[serde(default)]
+    completion_tokens: u32,
+}
+
+/// Build the JSON function schemas for the given tool names, in the
+/// `{"type": "function", "function": {...}}` shape most providers expect.
+fn tool_schemas(available_tools: &[String]) -> Vec<serde_json::Value> {
+    available_tools
+        .iter()
+        .filter_map(|name| {
+            let (description, parameters) = match name.as_str() {
+                "create_file" => (
+                    "Create a file with the given contents, relative to the sandbox root.",
+                    serde_json::json!({
+                        "type": "object",
+                        "properties": {
+                            "path": {"type": "string"},
+                            "contents": {"type": "string"},
+                        },
+                        "required": ["path", "contents"],
+                    }),
+                ),
+                "read_file" => (
+                    "Read a file's contents, relative to the sandbox root.",
+                    serde_json::json!({
+                        "type": "object",
+                        "properties": {"path": {"type": "string"}},
+                        "required": ["path"],
+                    }),
+                ),
+                "list_dir" => (
+                    "List entries in a directory, relative to the sandbox root.",
+                    serde_json::json!({
+                        "type": "object",
+                        "properties": {"path": {"type": "string"}},
+                        "required": [],
+                    }),
+                ),
+                "run_command" => (
+                    "Run a shell command inside the sandbox root and return its output.",
+                    serde_json::json!({
+                        "type": "object",
+                        "properties": {"command": {"type": "string"}},
+                        "required": ["command"],
+                    }),
+                ),
+                _ => return None,
+            };
+            Some(serde_json::json!({
+                "type": "function",
+                "function": {
+                    "name": name,
+                    "description": description,
+                    "parameters": parameters,
+                },
+            }))
+        })
+        .collect()
+}
+
+/// Resolve a tool-provided relative path against the sandbox root,
+/// rejecting anything that would escape it (e.g. `../../etc/passwd`).
+fn resolve_sandboxed_path(root: &Path, relative: &str) -> Result<PathBuf, SunwellError> {
+    let joined = root.join(relative);
+    let normalized = path_clean(&joined);
+    if !normalized.starts_with(root) {
+        return Err(SunwellError::new(
+            ErrorCode::ToolPermissionDenied,
+            format!("Path '{}' escapes the sandbox root", relative),
+        ));
+    }
+    Ok(normalized)
+}
+
+/// Lexically normalize a path (collapse `.`/`..` components) without
+/// requiring the path to exist, since `create_file` targets may not exist
+/// yet.
+fn path_clean(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Dispatch a single tool call against `sandbox_root`, returning the
+/// content to feed back to the model as a tool message.
+fn dispatch_tool(
+    sandbox_root: &Path,
+    call: &ToolCallRequest,
+    allow_mutating_tools: bool,
+) -> Result<String, SunwellError> {
+    if MUTATING_TOOLS.contains(&call.function.name.as_str()) && !allow_mutating_tools {
+        return Ok(format!(
+            "Tool '{}' was not executed: mutating tools are disabled for this session (allow_mutating_tools=false)",
+            call.function.name
+        ));
+    }
+
+    let args: serde_json::Value = serde_json::from_str(&call.function.arguments).map_err(|e| {
+        SunwellError::new(ErrorCode::ToolInvalidArguments, format!("Invalid tool arguments JSON: {}", e))
+    })?;
+
+    match call.function.name.as_str() {
+        "create_file" => {
+            let path = args
+                .get("path")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| SunwellError::new(ErrorCode::ToolInvalidArguments, "create_file requires 'path'"))?;
+            let contents = args.get("contents").and_then(|v| v.as_str()).unwrap_or("");
+            let target = resolve_sandboxed_path(sandbox_root, path)?;
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+            }
+            std::fs::write(&target, contents).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+            Ok(format!("Created {}", path))
+        }
+        "read_file" => {
+            let path = args
+                .get("path")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| SunwellError::new(ErrorCode::ToolInvalidArguments, "read_file requires 'path'"))?;
+            let target = resolve_sandboxed_path(sandbox_root, path)?;
+            std::fs::read_to_string(&target)
+                .map_err(|e| SunwellError::new(ErrorCode::ToolExecutionFailed, format!("Failed to read {}: {}", path, e)))
+        }
+        "list_dir" => {
+            let path = args.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+            let target = resolve_sandboxed_path(sandbox_root, path)?;
+            let entries = std::fs::read_dir(&target)
+                .map_err(|e| SunwellError::new(ErrorCode::ToolExecutionFailed, format!("Failed to list {}: {}", path, e)))?;
+            let names: Vec<String> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect();
+            Ok(names.join("\n"))
+        }
+        "run_command" => {
+            let command = args
+                .get("command")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| SunwellError::new(ErrorCode::ToolInvalidArguments, "run_command requires 'command'"))?;
+            let output = std::process::Command::new("sh")
+                .args(["-c", command])
+                .current_dir(sandbox_root)
+                .output()
+                .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+            Ok(format!(
+                "exit status: {}\nstdout:\n{}\nstderr:\n{}",
+                output.status,
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr),
+            ))
+        }
+        other => Err(SunwellError::new(ErrorCode::ToolNotFound, format!("Unknown tool '{}'", other))),
+    }
+}
+
+/// Send the current message history plus tool schemas to the model
+/// provider and parse its response into an `AssistantTurn`.
+///
+/// Uses the OpenAI-compatible chat-completions wire format; `provider`
+/// selects the base URL the same way the `sunwell` CLI's `--provider`
+/// flag does (defaulting to OpenAI when unset).
+async fn query_model(
+    model: &str,
+    provider: Option<&str>,
+    messages: &[ChatMessage],
+    tools: &[serde_json::Value],
+) -> Result<AssistantTurn, SunwellError> {
+    let base_url = match provider {
+        Some("anthropic") => "https://api.anthropic.com/v1",
+        Some("openrouter") => "https://openrouter.ai/api/v1",
+        _ => "https://api.openai.com/v1",
+    };
+    let api_key = std::env::var("SUNWELL_EVAL_MODEL_API_KEY")
+        .map_err(|_| SunwellError::new(ErrorCode::ConfigEnvMissing, "SUNWELL_EVAL_MODEL_API_KEY is not set"))?;
+
+    let body = serde_json::json!({
+        "model": model,
+        "messages": messages,
+        "tools": tools,
+    });
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{}/chat/completions", base_url))
+        .bearer_auth(api_key)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| SunwellError::new(ErrorCode::ModelApiError, format!("Model request failed: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::ModelApiError,
+            format!("Model provider returned status {}", response.status()),
+        ));
+    }
+
+    let payload: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| SunwellError::new(ErrorCode::ModelResponseInvalid, format!("Failed to parse model response: {}", e)))?;
+
+    let choice = payload
+        .get("choices")
+        .and_then(|c| c.get(0))
+        .ok_or_else(|| SunwellError::new(ErrorCode::ModelResponseInvalid, "Model response had no choices"))?;
+
+    let message = choice
+        .get("message")
+        .ok_or_else(|| SunwellError::new(ErrorCode::ModelResponseInvalid, "Model response had no message"))?;
+
+    let usage: TurnUsage = payload.get("usage").and_then(|u| serde_json::from_value(u.clone()).ok()).unwrap_or_default();
+
+    Ok(AssistantTurn {
+        content: message.get("content").and_then(|v| v.as_str()).map(str::to_string),
+        tool_calls: message
+            .get("tool_calls")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default(),
+        usage,
+    })
+}
+
+/// Run a real multi-step tool-calling session for `task` against
+/// `sandbox_root`: send the prompt and tool schemas, dispatch every tool
+/// call the model makes, append the results, and repeat until the model
+/// stops calling tools or `max_steps` is hit.
+///
+/// `allow_mutating_tools` gates `create_file`/`run_command` so a runaway
+/// loop can't delete or overwrite files unless the caller opts in.
+pub async fn run_tool_calling_session(
+    task: &EvalTask,
+    model: &str,
+    provider: Option<&str>,
+    allow_mutating_tools: bool,
+    max_steps: Option<u32>,
+    sandbox_root: &Path,
+) -> Result<SingleShotResult, SunwellError> {
+    std::fs::create_dir_all(sandbox_root).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+
+    let tools = tool_schemas(&task.available_tools);
+    let mut messages = vec![
+        ChatMessage::system(
+            "You are completing a coding task in a sandboxed working directory. Use the available tools to \
+             create and inspect files. Stop calling tools once the task is complete.",
+        ),
+        ChatMessage::user(task.prompt.clone()),
+    ];
+
+    let max_steps = max_steps.unwrap_or(DEFAULT_MAX_STEPS);
+    let started_at = Instant::now();
+    let mut turns = 0u32;
+    let mut input_tokens = 0u32;
+    let mut output_tokens = 0u32;
+
+    loop {
+        if turns >= max_steps {
+            break;
+        }
+        turns += 1;
+
+        let turn = query_model(model, provider, &messages, &tools).await?;
+        input_tokens += turn.usage.prompt_tokens;
+        output_tokens += turn.usage.completion_tokens;
+
+        if turn.tool_calls.is_empty() {
+            messages.push(ChatMessage {
+                role: "assistant".to_string(),
+                content: turn.content,
+                tool_calls: None,
+                tool_call_id: None,
+            });
+            break;
+        }
+
+        messages.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: turn.content,
+            tool_calls: Some(turn.tool_calls.clone()),
+            tool_call_id: None,
+        });
+
+        // Execute every tool call from this turn before re-querying, so a
+        // model that batches several calls at once gets all the results
+        // back in one round.
+        for call in &turn.tool_calls {
+            let result = match dispatch_tool(sandbox_root, call, allow_mutating_tools) {
+                Ok(output) => output,
+                Err(e) => format!("Tool failed: {}", e),
+            };
+            messages.push(ChatMessage::tool_result(call.id.clone(), result));
+        }
+    }
+
+    let files = list_created_files(sandbox_root);
+
+    Ok(SingleShotResult {
+        files,
+        time_seconds: started_at.elapsed().as_secs_f64(),
+        turns,
+        input_tokens,
+        output_tokens,
+    })
+}
+
+/// Walk the sandbox root and return every file path under it (relative to
+/// the root), including any files that were there before the session.
+fn list_created_files(sandbox_root: &Path) -> Vec<String> {
+    fn walk(dir: &Path, root: &Path, out: &mut Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else { return };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                walk(&path, root, out);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                out.push(relative.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(sandbox_root, sandbox_root, &mut out);
+    out
+}
@@ -0,0 +1,182 @@
+//! Live project file-tree watching — incremental deltas instead of re-scans.
+//!
+//! `list_project_files` is a one-shot recursive snapshot, so the frontend's
+//! tree goes stale the moment the agent (or the user) writes a file.
+//! `ProjectFileWatcherManager` watches a project root with the `notify`
+//! crate, recursively, honoring the same ignore list `list_project_files`
+//! uses, and debounces rapid bursts into coalesced `file-tree-changed`
+//! events (mirroring `lens_watcher`'s debounce shape) carrying only the
+//! changed paths rather than forcing a full re-scan. Best-effort: a watch
+//! failure or a burst of noisy events never blocks the command thread.
+
+use crate::commands::is_ignored_entry_name;
+use crate::error::SunwellError;
+use crate::sunwell_err;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How long to wait for a path's events to go quiet before emitting.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Kind of change observed for a watched path.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// One coalesced change, part of a `FileTreeChangedEvent`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileTreeDelta {
+    pub path: String,
+    pub kind: FileChangeKind,
+}
+
+/// Payload for the `file-tree-changed` Tauri event.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileTreeChangedEvent {
+    pub project_path: String,
+    pub changes: Vec<FileTreeDelta>,
+}
+
+/// A running watcher for one project. Dropping this stops watching (the
+/// `notify` watcher is torn down) and signals the debounce thread to exit.
+struct ProjectWatchHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Tracks at most one live file-tree watcher per project path, mirroring
+/// `LensWatcherManager`'s start/stop shape (one watcher object, not a
+/// detached self-cleaning task, so plain `Mutex<HashMap<..>>` is enough).
+#[derive(Default)]
+pub struct ProjectFileWatcherManager {
+    handles: Mutex<HashMap<String, ProjectWatchHandle>>,
+}
+
+impl ProjectFileWatcherManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start watching `project_path`, or do nothing if already watching it.
+    pub fn start(&self, project_path: String, app: AppHandle) -> Result<(), SunwellError> {
+        let mut handles = self.handles.lock().unwrap();
+        if handles.contains_key(&project_path) {
+            return Ok(());
+        }
+
+        let (event_tx, event_rx) = mpsc::channel::<Event>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let _ = event_tx.send(event);
+            }
+        })
+        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to create file watcher: {}", e))?;
+
+        watcher
+            .watch(Path::new(&project_path), RecursiveMode::Recursive)
+            .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to watch {}: {}", project_path, e))?;
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let thread_project_path = project_path.clone();
+        thread::spawn(move || debounce_loop(thread_project_path, app, event_rx, stop_rx));
+
+        handles.insert(project_path, ProjectWatchHandle { _watcher: watcher, stop_tx });
+        Ok(())
+    }
+
+    /// Stop watching `project_path`. A no-op if not currently watched.
+    pub fn stop(&self, project_path: &str) {
+        if let Some(handle) = self.handles.lock().unwrap().remove(project_path) {
+            let _ = handle.stop_tx.send(());
+        }
+    }
+}
+
+fn change_kind(kind: &EventKind) -> Option<FileChangeKind> {
+    match kind {
+        EventKind::Create(_) => Some(FileChangeKind::Created),
+        EventKind::Modify(_) => Some(FileChangeKind::Modified),
+        EventKind::Remove(_) => Some(FileChangeKind::Removed),
+        _ => None,
+    }
+}
+
+/// Whether any path component (not just the changed file's own name) falls
+/// under an ignored directory, e.g. `src/node_modules/foo/index.js`.
+fn is_ignored_path(path: &Path) -> bool {
+    path.components().any(|c| is_ignored_entry_name(&c.as_os_str().to_string_lossy()))
+}
+
+/// Coalesce raw filesystem events into one delta per path, holding each
+/// path's latest change kind until its events go quiet for `DEBOUNCE`
+/// before emitting a single `file-tree-changed` event for the batch.
+fn debounce_loop(project_path: String, app: AppHandle, event_rx: mpsc::Receiver<Event>, stop_rx: mpsc::Receiver<()>) {
+    let mut pending: HashMap<String, (FileChangeKind, Instant)> = HashMap::new();
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            return;
+        }
+
+        match event_rx.recv_timeout(DEBOUNCE) {
+            Ok(event) => {
+                let Some(kind) = change_kind(&event.kind) else { continue };
+                for path in &event.paths {
+                    if is_ignored_path(path) {
+                        continue;
+                    }
+                    pending.insert(path.to_string_lossy().into_owned(), (kind, Instant::now()));
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        let settled: Vec<String> =
+            pending.iter().filter(|(_, (_, seen))| seen.elapsed() >= DEBOUNCE).map(|(path, _)| path.clone()).collect();
+
+        if settled.is_empty() {
+            continue;
+        }
+
+        let changes: Vec<FileTreeDelta> = settled
+            .into_iter()
+            .filter_map(|path| pending.remove(&path).map(|(kind, _)| FileTreeDelta { path, kind }))
+            .collect();
+
+        let _ = app.emit("file-tree-changed", FileTreeChangedEvent { project_path: project_path.clone(), changes });
+    }
+}
+
+/// Start watching `project_path`'s file tree, emitting coalesced
+/// `file-tree-changed` events as files are created/modified/removed. A
+/// no-op if this project is already being watched.
+#[tauri::command]
+pub async fn watch_project_files(
+    project_path: String,
+    app: AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.file_watcher.start(project_path, app).map_err(|e| e.to_json())
+}
+
+/// Stop watching `project_path`'s file tree, if it's being watched.
+#[tauri::command]
+pub async fn stop_watching_project_files(
+    project_path: String,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.file_watcher.stop(&project_path);
+    Ok(())
+}
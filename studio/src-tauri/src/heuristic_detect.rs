@@ -3,10 +3,13 @@
 //! This module provides fast, deterministic run detection without AI.
 //! Used as a fallback when AI is unavailable or times out.
 
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::run_analysis::{Confidence, Prerequisite, RunAnalysis, Source};
+use serde::{Deserialize, Serialize};
+
+use crate::run_analysis::{Confidence, Prerequisite, RunAnalysis, RunCommand, Source};
 
 /// Detect how to run a project using heuristics (no AI).
 ///
@@ -17,28 +20,137 @@ use crate::run_analysis::{Confidence, Prerequisite, RunAnalysis, Source};
 /// 4. pyproject.toml / requirements.txt
 /// 5. docker-compose.yml
 pub fn heuristic_detect(path: &Path) -> Option<RunAnalysis> {
-    // Try each detection strategy in order
-    if let Some(analysis) = detect_nodejs(path) {
-        return Some(analysis);
-    }
-    
-    if let Some(analysis) = detect_rust(path) {
-        return Some(analysis);
+    detect_at(path)
+}
+
+/// Run every detection strategy against `path` and return each match's own
+/// `RunAnalysis`, in priority order, without merging them together. Used by
+/// callers that want to present the full set of launch paths for a project
+/// rather than a single primary choice — a repo with both a
+/// `docker-compose.yml` and a `package.json` legitimately supports both.
+pub fn heuristic_detect_all(path: &Path) -> Vec<RunAnalysis> {
+    [detect_nodejs, detect_rust, detect_python, detect_makefile, detect_docker]
+        .into_iter()
+        .filter_map(|detect| detect(path))
+        .collect()
+}
+
+/// Run the detection strategies (in priority order) against a single
+/// directory, without looking at its subdirectories. The first strategy to
+/// match is the primary result; any runner-ups are folded into its
+/// `alternatives` instead of being discarded.
+fn detect_at(path: &Path) -> Option<RunAnalysis> {
+    let mut matches = heuristic_detect_all(path);
+    if matches.is_empty() {
+        return None;
     }
-    
-    if let Some(analysis) = detect_python(path) {
-        return Some(analysis);
+
+    let mut primary = matches.remove(0);
+    primary.alternatives.extend(matches.into_iter().map(runner_up_as_alternative));
+    Some(primary)
+}
+
+/// Turn a runner-up strategy's full `RunAnalysis` into a single
+/// `RunCommand` entry for the primary result's `alternatives` list.
+fn runner_up_as_alternative(analysis: RunAnalysis) -> RunCommand {
+    RunCommand::new(
+        analysis.command,
+        analysis.command_description,
+        Some(format!("if you prefer {}", analysis.project_type)),
+    )
+}
+
+/// Directory names whose subtree is never worth descending into while
+/// looking for runnable projects.
+const RECURSE_SKIP_DIRS: &[&str] = &["node_modules", "target", ".git", "dist", "build", ".venv", ".sunwell"];
+
+/// Detect how to run every runnable project under `path`, descending into
+/// subdirectories (monorepo-style) up to `max_depth` levels.
+///
+/// Unlike `heuristic_detect` (root-only, first match wins), this returns
+/// every match found, each with `working_dir` set to its path relative to
+/// `path` — so `command` plus `working_dir` together are runnable from the
+/// repo root, the same idea as cargo's `-C <dir>` flag. Results are ranked
+/// root-first, then by confidence, and a cargo workspace's own members are
+/// not reported separately from the workspace root that already covers
+/// them via `alternatives`.
+pub fn heuristic_detect_recursive(path: &Path, max_depth: usize) -> Vec<RunAnalysis> {
+    let mut results = Vec::new();
+    let mut workspace_members = Vec::new();
+    walk_for_detection(path, path, 0, max_depth, &mut results, &mut workspace_members);
+
+    results.retain(|analysis| {
+        analysis
+            .working_dir
+            .as_deref()
+            .map_or(true, |dir| !workspace_members.iter().any(|m: &PathBuf| m == Path::new(dir)))
+    });
+
+    rank_detections(&mut results);
+    results
+}
+
+/// Recursive worker behind `heuristic_detect_recursive`. `workspace_members`
+/// accumulates the relative paths of any cargo workspace members found
+/// along the way, so the caller can drop them from the final result set.
+fn walk_for_detection(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    max_depth: usize,
+    results: &mut Vec<RunAnalysis>,
+    workspace_members: &mut Vec<PathBuf>,
+) {
+    let relative = dir.strip_prefix(root).unwrap_or(dir);
+
+    if let Some(mut analysis) = detect_at(dir) {
+        analysis.working_dir =
+            if relative.as_os_str().is_empty() { None } else { Some(relative.to_string_lossy().into_owned()) };
+
+        if let Ok(content) = fs::read_to_string(dir.join("Cargo.toml")) {
+            if let Ok(manifest) = toml::from_str::<CargoManifest>(&content) {
+                if let Some(workspace) = &manifest.workspace {
+                    for member in resolve_workspace_members(dir, &workspace.members) {
+                        workspace_members.push(relative.join(member));
+                    }
+                }
+            }
+        }
+
+        results.push(analysis);
     }
-    
-    if let Some(analysis) = detect_makefile(path) {
-        return Some(analysis);
+
+    if depth >= max_depth {
+        return;
     }
-    
-    if let Some(analysis) = detect_docker(path) {
-        return Some(analysis);
+
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let child = entry.path();
+        if !child.is_dir() {
+            continue;
+        }
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') || RECURSE_SKIP_DIRS.contains(&name.as_ref()) {
+            continue;
+        }
+        walk_for_detection(root, &child, depth + 1, max_depth, results, workspace_members);
     }
-    
-    None
+}
+
+/// Sort matches root-first (shallowest `working_dir` first), then by
+/// confidence — the order a user picking between them would want.
+fn rank_detections(results: &mut [RunAnalysis]) {
+    results.sort_by_key(|a| {
+        let depth = a.working_dir.as_deref().map(|d| d.matches('/').count() + 1).unwrap_or(0);
+        let confidence_rank = match a.confidence {
+            Confidence::High => 0,
+            Confidence::Medium => 1,
+            Confidence::Low => 2,
+        };
+        (depth, confidence_rank)
+    });
 }
 
 /// Detect Node.js projects via package.json.
@@ -51,38 +163,35 @@ fn detect_nodejs(path: &Path) -> Option<RunAnalysis> {
     let content = fs::read_to_string(&package_json_path).ok()?;
     let json: serde_json::Value = serde_json::from_str(&content).ok()?;
     
-    let scripts = json.get("scripts")?.as_object()?;
-    
+    let scripts = json.get("scripts").and_then(|s| s.as_object());
+
     // Determine package manager
-    let package_manager = if path.join("pnpm-lock.yaml").exists() {
-        "pnpm"
-    } else if path.join("yarn.lock").exists() {
-        "yarn"
-    } else if path.join("bun.lockb").exists() {
-        "bun"
-    } else {
-        "npm"
-    };
-    
-    // Check for common dev scripts in order of preference
-    let (script_name, description) = if scripts.contains_key("dev") {
-        ("dev", "Start development server")
-    } else if scripts.contains_key("start") {
-        ("start", "Start server")
-    } else if scripts.contains_key("serve") {
-        ("serve", "Start server")
-    } else {
-        return None;
-    };
-    
-    let command = format!("{} run {}", package_manager, script_name);
-    
+    let package_manager = detect_nodejs_package_manager(path);
+
     // Detect framework from dependencies
     let dependencies = json.get("dependencies").and_then(|d| d.as_object());
     let dev_dependencies = json.get("devDependencies").and_then(|d| d.as_object());
-    
+
     let framework = detect_nodejs_framework(dependencies, dev_dependencies);
-    let (expected_port, expected_url) = detect_nodejs_port(&framework, scripts.get(script_name));
+
+    // Check for common dev scripts in order of preference. If the manifest
+    // doesn't declare one, fall back to the canonical dev command for a
+    // recognized framework dependency instead of giving up — e.g. a fresh
+    // `create-react-app`/`create-vite` scaffold whose `package.json` hasn't
+    // been hand-edited yet.
+    let (command, description) = if let Some((name, desc)) =
+        scripts.and_then(|s| ["dev", "start", "serve"].into_iter().find(|name| s.contains_key(*name)))
+            .map(|name| (name, script_description(name)))
+    {
+        (format!("{} run {}", package_manager, name), desc)
+    } else if let Some((canonical, _)) = framework.as_deref().and_then(framework_canonical_command) {
+        (canonical.to_string(), "Start development server (framework default)")
+    } else {
+        return None;
+    };
+
+    let (expected_port, expected_url) =
+        detect_nodejs_port(&framework, scripts.and_then(|s| s.get("dev").or_else(|| s.get("start")).or_else(|| s.get("serve"))));
     
     // Check prerequisites
     let has_node_modules = path.join("node_modules").exists();
@@ -110,6 +219,7 @@ fn detect_nodejs(path: &Path) -> Option<RunAnalysis> {
         working_dir: None,
         alternatives: vec![],
         prerequisites,
+        env: vec![],
         expected_port,
         expected_url,
         confidence: Confidence::High,
@@ -119,6 +229,19 @@ fn detect_nodejs(path: &Path) -> Option<RunAnalysis> {
     })
 }
 
+/// Which Node.js package manager a project's lockfile indicates is in use.
+fn detect_nodejs_package_manager(path: &Path) -> &'static str {
+    if path.join("pnpm-lock.yaml").exists() {
+        "pnpm"
+    } else if path.join("yarn.lock").exists() {
+        "yarn"
+    } else if path.join("bun.lockb").exists() {
+        "bun"
+    } else {
+        "npm"
+    }
+}
+
 /// Detect Node.js framework from dependencies.
 fn detect_nodejs_framework(
     deps: Option<&serde_json::Map<String, serde_json::Value>>,
@@ -128,7 +251,7 @@ fn detect_nodejs_framework(
         .chain(dev_deps.iter())
         .flat_map(|d| d.keys().map(|k| k.as_str()))
         .collect();
-    
+
     // Check for frameworks in order of specificity
     if all_deps.contains(&"next") {
         Some("Next.js".to_string())
@@ -136,6 +259,8 @@ fn detect_nodejs_framework(
         Some("Nuxt".to_string())
     } else if all_deps.contains(&"@sveltejs/kit") {
         Some("SvelteKit".to_string())
+    } else if all_deps.contains(&"react-scripts") {
+        Some("Create React App".to_string())
     } else if all_deps.contains(&"svelte") {
         Some("Svelte".to_string())
     } else if all_deps.contains(&"vite") && all_deps.contains(&"react") {
@@ -161,6 +286,31 @@ fn detect_nodejs_framework(
     }
 }
 
+/// Human-readable description for a conventional `package.json` script name.
+fn script_description(script_name: &str) -> &'static str {
+    match script_name {
+        "dev" => "Start development server",
+        "serve" => "Start server",
+        _ => "Start server",
+    }
+}
+
+/// The canonical dev command and expected port for a framework dependency
+/// that was detected without a matching `dev`/`start`/`serve` script in
+/// `package.json` — e.g. a scaffold whose scripts haven't been customized.
+fn framework_canonical_command(framework: &str) -> Option<(&'static str, u16)> {
+    match framework {
+        "Next.js" => Some(("next dev", 3000)),
+        "Nuxt" => Some(("nuxt dev", 3000)),
+        "SvelteKit" => Some(("vite dev", 5173)),
+        "Create React App" => Some(("react-scripts start", 3000)),
+        "Vite" | "Vite + React" | "Vite + Vue" => Some(("vite", 5173)),
+        "Express" => Some(("node index.js", 3000)),
+        "Fastify" => Some(("node index.js", 3000)),
+        _ => None,
+    }
+}
+
 /// Detect typical port for Node.js framework.
 fn detect_nodejs_port(
     framework: &Option<String>,
@@ -184,6 +334,7 @@ fn detect_nodejs_port(
         Some("Next.js") => (Some(3000), Some("http://localhost:3000".to_string())),
         Some("Nuxt") => (Some(3000), Some("http://localhost:3000".to_string())),
         Some("SvelteKit") => (Some(5173), Some("http://localhost:5173".to_string())),
+        Some("Create React App") => (Some(3000), Some("http://localhost:3000".to_string())),
         Some(f) if f.contains("Vite") => (Some(5173), Some("http://localhost:5173".to_string())),
         Some("Express") | Some("Fastify") | Some("Koa") | Some("Hono") => {
             (Some(3000), Some("http://localhost:3000".to_string()))
@@ -201,20 +352,162 @@ fn detect_nodejs_language(path: &Path) -> String {
     }
 }
 
-/// Detect Rust projects via Cargo.toml.
+/// Top-level shape of a `Cargo.toml` we care about — just enough to
+/// resolve runnable targets, not a full manifest model.
+#[derive(Debug, Deserialize, Default)]
+struct CargoManifest {
+    package: Option<CargoPackage>,
+    #[serde(default)]
+    bin: Vec<CargoBinTarget>,
+    workspace: Option<CargoWorkspace>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    name: String,
+    #[serde(default, rename = "default-run")]
+    default_run: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoBinTarget {
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct CargoWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default, rename = "default-members")]
+    default_members: Vec<String>,
+}
+
+/// One runnable `cargo run` target: which workspace member crate it lives
+/// in (`None` for a non-workspace crate) and its binary name.
+#[derive(Debug, Clone)]
+struct RunnableTarget {
+    member: Option<String>,
+    bin_name: String,
+}
+
+impl RunnableTarget {
+    fn run_command(&self) -> String {
+        match &self.member {
+            Some(member) => format!("cargo run -p {} --bin {}", member, self.bin_name),
+            None => format!("cargo run --bin {}", self.bin_name),
+        }
+    }
+}
+
+/// The parts of `.cargo/config.toml` that change how `cargo run`
+/// resolves: user-defined aliases, environment variables, and a
+/// cross-compilation runner.
+#[derive(Debug, Deserialize, Default)]
+struct CargoConfig {
+    #[serde(default)]
+    alias: HashMap<String, CargoAliasValue>,
+    #[serde(default)]
+    env: HashMap<String, CargoEnvValue>,
+    build: Option<CargoBuildConfig>,
+    #[serde(default)]
+    target: HashMap<String, CargoTargetConfig>,
+}
+
+/// Cargo accepts an alias (or a `runner`) as either a single command
+/// string or an argv-style array.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CargoAliasValue {
+    Line(String),
+    Args(Vec<String>),
+}
+
+impl CargoAliasValue {
+    fn as_command_line(&self) -> String {
+        match self {
+            CargoAliasValue::Line(s) => s.clone(),
+            CargoAliasValue::Args(parts) => parts.join(" "),
+        }
+    }
+}
+
+/// An `[env]` entry is either a plain string or `{ value = "...", ... }`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CargoEnvValue {
+    Plain(String),
+    Detailed { value: String },
+}
+
+impl CargoEnvValue {
+    fn value(&self) -> &str {
+        match self {
+            CargoEnvValue::Plain(s) => s,
+            CargoEnvValue::Detailed { value } => value,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct CargoBuildConfig {
+    target: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTargetConfig {
+    runner: Option<CargoAliasValue>,
+}
+
+/// Read `.cargo/config.toml` the way cargo resolves it for a project
+/// root, falling back to the legacy unextensioned `.cargo/config`.
+fn read_cargo_config(path: &Path) -> Option<CargoConfig> {
+    let cargo_dir = path.join(".cargo");
+    let content = fs::read_to_string(cargo_dir.join("config.toml"))
+        .or_else(|_| fs::read_to_string(cargo_dir.join("config")))
+        .ok()?;
+    toml::from_str(&content).ok()
+}
+
+/// Detect a known web framework from `Cargo.lock`'s flattened package
+/// list, for the case where `Cargo.toml` itself doesn't name it directly
+/// (a workspace root with the framework only in a member's dependencies).
+fn cargo_lock_framework(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path.join("Cargo.lock")).ok()?;
+    let lock: CargoLock = toml::from_str(&content).ok()?;
+    let names: Vec<&str> = lock.packages.iter().map(|p| p.name.as_str()).collect();
+
+    if names.contains(&"actix-web") {
+        Some("Actix Web".to_string())
+    } else if names.contains(&"axum") {
+        Some("Axum".to_string())
+    } else if names.contains(&"rocket") {
+        Some("Rocket".to_string())
+    } else if names.contains(&"warp") {
+        Some("Warp".to_string())
+    } else {
+        None
+    }
+}
+
+/// Detect Rust projects via Cargo.toml, parsing it as TOML instead of
+/// string-scanning for `"[[bin]]"` so workspaces and multi-binary crates
+/// resolve correctly.
 fn detect_rust(path: &Path) -> Option<RunAnalysis> {
     let cargo_toml_path = path.join("Cargo.toml");
     if !cargo_toml_path.exists() {
         return None;
     }
-    
+
     let content = fs::read_to_string(&cargo_toml_path).ok()?;
-    
-    // Check if it's a binary crate (has [[bin]] or [package] without library-only markers)
-    let is_binary = content.contains("[[bin]]") || 
-        (content.contains("[package]") && !content.contains("lib.rs"));
-    
-    // Detect framework from dependencies
+    let manifest: CargoManifest = toml::from_str(&content).ok()?;
+    let is_workspace = manifest.workspace.is_some();
+
+    let (targets, default_member_names) = collect_runnable_targets(path, &manifest);
+    let default_run = manifest.package.as_ref().and_then(|p| p.default_run.clone());
+    let (primary, rest) = pick_primary_target(&targets, default_run.as_deref(), &default_member_names);
+
+    // Detect framework from dependencies (same string scan as before —
+    // dependency names are plain text either way).
     let framework = if content.contains("actix-web") {
         Some("Actix Web".to_string())
     } else if content.contains("axum") {
@@ -226,9 +519,13 @@ fn detect_rust(path: &Path) -> Option<RunAnalysis> {
     } else if content.contains("tauri") {
         Some("Tauri".to_string())
     } else {
-        None
+        // A workspace root's own Cargo.toml often has no `[dependencies]`
+        // at all — the framework lives in a member crate. Fall back to
+        // Cargo.lock, which flattens the whole dependency graph by name
+        // regardless of which crate pulled it in.
+        cargo_lock_framework(path)
     };
-    
+
     // Check prerequisites
     let has_target = path.join("target").exists();
     let prerequisites = if has_target {
@@ -241,27 +538,68 @@ fn detect_rust(path: &Path) -> Option<RunAnalysis> {
             required: false, // cargo run will build automatically
         }]
     };
-    
+
     let (expected_port, expected_url) = match framework.as_deref() {
         Some("Actix Web") | Some("Axum") | Some("Rocket") | Some("Warp") => {
             (Some(8080), Some("http://localhost:8080".to_string()))
         }
         _ => (None, None),
     };
-    
+
+    let (mut command, mut command_description) = match &primary {
+        // A single binary in a non-workspace crate is exactly what plain
+        // `cargo run` already resolves to — no need for `--bin`.
+        Some(_) if !is_workspace && targets.len() == 1 => {
+            ("cargo run".to_string(), "Run the binary crate".to_string())
+        }
+        Some(target) => (target.run_command(), format!("Run the '{}' binary", target.bin_name)),
+        None => ("cargo run".to_string(), "Build and run".to_string()),
+    };
+
+    let alternatives = rest
+        .iter()
+        .map(|target| {
+            RunCommand::new(
+                target.run_command(),
+                format!("Run the '{}' binary", target.bin_name),
+                target.member.clone(),
+            )
+        })
+        .collect();
+
+    // Honor `.cargo/config.toml`: a user-defined `run`/`dev`/`serve`
+    // alias takes priority over the generic `cargo run` we just built,
+    // `[env]` entries ride along on the analysis, and a configured
+    // `target.<triple>.runner` is surfaced in the description so the
+    // user knows the binary won't launch directly.
+    let mut env = Vec::new();
+    if let Some(config) = read_cargo_config(path) {
+        if let Some((alias_name, alias_line)) =
+            ["run", "dev", "serve"].iter().find_map(|name| config.alias.get(*name).map(|v| (*name, v.as_command_line())))
+        {
+            command = format!("cargo {}", alias_line);
+            command_description = format!("Run the project's 'cargo {}' alias", alias_name);
+        }
+
+        env = config.env.iter().map(|(k, v)| (k.clone(), v.value().to_string())).collect();
+        env.sort();
+
+        if let Some(runner) = config.build.as_ref().and_then(|b| b.target.as_deref()).and_then(|triple| config.target.get(triple)).and_then(|t| t.runner.as_ref())
+        {
+            command_description = format!("{} (runner: {})", command_description, runner.as_command_line());
+        }
+    }
+
     Some(RunAnalysis {
         project_type: format!("{} application", framework.as_deref().unwrap_or("Rust")),
         framework,
         language: "Rust".to_string(),
-        command: "cargo run".to_string(),
-        command_description: if is_binary {
-            "Run the binary crate".to_string()
-        } else {
-            "Build and run".to_string()
-        },
+        command,
+        command_description,
         working_dir: None,
-        alternatives: vec![],
+        alternatives,
         prerequisites,
+        env,
         expected_port,
         expected_url,
         confidence: Confidence::High,
@@ -271,6 +609,239 @@ fn detect_rust(path: &Path) -> Option<RunAnalysis> {
     })
 }
 
+/// Enumerate every runnable binary target reachable from `path`'s
+/// manifest: for a plain crate, its own binaries; for a workspace, every
+/// member's binaries. Also resolves `[workspace] default-members` (given
+/// as member *paths*) to the package *names* `pick_primary_target` needs,
+/// since that's what `cargo run -p` takes.
+fn collect_runnable_targets(path: &Path, manifest: &CargoManifest) -> (Vec<RunnableTarget>, Vec<String>) {
+    let Some(workspace) = &manifest.workspace else {
+        let targets =
+            package_bin_names(path, manifest).into_iter().map(|bin_name| RunnableTarget { member: None, bin_name }).collect();
+        return (targets, Vec::new());
+    };
+
+    let mut targets = Vec::new();
+    let mut default_member_names = Vec::new();
+    for member_path in resolve_workspace_members(path, &workspace.members) {
+        let member_dir = path.join(&member_path);
+        let Ok(member_content) = fs::read_to_string(member_dir.join("Cargo.toml")) else { continue };
+        let Ok(member_manifest) = toml::from_str::<CargoManifest>(&member_content) else { continue };
+        let member_name =
+            member_manifest.package.as_ref().map(|p| p.name.clone()).unwrap_or_else(|| member_path.clone());
+
+        if workspace.default_members.iter().any(|d| *d == member_path) {
+            default_member_names.push(member_name.clone());
+        }
+
+        for bin_name in package_bin_names(&member_dir, &member_manifest) {
+            targets.push(RunnableTarget { member: Some(member_name.clone()), bin_name });
+        }
+    }
+    (targets, default_member_names)
+}
+
+/// Binary target names for a single (non-workspace) crate manifest:
+/// explicit `[[bin]]` names, or the implicit `src/main.rs` binary (named
+/// after the package) when none are declared.
+fn package_bin_names(crate_dir: &Path, manifest: &CargoManifest) -> Vec<String> {
+    if !manifest.bin.is_empty() {
+        return manifest.bin.iter().map(|b| b.name.clone()).collect();
+    }
+
+    if crate_dir.join("src/main.rs").exists() {
+        if let Some(package) = &manifest.package {
+            return vec![package.name.clone()];
+        }
+    }
+
+    Vec::new()
+}
+
+/// One member of a monorepo's declared workspace — independently
+/// installable/runnable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonorepoMember {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Enumerate a monorepo's declared workspace members, trying each
+/// workspace manifest format in turn: pnpm (`pnpm-workspace.yaml`), plain
+/// npm/yarn (`package.json` `workspaces`), and Cargo (`[workspace]
+/// members`). Returns an empty vec if `path` isn't a recognized monorepo
+/// root in any of these formats.
+pub fn enumerate_monorepo_members(path: &Path) -> Vec<MonorepoMember> {
+    if let Some(members) = pnpm_workspace_members(path) {
+        return members;
+    }
+    if let Some(members) = npm_workspace_members(path) {
+        return members;
+    }
+    cargo_workspace_members(path).unwrap_or_default()
+}
+
+/// Resolve workspace-glob patterns (the `"dir/*"` form shared by pnpm and
+/// npm/yarn workspaces) relative to `root` into directories that actually
+/// exist, dropping pnpm's `!exclude` negation entries since none of our
+/// callers need to act on them.
+fn resolve_workspace_globs(root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut resolved = Vec::new();
+    for pattern in patterns {
+        if pattern.starts_with('!') {
+            continue;
+        }
+        if let Some(prefix) = pattern.strip_suffix("/*") {
+            let Ok(entries) = fs::read_dir(root.join(prefix)) else { continue };
+            for entry in entries.flatten() {
+                if entry.path().is_dir() {
+                    resolved.push(Path::new(prefix).join(entry.file_name()));
+                }
+            }
+        } else {
+            let member_dir = root.join(pattern);
+            if member_dir.is_dir() {
+                resolved.push(PathBuf::from(pattern));
+            }
+        }
+    }
+    resolved
+}
+
+/// The `name` field of a member's own `package.json`, if it has one.
+fn package_json_name(member_dir: &Path) -> Option<String> {
+    let content = fs::read_to_string(member_dir.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    json.get("name").and_then(|n| n.as_str()).map(|s| s.to_string())
+}
+
+/// A member's display name: its own `package.json` `name` field if present,
+/// else its directory's own name.
+fn member_display_name(member_dir: &Path, relative_path: &Path) -> String {
+    package_json_name(member_dir)
+        .unwrap_or_else(|| relative_path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default())
+}
+
+#[derive(Debug, Deserialize)]
+struct PnpmWorkspaceFile {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+fn pnpm_workspace_members(path: &Path) -> Option<Vec<MonorepoMember>> {
+    let content = fs::read_to_string(path.join("pnpm-workspace.yaml")).ok()?;
+    let workspace: PnpmWorkspaceFile = serde_yaml::from_str(&content).ok()?;
+
+    Some(
+        resolve_workspace_globs(path, &workspace.packages)
+            .into_iter()
+            .map(|relative| {
+                let member_dir = path.join(&relative);
+                MonorepoMember { name: member_display_name(&member_dir, &relative), path: member_dir }
+            })
+            .collect(),
+    )
+}
+
+/// npm/yarn's `package.json` `workspaces` field, either the plain array
+/// form or yarn's `{ "packages": [...] }` object form.
+fn npm_workspace_members(path: &Path) -> Option<Vec<MonorepoMember>> {
+    let content = fs::read_to_string(path.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let workspaces = json.get("workspaces")?;
+
+    let patterns: Vec<String> = if let Some(arr) = workspaces.as_array() {
+        arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect()
+    } else {
+        workspaces
+            .get("packages")?
+            .as_array()?
+            .iter()
+            .filter_map(|v| v.as_str().map(|s| s.to_string()))
+            .collect()
+    };
+
+    Some(
+        resolve_workspace_globs(path, &patterns)
+            .into_iter()
+            .map(|relative| {
+                let member_dir = path.join(&relative);
+                MonorepoMember { name: member_display_name(&member_dir, &relative), path: member_dir }
+            })
+            .collect(),
+    )
+}
+
+fn cargo_workspace_members(path: &Path) -> Option<Vec<MonorepoMember>> {
+    let content = fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    let manifest: CargoManifest = toml::from_str(&content).ok()?;
+    let workspace = manifest.workspace?;
+
+    Some(
+        resolve_workspace_members(path, &workspace.members)
+            .into_iter()
+            .map(|relative| {
+                let member_dir = path.join(&relative);
+                let name = fs::read_to_string(member_dir.join("Cargo.toml"))
+                    .ok()
+                    .and_then(|content| toml::from_str::<CargoManifest>(&content).ok())
+                    .and_then(|m| m.package.map(|p| p.name))
+                    .unwrap_or_else(|| relative.clone());
+                MonorepoMember { name, path: member_dir }
+            })
+            .collect(),
+    )
+}
+
+/// Expand workspace `members` entries, supporting the `"dir/*"` glob form
+/// cargo workspaces commonly use to include every crate under a directory
+/// without listing each one individually.
+fn resolve_workspace_members(root: &Path, members: &[String]) -> Vec<String> {
+    let mut resolved = Vec::new();
+    for member in members {
+        if let Some(prefix) = member.strip_suffix("/*") {
+            let Ok(entries) = fs::read_dir(root.join(prefix)) else { continue };
+            for entry in entries.flatten() {
+                if entry.path().join("Cargo.toml").exists() {
+                    if let Some(name) = entry.file_name().to_str() {
+                        resolved.push(format!("{}/{}", prefix, name));
+                    }
+                }
+            }
+        } else {
+            resolved.push(member.clone());
+        }
+    }
+    resolved
+}
+
+/// Choose the primary runnable target — `default-run` if the manifest
+/// names one, the workspace's first `default-members` entry otherwise, or
+/// simply the first target found — and return it alongside the rest as
+/// alternatives.
+fn pick_primary_target(
+    targets: &[RunnableTarget],
+    default_run: Option<&str>,
+    default_members: &[String],
+) -> (Option<RunnableTarget>, Vec<RunnableTarget>) {
+    if targets.is_empty() {
+        return (None, Vec::new());
+    }
+
+    let primary_index = default_run
+        .and_then(|name| targets.iter().position(|t| t.bin_name == name))
+        .or_else(|| {
+            default_members
+                .first()
+                .and_then(|member| targets.iter().position(|t| t.member.as_deref() == Some(member.as_str())))
+        })
+        .unwrap_or(0);
+
+    let mut remaining = targets.to_vec();
+    let primary = remaining.remove(primary_index);
+    (Some(primary), remaining)
+}
+
 /// Detect Python projects.
 fn detect_python(path: &Path) -> Option<RunAnalysis> {
     let has_pyproject = path.join("pyproject.toml").exists();
@@ -383,6 +954,7 @@ fn detect_python(path: &Path) -> Option<RunAnalysis> {
         working_dir: None,
         alternatives: vec![],
         prerequisites,
+        env: vec![],
         expected_port,
         expected_url,
         confidence: Confidence::Medium,
@@ -403,37 +975,145 @@ fn read_python_deps(path: &Path) -> Option<String> {
     fs::read_to_string(path.join("requirements.txt")).ok()
 }
 
+/// A target parsed out of a Makefile: its name and the tab-indented recipe
+/// lines that follow it.
+struct MakeTarget {
+    name: String,
+    recipe: Vec<String>,
+}
+
+/// Commands a target's recipe might run that indicate it starts a dev
+/// server, even if the target itself isn't named `run`/`dev`/`start`/`serve`.
+const DEV_SERVER_HINTS: &[&str] = &[
+    "npm run", "yarn ", "pnpm ", "vite", "webpack-dev-server", "flask run", "uvicorn", "gunicorn",
+    "python -m http.server", "rails s", "next dev", "ng serve",
+];
+
+/// Parse a single non-recipe Makefile line into the target name(s) it
+/// declares, or an empty list if it isn't a target line at all — a
+/// variable assignment (`VAR := value`, `VAR = value`), a conditional
+/// directive, or a line with no colon.
+fn parse_target_line(line: &str) -> Vec<String> {
+    let Some(colon_index) = line.find(':') else { return Vec::new() };
+    let (head, rest) = line.split_at(colon_index);
+
+    if rest.starts_with(":=") || head.contains('=') {
+        return Vec::new();
+    }
+
+    head.split_whitespace()
+        .map(|s| s.to_string())
+        // `.PHONY`/`.DEFAULT`/etc. are directives, not runnable targets,
+        // and `$(VAR)`-expanded names aren't statically known.
+        .filter(|name| !name.starts_with('.') && !name.contains('$'))
+        .collect()
+}
+
+/// Parse a Makefile's real targets and their recipes, skipping variable
+/// assignments, `.PHONY` declarations, and comments. `depth` guards
+/// against following `include`/`-include` more than one level deep.
+fn parse_makefile_targets(dir: &Path, content: &str, depth: u8) -> Vec<MakeTarget> {
+    let mut targets = Vec::new();
+    let mut lines = content.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+
+        if depth == 0 {
+            if let Some(rest) = trimmed.strip_prefix("include ").or_else(|| trimmed.strip_prefix("-include ")) {
+                for included in rest.split_whitespace() {
+                    if let Ok(included_content) = fs::read_to_string(dir.join(included)) {
+                        targets.extend(parse_makefile_targets(dir, &included_content, depth + 1));
+                    }
+                }
+                continue;
+            }
+        }
+
+        if line.starts_with('\t') || trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        for name in parse_target_line(trimmed) {
+            let mut recipe = Vec::new();
+            while let Some(next) = lines.peek() {
+                if next.starts_with('\t') {
+                    recipe.push(lines.next().unwrap().trim().to_string());
+                } else {
+                    break;
+                }
+            }
+            targets.push(MakeTarget { name, recipe });
+        }
+    }
+
+    targets
+}
+
+/// A target is worth offering as a run command if it's named one of the
+/// conventional entry points, or its recipe invokes something that looks
+/// like a dev server.
+fn is_runnable_looking(target: &MakeTarget) -> bool {
+    const KNOWN_NAMES: &[&str] = &["run", "dev", "start", "serve"];
+    KNOWN_NAMES.contains(&target.name.as_str())
+        || target.recipe.iter().any(|line| DEV_SERVER_HINTS.iter().any(|hint| line.contains(hint)))
+}
+
+/// Human-readable description for a Makefile target's run command.
+fn makefile_target_description(name: &str) -> String {
+    match name {
+        "dev" => "Run development mode".to_string(),
+        "run" => "Run the project".to_string(),
+        "start" => "Start the project".to_string(),
+        "serve" => "Start server".to_string(),
+        other => format!("Run '{}'", other),
+    }
+}
+
 /// Detect Makefile-based projects.
 fn detect_makefile(path: &Path) -> Option<RunAnalysis> {
     let makefile_path = path.join("Makefile");
     if !makefile_path.exists() {
         return None;
     }
-    
+
     let content = fs::read_to_string(&makefile_path).ok()?;
-    
-    // Check for common targets
-    let (target, description) = if content.contains("\ndev:") || content.contains("\ndev ") {
-        ("dev", "Run development mode")
-    } else if content.contains("\nrun:") || content.contains("\nrun ") {
-        ("run", "Run the project")
-    } else if content.contains("\nstart:") || content.contains("\nstart ") {
-        ("start", "Start the project")
-    } else if content.contains("\nserve:") || content.contains("\nserve ") {
-        ("serve", "Start server")
-    } else {
+    let mut seen = std::collections::HashSet::new();
+    let runnable: Vec<MakeTarget> = parse_makefile_targets(path, &content, 0)
+        .into_iter()
+        .filter(|t| seen.insert(t.name.clone()))
+        .filter(is_runnable_looking)
+        .collect();
+
+    if runnable.is_empty() {
         return None;
-    };
-    
+    }
+
+    // Prefer the conventional entry points in order; fall back to
+    // whichever dev-server-looking target was found first.
+    const PREFERENCE: &[&str] = &["dev", "run", "start", "serve"];
+    let primary_index =
+        PREFERENCE.iter().find_map(|name| runnable.iter().position(|t| t.name == *name)).unwrap_or(0);
+
+    let alternatives = runnable
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != primary_index)
+        .map(|(_, t)| RunCommand::new(format!("make {}", t.name), makefile_target_description(&t.name), None))
+        .collect();
+
+    let primary = &runnable[primary_index];
+
     Some(RunAnalysis {
         project_type: "Makefile project".to_string(),
         framework: None,
         language: "unknown".to_string(),
-        command: format!("make {}", target),
-        command_description: description.to_string(),
+        command: format!("make {}", primary.name),
+        command_description: makefile_target_description(&primary.name),
         working_dir: None,
-        alternatives: vec![],
+        alternatives,
         prerequisites: vec![],
+        env: vec![],
         expected_port: None,
         expected_url: None,
         confidence: Confidence::Medium,
@@ -484,6 +1164,117 @@ fn is_docker_running() -> bool {
         .unwrap_or(false)
 }
 
+// =============================================================================
+// Project Environment Report (project doctor)
+// =============================================================================
+
+/// One dependency resolved by a lockfile, with the version actually in use
+/// rather than the range a manifest allows.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyVersion {
+    pub name: String,
+    pub version: String,
+}
+
+/// A toolchain binary this project needs but that isn't on `PATH`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingTool {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Structured report of the runtime/toolchain a project needs, so the
+/// frontend can warn before `launch_preview` or `run_goal` fails partway
+/// through instead of after.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectEnvironment {
+    pub framework: Option<String>,
+    pub package_manager: Option<String>,
+    pub node_version: Option<String>,
+    pub language: Option<String>,
+    pub rust_crate_name: Option<String>,
+    #[serde(default)]
+    pub rust_dependencies: Vec<DependencyVersion>,
+    #[serde(default)]
+    pub missing_tools: Vec<MissingTool>,
+}
+
+/// `cargo metadata`-free model of the few `Cargo.lock` fields we need: just
+/// each resolved package's name and version, not its dependency graph.
+#[derive(Debug, Deserialize, Default)]
+struct CargoLock {
+    #[serde(default, rename = "package")]
+    packages: Vec<CargoLockPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoLockPackage {
+    name: String,
+    version: String,
+}
+
+/// `<tool> --version`'s stdout, trimmed, or `None` if `tool` isn't on
+/// `PATH` or exits non-zero.
+fn tool_version(tool: &str, args: &[&str]) -> Option<String> {
+    std::process::Command::new(tool)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+}
+
+/// Inspect `path` for the framework, package manager, and toolchain
+/// versions it needs to run, flagging any required tool that's missing
+/// from `PATH` rather than letting a later command fail on it.
+pub fn inspect_environment(path: &Path) -> ProjectEnvironment {
+    let mut env = ProjectEnvironment::default();
+
+    if let Ok(content) = fs::read_to_string(path.join("package.json")) {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            let dependencies = json.get("dependencies").and_then(|d| d.as_object());
+            let dev_dependencies = json.get("devDependencies").and_then(|d| d.as_object());
+            env.framework = detect_nodejs_framework(dependencies, dev_dependencies);
+            env.package_manager = Some(detect_nodejs_package_manager(path).to_string());
+            env.language = Some(detect_nodejs_language(path));
+
+            match tool_version("node", &["--version"]) {
+                Some(version) => env.node_version = Some(version),
+                None => env.missing_tools.push(MissingTool {
+                    name: "node".to_string(),
+                    reason: "package.json present but `node` was not found on PATH".to_string(),
+                }),
+            }
+        }
+    }
+
+    if let Ok(content) = fs::read_to_string(path.join("Cargo.toml")) {
+        if let Ok(manifest) = toml::from_str::<CargoManifest>(&content) {
+            env.rust_crate_name = manifest.package.map(|p| p.name);
+        }
+
+        if let Ok(lock_content) = fs::read_to_string(path.join("Cargo.lock")) {
+            if let Ok(lock) = toml::from_str::<CargoLock>(&lock_content) {
+                env.rust_dependencies = lock
+                    .packages
+                    .into_iter()
+                    .map(|p| DependencyVersion { name: p.name, version: p.version })
+                    .collect();
+            }
+        }
+
+        if tool_version("cargo", &["--version"]).is_none() {
+            env.missing_tools.push(MissingTool {
+                name: "cargo".to_string(),
+                reason: "Cargo.toml present but `cargo` was not found on PATH".to_string(),
+            });
+        }
+    }
+
+    env
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -552,7 +1343,82 @@ actix-web = "4"
         assert_eq!(analysis.language, "Rust");
         assert_eq!(analysis.framework, Some("Actix Web".to_string()));
     }
-    
+
+    #[test]
+    fn test_detect_rust_multi_bin_uses_default_run() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        let cargo_toml = r#"
+[package]
+name = "test-app"
+version = "0.1.0"
+default-run = "server"
+
+[[bin]]
+name = "server"
+
+[[bin]]
+name = "worker"
+"#;
+        fs::write(path.join("Cargo.toml"), cargo_toml).unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "cargo run --bin server");
+        assert_eq!(analysis.alternatives.len(), 1);
+        assert_eq!(analysis.alternatives[0].command, "cargo run --bin worker");
+    }
+
+    #[test]
+    fn test_detect_rust_workspace_multiple_members() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Cargo.toml"), r#"
+[workspace]
+members = ["crates/*"]
+default-members = ["crates/cli"]
+"#).unwrap();
+
+        fs::create_dir_all(path.join("crates/cli/src")).unwrap();
+        fs::write(path.join("crates/cli/Cargo.toml"), "[package]\nname = \"cli\"\nversion = \"0.1.0\"\n").unwrap();
+        fs::write(path.join("crates/cli/src/main.rs"), "fn main() {}").unwrap();
+
+        fs::create_dir_all(path.join("crates/daemon/src")).unwrap();
+        fs::write(path.join("crates/daemon/Cargo.toml"), "[package]\nname = \"daemon\"\nversion = \"0.1.0\"\n").unwrap();
+        fs::write(path.join("crates/daemon/src/main.rs"), "fn main() {}").unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "cargo run -p cli --bin cli");
+        assert_eq!(analysis.alternatives.len(), 1);
+        assert_eq!(analysis.alternatives[0].command, "cargo run -p daemon --bin daemon");
+    }
+
+    #[test]
+    fn test_detect_rust_honors_cargo_config_alias_and_env() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Cargo.toml"), "[package]\nname = \"test-app\"\nversion = \"0.1.0\"\n").unwrap();
+        fs::create_dir_all(path.join(".cargo")).unwrap();
+        fs::write(path.join(".cargo/config.toml"), r#"
+[alias]
+run = "run --release -- --port 9000"
+
+[env]
+DATABASE_URL = "postgres://localhost/test"
+RUST_LOG = { value = "debug" }
+"#).unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "cargo run --release -- --port 9000");
+        assert!(analysis.env.contains(&("DATABASE_URL".to_string(), "postgres://localhost/test".to_string())));
+        assert!(analysis.env.contains(&("RUST_LOG".to_string(), "debug".to_string())));
+    }
+
     #[test]
     fn test_detect_python_fastapi() {
         let dir = tempdir().unwrap();
@@ -592,10 +1458,240 @@ run:
     fn test_no_detection() {
         let dir = tempdir().unwrap();
         let path = dir.path();
-        
+
         // Empty directory
         let analysis = heuristic_detect(path);
-        
+
         assert!(analysis.is_none());
     }
+
+    #[test]
+    fn test_recursive_detects_nested_frontend_and_backend() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::create_dir_all(path.join("frontend")).unwrap();
+        fs::write(path.join("frontend/package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+
+        fs::create_dir_all(path.join("backend")).unwrap();
+        fs::write(path.join("backend/requirements.txt"), "fastapi\nuvicorn\n").unwrap();
+        fs::write(path.join("backend/app.py"), "from fastapi import FastAPI\napp = FastAPI()").unwrap();
+
+        let results = heuristic_detect_recursive(path, 2);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].working_dir, Some("frontend".to_string()));
+        assert_eq!(results[1].working_dir, Some("backend".to_string()));
+    }
+
+    #[test]
+    fn test_recursive_sets_root_working_dir_to_none() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+
+        let results = heuristic_detect_recursive(path, 2);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].working_dir, None);
+    }
+
+    #[test]
+    fn test_recursive_skips_node_modules_and_respects_max_depth() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::create_dir_all(path.join("node_modules/some-dep")).unwrap();
+        fs::write(path.join("node_modules/some-dep/package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+
+        fs::create_dir_all(path.join("a/b/c")).unwrap();
+        fs::write(path.join("a/b/c/package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+
+        let results = heuristic_detect_recursive(path, 1);
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_recursive_does_not_report_workspace_members_separately() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Cargo.toml"), r#"
+[workspace]
+members = ["crates/*"]
+"#).unwrap();
+
+        fs::create_dir_all(path.join("crates/cli/src")).unwrap();
+        fs::write(path.join("crates/cli/Cargo.toml"), "[package]\nname = \"cli\"\nversion = \"0.1.0\"\n").unwrap();
+        fs::write(path.join("crates/cli/src/main.rs"), "fn main() {}").unwrap();
+
+        let results = heuristic_detect_recursive(path, 3);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].working_dir, None);
+    }
+
+    #[test]
+    fn test_heuristic_detect_all_returns_every_strategy() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+        fs::write(path.join("docker-compose.yml"), "services: {}").unwrap();
+
+        let results = heuristic_detect_all(path);
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].command.contains("npm"));
+        assert!(results[1].command.contains("docker-compose"));
+    }
+
+    #[test]
+    fn test_heuristic_detect_folds_runner_ups_into_alternatives() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("package.json"), r#"{"scripts": {"dev": "vite"}}"#).unwrap();
+        fs::write(path.join("docker-compose.yml"), "services: {}").unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert!(analysis.command.contains("npm"));
+        assert_eq!(analysis.alternatives.len(), 1);
+        assert_eq!(analysis.alternatives[0].command, "docker-compose up");
+    }
+
+    #[test]
+    fn test_detect_makefile_ignores_variable_assignments_and_pattern_rules() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        let makefile = "
+CC := gcc
+VERSION = 1.0.0
+
+%.o: %.c
+\t$(CC) -c $< -o $@
+
+.PHONY: dev
+dev:
+\t@echo 'Starting dev'
+";
+        fs::write(path.join("Makefile"), makefile).unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "make dev");
+        assert!(analysis.alternatives.is_empty());
+    }
+
+    #[test]
+    fn test_detect_makefile_follows_include_one_level_deep() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Makefile"), "include common.mk\n").unwrap();
+        fs::write(path.join("common.mk"), "serve:\n\t@echo 'Serving'\n").unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "make serve");
+    }
+
+    #[test]
+    fn test_detect_makefile_finds_dev_server_recipe_under_other_name() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        let makefile = "
+backend:
+\tuvicorn app:app --reload
+
+web:
+\tnpm run dev
+";
+        fs::write(path.join("Makefile"), makefile).unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "make backend");
+        assert_eq!(analysis.alternatives.len(), 1);
+        assert_eq!(analysis.alternatives[0].command, "make web");
+    }
+
+    #[test]
+    fn test_detect_nodejs_falls_back_to_framework_canonical_command() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        // No "dev"/"start"/"serve" script declared — just the dependency.
+        fs::write(
+            path.join("package.json"),
+            r#"{"name": "cra-app", "dependencies": {"react-scripts": "5.0.0"}}"#,
+        )
+        .unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.command, "react-scripts start");
+        assert_eq!(analysis.framework, Some("Create React App".to_string()));
+        assert_eq!(analysis.expected_port, Some(3000));
+    }
+
+    #[test]
+    fn test_detect_rust_framework_from_cargo_lock_when_workspace_root_has_none() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n").unwrap();
+        fs::write(
+            path.join("Cargo.lock"),
+            "[[package]]\nname = \"axum\"\nversion = \"0.7.0\"\n",
+        )
+        .unwrap();
+
+        let analysis = heuristic_detect(path).unwrap();
+
+        assert_eq!(analysis.framework, Some("Axum".to_string()));
+    }
+
+    #[test]
+    fn test_inspect_environment_detects_nodejs_framework_and_manager() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(
+            path.join("package.json"),
+            r#"{"name": "test-app", "dependencies": {"react": "^18.0.0", "vite": "^5.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(path.join("pnpm-lock.yaml"), "").unwrap();
+
+        let env = inspect_environment(path);
+
+        assert_eq!(env.framework.as_deref(), Some("Vite + React"));
+        assert_eq!(env.package_manager.as_deref(), Some("pnpm"));
+    }
+
+    #[test]
+    fn test_inspect_environment_parses_cargo_lock_dependency_versions() {
+        let dir = tempdir().unwrap();
+        let path = dir.path();
+
+        fs::write(path.join("Cargo.toml"), "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n").unwrap();
+        fs::write(
+            path.join("Cargo.lock"),
+            "[[package]]\nname = \"serde\"\nversion = \"1.0.200\"\n",
+        )
+        .unwrap();
+
+        let env = inspect_environment(path);
+
+        assert_eq!(env.rust_crate_name.as_deref(), Some("demo"));
+        assert_eq!(env.rust_dependencies.len(), 1);
+        assert_eq!(env.rust_dependencies[0].name, "serde");
+        assert_eq!(env.rust_dependencies[0].version, "1.0.200");
+    }
 }
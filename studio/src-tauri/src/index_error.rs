@@ -0,0 +1,138 @@
+//! Typed Errors for Indexing Commands (RFC-108 addendum)
+//!
+//! Every indexing command used to return `Result<_, String>`, built from
+//! ad-hoc `format!`/`.to_string()` calls at each failure site — fine for a
+//! toast, but the frontend had no way to tell "no workspace open" apart
+//! from "index corrupted" without string-matching the message, so it
+//! couldn't e.g. offer a rebuild button only when the index is actually
+//! `Corrupted`. `IndexError`, modeled on MeiliSearch's `Code`/`ErrCode`
+//! split, gives each failure a stable machine-readable `code` and a coarse
+//! `category` (`user_error` vs `internal`), serialized as
+//! `{ code, category, message }` instead of a flat string.
+//!
+//! Deliberately separate from `error::SunwellError`: that type's numeric
+//! `ErrorCode` domain is generated from schemas/error-codes.yaml for the
+//! Python/Rust shared error system, and indexing's failure modes (no
+//! workspace, binary missing, corrupted index) have no home there.
+
+use serde::Serialize;
+
+/// Coarse failure kind, so the frontend can decide whether a retry is even
+/// worth offering without inspecting `code` string-by-string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexErrorCategory {
+    /// Caused by the current app/request state (no workspace open yet,
+    /// unknown project id, invalid settings) — the user or frontend can
+    /// fix it directly.
+    UserError,
+    /// Caused by the environment or the `sunwell` subprocess — nothing the
+    /// frontend can fix except retry, rebuild, or report it.
+    Internal,
+}
+
+#[derive(Debug, Clone)]
+pub enum IndexError {
+    /// No project is open, or no index has been started for it yet.
+    NoWorkspace,
+    /// No `IndexHandle` exists for the given project id.
+    IndexNotFound { project_id: String },
+    /// `IndexSettings.exclude_patterns` (or a `.gitignore`/`.sunwellignore`
+    /// file) failed to compile into a `RuleSet`, or a query asked for an
+    /// unsupported `export_format`.
+    InvalidSettings(String),
+    /// The `sunwell` binary isn't on `PATH`.
+    BinaryNotFound,
+    /// The `sunwell index build` subprocess exited non-zero.
+    BuildFailed { exit_code: Option<i32> },
+    /// `sunwell index query` failed to run or its output couldn't be read.
+    QueryFailed(String),
+    /// `sunwell index metrics` failed to run or its output couldn't be read.
+    MetricsFailed(String),
+    /// The index answered successfully but its response was unreadable —
+    /// the on-disk index is almost certainly corrupt and needs a rebuild.
+    Corrupted(String),
+    /// Spawning or signaling the subprocess failed for a reason other than
+    /// a missing binary (e.g. the OS refused to deliver the kill signal), or
+    /// writing a query export file failed.
+    ProcessError(String),
+}
+
+impl IndexError {
+    /// Stable, machine-readable identifier for the frontend to switch on.
+    pub fn code(&self) -> &'static str {
+        match self {
+            IndexError::NoWorkspace => "no_workspace",
+            IndexError::IndexNotFound { .. } => "index_not_found",
+            IndexError::InvalidSettings(_) => "invalid_settings",
+            IndexError::BinaryNotFound => "binary_not_found",
+            IndexError::BuildFailed { .. } => "build_failed",
+            IndexError::QueryFailed(_) => "query_failed",
+            IndexError::MetricsFailed(_) => "metrics_failed",
+            IndexError::Corrupted(_) => "corrupted",
+            IndexError::ProcessError(_) => "process_error",
+        }
+    }
+
+    pub fn category(&self) -> IndexErrorCategory {
+        match self {
+            IndexError::NoWorkspace | IndexError::IndexNotFound { .. } | IndexError::InvalidSettings(_) => {
+                IndexErrorCategory::UserError
+            }
+            IndexError::BinaryNotFound
+            | IndexError::BuildFailed { .. }
+            | IndexError::QueryFailed(_)
+            | IndexError::MetricsFailed(_)
+            | IndexError::Corrupted(_)
+            | IndexError::ProcessError(_) => IndexErrorCategory::Internal,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            IndexError::NoWorkspace => "No workspace is open for this project".to_string(),
+            IndexError::IndexNotFound { project_id } => format!("No index for project {}", project_id),
+            IndexError::InvalidSettings(detail) => format!("Invalid index settings: {}", detail),
+            IndexError::BinaryNotFound => "The sunwell CLI could not be found on PATH".to_string(),
+            IndexError::BuildFailed { exit_code: Some(code) } => format!("Indexing exited with code {}", code),
+            IndexError::BuildFailed { exit_code: None } => "Indexing process exited without a status code".to_string(),
+            IndexError::QueryFailed(detail) => format!("Index query failed: {}", detail),
+            IndexError::MetricsFailed(detail) => format!("Failed to read index metrics: {}", detail),
+            IndexError::Corrupted(detail) => format!("Index is corrupted: {}", detail),
+            IndexError::ProcessError(detail) => format!("Indexing process error: {}", detail),
+        }
+    }
+
+    /// Classify a failure to spawn or signal the `sunwell` subprocess: a
+    /// `NotFound` io error means the binary isn't installed, anything else
+    /// is an opaque process error.
+    pub fn from_spawn_error(e: &std::io::Error) -> Self {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            IndexError::BinaryNotFound
+        } else {
+            IndexError::ProcessError(e.to_string())
+        }
+    }
+}
+
+impl Serialize for IndexError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("category", &self.category())?;
+        state.serialize_field("message", &self.message())?;
+        state.end()
+    }
+}
+
+impl std::fmt::Display for IndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+
+impl std::error::Error for IndexError {}
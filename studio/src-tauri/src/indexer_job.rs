@@ -0,0 +1,131 @@
+//! Resumable Indexing Job Checkpoints (RFC-108 addendum)
+//!
+//! `start_indexing_service` used to spawn the `sunwell index build`
+//! subprocess and lose all progress the moment the app closed or crashed
+//! mid-build — a large workspace paid for a full re-index every time. This
+//! module adds the write side that closes that gap, the same way
+//! `job_manager::JobManager` does for agent runs: fold each streamed
+//! `IndexStatus` update into an `IndexJobState` and persist it as msgpack
+//! to `.sunwell/index/job_state.msgpack`, atomically (write-temp-then-
+//! rename, per `job_manager::write_atomic`) so a crash mid-serialization
+//! can never corrupt the on-disk state.
+//!
+//! `start_indexing_service` reads this file back to decide whether to
+//! resume an incomplete job instead of rebuilding from scratch;
+//! `rebuild_index` deletes it so a forced rebuild always starts clean.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Bumped whenever `IndexJobState`'s shape changes in a way that isn't
+/// backward-compatible for readers.
+pub const INDEX_JOB_SCHEMA_VERSION: u32 = 1;
+
+/// Minimum time between checkpoint writes while status updates stream in,
+/// so a fast-moving build (many small files) doesn't turn every line of
+/// CLI output into a disk write.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Persisted progress for one workspace's indexing job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexJobState {
+    pub schema_version: u32,
+    pub workspace_root: String,
+    /// Path of the file the build last reported as its `current_file` —
+    /// passed back to the build subprocess as `--resume-from` on resume.
+    pub cursor: Option<String>,
+    /// Every earlier cursor, in order: the files the build has moved past.
+    /// Only persisted; a resume hands just `cursor` to the subprocess.
+    pub files_done: Vec<String>,
+    /// Current phase, mirroring `IndexState` as a string (`building`,
+    /// `verifying`, ...) so the job file stays readable without importing
+    /// `indexing`'s types.
+    pub phase: String,
+    pub complete: bool,
+    pub updated_at: String,
+}
+
+impl IndexJobState {
+    pub fn new(workspace_root: &Path) -> Self {
+        Self {
+            schema_version: INDEX_JOB_SCHEMA_VERSION,
+            workspace_root: workspace_root.to_string_lossy().into_owned(),
+            cursor: None,
+            files_done: Vec::new(),
+            phase: "building".to_string(),
+            complete: false,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Fold one streamed `IndexStatus` update into this job's progress.
+    pub fn record_status(&mut self, phase: &str, current_file: Option<&str>, complete: bool) {
+        self.phase = phase.to_string();
+        if let Some(file) = current_file {
+            if self.cursor.as_deref() != Some(file) {
+                if let Some(prev) = self.cursor.replace(file.to_string()) {
+                    self.files_done.push(prev);
+                }
+            }
+        }
+        self.complete = complete;
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+    }
+}
+
+fn job_dir(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".sunwell").join("index")
+}
+
+fn job_state_path(workspace_root: &Path) -> PathBuf {
+    job_dir(workspace_root).join("job_state.msgpack")
+}
+
+/// Write `state` atomically: serialize to a sibling `.tmp` file, then
+/// `rename` it into place.
+pub fn write_job_state_atomic(workspace_root: &Path, state: &IndexJobState) -> std::io::Result<()> {
+    let dir = job_dir(workspace_root);
+    std::fs::create_dir_all(&dir)?;
+    let final_path = job_state_path(workspace_root);
+    let tmp_path = dir.join("job_state.msgpack.tmp");
+    let bytes = rmp_serde::to_vec(state).map_err(std::io::Error::other)?;
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, &final_path)
+}
+
+/// Read back a previously persisted job state, if any. A missing or
+/// unreadable file yields `None` rather than an error — there's simply
+/// nothing to resume from.
+pub fn read_job_state(workspace_root: &Path) -> Option<IndexJobState> {
+    let bytes = std::fs::read(job_state_path(workspace_root)).ok()?;
+    rmp_serde::from_slice(&bytes).ok()
+}
+
+/// Delete the persisted job state, so a forced rebuild always starts
+/// clean. A missing file is not an error.
+pub fn delete_job_state(workspace_root: &Path) {
+    let _ = std::fs::remove_file(job_state_path(workspace_root));
+}
+
+/// Throttles checkpoint writes to `CHECKPOINT_INTERVAL`. `should_write`
+/// always returns `true` the first time (there's no prior checkpoint to
+/// rate-limit against) and for a `force` write (the final status update,
+/// where progress must never be dropped on the floor).
+#[derive(Default)]
+pub struct CheckpointThrottle {
+    last_write: Option<Instant>,
+}
+
+impl CheckpointThrottle {
+    pub fn should_write(&mut self, force: bool) -> bool {
+        let ready = match self.last_write {
+            None => true,
+            Some(t) => force || t.elapsed() >= CHECKPOINT_INTERVAL,
+        };
+        if ready {
+            self.last_write = Some(Instant::now());
+        }
+        ready
+    }
+}
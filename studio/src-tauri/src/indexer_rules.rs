@@ -0,0 +1,179 @@
+//! Gitignore-Aware Indexer Rule Engine (RFC-108 addendum)
+//!
+//! `IndexSettings.exclude_patterns` used to be a raw `Vec<String>` passed
+//! opaquely to the `sunwell index build` subprocess, with no way to
+//! express accept/reject precedence or reuse existing ignore files. This
+//! module adds a real rule engine, modeled on Spacedrive's indexer rules:
+//! an `IndexerRule` pairs a declarative `IndexerRuleKind` with a compiled
+//! `globset::GlobSet` (or directory-name set), and a `RuleSet` evaluates a
+//! path against every configured rule — any matching reject rule wins over
+//! accepts, and the default (no accept rules configured, or nothing
+//! matched) is accept.
+//!
+//! Rules are loaded from three sources, in order: `.gitignore`,
+//! `.sunwellignore`, and `IndexSettings.exclude_patterns` — see
+//! `build_ruleset`. They're compiled once, not re-parsed per path, so
+//! `IndexingState` stores the already-compiled `RuleSet` and only
+//! recompiles it when `start_indexing_service` opens a workspace or
+//! `set_index_settings` changes the source patterns.
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Declarative rule configuration, as loaded from an ignore file or
+/// `IndexSettings.exclude_patterns`. Kept separate from the compiled
+/// `GlobSet` so the raw patterns can round-trip through settings JSON —
+/// the same split `run_analysis::RunCommand` uses between its `when`
+/// string and derived `condition`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IndexerRuleKind {
+    AcceptFilesByGlob { globs: Vec<String> },
+    RejectFilesByGlob { globs: Vec<String> },
+    AcceptIfChildrenDirectoriesArePresent { children: Vec<String> },
+    RejectIfAncestorDirectoryName { names: Vec<String> },
+}
+
+/// One compiled rule. `kind` is the serializable source of truth;
+/// `compiled` is rebuilt from it every time a `RuleSet` is constructed and
+/// never serialized.
+#[derive(Debug, Clone)]
+pub struct IndexerRule {
+    pub kind: IndexerRuleKind,
+    compiled: CompiledRule,
+}
+
+#[derive(Debug, Clone)]
+enum CompiledRule {
+    Glob(GlobSet),
+    Names(HashSet<String>),
+}
+
+impl IndexerRule {
+    pub fn compile(kind: IndexerRuleKind) -> Result<Self, String> {
+        let compiled = match &kind {
+            IndexerRuleKind::AcceptFilesByGlob { globs } | IndexerRuleKind::RejectFilesByGlob { globs } => {
+                CompiledRule::Glob(compile_globset(globs)?)
+            }
+            IndexerRuleKind::AcceptIfChildrenDirectoriesArePresent { children } => {
+                CompiledRule::Names(children.iter().cloned().collect())
+            }
+            IndexerRuleKind::RejectIfAncestorDirectoryName { names } => {
+                CompiledRule::Names(names.iter().cloned().collect())
+            }
+        };
+        Ok(Self { kind, compiled })
+    }
+
+    fn is_accept(&self) -> bool {
+        matches!(
+            self.kind,
+            IndexerRuleKind::AcceptFilesByGlob { .. } | IndexerRuleKind::AcceptIfChildrenDirectoriesArePresent { .. }
+        )
+    }
+
+    /// Whether this rule applies to `relative_path` — already made
+    /// relative to the workspace root, since glob/name matching must not
+    /// see absolute paths (a workspace opened from any location should
+    /// match identically). `dir_entries` is the directory's immediate
+    /// child names, only consulted by
+    /// `AcceptIfChildrenDirectoriesArePresent`; pass `None` for files.
+    fn matches(&self, relative_path: &Path, is_dir: bool, dir_entries: Option<&[String]>) -> bool {
+        match (&self.kind, &self.compiled) {
+            (IndexerRuleKind::AcceptFilesByGlob { .. }, CompiledRule::Glob(set))
+            | (IndexerRuleKind::RejectFilesByGlob { .. }, CompiledRule::Glob(set)) => set.is_match(relative_path),
+            (IndexerRuleKind::AcceptIfChildrenDirectoriesArePresent { .. }, CompiledRule::Names(names)) => {
+                is_dir && dir_entries.is_some_and(|entries| entries.iter().any(|entry| names.contains(entry)))
+            }
+            (IndexerRuleKind::RejectIfAncestorDirectoryName { .. }, CompiledRule::Names(names)) => relative_path
+                .components()
+                .any(|component| component.as_os_str().to_str().is_some_and(|name| names.contains(name))),
+            _ => false,
+        }
+    }
+}
+
+fn compile_globset(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| format!("Invalid glob pattern '{}': {}", pattern, e))?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| format!("Failed to compile indexer glob set: {}", e))
+}
+
+/// A compiled set of rules, ready to evaluate paths without re-parsing
+/// patterns. Built once — at `start_indexing_service` time, or whenever
+/// `set_index_settings` changes `exclude_patterns`. `ProjectDetector::walk`
+/// checks each entry against a set of its own, compiled from the
+/// workspace's ignore files without `exclude_patterns`, before descending
+/// into or counting it.
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<IndexerRule>,
+}
+
+impl RuleSet {
+    pub fn compile(kinds: Vec<IndexerRuleKind>) -> Result<Self, String> {
+        let rules = kinds.into_iter().map(IndexerRule::compile).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Whether `relative_path` should be indexed. A directory that comes
+    /// back `false` must not be descended into at all — its whole subtree
+    /// is excluded, not just the directory entry itself.
+    pub fn is_indexable(&self, relative_path: &Path, is_dir: bool, dir_entries: Option<&[String]>) -> bool {
+        let mut any_accept_rule = false;
+        let mut accepted = false;
+
+        for rule in &self.rules {
+            let matched = rule.matches(relative_path, is_dir, dir_entries);
+            if rule.is_accept() {
+                any_accept_rule = true;
+                accepted = accepted || matched;
+            } else if matched {
+                // A matching reject rule wins over any accept, regardless
+                // of which was declared first.
+                return false;
+            }
+        }
+
+        if any_accept_rule {
+            accepted
+        } else {
+            true
+        }
+    }
+}
+
+/// Load patterns from a `.gitignore`-style ignore file, one glob per
+/// non-empty, non-comment line. A missing file yields an empty list
+/// rather than an error — an absent optional ignore file isn't a failure.
+pub fn load_ignore_file(path: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')).map(str::to_string).collect()
+}
+
+/// Build the full `RuleSet` for a workspace: `.gitignore` and
+/// `.sunwellignore` each become their own `RejectFilesByGlob` rule (so one
+/// file's invalid pattern doesn't hide which file it came from), and
+/// `IndexSettings.exclude_patterns` becomes a final `RejectFilesByGlob`
+/// rule so existing settings keep working unchanged.
+pub fn build_ruleset(workspace_root: &Path, exclude_patterns: &[String]) -> Result<RuleSet, String> {
+    let mut kinds = Vec::new();
+    for ignore_file in [".gitignore", ".sunwellignore"] {
+        let globs = load_ignore_file(&workspace_root.join(ignore_file));
+        if !globs.is_empty() {
+            kinds.push(IndexerRuleKind::RejectFilesByGlob { globs });
+        }
+    }
+    if !exclude_patterns.is_empty() {
+        kinds.push(IndexerRuleKind::RejectFilesByGlob { globs: exclude_patterns.to_vec() });
+    }
+
+    RuleSet::compile(kinds)
+}
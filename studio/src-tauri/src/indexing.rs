@@ -1,11 +1,31 @@
 //! Codebase indexing Tauri commands (RFC-108)
 //!
 //! Provides commands for the IndexStatus component and semantic search.
-
+//!
+//! `IndexingState` used to hold a single `workspace_root`/`child_process`
+//! pair, so opening a second project clobbered the first's in-flight
+//! index. It's now an index-controller registry, inspired by MeiliSearch's
+//! actor index controller: a `HashMap<ProjectId, Arc<IndexHandle>>` where
+//! each `IndexHandle` owns its own status/settings/child process/rule set,
+//! keyed by `project::generate_project_id`. Every command below takes the
+//! `project_id` it should dispatch to, and `index-status` events carry that
+//! id so the frontend can keep several projects' indexes warm at once.
+//!
+//! Commands return `Result<_, index_error::IndexError>` rather than a raw
+//! `String`, so the frontend can branch on a stable `code` (e.g. offer a
+//! rebuild button only on `corrupted`) instead of matching message text.
+
+use crate::index_error::IndexError;
+use crate::indexer_job::{self, IndexJobState};
+use crate::indexer_rules::{build_ruleset, RuleSet};
+use crate::project::ProjectDetector;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, Manager, State};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
 use tokio::sync::RwLock;
 
 // ═══════════════════════════════════════════════════════════════
@@ -25,6 +45,10 @@ pub enum IndexState {
     Updating,
     Degraded,
     Error,
+    /// Build was paused mid-way and checkpointed; `resume_indexing_service`
+    /// (or a plain `start_indexing_service` on the same workspace) picks up
+    /// from the saved `IndexJobState` cursor.
+    Paused,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -49,6 +73,15 @@ pub struct IndexQuery {
     pub text: String,
     pub top_k: Option<u32>,
     pub threshold: Option<f32>,
+    /// Blend weight between semantic similarity and a lexical BM25-style
+    /// keyword score: `1.0` is semantic-only (the default when unset),
+    /// `0.0` is lexical-only. Lets an exact identifier match rank
+    /// alongside semantic neighbors instead of being drowned out by
+    /// embedding similarity alone. See `apply_hybrid_ranking`.
+    pub alpha: Option<f32>,
+    /// Output format for `query_index_stream`; only `"jsonl"` is
+    /// recognized today. Ignored by `query_index` itself.
+    pub export_format: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,16 +140,87 @@ pub struct IndexMetrics {
     pub is_healthy: bool,
 }
 
+/// `index-status` events carry the id of the project they belong to, since
+/// several projects can be indexing at once — unlike `IndexStatus` itself,
+/// this is never stored, only emitted.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexStatusEvent {
+    project_id: String,
+    status: IndexStatus,
+}
+
+/// Summary of one active index, for `list_active_indexes`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveIndexSummary {
+    pub project_id: String,
+    pub project_type: Option<String>,
+    pub state: IndexState,
+}
+
 // ═══════════════════════════════════════════════════════════════
 // STATE
 // ═══════════════════════════════════════════════════════════════
 
-#[derive(Default)]
-pub struct IndexingState {
+/// One project's index: its own status, settings, running build process,
+/// and compiled rule set, so indexing one project never touches another's.
+pub struct IndexHandle {
     pub status: Arc<RwLock<IndexStatus>>,
     pub settings: Arc<RwLock<IndexSettings>>,
-    pub workspace_root: Arc<RwLock<Option<PathBuf>>>,
+    pub workspace_root: PathBuf,
     pub child_process: Arc<RwLock<Option<tokio::process::Child>>>,
+    /// Compiled `.gitignore` / `.sunwellignore` / `exclude_patterns` rules
+    /// for this project. Rebuilt by `start_indexing_service` and
+    /// `set_index_settings`. Nothing reads it: `ProjectDetector::walk`
+    /// compiles its own set from the ignore files alone, so
+    /// `exclude_patterns` does not affect the walk.
+    pub rule_set: Arc<RwLock<RuleSet>>,
+}
+
+impl IndexHandle {
+    fn new(workspace_root: PathBuf) -> Self {
+        Self {
+            status: Arc::new(RwLock::new(IndexStatus::default())),
+            settings: Arc::new(RwLock::new(IndexSettings::default())),
+            workspace_root,
+            child_process: Arc::new(RwLock::new(None)),
+            rule_set: Arc::new(RwLock::new(RuleSet::default())),
+        }
+    }
+}
+
+/// Index-controller registry keyed by `project::generate_project_id`,
+/// modeled on MeiliSearch's actor index controller: each project gets its
+/// own `IndexHandle` rather than sharing one set of global fields, so
+/// opening a second project can't clobber the first's in-flight index.
+#[derive(Default)]
+pub struct IndexingState {
+    handles: RwLock<HashMap<String, Arc<IndexHandle>>>,
+}
+
+impl IndexingState {
+    /// Look up a project's handle, creating one rooted at `workspace_root`
+    /// if this is the first time `project_id` has been seen.
+    async fn get_or_create(&self, project_id: &str, workspace_root: &Path) -> Arc<IndexHandle> {
+        let mut handles = self.handles.write().await;
+        handles
+            .entry(project_id.to_string())
+            .or_insert_with(|| Arc::new(IndexHandle::new(workspace_root.to_path_buf())))
+            .clone()
+    }
+
+    /// Look up an already-started project's handle.
+    async fn get(&self, project_id: &str) -> Result<Arc<IndexHandle>, IndexError> {
+        self.handles
+            .read()
+            .await
+            .get(project_id)
+            .cloned()
+            .ok_or_else(|| IndexError::IndexNotFound {
+                project_id: project_id.to_string(),
+            })
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -127,175 +231,546 @@ pub struct IndexingState {
 pub async fn start_indexing_service(
     app: AppHandle,
     state: State<'_, IndexingState>,
+    project_id: String,
     workspace_path: String,
-) -> Result<(), String> {
+) -> Result<(), IndexError> {
+    if workspace_path.is_empty() {
+        return Err(IndexError::NoWorkspace);
+    }
     let path = PathBuf::from(&workspace_path);
-    *state.workspace_root.write().await = Some(path.clone());
+    let handle = state.get_or_create(&project_id, &path).await;
+
+    let exclude_patterns = handle.settings.read().await.exclude_patterns.clone();
+    let rule_set = build_ruleset(&path, &exclude_patterns).map_err(IndexError::InvalidSettings)?;
+    *handle.rule_set.write().await = rule_set;
+
+    // RFC-108 addendum: resume an incomplete job left behind by a prior
+    // crash or close instead of paying for a full re-index every time.
+    let job_state = match indexer_job::read_job_state(&path) {
+        Some(job) if !job.complete => job,
+        _ => IndexJobState::new(&path),
+    };
+    let resume_from = job_state.cursor.clone();
 
     let app_clone = app.clone();
-    let status = state.status.clone();
-    let child_holder = state.child_process.clone();
+    let status = handle.status.clone();
+    let child_holder = handle.child_process.clone();
 
     // Spawn background indexing task
     tokio::spawn(async move {
-        use tokio::io::{AsyncBufReadExt, BufReader};
-
-        // Start sunwell index build process
-        let child_result = tokio::process::Command::new("sunwell")
-            .args(["index", "build", "--json", "--progress"])
-            .current_dir(&path)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn();
-
-        let mut child = match child_result {
-            Ok(c) => c,
-            Err(e) => {
-                let mut s = status.write().await;
-                s.state = IndexState::Error;
-                s.error = Some(format!("Failed to start sunwell: {}", e));
-                let _ = app_clone.emit("index-status", s.clone());
-                return;
-            }
-        };
+        run_build(
+            app_clone,
+            project_id,
+            status,
+            child_holder,
+            path,
+            job_state,
+            resume_from,
+        )
+        .await;
+    });
 
-        // Read stdout for status updates
-        if let Some(stdout) = child.stdout.take() {
-            let reader = BufReader::new(stdout);
-            let mut lines = reader.lines();
+    Ok(())
+}
+
+/// Spawn the `sunwell index build` subprocess, stream its status updates,
+/// and checkpoint progress via `indexer_job` as it goes. Shared by
+/// `start_indexing_service` (fresh or auto-resumed) and
+/// `resume_indexing_service` (explicit resume) so the spawn/stream/
+/// checkpoint sequence only lives in one place.
+#[allow(clippy::too_many_arguments)]
+async fn run_build(
+    app: AppHandle,
+    project_id: String,
+    status: Arc<RwLock<IndexStatus>>,
+    child_holder: Arc<RwLock<Option<tokio::process::Child>>>,
+    path: PathBuf,
+    mut job_state: IndexJobState,
+    resume_from: Option<String>,
+) {
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    // RFC-108 addendum: the CLI's own `--progress` lines can be sparse
+    // early on, so run a live file-count scan alongside the build to give
+    // the UI real numbers right away. Whichever of the two updates
+    // `IndexStatus` last wins: a CLI status line replaces the whole status,
+    // and the walker overwrites `file_count` on every tick until its walk
+    // finishes.
+    tokio::spawn(walk_with_live_status(
+        app.clone(),
+        project_id.clone(),
+        status.clone(),
+        path.clone(),
+    ));
+
+    let mut args = vec![
+        "index".to_string(),
+        "build".to_string(),
+        "--json".to_string(),
+        "--progress".to_string(),
+    ];
+    if let Some(cursor) = &resume_from {
+        args.push("--resume-from".to_string());
+        args.push(cursor.clone());
+    }
 
-            while let Ok(Some(line)) = lines.next_line().await {
-                if let Ok(update) = serde_json::from_str::<IndexStatus>(&line) {
-                    *status.write().await = update.clone();
-                    let _ = app_clone.emit("index-status", update);
+    let child_result = tokio::process::Command::new("sunwell")
+        .args(&args)
+        .current_dir(&path)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn();
+
+    let mut child = match child_result {
+        Ok(c) => c,
+        Err(e) => {
+            let err = IndexError::from_spawn_error(&e);
+            let mut s = status.write().await;
+            s.state = IndexState::Error;
+            s.error = Some(err.message());
+            emit_status(&app, &project_id, &s);
+            return;
+        }
+    };
+
+    let mut throttle = indexer_job::CheckpointThrottle::default();
+    let stdout = child.stdout.take();
+
+    // Hand the child to shared state so `pause_indexing_service` can reach
+    // in and kill it; the reading loop below holds no other reference to it.
+    *child_holder.write().await = Some(child);
+
+    // Read stdout for status updates
+    if let Some(stdout) = stdout {
+        let reader = BufReader::new(stdout);
+        let mut lines = reader.lines();
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Ok(update) = serde_json::from_str::<IndexStatus>(&line) {
+                let phase = serde_json::to_value(&update.state)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                job_state.record_status(&phase, update.current_file.as_deref(), false);
+                if throttle.should_write(false) {
+                    let _ = indexer_job::write_job_state_atomic(&path, &job_state);
                 }
+                *status.write().await = update.clone();
+                emit_status(&app, &project_id, &update);
             }
         }
+    }
 
-        // Wait for process to complete
-        let exit_status = child.wait().await;
+    // If `pause_indexing_service` already took the child out to kill it,
+    // there's nothing left to wait on — the last checkpoint written above
+    // is exactly what `resume_indexing_service` needs.
+    let Some(mut child) = child_holder.write().await.take() else {
+        return;
+    };
 
-        // Check exit status
-        if let Ok(status_code) = exit_status {
-            if !status_code.success() {
-                let mut s = status.write().await;
-                s.state = IndexState::Error;
-                s.error = Some(format!("Indexing exited with code {:?}", status_code.code()));
-                let _ = app_clone.emit("index-status", s.clone());
-            }
+    // Wait for process to complete
+    let exit_status = child.wait().await;
+
+    // Check exit status
+    let complete = matches!(&exit_status, Ok(code) if code.success());
+    let phase = job_state.phase.clone();
+    job_state.record_status(&phase, None, complete);
+    let _ = indexer_job::write_job_state_atomic(&path, &job_state);
+
+    if let Ok(status_code) = exit_status {
+        if !status_code.success() {
+            let err = IndexError::BuildFailed {
+                exit_code: status_code.code(),
+            };
+            let mut s = status.write().await;
+            s.state = IndexState::Error;
+            s.error = Some(err.message());
+            emit_status(&app, &project_id, &s);
         }
+    }
+}
+
+/// Run `ProjectDetector::walk` over `path` and mirror its live file count
+/// into `status` every tick, so the UI has real numbers to show before (or
+/// even instead of, on a CLI version too old to emit `--progress` lines)
+/// the build subprocess reports anything itself.
+async fn walk_with_live_status(
+    app: AppHandle,
+    project_id: String,
+    status: Arc<RwLock<IndexStatus>>,
+    path: PathBuf,
+) {
+    let files_so_far = Arc::new(AtomicU64::new(0));
+    let counter = files_so_far.clone();
+
+    let walk_future = ProjectDetector::new().walk(&path, move |files, _bytes| {
+        counter.store(files, Ordering::Relaxed);
     });
+    tokio::pin!(walk_future);
+
+    let mut ticker = tokio::time::interval(Duration::from_millis(500));
+    let result = loop {
+        tokio::select! {
+            result = &mut walk_future => break result,
+            _ = ticker.tick() => {
+                publish_file_count(&app, &project_id, &status, files_so_far.load(Ordering::Relaxed)).await;
+            }
+        }
+    };
 
-    Ok(())
+    publish_file_count(&app, &project_id, &status, result.files_count).await;
+}
+
+async fn publish_file_count(
+    app: &AppHandle,
+    project_id: &str,
+    status: &Arc<RwLock<IndexStatus>>,
+    file_count: u64,
+) {
+    let mut s = status.write().await;
+    s.file_count = Some(file_count as u32);
+    emit_status(app, project_id, &s);
+}
+
+/// Emit an `index-status` event tagged with the project it belongs to.
+fn emit_status(app: &AppHandle, project_id: &str, status: &IndexStatus) {
+    let _ = app.emit(
+        "index-status",
+        IndexStatusEvent {
+            project_id: project_id.to_string(),
+            status: status.clone(),
+        },
+    );
 }
 
 #[tauri::command]
-pub async fn stop_indexing_service(state: State<'_, IndexingState>) -> Result<(), String> {
-    if let Some(mut child) = state.child_process.write().await.take() {
+pub async fn stop_indexing_service(
+    state: State<'_, IndexingState>,
+    project_id: String,
+) -> Result<(), IndexError> {
+    let handle = state.get(&project_id).await?;
+
+    if let Some(mut child) = handle.child_process.write().await.take() {
         let _ = child.kill().await;
     }
 
-    let mut status = state.status.write().await;
+    let mut status = handle.status.write().await;
     *status = IndexStatus::default();
 
     Ok(())
 }
 
+/// Pause the running build: best-effort terminate the subprocess (there's
+/// no IPC protocol with the CLI for a graceful "checkpoint and exit"
+/// signal, so this is the same `start_kill` approach `naaru_cancel_all`
+/// uses) and leave the last-streamed `IndexJobState` checkpoint in place
+/// for `resume_indexing_service` to pick up from.
+#[tauri::command]
+pub async fn pause_indexing_service(
+    state: State<'_, IndexingState>,
+    project_id: String,
+) -> Result<(), IndexError> {
+    let handle = state.get(&project_id).await?;
+
+    if let Some(mut child) = handle.child_process.write().await.take() {
+        child
+            .start_kill()
+            .map_err(|e| IndexError::from_spawn_error(&e))?;
+    }
+
+    let mut status = handle.status.write().await;
+    status.state = IndexState::Paused;
+
+    Ok(())
+}
+
+/// Resume a paused or interrupted build for a project that's already been
+/// started at least once (and so already has a handle). `start_indexing_service`
+/// already auto-detects an incomplete job, so this just re-invokes it for
+/// the workspace root recorded in the project's handle — the distinct
+/// command exists so the frontend can offer "Resume" without re-supplying
+/// a `workspace_path` it may no longer have on hand.
+#[tauri::command]
+pub async fn resume_indexing_service(
+    app: AppHandle,
+    state: State<'_, IndexingState>,
+    project_id: String,
+) -> Result<(), IndexError> {
+    let handle = state.get(&project_id).await?;
+    let root_str = handle.workspace_root.to_string_lossy().into_owned();
+    start_indexing_service(app, state, project_id, root_str).await
+}
+
 #[tauri::command]
 pub async fn query_index(
-    query: IndexQuery,
     state: State<'_, IndexingState>,
-) -> Result<IndexResult, String> {
-    let workspace_root = state.workspace_root.read().await;
-    let Some(root) = workspace_root.as_ref() else {
-        return Ok(IndexResult {
-            chunks: vec![],
-            fallback_used: true,
-            query_time_ms: 0,
-            total_chunks_searched: 0,
-        });
-    };
+    project_id: String,
+    query: IndexQuery,
+) -> Result<IndexResult, IndexError> {
+    let handle = state.get(&project_id).await?;
+    run_index_query(&handle, &query).await
+}
 
+/// Run `sunwell index query` and re-rank the returned chunks, shared by
+/// `query_index` (materialized `Vec`) and `query_index_stream` (written out
+/// as JSONL). The CLI's own `score` is treated as the semantic component;
+/// `query.alpha`, if set, blends it with a lexical BM25-style score over
+/// `content`/`name` via `apply_hybrid_ranking` before `query.threshold` is
+/// applied, so both modes see identical ranking and filtering.
+async fn run_index_query(
+    handle: &IndexHandle,
+    query: &IndexQuery,
+) -> Result<IndexResult, IndexError> {
     let top_k = query.top_k.unwrap_or(10);
 
+    let mut args = vec![
+        "index".to_string(),
+        "query".to_string(),
+        "--json".to_string(),
+        "--top-k".to_string(),
+        top_k.to_string(),
+    ];
+    if let Some(threshold) = query.threshold {
+        args.push("--threshold".to_string());
+        args.push(threshold.to_string());
+    }
+    args.push(query.text.clone());
+
     let output = std::process::Command::new("sunwell")
-        .args([
-            "index",
-            "query",
-            "--json",
-            "--top-k",
-            &top_k.to_string(),
-            &query.text,
-        ])
-        .current_dir(root)
+        .args(&args)
+        .current_dir(&handle.workspace_root)
         .output()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| IndexError::from_spawn_error(&e))?;
 
-    if output.status.success() {
-        serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
-    } else {
-        Ok(IndexResult {
+    if !output.status.success() {
+        return Ok(IndexResult {
             chunks: vec![],
             fallback_used: true,
             query_time_ms: 0,
             total_chunks_searched: 0,
+        });
+    }
+
+    // A successful exit with an unreadable response body means the index
+    // itself is in a bad state, not that this particular query failed —
+    // that's exactly the signal the UI's rebuild button needs.
+    let mut result: IndexResult =
+        serde_json::from_slice(&output.stdout).map_err(|e| IndexError::Corrupted(e.to_string()))?;
+
+    if let Some(alpha) = query.alpha {
+        apply_hybrid_ranking(&mut result.chunks, &query.text, alpha);
+    }
+    if let Some(threshold) = query.threshold {
+        result.chunks.retain(|chunk| chunk.score >= threshold);
+    }
+    result.chunks.truncate(top_k as usize);
+
+    Ok(result)
+}
+
+/// Re-rank `chunks` in place by blending each chunk's existing (semantic)
+/// `score` with a lexical BM25-style score computed over `content` and
+/// `name` against `query_text`, so an exact identifier match ranks
+/// alongside semantic neighbors instead of being drowned out by embedding
+/// similarity alone. `alpha` is the semantic weight (`1.0` = semantic only,
+/// `0.0` = lexical only); IDF is computed over this result set, not the
+/// whole corpus, since that's all the CLI response gives us to work with.
+fn apply_hybrid_ranking(chunks: &mut [IndexChunk], query_text: &str, alpha: f32) {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let query_terms = tokenize(query_text);
+    if query_terms.is_empty() {
+        return;
+    }
+
+    let docs: Vec<Vec<String>> = chunks
+        .iter()
+        .map(|c| {
+            tokenize(&format!(
+                "{} {}",
+                c.name.as_deref().unwrap_or(""),
+                c.content
+            ))
         })
+        .collect();
+    let doc_count = docs.len() as f32;
+    let avg_doc_len = if docs.is_empty() {
+        0.0
+    } else {
+        docs.iter().map(|d| d.len() as f32).sum::<f32>() / doc_count
+    };
+
+    const K1: f32 = 1.2;
+    const B: f32 = 0.75;
+
+    let lexical_scores: Vec<f32> = docs
+        .iter()
+        .map(|doc| {
+            let doc_len = doc.len() as f32;
+            query_terms
+                .iter()
+                .map(|term| {
+                    let df = docs.iter().filter(|d| d.contains(term)).count() as f32;
+                    if df == 0.0 {
+                        return 0.0;
+                    }
+                    let idf = ((doc_count - df + 0.5) / (df + 0.5) + 1.0).ln();
+                    let tf = doc.iter().filter(|t| *t == term).count() as f32;
+                    let denom = tf + K1 * (1.0 - B + B * (doc_len / avg_doc_len.max(1.0)));
+                    idf * (tf * (K1 + 1.0)) / denom.max(f32::EPSILON)
+                })
+                .sum()
+        })
+        .collect();
+
+    let max_lexical = lexical_scores.iter().cloned().fold(0.0_f32, f32::max);
+
+    for (chunk, lexical) in chunks.iter_mut().zip(lexical_scores) {
+        let normalized_lexical = if max_lexical > 0.0 {
+            lexical / max_lexical
+        } else {
+            0.0
+        };
+        chunk.score = alpha * chunk.score + (1.0 - alpha) * normalized_lexical;
     }
+
+    chunks.sort_by(|a, b| b.score.total_cmp(&a.score));
 }
 
+/// Lowercase, alphanumeric-only tokenization shared by `apply_hybrid_ranking`.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// Stream `query_index`'s matched chunks to `output_path` as JSONL (one
+/// `IndexChunk` per line), modeled on MeiliSearch's jsonl document export,
+/// so a large result set can be piped to other tools without the caller
+/// ever materializing the whole `Vec<IndexChunk>` over Tauri's IPC
+/// boundary. `query.export_format` must be `"jsonl"` (or unset, which
+/// defaults to it) — it exists so future formats have a place to slot in
+/// without changing this command's signature again.
 #[tauri::command]
-pub async fn get_index_status(state: State<'_, IndexingState>) -> Result<IndexStatus, String> {
-    Ok(state.status.read().await.clone())
+pub async fn query_index_stream(
+    state: State<'_, IndexingState>,
+    project_id: String,
+    query: IndexQuery,
+    output_path: String,
+) -> Result<u32, IndexError> {
+    let handle = state.get(&project_id).await?;
+
+    if let Some(format) = &query.export_format {
+        if format != "jsonl" {
+            return Err(IndexError::InvalidSettings(format!(
+                "Unsupported export_format '{}'",
+                format
+            )));
+        }
+    }
+
+    let result = run_index_query(&handle, &query).await?;
+
+    let mut out = String::new();
+    for chunk in &result.chunks {
+        let line =
+            serde_json::to_string(chunk).map_err(|e| IndexError::QueryFailed(e.to_string()))?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    std::fs::write(&output_path, out).map_err(|e| IndexError::ProcessError(e.to_string()))?;
+
+    Ok(result.chunks.len() as u32)
+}
+
+#[tauri::command]
+pub async fn get_index_status(
+    state: State<'_, IndexingState>,
+    project_id: String,
+) -> Result<IndexStatus, IndexError> {
+    let handle = state.get(&project_id).await?;
+    Ok(handle.status.read().await.clone())
 }
 
 #[tauri::command]
 pub async fn rebuild_index(
     app: AppHandle,
     state: State<'_, IndexingState>,
-) -> Result<(), String> {
-    let workspace_root = state.workspace_root.read().await;
-    let Some(root) = workspace_root.as_ref() else {
-        return Err("No workspace opened".into());
-    };
+    project_id: String,
+) -> Result<(), IndexError> {
+    let handle = state.get(&project_id).await?;
+    let root = handle.workspace_root.clone();
 
-    // Clear cache
+    // Clear cache and the resumable-job checkpoint, so a forced rebuild
+    // always starts clean rather than resuming from stale progress.
     let cache_dir = root.join(".sunwell").join("index");
     if cache_dir.exists() {
         let _ = std::fs::remove_dir_all(&cache_dir);
     }
+    indexer_job::delete_job_state(&root);
 
     let root_str = root.to_string_lossy().to_string();
 
     // Restart indexing
-    drop(workspace_root);
-    start_indexing_service(app, state, root_str).await
+    start_indexing_service(app, state, project_id, root_str).await
 }
 
 #[tauri::command]
 pub async fn set_index_settings(
-    settings: IndexSettings,
     state: State<'_, IndexingState>,
-) -> Result<(), String> {
-    *state.settings.write().await = settings;
+    project_id: String,
+    settings: IndexSettings,
+) -> Result<(), IndexError> {
+    let handle = state.get(&project_id).await?;
+
+    let rule_set = build_ruleset(&handle.workspace_root, &settings.exclude_patterns)
+        .map_err(IndexError::InvalidSettings)?;
+    *handle.rule_set.write().await = rule_set;
+    *handle.settings.write().await = settings;
+
     Ok(())
 }
 
 #[tauri::command]
-pub async fn get_index_metrics(state: State<'_, IndexingState>) -> Result<IndexMetrics, String> {
-    let workspace_root = state.workspace_root.read().await;
-    let Some(root) = workspace_root.as_ref() else {
-        return Err("No workspace opened".into());
-    };
+pub async fn get_index_metrics(
+    state: State<'_, IndexingState>,
+    project_id: String,
+) -> Result<IndexMetrics, IndexError> {
+    let handle = state.get(&project_id).await?;
 
     let output = std::process::Command::new("sunwell")
         .args(["index", "metrics", "--json"])
-        .current_dir(root)
+        .current_dir(&handle.workspace_root)
         .output()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| IndexError::from_spawn_error(&e))?;
 
     if output.status.success() {
-        serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())
+        serde_json::from_slice(&output.stdout).map_err(|e| IndexError::MetricsFailed(e.to_string()))
     } else {
-        Err("Failed to get metrics".into())
+        Err(IndexError::MetricsFailed(format!(
+            "sunwell index metrics exited with code {:?}",
+            output.status.code()
+        )))
+    }
+}
+
+/// List every project with an active (even if paused/errored) index, for
+/// the home-screen `RecentProject` list to show which projects are warm.
+#[tauri::command]
+pub async fn list_active_indexes(
+    state: State<'_, IndexingState>,
+) -> Result<Vec<ActiveIndexSummary>, IndexError> {
+    let handles = state.handles.read().await;
+    let mut summaries = Vec::with_capacity(handles.len());
+
+    for (project_id, handle) in handles.iter() {
+        let status = handle.status.read().await;
+        summaries.push(ActiveIndexSummary {
+            project_id: project_id.clone(),
+            project_type: status.project_type.clone(),
+            state: status.state.clone(),
+        });
     }
+
+    Ok(summaries)
 }
@@ -0,0 +1,86 @@
+//! Content-addressed digests and corruption detection for intelligence
+//! entries (RFC-119).
+//!
+//! `intelligence_store::reconcile` used to insert every decision/failure/
+//! dead-end it parsed, even if an identical one (same approach, same
+//! reason) had already been ingested from an earlier run — repeated agent
+//! runs frequently rediscover the same dead end verbatim. This module
+//! gives each record a content-addressed, Subresource-Integrity-style
+//! digest (`sha256-<base64>`, computed the same way `audit_integrity`
+//! hand-rolls its own hashing rather than pulling in a crate for one call
+//! site — here a tiny base64 encoder, since SRI's format is base64 rather
+//! than audit_integrity's hex) over its normalized text fields, so
+//! `reconcile` can dedupe by digest instead of by id and bump an
+//! `occurrence_count` on a repeat instead of inserting a duplicate row.
+//!
+//! A record *written with* a `contentHash` field of its own (forward
+//! compatible with a future CLI that stamps one on write) is also
+//! re-verified against its recomputed digest on ingest; a mismatch
+//! produces an `IntegrityError` carrying the source line number instead of
+//! being silently skipped like an ordinary malformed line, so a caller can
+//! tell "this project has no history yet" apart from "this project's
+//! history was corrupted".
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// A line that failed digest verification during ingest — its stored
+/// `contentHash` didn't match what was recomputed from its own content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityError {
+    /// 0-based line number within the source `.jsonl` file.
+    pub line: usize,
+    pub source_file: String,
+    pub message: String,
+}
+
+/// A stable content digest over `parts`, joined with a separator that
+/// can't appear in any individual field so e.g. `("ab", "c")` and
+/// `("a", "bc")` never collide.
+pub(crate) fn content_digest(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            hasher.update([0x1f]); // ASCII unit separator
+        }
+        hasher.update(part.trim().as_bytes());
+    }
+    format!("sha256-{}", base64_encode(&hasher.finalize()))
+}
+
+/// Whether `stored_digest` matches the digest freshly recomputed from
+/// `parts`. A missing/empty `stored_digest` is treated as "nothing to
+/// verify" (`true`) — most records predate this field existing at all.
+pub(crate) fn verify_digest(parts: &[&str], stored_digest: &str) -> bool {
+    stored_digest.is_empty() || stored_digest == content_digest(parts)
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard (padded) base64, since no base64 crate is in this tree and SRI
+/// digests need exactly this encoding for one call site.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(BASE64_ALPHABET[((triple >> 18) & 0x3f) as usize] as char);
+        out.push(BASE64_ALPHABET[((triple >> 12) & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[((triple >> 6) & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(triple & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
@@ -0,0 +1,585 @@
+//! SQLite-backed intelligence index — a rebuildable, queryable mirror of
+//! the decisions/failures/dead-ends logs (RFC-118).
+//!
+//! `.sunwell/intelligence/{decisions,failures,dead_ends}.jsonl` remain the
+//! append-only source of truth (see `memory::get_intelligence`); this
+//! module mirrors them into `.sunwell/intelligence/index.sqlite` so
+//! `search_dead_ends`/`recent_decisions` run as indexed SQL — including
+//! full-text search via an FTS5 virtual table over `approach`/`reason`/
+//! `rationale` — instead of a linear JSONL re-parse on every call.
+//! `reconcile` ingests only the lines appended since the last indexed byte
+//! offset (tracked per file in `meta`), so it stays cheap as a log grows,
+//! mirroring `dag_store`'s "JSONL stays the source of truth, SQLite is a
+//! rebuildable cache" philosophy — including free functions over a
+//! `Connection` rather than a dedicated store struct, the shape that
+//! module already established for this kind of index.
+//!
+//! Every query here reconciles first and then reads the database;
+//! callers should still treat a query error as non-fatal and fall back to
+//! `memory::get_intelligence` the way `dag_store`'s doc comment asks of
+//! its own callers.
+//!
+//! Each row also carries a `content_hash` (RFC-119, `intelligence_integrity`)
+//! — an SRI-style digest of its normalized text fields. Dead ends dedupe on
+//! it: an incoming line whose content matches an existing row bumps that
+//! row's `occurrence_count` instead of inserting a duplicate, since repeated
+//! agent runs rediscover the same dead end verbatim often enough to bloat
+//! `dead_ends.jsonl`. Decisions/failures already dedupe by their own stable
+//! `id` (an upsert, not an insert), so `content_hash` is recorded on them
+//! for integrity checking but doesn't change their insert behavior. Any
+//! incoming line that carries its own `contentHash` field is also
+//! re-verified against its recomputed digest; a mismatch is collected as an
+//! `IntegrityError` rather than silently dropped.
+
+use crate::intelligence_integrity::{content_digest, verify_digest, IntegrityError};
+use crate::memory::{Decision, FailedApproach};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the schema changes; `ensure_schema` wipes and
+/// recreates every table (and resets the ingest offsets, forcing a full
+/// re-ingest from byte 0) when the stored version doesn't match.
+const SCHEMA_VERSION: i64 = 2;
+
+fn db_path(project_path: &Path) -> PathBuf {
+    project_path.join(".sunwell/intelligence/index.sqlite")
+}
+
+fn open(project_path: &Path) -> Result<Connection, String> {
+    let path = db_path(project_path);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create intelligence directory: {}", e))?;
+    }
+    let conn =
+        Connection::open(&path).map_err(|e| format!("Failed to open intelligence store: {}", e))?;
+    ensure_schema(&conn)?;
+    Ok(conn)
+}
+
+fn ensure_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS meta (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create meta table: {}", e))?;
+
+    let stored_version: Option<i64> = conn
+        .query_row(
+            "SELECT value FROM meta WHERE key = 'schema_version'",
+            [],
+            |row| row.get::<_, String>(0),
+        )
+        .ok()
+        .and_then(|v| v.parse().ok());
+
+    if stored_version != Some(SCHEMA_VERSION) {
+        conn.execute_batch(
+            "DROP TABLE IF EXISTS decisions;
+             DROP TABLE IF EXISTS failures;
+             DROP TABLE IF EXISTS dead_ends;
+             DROP TABLE IF EXISTS intel_fts;
+             DELETE FROM meta WHERE key LIKE 'offset:%' OR key LIKE 'line_count:%';",
+        )
+        .map_err(|e| format!("Failed to reset intelligence store schema: {}", e))?;
+        conn.execute(
+            "INSERT INTO meta (key, value) VALUES ('schema_version', ?1)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            [SCHEMA_VERSION.to_string()],
+        )
+        .map_err(|e| format!("Failed to record schema version: {}", e))?;
+    }
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS decisions (
+            id TEXT PRIMARY KEY,
+            decision TEXT NOT NULL,
+            rationale TEXT NOT NULL,
+            created_at TEXT,
+            scope TEXT,
+            content_hash TEXT,
+            occurrence_count INTEGER NOT NULL DEFAULT 1
+         );
+         CREATE TABLE IF NOT EXISTS failures (
+            id TEXT PRIMARY KEY,
+            approach TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            created_at TEXT,
+            context TEXT,
+            content_hash TEXT,
+            occurrence_count INTEGER NOT NULL DEFAULT 1
+         );
+         CREATE TABLE IF NOT EXISTS dead_ends (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            approach TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            created_at TEXT,
+            context TEXT,
+            content_hash TEXT UNIQUE,
+            occurrence_count INTEGER NOT NULL DEFAULT 1
+         );
+         CREATE VIRTUAL TABLE IF NOT EXISTS intel_fts USING fts5(
+            source_kind UNINDEXED,
+            source_id UNINDEXED,
+            text
+         );",
+    )
+    .map_err(|e| format!("Failed to create intelligence store tables: {}", e))?;
+
+    Ok(())
+}
+
+fn stored_offset(conn: &Connection, file_key: &str) -> u64 {
+    conn.query_row(
+        "SELECT value FROM meta WHERE key = ?1",
+        [format!("offset:{}", file_key)],
+        |row| row.get::<_, String>(0),
+    )
+    .ok()
+    .and_then(|v| v.parse().ok())
+    .unwrap_or(0)
+}
+
+fn store_offset(tx: &rusqlite::Transaction, file_key: &str, offset: u64) -> Result<(), String> {
+    tx.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        rusqlite::params![format!("offset:{}", file_key), offset.to_string()],
+    )
+    .map_err(|e| format!("Failed to record ingest offset for {}: {}", file_key, e))?;
+    Ok(())
+}
+
+/// How many lines of `file_key` have already been ingested as of the last
+/// reconcile — the base a batch's local index is added to so
+/// `check_line_integrity` can report the line's absolute position in the
+/// `.jsonl` file rather than just its position within this batch.
+fn stored_line_count(conn: &Connection, file_key: &str) -> usize {
+    conn.query_row(
+        "SELECT value FROM meta WHERE key = ?1",
+        [format!("line_count:{}", file_key)],
+        |row| row.get::<_, String>(0),
+    )
+    .ok()
+    .and_then(|v| v.parse().ok())
+    .unwrap_or(0)
+}
+
+fn store_line_count(
+    tx: &rusqlite::Transaction,
+    file_key: &str,
+    line_count: usize,
+) -> Result<(), String> {
+    tx.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        rusqlite::params![format!("line_count:{}", file_key), line_count.to_string()],
+    )
+    .map_err(|e| format!("Failed to record ingest line count for {}: {}", file_key, e))?;
+    Ok(())
+}
+
+/// Reads the bytes of `path` appended since `offset`, returning them as
+/// whole lines (a partially-written final line, if the file is mid-append,
+/// is left unconsumed and will be re-read — with `offset` unadvanced past
+/// it — on the next reconcile) plus the byte offset up to which lines were
+/// actually consumed.
+fn read_new_lines(path: &Path, offset: u64) -> Result<(Vec<String>, u64), String> {
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let len = file
+        .metadata()
+        .map_err(|e| format!("Failed to stat {}: {}", path.display(), e))?
+        .len();
+    if len <= offset {
+        // File unchanged, or truncated/replaced shorter than our last
+        // offset — in the latter case, start over from the beginning
+        // rather than seeking past the new end.
+        return Ok((Vec::new(), if len < offset { 0 } else { offset }));
+    }
+
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| format!("Failed to seek {}: {}", path.display(), e))?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let ends_with_newline = buf.ends_with('\n');
+    let mut lines: Vec<&str> = buf.lines().collect();
+    let mut consumed = buf.len() as u64;
+    if !ends_with_newline {
+        // Trailing partial line from a write still in progress — drop it
+        // and don't advance the offset past it.
+        if let Some(partial) = lines.pop() {
+            consumed -= partial.len() as u64;
+        }
+    }
+
+    Ok((
+        lines
+            .into_iter()
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect(),
+        offset + consumed,
+    ))
+}
+
+/// Checks an incoming line's own `contentHash` field (if any) against the
+/// digest recomputed from `parts`, recording an `IntegrityError` on
+/// mismatch. Lines with no `contentHash` of their own have nothing to
+/// verify yet — most records predate this field existing at all.
+/// `line_number` must be the line's absolute, 0-based position in the
+/// source `.jsonl` file (batch index plus the line count already ingested
+/// as of the last reconcile), not just its index within this batch.
+fn check_line_integrity(
+    value: &serde_json::Value,
+    source_file: &str,
+    line_number: usize,
+    parts: &[&str],
+    errors: &mut Vec<IntegrityError>,
+) {
+    let stored = value
+        .get("contentHash")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    if !verify_digest(parts, stored) {
+        errors.push(IntegrityError {
+            line: line_number,
+            source_file: source_file.to_string(),
+            message: "stored contentHash does not match recomputed digest".to_string(),
+        });
+    }
+}
+
+/// One decision/failure/dead-end newly ingested by `reconcile`, as emitted
+/// to `intelligence_watcher`'s live subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IntelligenceEvent {
+    Decision(Decision),
+    Failure(FailedApproach),
+    DeadEnd(DeadEndRow),
+}
+
+/// Ingests whatever's been appended to each of the three `.jsonl` logs
+/// since the last reconcile, inside one transaction, and returns both any
+/// digest mismatches found and one `IntelligenceEvent` per newly ingested
+/// line (including repeat dead ends that only bumped an occurrence count —
+/// from a tailing subscriber's point of view, a line was still appended).
+/// JSONL stays the source of truth — a bad (unparseable) line is skipped,
+/// not an error for the whole reconcile, matching
+/// `memory::compute_intelligence_sync`'s best-effort parsing. A line that
+/// parses fine but carries a stale `contentHash` is not skipped — it's
+/// still ingested, and its digest mismatch is reported alongside it.
+pub(crate) fn reconcile(
+    project_path: &Path,
+) -> Result<(Vec<IntegrityError>, Vec<IntelligenceEvent>), String> {
+    let intel_path = project_path.join(".sunwell/intelligence");
+    let mut conn = open(project_path)?;
+    let mut integrity_errors = Vec::new();
+    let mut events = Vec::new();
+
+    let decisions_offset = stored_offset(&conn, "decisions");
+    let decisions_line_base = stored_line_count(&conn, "decisions");
+    let (decision_lines, decisions_new_offset) =
+        read_new_lines(&intel_path.join("decisions.jsonl"), decisions_offset).unwrap_or_default();
+
+    let failures_offset = stored_offset(&conn, "failures");
+    let failures_line_base = stored_line_count(&conn, "failures");
+    let (failure_lines, failures_new_offset) =
+        read_new_lines(&intel_path.join("failures.jsonl"), failures_offset).unwrap_or_default();
+
+    let dead_ends_offset = stored_offset(&conn, "dead_ends");
+    let dead_ends_line_base = stored_line_count(&conn, "dead_ends");
+    let (dead_end_lines, dead_ends_new_offset) =
+        read_new_lines(&intel_path.join("dead_ends.jsonl"), dead_ends_offset).unwrap_or_default();
+
+    // A log that shrank below its stored offset is read again from byte 0,
+    // so its line numbering restarts as well.
+    let decisions_line_base = if decisions_new_offset < decisions_offset { 0 } else { decisions_line_base };
+    let failures_line_base = if failures_new_offset < failures_offset { 0 } else { failures_line_base };
+    let dead_ends_line_base = if dead_ends_new_offset < dead_ends_offset { 0 } else { dead_ends_line_base };
+
+    let offsets_unchanged = decisions_new_offset == decisions_offset
+        && failures_new_offset == failures_offset
+        && dead_ends_new_offset == dead_ends_offset;
+    if offsets_unchanged && decision_lines.is_empty() && failure_lines.is_empty() && dead_end_lines.is_empty() {
+        return Ok((integrity_errors, events));
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Failed to start intelligence store transaction: {}", e))?;
+
+    for (i, line) in decision_lines.iter().enumerate() {
+        let Ok(raw) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Ok(decision) = serde_json::from_value::<Decision>(raw.clone()) else {
+            continue;
+        };
+        let hash = content_digest(&[&decision.decision, &decision.rationale]);
+        check_line_integrity(
+            &raw,
+            "decisions.jsonl",
+            decisions_line_base + i,
+            &[&decision.decision, &decision.rationale],
+            &mut integrity_errors,
+        );
+        tx.execute(
+            "INSERT INTO decisions (id, decision, rationale, created_at, scope, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(id) DO UPDATE SET
+                decision = excluded.decision,
+                rationale = excluded.rationale,
+                created_at = excluded.created_at,
+                scope = excluded.scope,
+                content_hash = excluded.content_hash",
+            rusqlite::params![
+                decision.id,
+                decision.decision,
+                decision.rationale,
+                decision.created_at,
+                decision.scope,
+                hash
+            ],
+        )
+        .map_err(|e| format!("Failed to upsert decision row: {}", e))?;
+        tx.execute(
+            "INSERT INTO intel_fts (source_kind, source_id, text) VALUES ('decision', ?1, ?2)",
+            rusqlite::params![
+                decision.id,
+                format!("{} {}", decision.decision, decision.rationale)
+            ],
+        )
+        .map_err(|e| format!("Failed to index decision: {}", e))?;
+        events.push(IntelligenceEvent::Decision(decision));
+    }
+
+    for (i, line) in failure_lines.iter().enumerate() {
+        let Ok(raw) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Ok(failure) = serde_json::from_value::<FailedApproach>(raw.clone()) else {
+            continue;
+        };
+        let hash = content_digest(&[&failure.approach, &failure.reason]);
+        check_line_integrity(
+            &raw,
+            "failures.jsonl",
+            failures_line_base + i,
+            &[&failure.approach, &failure.reason],
+            &mut integrity_errors,
+        );
+        tx.execute(
+            "INSERT INTO failures (id, approach, reason, created_at, context, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(id) DO UPDATE SET
+                approach = excluded.approach,
+                reason = excluded.reason,
+                created_at = excluded.created_at,
+                context = excluded.context,
+                content_hash = excluded.content_hash",
+            rusqlite::params![
+                failure.id,
+                failure.approach,
+                failure.reason,
+                failure.created_at,
+                failure.context,
+                hash
+            ],
+        )
+        .map_err(|e| format!("Failed to upsert failure row: {}", e))?;
+        tx.execute(
+            "INSERT INTO intel_fts (source_kind, source_id, text) VALUES ('failure', ?1, ?2)",
+            rusqlite::params![
+                failure.id,
+                format!("{} {}", failure.approach, failure.reason)
+            ],
+        )
+        .map_err(|e| format!("Failed to index failure: {}", e))?;
+        events.push(IntelligenceEvent::Failure(failure));
+    }
+
+    for (i, line) in dead_end_lines.iter().enumerate() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let approach = value.get("approach").and_then(|v| v.as_str()).unwrap_or("");
+        let reason = value.get("reason").and_then(|v| v.as_str()).unwrap_or("");
+        let created_at = value.get("created_at").and_then(|v| v.as_str());
+        let context = value.get("context").and_then(|v| v.as_str());
+        let hash = content_digest(&[approach, reason]);
+        check_line_integrity(
+            &value,
+            "dead_ends.jsonl",
+            dead_ends_line_base + i,
+            &[approach, reason],
+            &mut integrity_errors,
+        );
+
+        let existing_id: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM dead_ends WHERE content_hash = ?1",
+                [&hash],
+                |row| row.get(0),
+            )
+            .ok();
+
+        if let Some(id) = existing_id {
+            // Seen this exact approach/reason before — bump the count
+            // instead of inserting a duplicate row.
+            tx.execute(
+                "UPDATE dead_ends SET occurrence_count = occurrence_count + 1,
+                    created_at = COALESCE(?2, created_at),
+                    context = COALESCE(?3, context)
+                 WHERE id = ?1",
+                rusqlite::params![id, created_at, context],
+            )
+            .map_err(|e| format!("Failed to bump dead end occurrence count: {}", e))?;
+            let row = tx
+                .query_row(
+                    "SELECT id, approach, reason, created_at, context, occurrence_count
+                     FROM dead_ends WHERE id = ?1",
+                    [id],
+                    |row| {
+                        Ok(DeadEndRow {
+                            id: row.get(0)?,
+                            approach: row.get(1)?,
+                            reason: row.get(2)?,
+                            created_at: row.get(3)?,
+                            context: row.get(4)?,
+                            occurrence_count: row.get(5)?,
+                        })
+                    },
+                )
+                .map_err(|e| format!("Failed to read back bumped dead end row: {}", e))?;
+            events.push(IntelligenceEvent::DeadEnd(row));
+            continue;
+        }
+
+        tx.execute(
+            "INSERT INTO dead_ends (approach, reason, created_at, context, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![approach, reason, created_at, context, hash],
+        )
+        .map_err(|e| format!("Failed to insert dead end row: {}", e))?;
+        let id = tx.last_insert_rowid();
+        tx.execute(
+            "INSERT INTO intel_fts (source_kind, source_id, text) VALUES ('dead_end', ?1, ?2)",
+            rusqlite::params![id.to_string(), format!("{} {}", approach, reason)],
+        )
+        .map_err(|e| format!("Failed to index dead end: {}", e))?;
+        events.push(IntelligenceEvent::DeadEnd(DeadEndRow {
+            id,
+            approach: approach.to_string(),
+            reason: reason.to_string(),
+            created_at: created_at.map(String::from),
+            context: context.map(String::from),
+            occurrence_count: 1,
+        }));
+    }
+
+    store_offset(&tx, "decisions", decisions_new_offset)?;
+    store_offset(&tx, "failures", failures_new_offset)?;
+    store_offset(&tx, "dead_ends", dead_ends_new_offset)?;
+    store_line_count(&tx, "decisions", decisions_line_base + decision_lines.len())?;
+    store_line_count(&tx, "failures", failures_line_base + failure_lines.len())?;
+    store_line_count(&tx, "dead_ends", dead_ends_line_base + dead_end_lines.len())?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit intelligence store transaction: {}", e))?;
+    Ok((integrity_errors, events))
+}
+
+/// A dead end matched by `search_dead_ends`, including its stable store id
+/// (dead ends have no natural id of their own — see `memory::DeadEnd`) and
+/// how many times an identical approach/reason has been re-ingested.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadEndRow {
+    pub id: i64,
+    pub approach: String,
+    pub reason: String,
+    pub created_at: Option<String>,
+    pub context: Option<String>,
+    pub occurrence_count: i64,
+}
+
+/// Full-text search over dead ends' `approach`/`reason`, reconciling any
+/// newly appended lines first.
+#[tauri::command]
+pub async fn search_dead_ends(path: String, query: String) -> Result<Vec<DeadEndRow>, String> {
+    let project_path = PathBuf::from(&path);
+    reconcile(&project_path)?;
+    let conn = open(&project_path)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT d.id, d.approach, d.reason, d.created_at, d.context, d.occurrence_count
+             FROM intel_fts f
+             JOIN dead_ends d ON d.id = CAST(f.source_id AS INTEGER)
+             WHERE f.source_kind = 'dead_end' AND intel_fts MATCH ?1
+             ORDER BY d.id DESC",
+        )
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let rows = stmt
+        .query_map([query], |row| {
+            Ok(DeadEndRow {
+                id: row.get(0)?,
+                approach: row.get(1)?,
+                reason: row.get(2)?,
+                created_at: row.get(3)?,
+                context: row.get(4)?,
+                occurrence_count: row.get(5)?,
+            })
+        })
+        .map_err(|e| format!("Failed to run query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read query results: {}", e))
+}
+
+/// Reconciles any newly appended `.jsonl` lines and returns whatever digest
+/// mismatches were found, so a caller can distinguish "this project has no
+/// recorded intelligence yet" (empty result) from "this project's
+/// intelligence store is corrupted" (non-empty result).
+#[tauri::command]
+pub async fn check_intelligence_integrity(path: String) -> Result<Vec<IntegrityError>, String> {
+    reconcile(&PathBuf::from(&path)).map(|(integrity_errors, _events)| integrity_errors)
+}
+
+/// The `limit` most recently ingested decisions, reconciling any newly
+/// appended lines first. Ordered by insertion order (the `decisions` table
+/// has no explicit rowid-free declaration, so SQLite's implicit rowid
+/// tracks ingest order) rather than `created_at`, since that field is
+/// optional and not reliably sortable across records that omit it.
+#[tauri::command]
+pub async fn recent_decisions(path: String, limit: u32) -> Result<Vec<Decision>, String> {
+    let project_path = PathBuf::from(&path);
+    reconcile(&project_path)?;
+    let conn = open(&project_path)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, decision, rationale, created_at, scope FROM decisions
+             ORDER BY rowid DESC LIMIT ?1",
+        )
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let rows = stmt
+        .query_map([limit], |row| {
+            Ok(Decision {
+                id: row.get(0)?,
+                decision: row.get(1)?,
+                rationale: row.get(2)?,
+                created_at: row.get(3)?,
+                scope: row.get(4)?,
+            })
+        })
+        .map_err(|e| format!("Failed to run query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read query results: {}", e))
+}
@@ -0,0 +1,181 @@
+//! Live-tailing watcher for the intelligence JSONL logs (RFC-120).
+//!
+//! `intelligence_store::reconcile` already tracks a per-file byte offset so
+//! it only parses lines appended since the last call — this module just
+//! drives that offset tracking continuously instead of once per query.
+//! `IntelligenceWatcherManager::start` reconciles once up front (seeking
+//! every tracked offset to end-of-file without emitting anything, so a
+//! fresh watch doesn't replay a project's entire history), then watches
+//! `.sunwell/intelligence` with `notify` the same way `memory_watcher`
+//! watches `.sunwell/memory`: a debounced `std::thread` that calls
+//! `reconcile` again on every settled burst and emits one `intelligence-event`
+//! per newly ingested line via `tauri::Emitter`. Emitting is already
+//! non-blocking and synchronous (`AppHandle::emit` queues for delivery and
+//! returns immediately — it never awaits a subscriber), so a slow frontend
+//! can't stall the debounce thread; a reconnecting subscriber just gets the
+//! events reconciled after it starts listening rather than a replay, since
+//! the byte offsets already advanced past anything it missed while away.
+
+use crate::error::SunwellError;
+use crate::intelligence_store;
+use crate::sunwell_err;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How long to wait for a project's events to go quiet before reconciling.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A running watcher for one project. Dropping this stops watching (the
+/// `notify` watcher is torn down) and signals the debounce thread to exit.
+struct IntelligenceWatchHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Tracks at most one live intelligence watcher per project path, mirroring
+/// `MemoryWatcherManager`'s start/stop shape.
+#[derive(Default)]
+pub struct IntelligenceWatcherManager {
+    handles: Mutex<HashMap<String, IntelligenceWatchHandle>>,
+}
+
+impl IntelligenceWatcherManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tailing `project_path`'s intelligence directory, catching the
+    /// store up to end-of-file first without emitting the backlog. A no-op
+    /// if already watching this project.
+    pub fn start(&self, project_path: String, app: AppHandle) -> Result<(), SunwellError> {
+        let mut handles = self.handles.lock().unwrap();
+        if handles.contains_key(&project_path) {
+            return Ok(());
+        }
+
+        // Prime the offsets to end-of-file so only genuinely new lines
+        // (appended after the watch starts) are emitted as events.
+        let _ = intelligence_store::reconcile(&PathBuf::from(&project_path));
+
+        let (event_tx, event_rx) = mpsc::channel::<()>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = event_tx.send(());
+            }
+        })
+        .map_err(|e| {
+            sunwell_err!(
+                RuntimeStateInvalid,
+                "Failed to create intelligence watcher: {}",
+                e
+            )
+        })?;
+
+        let dir = PathBuf::from(&project_path).join(".sunwell/intelligence");
+        if dir.exists() {
+            watcher
+                .watch(&dir, RecursiveMode::NonRecursive)
+                .map_err(|e| {
+                    sunwell_err!(
+                        RuntimeStateInvalid,
+                        "Failed to watch {}: {}",
+                        dir.display(),
+                        e
+                    )
+                })?;
+        }
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let thread_project_path = project_path.clone();
+        thread::spawn(move || debounce_loop(thread_project_path, app, event_rx, stop_rx));
+
+        handles.insert(
+            project_path,
+            IntelligenceWatchHandle {
+                _watcher: watcher,
+                stop_tx,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop tailing `project_path`. A no-op if not currently watched.
+    pub fn stop(&self, project_path: &str) {
+        if let Some(handle) = self.handles.lock().unwrap().remove(project_path) {
+            let _ = handle.stop_tx.send(());
+        }
+    }
+}
+
+/// Waits for a project's events to go quiet for `DEBOUNCE` before
+/// reconciling and emitting one `intelligence-event` per newly ingested
+/// line, in order. Each `emit` is synchronous and queues for delivery
+/// rather than waiting on a subscriber, so a slow consumer can't block
+/// this thread the way an `await`-per-send would.
+fn debounce_loop(
+    project_path: String,
+    app: AppHandle,
+    event_rx: mpsc::Receiver<()>,
+    stop_rx: mpsc::Receiver<()>,
+) {
+    let mut dirty_since: Option<Instant> = None;
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            return;
+        }
+
+        match event_rx.recv_timeout(DEBOUNCE) {
+            Ok(()) => dirty_since = Some(Instant::now()),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        let Some(since) = dirty_since else {
+            continue;
+        };
+        if since.elapsed() < DEBOUNCE {
+            continue;
+        }
+        dirty_since = None;
+
+        let Ok((_integrity_errors, events)) =
+            intelligence_store::reconcile(&PathBuf::from(&project_path))
+        else {
+            continue;
+        };
+        for event in events {
+            let _ = app.emit("intelligence-event", &event);
+        }
+    }
+}
+
+/// Start tailing `project_path`'s intelligence logs, emitting an
+/// `intelligence-event` for each newly appended decision/failure/dead-end.
+/// A no-op if already watching this project.
+#[tauri::command]
+pub async fn start_intelligence_watch(
+    project_path: String,
+    app: AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state
+        .intelligence_watcher
+        .start(project_path, app)
+        .map_err(|e| e.to_json())
+}
+
+/// Stop tailing `project_path`'s intelligence logs, if watched.
+#[tauri::command]
+pub async fn stop_intelligence_watch(
+    project_path: String,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.intelligence_watcher.stop(&project_path);
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
+use crate::telemetry::{self, CommandTimer};
 use crate::util::{parse_json_safe, sunwell_command};
 use serde::{Deserialize, Serialize};
 
@@ -38,12 +39,14 @@ pub struct ConversationMessage {
 }
 
 /// Process a user goal through the generative interface.
+#[tracing::instrument(skip(goal, data_dir, history), fields(route_type, wall_clock_ms))]
 #[tauri::command]
 pub async fn process_goal(
     goal: String,
     data_dir: Option<String>,
     history: Option<Vec<ConversationMessage>>,
 ) -> Result<InterfaceOutput, String> {
+    let timer = CommandTimer::start();
     let mut args = vec![
         "interface".to_string(),
         "process".to_string(),
@@ -60,33 +63,43 @@ pub async fn process_goal(
     // Pass conversation history if available
     if let Some(hist) = history {
         if !hist.is_empty() {
-            let history_json = serde_json::to_string(&hist)
-                .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize history: {}", e).to_json())?;
+            let history_json = serde_json::to_string(&hist).map_err(|e| {
+                let err = sunwell_err!(ConfigInvalid, "Failed to serialize history: {}", e);
+                telemetry::record_failure(&err);
+                err.to_json()
+            })?;
             args.push("--history".to_string());
             args.push(history_json);
         }
     }
 
-    let output = sunwell_command()
-        .args(&args)
-        .output()
-        .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
-        })?;
+    let output = sunwell_command().args(&args).output().map_err(|e| {
+        let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Check if sunwell CLI is installed"]);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(sunwell_err!(SkillExecutionFailed, "Processing failed: {}", stderr)
-            .with_hints(vec!["Check the input goal", "Verify model availability"])
-            .to_json());
+        let err = sunwell_err!(SkillExecutionFailed, "Processing failed: {}", stderr)
+            .with_hints(vec!["Check the input goal", "Verify model availability"]);
+        telemetry::record_failure(&err);
+        return Err(err.to_json());
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    parse_json_safe(&stdout)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse output: {}", e).to_json())
+    let result: InterfaceOutput = parse_json_safe(&stdout).map_err(|e| {
+        let err = sunwell_err!(ConfigInvalid, "Failed to parse output: {}", e);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
+
+    let span = tracing::Span::current();
+    span.record("route_type", result.output_type.as_str());
+    span.record("wall_clock_ms", timer.elapsed_ms() as u64);
+    Ok(result)
 }
 
 /// List configured providers.
@@ -182,11 +195,13 @@ pub struct PanelSpec {
 ///
 /// Returns composition spec before full content is ready,
 /// enabling skeleton rendering while content streams in.
+#[tracing::instrument(skip(input, current_page), fields(page_type = current_page.as_deref().unwrap_or(""), wall_clock_ms))]
 #[tauri::command]
 pub async fn predict_composition(
     input: String,
     current_page: Option<String>,
 ) -> Result<Option<CompositionSpec>, String> {
+    let timer = CommandTimer::start();
     let mut args = vec![
         "interface".to_string(),
         "compose".to_string(),
@@ -200,14 +215,12 @@ pub async fn predict_composition(
         args.push(page);
     }
 
-    let output = sunwell_command()
-        .args(&args)
-        .output()
-        .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
-        })?;
+    let output = sunwell_command().args(&args).output().map_err(|e| {
+        let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Check if sunwell CLI is installed"]);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
 
     if !output.status.success() {
         // Non-fatal for composition - return None and let full pipeline handle it
@@ -216,20 +229,28 @@ pub async fn predict_composition(
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    parse_json_safe(&stdout)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse composition: {}", e).to_json())
+    let result = parse_json_safe(&stdout).map_err(|e| {
+        let err = sunwell_err!(ConfigInvalid, "Failed to parse composition: {}", e);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
+
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+    Ok(result)
 }
 
 /// Execute a block action (RFC-080).
 ///
 /// Block actions are quick operations embedded in Home blocks,
 /// like completing a habit, checking a list item, etc.
+#[tracing::instrument(skip(item_id, data_dir), fields(action_id = %action_id, wall_clock_ms))]
 #[tauri::command]
 pub async fn execute_block_action(
     action_id: String,
     item_id: Option<String>,
     data_dir: Option<String>,
 ) -> Result<BlockActionResult, String> {
+    let timer = CommandTimer::start();
     let mut args = vec![
         "interface".to_string(),
         "action".to_string(),
@@ -248,24 +269,29 @@ pub async fn execute_block_action(
         args.push(dir);
     }
 
-    let output = sunwell_command()
-        .args(&args)
-        .output()
-        .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
-        })?;
+    let output = sunwell_command().args(&args).output().map_err(|e| {
+        let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Check if sunwell CLI is installed"]);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(sunwell_err!(SkillExecutionFailed, "Action '{}' failed: {}", action_id, stderr)
-            .with_hints(vec!["Check the action parameters"])
-            .to_json());
+        let err = sunwell_err!(SkillExecutionFailed, "Action '{}' failed: {}", action_id, stderr)
+            .with_hints(vec!["Check the action parameters"]);
+        telemetry::record_failure(&err);
+        return Err(err.to_json());
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    parse_json_safe(&stdout)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse action result: {}", e).to_json())
+    let result = parse_json_safe(&stdout).map_err(|e| {
+        let err = sunwell_err!(ConfigInvalid, "Failed to parse action result: {}", e);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
+
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+    Ok(result)
 }
@@ -0,0 +1,166 @@
+//! Resumable-job tracking with crash-safe checkpoint persistence.
+//!
+//! `find_latest_checkpoint` (commands.rs) reads whatever checkpoint happens
+//! to be newest under a project's `.sunwell/checkpoints/`, but until now
+//! only the `sunwell` CLI subprocess ever wrote one — a Studio-only run (or
+//! a CLI that crashed before its own first checkpoint) left nothing to
+//! resume from. `JobManager` is the write side that closes that gap: it
+//! folds each tracked session's task-lifecycle events into a `JobCheckpoint`
+//! and persists it after every step, so `recover_jobs` always has something
+//! to find at the next launch.
+//!
+//! Checkpoints are written as versioned msgpack (`rmp-serde`) rather than
+//! JSON: `JobCheckpoint` is a strongly-typed, schema-versioned struct, so
+//! per-step writes on large task graphs stay cheap and `find_latest_checkpoint`
+//! no longer has to guess field names the way it must for legacy `.json`
+//! checkpoints written by older CLI versions.
+
+use crate::agent::{AgentEvent, SessionId};
+use crate::commands::CheckpointTask;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Bumped whenever `JobCheckpoint`'s shape changes in a way that isn't
+/// backward-compatible for readers (e.g. a renamed or removed field).
+/// `find_latest_checkpoint` doesn't currently branch on this — it's here so
+/// a future reader has somewhere to add that branch instead of guessing.
+pub const CHECKPOINT_SCHEMA_VERSION: u32 = 1;
+
+/// One session's checkpointed state, serialized as msgpack to
+/// `.sunwell/checkpoints/studio-<session_id>.msgpack`. Field names match
+/// what `find_latest_checkpoint` already expects for legacy JSON
+/// checkpoints (`goal`, `tasks`, `completed_ids`), so Studio-written and
+/// CLI-written checkpoints describe the same shape even though only the
+/// msgpack ones carry it as a real schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobCheckpoint {
+    pub schema_version: u32,
+    pub goal: String,
+    pub workspace_path: String,
+    pub tasks: Vec<CheckpointTask>,
+    pub completed_ids: HashSet<String>,
+    pub provider: Option<String>,
+    pub lens: Option<String>,
+    /// Count of `task_start` events seen so far. Not consulted by
+    /// `find_latest_checkpoint` (which derives progress from `tasks`/
+    /// `completed_ids` directly) — kept for debugging a stuck run.
+    pub step: u32,
+    pub updated_at: String,
+}
+
+impl JobCheckpoint {
+    fn new(goal: String, workspace_path: String, provider: Option<String>, lens: Option<String>) -> Self {
+        Self {
+            schema_version: CHECKPOINT_SCHEMA_VERSION,
+            goal,
+            workspace_path,
+            tasks: Vec::new(),
+            completed_ids: HashSet::new(),
+            provider,
+            lens,
+            step: 0,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Fold one more agent event into this checkpoint's task list and
+    /// cursor. Unrecognized event types, or a `task_start`/`task_complete`
+    /// missing its `id`, are ignored rather than erroring.
+    fn fold(&mut self, event: &AgentEvent) {
+        match event.event_type.as_str() {
+            "task_start" => {
+                if let Some(id) = event.data.get("id").and_then(|v| v.as_str()) {
+                    if !self.tasks.iter().any(|t| t.id == id) {
+                        let description = event
+                            .data
+                            .get("description")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Task")
+                            .to_string();
+                        self.tasks.push(CheckpointTask { id: id.to_string(), description, completed: false });
+                    }
+                    self.step += 1;
+                }
+            }
+            "task_complete" => {
+                if let Some(id) = event.data.get("id").and_then(|v| v.as_str()) {
+                    self.completed_ids.insert(id.to_string());
+                    if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+                        task.completed = true;
+                    }
+                }
+            }
+            _ => return,
+        }
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+    }
+}
+
+/// Write `checkpoint` to `checkpoints_dir/studio-<session_id>.msgpack`
+/// atomically: serialize to a sibling `.tmp` file, then `rename` it into
+/// place, so a crash mid-write never leaves a corrupt or partial checkpoint
+/// behind for `find_latest_checkpoint` to trip over. Msgpack keeps these
+/// cheap to write every step even for projects with hundreds of tasks.
+fn write_atomic(checkpoints_dir: &Path, session_id: &str, checkpoint: &JobCheckpoint) -> std::io::Result<()> {
+    std::fs::create_dir_all(checkpoints_dir)?;
+    let final_path = checkpoints_dir.join(format!("studio-{}.msgpack", session_id));
+    let tmp_path = checkpoints_dir.join(format!("studio-{}.msgpack.tmp", session_id));
+    let bytes = rmp_serde::to_vec(checkpoint).map_err(std::io::Error::other)?;
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, &final_path)
+}
+
+/// Tracks one `JobCheckpoint` per in-flight session, keyed by `SessionId`,
+/// and persists it to `.sunwell/checkpoints/` after every task-lifecycle
+/// event folded in via `record_event`.
+#[derive(Default)]
+pub struct JobManager {
+    jobs: Mutex<std::collections::HashMap<SessionId, (PathBuf, JobCheckpoint)>>,
+}
+
+impl JobManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a freshly started (or resumed) job so it's ready to receive
+    /// `record_event` calls. `goal` is best-effort for a resume — callers
+    /// that don't know the original goal text can pass an empty string;
+    /// it's overwritten by nothing here, so the prior checkpoint's goal
+    /// (if any) is simply not preserved across a Studio restart of tracking.
+    pub fn start(&self, session_id: SessionId, project_path: &Path, goal: &str, provider: Option<String>, lens: Option<String>) {
+        let checkpoints_dir = project_path.join(".sunwell").join("checkpoints");
+        let checkpoint = JobCheckpoint::new(goal.to_string(), project_path.to_string_lossy().into_owned(), provider, lens);
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.insert(session_id, (checkpoints_dir, checkpoint));
+        }
+    }
+
+    /// Fold `event` into `session_id`'s checkpoint and persist it. Best
+    /// effort: a write failure (disk full, permissions) is logged and
+    /// otherwise ignored rather than aborting the agent run. A no-op if
+    /// `session_id` was never registered via `start`.
+    pub fn record_event(&self, session_id: &SessionId, event: &AgentEvent) {
+        let mut jobs = match self.jobs.lock() {
+            Ok(jobs) => jobs,
+            Err(_) => return,
+        };
+        let Some((checkpoints_dir, checkpoint)) = jobs.get_mut(session_id) else { return };
+        checkpoint.fold(event);
+        if let Err(e) = write_atomic(checkpoints_dir, session_id, checkpoint) {
+            eprintln!("[job_manager] failed to write checkpoint for session {}: {}", session_id, e);
+        }
+    }
+
+    /// Stop tracking `session_id` once its run reaches a terminal state.
+    /// The on-disk checkpoint is left in place — `recover_jobs` and
+    /// `resume_project` read it independently of whether the job is still
+    /// tracked in memory.
+    pub fn finish(&self, session_id: &SessionId) {
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.remove(session_id);
+        }
+    }
+}
@@ -6,7 +6,8 @@ use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
 use crate::util::{parse_json_safe, sunwell_command};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
 
 /// Lens summary for UI display.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +53,19 @@ pub struct LensLibraryEntry {
     /// Usage counts for last 7 days (for sparkline)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage_history: Option<Vec<u32>>,
+
+    // Remote lens registry: populated when `source == "registry"`, i.e.
+    // this lens was installed via `install_lens` rather than forked
+    // locally or shipped with Sunwell.
+    /// Registry this lens was installed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_url: Option<String>,
+    /// The semver version currently installed from that registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_version: Option<String>,
+    /// A newer compatible version published upstream, if a check found one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_update: Option<String>,
 }
 
 /// Version info for a lens.
@@ -105,6 +119,14 @@ pub struct LensDetail {
 pub struct ProjectLensConfig {
     pub default_lens: Option<String>,
     pub auto_select: bool,
+    /// Glob patterns a lens name must match to be selectable. `None` means
+    /// no allow-list is in effect (every lens is a candidate).
+    #[serde(default)]
+    pub allowed_lenses: Option<Vec<String>>,
+    /// Glob patterns that forbid a lens outright, regardless of
+    /// `allowed_lenses` — deny always takes precedence.
+    #[serde(default)]
+    pub denied_lenses: Vec<String>,
 }
 
 impl ProjectLensConfig {
@@ -123,6 +145,14 @@ impl ProjectLensConfig {
                             .get("auto_lens")
                             .and_then(|v| v.as_bool())
                             .unwrap_or(true),
+                        allowed_lenses: config.get("allowed_lenses").and_then(|v| v.as_sequence()).map(|seq| {
+                            seq.iter().filter_map(|v| v.as_str().map(String::from)).collect()
+                        }),
+                        denied_lenses: config
+                            .get("denied_lenses")
+                            .and_then(|v| v.as_sequence())
+                            .map(|seq| seq.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                            .unwrap_or_default(),
                     };
                 }
             }
@@ -158,6 +188,28 @@ impl ProjectLensConfig {
                 serde_yaml::Value::String("auto_lens".into()),
                 serde_yaml::Value::Bool(self.auto_select),
             );
+
+            if let Some(allowed) = &self.allowed_lenses {
+                map.insert(
+                    serde_yaml::Value::String("allowed_lenses".into()),
+                    serde_yaml::Value::Sequence(
+                        allowed.iter().map(|s| serde_yaml::Value::String(s.clone())).collect(),
+                    ),
+                );
+            } else {
+                map.remove(&serde_yaml::Value::String("allowed_lenses".into()));
+            }
+
+            if self.denied_lenses.is_empty() {
+                map.remove(&serde_yaml::Value::String("denied_lenses".into()));
+            } else {
+                map.insert(
+                    serde_yaml::Value::String("denied_lenses".into()),
+                    serde_yaml::Value::Sequence(
+                        self.denied_lenses.iter().map(|s| serde_yaml::Value::String(s.clone())).collect(),
+                    ),
+                );
+            }
         }
 
         // Ensure directory exists
@@ -172,6 +224,95 @@ impl ProjectLensConfig {
     }
 }
 
+// =============================================================================
+// Per-project lens capability scoping (allow/deny)
+// =============================================================================
+
+/// Result of checking whether a project's policy permits a lens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LensPermission {
+    Allowed,
+    /// Matched a pattern in `denied_lenses`.
+    Denied { pattern: String },
+    /// `allowed_lenses` is set and no pattern in it matched.
+    NotAllowlisted,
+}
+
+/// Match a single `*`-wildcard glob pattern against `name` (no other glob
+/// metacharacters are supported — this is intentionally minimal). `*`
+/// matches any run of characters, including none.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let segments: Vec<&str> = pattern.split('*').collect();
+    if segments.len() == 1 {
+        return name == pattern;
+    }
+
+    let Some(first) = segments.first() else { return false };
+    if !name.starts_with(first) {
+        return false;
+    }
+    let Some(last) = segments.last() else { return false };
+    if !name.ends_with(last) {
+        return false;
+    }
+
+    // Walk the middle segments left to right, consuming each the first
+    // place it appears after the previous one.
+    let mut cursor = first.len();
+    for segment in &segments[1..segments.len() - 1] {
+        if segment.is_empty() {
+            continue;
+        }
+        match name[cursor..].find(segment) {
+            Some(pos) => cursor += pos + segment.len(),
+            None => return false,
+        }
+    }
+
+    cursor <= name.len() - last.len()
+}
+
+/// Check a project's allow/deny policy for `lens_name`, following the
+/// Tauri ACL convention of deny-takes-precedence: a lens is permitted
+/// only if it matches `allowed_lenses` (or that list is absent) AND
+/// matches no pattern in `denied_lenses`.
+pub fn check_lens_allowed(project_path: &Path, lens_name: &str) -> LensPermission {
+    let config = ProjectLensConfig::load(project_path);
+
+    if let Some(pattern) = config.denied_lenses.iter().find(|p| glob_match(p, lens_name)) {
+        return LensPermission::Denied { pattern: pattern.clone() };
+    }
+
+    if let Some(allowed) = &config.allowed_lenses {
+        if !allowed.iter().any(|p| glob_match(p, lens_name)) {
+            return LensPermission::NotAllowlisted;
+        }
+    }
+
+    LensPermission::Allowed
+}
+
+/// Enforce `check_lens_allowed`, turning a non-`Allowed` result into a
+/// `LensForbidden` error naming the project's policy file.
+fn enforce_lens_allowed(project_path: &Path, lens_name: &str) -> Result<(), SunwellError> {
+    match check_lens_allowed(project_path, lens_name) {
+        LensPermission::Allowed => Ok(()),
+        LensPermission::Denied { pattern } => Err(sunwell_err!(
+            LensForbidden,
+            "Lens '{}' is denied by this project's policy (matches '{}')",
+            lens_name,
+            pattern
+        )
+        .with_hints(vec!["Review denied_lenses in .sunwell/config.yaml"])),
+        LensPermission::NotAllowlisted => Err(sunwell_err!(
+            LensForbidden,
+            "Lens '{}' is not in this project's allowed_lenses",
+            lens_name
+        )
+        .with_hints(vec!["Review allowed_lenses in .sunwell/config.yaml"])),
+    }
+}
+
 /// List all available lenses by calling the Python CLI.
 #[tauri::command]
 pub async fn list_lenses() -> Result<Vec<LensSummary>, String> {
@@ -218,14 +359,25 @@ pub async fn get_lens_detail(name: String) -> Result<LensDetail, String> {
         .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse lens detail: {}", e).to_json())
 }
 
-/// Get project lens configuration.
+/// Get project lens configuration. Fails with `LensIntegrityMismatch` if
+/// the project's default lens is locked and its content has since drifted
+/// from the recorded checksum.
 #[tauri::command]
 pub async fn get_project_lens_config(path: String) -> Result<ProjectLensConfig, String> {
     let project_path = std::path::PathBuf::from(&path);
-    Ok(ProjectLensConfig::load(&project_path))
+    let config = ProjectLensConfig::load(&project_path);
+
+    if let Some(name) = &config.default_lens {
+        verify_lens_integrity(&project_path, name).map_err(|e| e.to_json())?;
+    }
+
+    Ok(config)
 }
 
-/// Set project default lens.
+/// Set project default lens. Fails with `LensForbidden` if the project's
+/// allow/deny policy rejects the lens, or `LensIntegrityMismatch` if the
+/// lens being activated is locked and its content has since drifted from
+/// the recorded checksum.
 #[tauri::command]
 pub async fn set_project_lens(
     path: String,
@@ -233,10 +385,17 @@ pub async fn set_project_lens(
     auto_select: bool,
 ) -> Result<(), String> {
     let project_path = std::path::PathBuf::from(&path);
-    let config = ProjectLensConfig {
-        default_lens: lens_name,
-        auto_select,
-    };
+
+    if let Some(name) = &lens_name {
+        enforce_lens_allowed(&project_path, name).map_err(|e| e.to_json())?;
+        verify_lens_integrity(&project_path, name).map_err(|e| e.to_json())?;
+    }
+
+    // Preserve the existing allow/deny policy — this command only ever
+    // changes the default lens and auto-select fields.
+    let mut config = ProjectLensConfig::load(&project_path);
+    config.default_lens = lens_name;
+    config.auto_select = auto_select;
     config.save(&project_path)
 }
 
@@ -271,8 +430,14 @@ pub async fn get_lens_library(filter: Option<String>) -> Result<Vec<LensLibraryE
     }
 
     let json_str = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&json_str)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse lens library: {}", e).to_json())
+    let mut entries: Vec<LensLibraryEntry> = parse_json_safe(&json_str)
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse lens library: {}", e).to_json())?;
+
+    for entry in &mut entries {
+        crate::lens_registry::annotate_registry_entry(entry).await;
+    }
+
+    Ok(entries)
 }
 
 /// Fork a lens to create an editable copy.
@@ -427,6 +592,118 @@ pub async fn get_lens_versions(name: String) -> Result<Vec<LensVersionInfo>, Str
         .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse lens versions: {}", e).to_json())
 }
 
+/// One heuristic's change between two lens versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HeuristicChange {
+    Added(HeuristicSummary),
+    Removed(HeuristicSummary),
+    Modified {
+        name: String,
+        old_rule: String,
+        new_rule: String,
+        old_priority: f32,
+        new_priority: f32,
+    },
+}
+
+/// A change to a lens's `communication_style` between two versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunicationStyleChange {
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Structured, field-aware diff between two versions of a lens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensDiff {
+    pub name: String,
+    pub from_version: String,
+    pub to_version: String,
+    pub heuristics: Vec<HeuristicChange>,
+    pub communication_style_change: Option<CommunicationStyleChange>,
+    pub skills_added: Vec<String>,
+    pub skills_removed: Vec<String>,
+}
+
+/// Load a lens's full detail as it existed at a specific version.
+fn get_lens_detail_at_version(name: &str, version: &str) -> Result<LensDetail, SunwellError> {
+    let output = sunwell_command()
+        .args(["lens", "show", name, "--version", version, "--json"])
+        .output()
+        .map_err(|e| {
+            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"])
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(sunwell_err!(LensVersionConflict, "Failed to load '{}' at version {}: {}", name, version, stderr)
+            .with_hints(vec!["Check if the version exists", "Run get_lens_versions to see available versions"]));
+    }
+
+    let json_str = String::from_utf8_lossy(&output.stdout);
+    parse_json_safe(&json_str).map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse lens detail: {}", e))
+}
+
+/// Keyed set-difference between two heuristic lists, keyed by
+/// `HeuristicSummary.name`: entries present on only one side are
+/// `Added`/`Removed`, entries present on both are `Modified` only when
+/// `rule` or `priority` differ.
+fn diff_heuristics(from: &[HeuristicSummary], to: &[HeuristicSummary]) -> Vec<HeuristicChange> {
+    let mut changes = Vec::new();
+
+    for new in to {
+        match from.iter().find(|h| h.name == new.name) {
+            None => changes.push(HeuristicChange::Added(new.clone())),
+            Some(old) if old.rule != new.rule || old.priority != new.priority => {
+                changes.push(HeuristicChange::Modified {
+                    name: new.name.clone(),
+                    old_rule: old.rule.clone(),
+                    new_rule: new.rule.clone(),
+                    old_priority: old.priority,
+                    new_priority: new.priority,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    for old in from {
+        if !to.iter().any(|h| h.name == old.name) {
+            changes.push(HeuristicChange::Removed(old.clone()));
+        }
+    }
+
+    changes
+}
+
+/// Diff two versions of a lens for a "compare before rollback" view:
+/// added/removed/modified heuristics, a `communication_style` change if
+/// any, and added/removed skills.
+#[tauri::command]
+pub async fn diff_lens_versions(
+    name: String,
+    from_version: String,
+    to_version: String,
+) -> Result<LensDiff, String> {
+    let from = get_lens_detail_at_version(&name, &from_version).map_err(|e| e.to_json())?;
+    let to = get_lens_detail_at_version(&name, &to_version).map_err(|e| e.to_json())?;
+
+    let heuristics = diff_heuristics(&from.heuristics, &to.heuristics);
+
+    let communication_style_change = if from.communication_style != to.communication_style {
+        Some(CommunicationStyleChange { old: from.communication_style.clone(), new: to.communication_style.clone() })
+    } else {
+        None
+    };
+
+    let skills_added = to.skills.iter().filter(|s| !from.skills.contains(s)).cloned().collect();
+    let skills_removed = from.skills.iter().filter(|s| !to.skills.contains(s)).cloned().collect();
+
+    Ok(LensDiff { name, from_version, to_version, heuristics, communication_style_change, skills_added, skills_removed })
+}
+
 /// Rollback a lens to a previous version.
 #[tauri::command]
 pub async fn rollback_lens(name: String, version: String) -> Result<(), String> {
@@ -449,9 +726,16 @@ pub async fn rollback_lens(name: String, version: String) -> Result<(), String>
     Ok(())
 }
 
-/// Set the global default lens.
+/// Set the global default lens. Fails with `LensForbidden` if the
+/// current directory's project policy (if any) rejects the lens.
 #[tauri::command]
 pub async fn set_default_lens(name: Option<String>) -> Result<(), String> {
+    if let Some(n) = &name {
+        if let Ok(cwd) = std::env::current_dir() {
+            enforce_lens_allowed(&cwd, n).map_err(|e| e.to_json())?;
+        }
+    }
+
     let args: Vec<&str> = if let Some(ref n) = name {
         vec!["lens", "set-default", n]
     } else {
@@ -477,41 +761,40 @@ pub async fn set_default_lens(name: Option<String>) -> Result<(), String> {
     Ok(())
 }
 
-/// Get raw lens content for editing.
-#[tauri::command]
-pub async fn get_lens_content(name: String) -> Result<String, String> {
-    // Find lens path - check user lenses first
+/// Find and read a lens's raw content — user lenses first, falling back to
+/// the builtin `lenses/` directory under the current working directory.
+pub(crate) fn read_lens_content(name: &str) -> Result<String, SunwellError> {
     let user_path = dirs::home_dir()
-        .ok_or_else(|| sunwell_err!(ConfigMissing, "Could not find home directory").to_json())?
+        .ok_or_else(|| sunwell_err!(ConfigMissing, "Could not find home directory"))?
         .join(".sunwell")
         .join("lenses")
         .join(format!("{}.lens", name));
 
     if user_path.exists() {
         return std::fs::read_to_string(&user_path).map_err(|e| {
-            SunwellError::from_error(ErrorCode::FileNotFound, e)
-                .with_hints(vec!["Check file permissions"])
-                .to_json()
+            SunwellError::from_error(ErrorCode::FileNotFound, e).with_hints(vec!["Check file permissions"])
         });
     }
 
-    // Try builtin path (cwd/lenses)
     let builtin_path = std::env::current_dir()
-        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to get current dir: {}", e).to_json())?
+        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to get current dir: {}", e))?
         .join("lenses")
         .join(format!("{}.lens", name));
 
     if builtin_path.exists() {
         return std::fs::read_to_string(&builtin_path).map_err(|e| {
-            SunwellError::from_error(ErrorCode::FileNotFound, e)
-                .with_hints(vec!["Check file permissions"])
-                .to_json()
+            SunwellError::from_error(ErrorCode::FileNotFound, e).with_hints(vec!["Check file permissions"])
         });
     }
 
     Err(sunwell_err!(LensNotFound, "Lens not found: {}", name)
-        .with_hints(vec!["Run 'sunwell lens list' to see available lenses"])
-        .to_json())
+        .with_hints(vec!["Run 'sunwell lens list' to see available lenses"]))
+}
+
+/// Get raw lens content for editing.
+#[tauri::command]
+pub async fn get_lens_content(name: String) -> Result<String, String> {
+    read_lens_content(&name).map_err(|e| e.to_json())
 }
 
 /// Export a lens to a file (RFC-100).
@@ -585,9 +868,185 @@ pub async fn record_lens_usage(name: String) -> Result<(), String> {
     
     // Non-critical - don't fail if usage tracking fails
     if !output.status.success() {
-        eprintln!("Warning: Failed to record lens usage: {}", 
+        eprintln!("Warning: Failed to record lens usage: {}",
             String::from_utf8_lossy(&output.stderr));
     }
-    
+
     Ok(())
 }
+
+// =============================================================================
+// Lens Lockfile — checksum-pinned resolution
+// =============================================================================
+//
+// Mirrors how a package-manager lockfile (e.g. deno's `lockfile`/`checksum`
+// modules) pins resolved dependencies: `.sunwell/sunwell.lock` records the
+// exact content checksum of every lens a project has locked, so a fork,
+// rollback, or edit to that lens is detected instead of silently changing
+// the project's behavior.
+
+/// Hash algorithm tag stored alongside each lockfile checksum, so a future
+/// switch to a different digest stays backward-compatible with lockfiles
+/// written by older versions.
+const LOCK_ALGORITHM: &str = "sha256";
+
+/// A single lens pinned in a project's `sunwell.lock`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensLockEntry {
+    pub name: String,
+    pub version: String,
+    pub algorithm: String,
+    pub checksum: String,
+}
+
+/// Project lens lockfile (`.sunwell/sunwell.lock`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LensLockfile {
+    #[serde(default)]
+    pub lenses: Vec<LensLockEntry>,
+}
+
+impl LensLockfile {
+    fn path(project_path: &Path) -> PathBuf {
+        project_path.join(".sunwell").join("sunwell.lock")
+    }
+
+    /// Load the lockfile, or an empty one if the project hasn't locked any
+    /// lenses yet.
+    pub fn load(project_path: &Path) -> Self {
+        let lock_path = Self::path(project_path);
+        std::fs::read_to_string(&lock_path)
+            .ok()
+            .and_then(|content| serde_yaml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, project_path: &Path) -> Result<(), SunwellError> {
+        let lock_path = Self::path(project_path);
+        if let Some(parent) = lock_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+        }
+
+        let yaml = serde_yaml::to_string(self)
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize lockfile: {}", e))?;
+        std::fs::write(&lock_path, yaml).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))
+    }
+
+    fn entry(&self, name: &str) -> Option<&LensLockEntry> {
+        self.lenses.iter().find(|l| l.name == name)
+    }
+}
+
+/// Re-serialize parsed YAML with mapping keys sorted, so semantically
+/// identical lens content hashes the same regardless of how its fields
+/// happen to be ordered on disk.
+pub(crate) fn canonical_lens_content(content: &str) -> Result<String, SunwellError> {
+    let value: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| sunwell_err!(LensParseError, "Failed to parse lens YAML: {}", e))?;
+    serde_yaml::to_string(&sort_yaml_keys(value))
+        .map_err(|e| sunwell_err!(LensParseError, "Failed to canonicalize lens YAML: {}", e))
+}
+
+/// Recursively sort mapping keys (sequence order is left alone — it's
+/// semantically meaningful, unlike mapping key order).
+fn sort_yaml_keys(value: serde_yaml::Value) -> serde_yaml::Value {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            let mut entries: Vec<(serde_yaml::Value, serde_yaml::Value)> =
+                map.into_iter().map(|(k, v)| (k, sort_yaml_keys(v))).collect();
+            entries.sort_by(|(a, _), (b, _)| yaml_sort_key(a).cmp(&yaml_sort_key(b)));
+
+            let mut sorted = serde_yaml::Mapping::new();
+            for (k, v) in entries {
+                sorted.insert(k, v);
+            }
+            serde_yaml::Value::Mapping(sorted)
+        }
+        serde_yaml::Value::Sequence(seq) => serde_yaml::Value::Sequence(seq.into_iter().map(sort_yaml_keys).collect()),
+        other => other,
+    }
+}
+
+/// Stable sort key for a YAML mapping key of any scalar type.
+fn yaml_sort_key(value: &serde_yaml::Value) -> String {
+    serde_yaml::to_string(value).unwrap_or_default()
+}
+
+/// Hex-encoded SHA-256 digest of `content`.
+pub(crate) fn sha256_hex(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Resolve the currently-active version string for a lens via the same
+/// source `get_lens_detail` uses, falling back to `"unknown"` if the CLI
+/// call fails (content can still be locked even if the version can't).
+pub(crate) fn resolved_lens_version(name: &str) -> String {
+    let Ok(output) = sunwell_command().args(["lens", "show", name, "--json"]).output() else {
+        return "unknown".to_string();
+    };
+    if !output.status.success() {
+        return "unknown".to_string();
+    }
+
+    let json_str = String::from_utf8_lossy(&output.stdout);
+    parse_json_safe::<LensDetail>(&json_str).map(|detail| detail.version).unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Compute a lock entry for a single lens: its resolved version and the
+/// SHA-256 checksum of its canonicalized content.
+fn lock_entry_for(name: &str) -> Result<LensLockEntry, SunwellError> {
+    let content = read_lens_content(name)?;
+    let checksum = sha256_hex(&canonical_lens_content(&content)?);
+
+    Ok(LensLockEntry {
+        name: name.to_string(),
+        version: resolved_lens_version(name),
+        algorithm: LOCK_ALGORITHM.to_string(),
+        checksum,
+    })
+}
+
+/// Verify a lens's current resolved content still matches what's recorded
+/// in the project's lockfile. Lenses that were never locked pass —
+/// locking is opt-in via `lock_project_lenses`.
+fn verify_lens_integrity(project_path: &Path, lens_name: &str) -> Result<(), SunwellError> {
+    let lockfile = LensLockfile::load(project_path);
+    let Some(entry) = lockfile.entry(lens_name) else { return Ok(()) };
+
+    let content = read_lens_content(lens_name)?;
+    let checksum = sha256_hex(&canonical_lens_content(&content)?);
+
+    if checksum != entry.checksum {
+        return Err(sunwell_err!(
+            LensIntegrityMismatch,
+            "Lens '{}' content does not match its locked checksum (locked version: {})",
+            lens_name,
+            entry.version
+        )
+        .with_hints(vec![
+            "Run lock_project_lenses to re-lock if this change is intentional",
+            "Rollback the lens to its locked version with rollback_lens",
+        ]));
+    }
+
+    Ok(())
+}
+
+/// Compute and write `.sunwell/sunwell.lock` for every lens a project
+/// currently activates (today: just its `default_lens`), pinning each to
+/// its resolved version and content checksum.
+#[tauri::command]
+pub async fn lock_project_lenses(path: String) -> Result<LensLockfile, String> {
+    let project_path = std::path::PathBuf::from(&path);
+    let config = ProjectLensConfig::load(&project_path);
+
+    let mut lockfile = LensLockfile::default();
+    if let Some(name) = &config.default_lens {
+        lockfile.lenses.push(lock_entry_for(name).map_err(|e| e.to_json())?);
+    }
+
+    lockfile.save(&project_path).map_err(|e| e.to_json())?;
+    Ok(lockfile)
+}
@@ -0,0 +1,250 @@
+//! Declarative Lens/Skill Manifest Loader (RFC-112)
+//!
+//! `get_skill_graph` (in `writer.rs`) always deferred to the Python CLI and
+//! fell back to an empty graph on any failure, which means a `SkillGraph`
+//! was never available offline. This module loads a `lens.toml` manifest
+//! directly in Rust — `[lens]` plus one or more `[[skills]]` tables
+//! describing `dependsOn`/`produces`/`requires` — and builds a
+//! [`SkillGraph`] from it without shelling out.
+//!
+//! A manifest lives at `<project_path>/.sunwell/lenses/<lens_name>/lens.toml`,
+//! mirroring the `.sunwell/config.yaml` convention `lens::ProjectLensConfig`
+//! already uses for project-level lens settings.
+//!
+//! Waves are computed with Kahn's algorithm, layered by level: in-degree is
+//! initialized from the dependency edges (an explicit local `dependsOn`
+//! entry, or a `requires` name matching another skill's `produces`), all
+//! zero-in-degree skills become wave 0, then each wave's nodes are removed,
+//! their successors' in-degrees decremented, and the newly-zeroed nodes
+//! become the next wave. Any skills left over once the queue empties form a
+//! cycle, reported by id rather than silently dropped or looped forever.
+
+use crate::writer::{LensSkill, SkillDependency, SkillGraph, SkillWave};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct ManifestFile {
+    lens: ManifestLens,
+    #[serde(default)]
+    skills: Vec<ManifestSkill>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestLens {
+    #[allow(dead_code)] // round-trips through the manifest; not needed once lens_name is known
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ManifestSkill {
+    id: String,
+    name: String,
+    #[serde(default)]
+    shortcut: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default, rename = "dependsOn")]
+    depends_on: Vec<SkillDependency>,
+    #[serde(default)]
+    produces: Vec<String>,
+    #[serde(default)]
+    requires: Vec<String>,
+}
+
+/// Returns the path a manifest for `lens_name` would live at under
+/// `project_path`, whether or not it currently exists.
+fn manifest_path(project_path: &Path, lens_name: &str) -> std::path::PathBuf {
+    project_path
+        .join(".sunwell")
+        .join("lenses")
+        .join(lens_name)
+        .join("lens.toml")
+}
+
+/// Loads and resolves `<project_path>/.sunwell/lenses/<lens_name>/lens.toml`
+/// into a `SkillGraph`, or `None` if no manifest exists there yet (the
+/// caller falls back to the CLI or an empty graph in that case).
+pub fn load_skill_graph(
+    project_path: &Path,
+    lens_name: &str,
+) -> Option<Result<SkillGraph, String>> {
+    let path = manifest_path(project_path, lens_name);
+    if !path.exists() {
+        return None;
+    }
+    Some(load_skill_graph_from_file(&path, lens_name))
+}
+
+fn load_skill_graph_from_file(path: &Path, lens_name: &str) -> Result<SkillGraph, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read lens manifest '{}': {}", path.display(), e))?;
+    let manifest: ManifestFile = toml::from_str(&raw)
+        .map_err(|e| format!("Failed to parse lens manifest '{}': {}", path.display(), e))?;
+
+    let content_hash = manifest_content_hash(&raw, &manifest.skills);
+
+    let mut skills = HashMap::new();
+    for skill in &manifest.skills {
+        skills.insert(
+            skill.id.clone(),
+            LensSkill {
+                id: skill.id.clone(),
+                name: skill.name.clone(),
+                shortcut: skill.shortcut.clone(),
+                description: skill.description.clone(),
+                category: skill.category.clone(),
+                depends_on: skill.depends_on.clone(),
+                produces: skill.produces.clone(),
+                requires: skill.requires.clone(),
+            },
+        );
+    }
+
+    let waves = layer_into_waves(&manifest.skills)?;
+
+    Ok(SkillGraph {
+        lens_name: lens_name.to_string(),
+        skills,
+        waves,
+        content_hash,
+    })
+}
+
+/// Builds the dependency edges (`from` must run before `to`) a skill
+/// participates in: an explicit local `dependsOn` entry, plus an implicit
+/// edge from any skill whose `produces` satisfies this skill's `requires`.
+fn dependency_edges(skills: &[ManifestSkill]) -> HashMap<String, HashSet<String>> {
+    let producers: HashMap<&str, &str> = skills
+        .iter()
+        .flat_map(|skill| {
+            skill
+                .produces
+                .iter()
+                .map(move |p| (p.as_str(), skill.id.as_str()))
+        })
+        .collect();
+
+    let mut edges: HashMap<String, HashSet<String>> = HashMap::new();
+    for skill in skills {
+        let deps = edges.entry(skill.id.clone()).or_default();
+
+        for dep in &skill.depends_on {
+            if dep.is_local {
+                deps.insert(dep.skill_name.clone());
+            }
+        }
+        for required in &skill.requires {
+            if let Some(producer) = producers.get(required.as_str()) {
+                deps.insert(producer.to_string());
+            }
+        }
+    }
+    edges
+}
+
+/// Layers skills into waves via Kahn's algorithm: in-degree counts how
+/// many not-yet-scheduled dependencies each skill has, wave 0 is every
+/// skill with in-degree zero, and each subsequent wave is whatever becomes
+/// zero once the prior wave's skills are removed from the graph.
+fn layer_into_waves(skills: &[ManifestSkill]) -> Result<Vec<SkillWave>, String> {
+    let edges = dependency_edges(skills);
+    let mut in_degree: HashMap<String, usize> = skills
+        .iter()
+        .map(|s| {
+            (
+                s.id.clone(),
+                edges.get(&s.id).map(HashSet::len).unwrap_or(0),
+            )
+        })
+        .collect();
+
+    // successors[x] = skills that depend on x, so removing x can unblock them
+    let mut successors: HashMap<String, Vec<String>> = HashMap::new();
+    for (id, deps) in &edges {
+        for dep in deps {
+            successors.entry(dep.clone()).or_default().push(id.clone());
+        }
+    }
+
+    let mut waves = Vec::new();
+    let mut remaining: HashSet<String> = skills.iter().map(|s| s.id.clone()).collect();
+    let mut frontier: VecDeque<String> = in_degree
+        .iter()
+        .filter(|(_, &deg)| deg == 0)
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    let mut wave_index = 0;
+    while !frontier.is_empty() {
+        let mut wave_skills: Vec<String> = frontier.drain(..).collect();
+        wave_skills.sort();
+        for id in &wave_skills {
+            remaining.remove(id);
+        }
+
+        let mut next_frontier = Vec::new();
+        for id in &wave_skills {
+            for successor in successors.get(id).into_iter().flatten() {
+                if let Some(deg) = in_degree.get_mut(successor) {
+                    *deg = deg.saturating_sub(1);
+                    if *deg == 0 && remaining.contains(successor) {
+                        next_frontier.push(successor.clone());
+                    }
+                }
+            }
+        }
+
+        waves.push(SkillWave {
+            wave_index,
+            skills: wave_skills,
+            estimated_duration_ms: None,
+        });
+        wave_index += 1;
+        frontier.extend(next_frontier);
+    }
+
+    if !remaining.is_empty() {
+        let mut cycle: Vec<String> = remaining.into_iter().collect();
+        cycle.sort();
+        return Err(format!(
+            "Lens manifest has a dependency cycle involving: {}",
+            cycle.join(", ")
+        ));
+    }
+
+    Ok(waves)
+}
+
+/// A stable hash of the manifest's raw text plus each skill's own fields,
+/// so cache invalidation (keyed off `SkillGraph.content_hash` elsewhere)
+/// works without the Python process computing it. The raw file text covers
+/// the skill bodies, and each skill's fields are folded in as well, in id
+/// order. Because the raw text is hashed, any edit to the file (reordering
+/// `[[skills]]` tables or whitespace included) changes the hash.
+fn manifest_content_hash(raw: &str, skills: &[ManifestSkill]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(raw.as_bytes());
+
+    let mut ids: Vec<&str> = skills.iter().map(|s| s.id.as_str()).collect();
+    ids.sort();
+    for id in ids {
+        let skill = skills.iter().find(|s| s.id == id).unwrap();
+        hasher.update(skill.id.as_bytes());
+        hasher.update(skill.name.as_bytes());
+        hasher.update(skill.description.as_bytes());
+        hasher.update(skill.category.as_bytes());
+        for produced in &skill.produces {
+            hasher.update(produced.as_bytes());
+        }
+        for required in &skill.requires {
+            hasher.update(required.as_bytes());
+        }
+    }
+
+    format!("{:x}", hasher.finalize())
+}
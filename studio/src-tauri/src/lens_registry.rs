@@ -0,0 +1,326 @@
+//! Remote lens registry — publish and install lenses over HTTP.
+//!
+//! Modeled on a deno-style package-publish flow: `publish_lens` uploads a
+//! lens's canonicalized content plus its SHA-256 checksum and semver
+//! `version` to a registry server, and `install_lens` resolves a semver
+//! requirement against that registry's published versions, downloads the
+//! matching content, verifies its checksum, and writes it under
+//! `~/.sunwell/lenses/`. An origin sidecar file records where an installed
+//! lens came from, so a later `install_lens` can tell a locally-edited
+//! lens apart from an untouched registry install and refuse to clobber it
+//! without `force`.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::lens::{self, LensLibraryEntry};
+use crate::sunwell_err;
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A single version a registry has published for a lens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegistryVersionEntry {
+    version: String,
+    checksum: String,
+}
+
+/// A version's full content, as returned by the registry's download endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegistryVersionContent {
+    version: String,
+    // Deliberately unverified against — see `install_lens`, which checks
+    // the recomputed checksum against `matched.checksum` (from the earlier
+    // version-listing call) instead, so a compromised download endpoint
+    // can't grade its own homework.
+    #[allow(dead_code)]
+    checksum: String,
+    content: String,
+}
+
+/// Body sent to the registry's publish endpoint.
+#[derive(Debug, Clone, Serialize)]
+struct PublishRequest<'a> {
+    version: &'a str,
+    checksum: &'a str,
+    content: &'a str,
+}
+
+/// Where an installed lens came from, recorded alongside it so a later
+/// install can tell an untouched registry install apart from one a user
+/// has since edited locally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegistryOrigin {
+    registry_url: String,
+    version: String,
+    checksum: String,
+}
+
+impl RegistryOrigin {
+    fn path(name: &str) -> Result<PathBuf, SunwellError> {
+        Ok(dirs::home_dir()
+            .ok_or_else(|| sunwell_err!(ConfigMissing, "Could not find home directory"))?
+            .join(".sunwell")
+            .join("lenses")
+            .join(format!("{}.origin.json", name)))
+    }
+
+    fn load(name: &str) -> Option<Self> {
+        let path = Self::path(name).ok()?;
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save(&self, name: &str) -> Result<(), SunwellError> {
+        let path = Self::path(name)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize lens origin: {}", e))?;
+        std::fs::write(path, json).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))
+    }
+}
+
+/// Result of publishing a lens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishResult {
+    pub success: bool,
+    pub version: String,
+    pub message: String,
+}
+
+/// Result of installing a lens from a registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallResult {
+    pub success: bool,
+    pub version: String,
+    pub path: String,
+    pub message: String,
+}
+
+/// Split a `registry_ref` of the form `https://registry.example.com/<lens-name>`
+/// into its base registry URL and the lens name the caller actually means
+/// to operate on. The name is always the ref's last path segment.
+fn split_registry_ref(registry_ref: &str) -> Result<(String, String), SunwellError> {
+    let trimmed = registry_ref.trim_end_matches('/');
+    let (base, name) = trimmed
+        .rsplit_once('/')
+        .ok_or_else(|| sunwell_err!(ConfigInvalid, "Registry reference '{}' has no lens name segment", registry_ref))?;
+
+    if name.is_empty() {
+        return Err(sunwell_err!(ConfigInvalid, "Registry reference '{}' has no lens name segment", registry_ref));
+    }
+
+    Ok((base.to_string(), name.to_string()))
+}
+
+/// Publish a lens's current content to a registry under a resolved semver
+/// version, authenticating with a bearer `token`.
+#[tauri::command]
+pub async fn publish_lens(name: String, registry_url: String, token: String) -> Result<PublishResult, String> {
+    let content = lens::read_lens_content(&name).map_err(|e| e.to_json())?;
+    let canonical = lens::canonical_lens_content(&content).map_err(|e| e.to_json())?;
+    let checksum = lens::sha256_hex(&canonical);
+    let version = lens::resolved_lens_version(&name);
+
+    Version::parse(&version).map_err(|e| {
+        sunwell_err!(ConfigInvalid, "Lens '{}' has no valid semver version to publish ({}): {}", name, version, e)
+            .to_json()
+    })?;
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{}/lenses/{}/versions", registry_url.trim_end_matches('/'), name))
+        .bearer_auth(&token)
+        .json(&PublishRequest { version: &version, checksum: &checksum, content: &canonical })
+        .send()
+        .await
+        .map_err(|e| {
+            SunwellError::new(ErrorCode::NetworkUnreachable, format!("Failed to reach registry: {}", e)).to_json()
+        })?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::NetworkUnreachable,
+            format!("Registry rejected publish of '{}': status {}", name, response.status()),
+        )
+        .to_json());
+    }
+
+    Ok(PublishResult { success: true, version, message: format!("Published '{}'", name) })
+}
+
+/// Resolve `version_req` against a registry's published versions for
+/// `name`, returning the highest-precedence version that satisfies it.
+async fn resolve_version(
+    client: &reqwest::Client,
+    registry_url: &str,
+    name: &str,
+    version_req: &str,
+) -> Result<RegistryVersionEntry, SunwellError> {
+    let req = VersionReq::parse(version_req)
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Invalid version requirement '{}': {}", version_req, e))?;
+
+    let response = client
+        .get(format!("{}/lenses/{}/versions", registry_url.trim_end_matches('/'), name))
+        .send()
+        .await
+        .map_err(|e| sunwell_err!(NetworkUnreachable, "Failed to reach registry: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(sunwell_err!(LensNotFound, "Registry has no lens named '{}': status {}", name, response.status()));
+    }
+
+    let versions: Vec<RegistryVersionEntry> = response
+        .json()
+        .await
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Registry returned malformed version list: {}", e))?;
+
+    versions
+        .into_iter()
+        .filter(|v| Version::parse(&v.version).map(|parsed| req.matches(&parsed)).unwrap_or(false))
+        .max_by(|a, b| {
+            Version::parse(&a.version).unwrap_or(Version::new(0, 0, 0))
+                .cmp(&Version::parse(&b.version).unwrap_or(Version::new(0, 0, 0)))
+        })
+        .ok_or_else(|| {
+            sunwell_err!(LensNotFound, "No version of '{}' satisfies requirement '{}'", name, version_req)
+        })
+}
+
+/// Install a lens from a registry, resolving `version_req` (e.g. `"^1.2.0"`)
+/// against its published versions and verifying the downloaded content's
+/// checksum before writing it. Refuses to overwrite a lens that was
+/// locally edited since its last registry install unless `force` is set.
+#[tauri::command]
+pub async fn install_lens(
+    registry_ref: String,
+    version_req: String,
+    force: Option<bool>,
+) -> Result<InstallResult, String> {
+    let (registry_url, name) = split_registry_ref(&registry_ref).map_err(|e| e.to_json())?;
+    let client = reqwest::Client::new();
+
+    let matched = resolve_version(&client, &registry_url, &name, &version_req).await.map_err(|e| e.to_json())?;
+
+    if let Some(origin) = RegistryOrigin::load(&name) {
+        if !force.unwrap_or(false) {
+            if let Ok(current_content) = lens::read_lens_content(&name) {
+                let current_checksum = lens::canonical_lens_content(&current_content)
+                    .map(|c| lens::sha256_hex(&c))
+                    .unwrap_or_default();
+                if current_checksum != origin.checksum {
+                    return Err(sunwell_err!(
+                        LensVersionConflict,
+                        "Lens '{}' was edited locally since its last registry install",
+                        name
+                    )
+                    .with_hints(vec!["Pass force=true to overwrite the local edits"])
+                    .to_json());
+                }
+            }
+        }
+    }
+
+    let response = client
+        .get(format!(
+            "{}/lenses/{}/versions/{}",
+            registry_url.trim_end_matches('/'),
+            name,
+            matched.version
+        ))
+        .send()
+        .await
+        .map_err(|e| {
+            SunwellError::new(ErrorCode::NetworkUnreachable, format!("Failed to reach registry: {}", e)).to_json()
+        })?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::NetworkUnreachable,
+            format!("Registry returned status {} fetching '{}'", response.status(), name),
+        )
+        .to_json());
+    }
+
+    let downloaded: RegistryVersionContent = response
+        .json()
+        .await
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Registry returned malformed lens content: {}", e).to_json())?;
+
+    if downloaded.version != matched.version {
+        return Err(sunwell_err!(
+            LensIntegrityMismatch,
+            "Registry sent version '{}' for '{}' but the resolved version was '{}'",
+            downloaded.version,
+            name,
+            matched.version
+        )
+        .to_json());
+    }
+
+    let checksum = lens::sha256_hex(
+        &lens::canonical_lens_content(&downloaded.content).map_err(|e| e.to_json())?,
+    );
+    // Verify against `matched.checksum` from the earlier version-listing
+    // call, not `downloaded.checksum` from this same download response —
+    // otherwise a compromised or buggy registry could serve tampered
+    // content alongside a checksum of its own choosing and this check
+    // would never catch it.
+    if checksum != matched.checksum {
+        return Err(sunwell_err!(
+            LensIntegrityMismatch,
+            "Downloaded content for '{}' does not match the checksum published for version '{}'",
+            name,
+            matched.version
+        )
+        .to_json());
+    }
+
+    let lens_path = dirs::home_dir()
+        .ok_or_else(|| sunwell_err!(ConfigMissing, "Could not find home directory").to_json())?
+        .join(".sunwell")
+        .join("lenses")
+        .join(format!("{}.lens", name));
+    if let Some(parent) = lens_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e).to_json())?;
+    }
+    std::fs::write(&lens_path, &downloaded.content)
+        .map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e).to_json())?;
+
+    RegistryOrigin { registry_url: registry_url.clone(), version: downloaded.version.clone(), checksum }
+        .save(&name)
+        .map_err(|e| e.to_json())?;
+
+    Ok(InstallResult {
+        success: true,
+        version: downloaded.version,
+        path: lens_path.to_string_lossy().to_string(),
+        message: format!("Installed '{}' from {}", name, registry_url),
+    })
+}
+
+/// Best-effort: if `entry` was installed from a registry, surface that
+/// origin and check whether a newer compatible version has been
+/// published. Network/parse failures are swallowed — an update check
+/// should never break the library listing.
+pub(crate) async fn annotate_registry_entry(entry: &mut LensLibraryEntry) {
+    let Some(origin) = RegistryOrigin::load(&entry.name) else { return };
+
+    entry.source = "registry".to_string();
+    entry.registry_url = Some(origin.registry_url.clone());
+    entry.registry_version = Some(origin.version.clone());
+
+    let Ok(current) = Version::parse(&origin.version) else { return };
+    let client = reqwest::Client::new();
+    // "^<current>" under semver excludes major bumps (and, before 1.0,
+    // minor bumps) from matching as compatible — good enough for a
+    // best-effort nudge.
+    let requirement = format!("^{}", origin.version);
+    if let Ok(candidate) = resolve_version(&client, &origin.registry_url, &entry.name, &requirement).await {
+        if Version::parse(&candidate.version).map(|v| v > current).unwrap_or(false) {
+            entry.available_update = Some(candidate.version);
+        }
+    }
+}
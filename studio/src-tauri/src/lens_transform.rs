@@ -0,0 +1,250 @@
+//! WASM Transform Lenses — composable heuristic post-processing.
+//!
+//! A transform lens is a small, sandboxed WASM module that rewrites a
+//! lens's resolved `LensDetail` at load time — filter-by-priority,
+//! prepend-house-rules, domain remapping — without forking the whole
+//! lens. Modules run with no WASI context, so they have no filesystem or
+//! network access, and with fuel metering enabled (`TRANSFORM_FUEL`), so a
+//! module stuck in an infinite loop traps instead of hanging the calling
+//! thread forever. The pipeline is fail-closed: a module that traps (fuel
+//! exhaustion included), fails to instantiate, doesn't honor the ABI
+//! below, or reports an implausible `output_len` aborts the whole
+//! pipeline with `LensTransformFailed` rather than silently dropping
+//! heuristics or handing a bogus length straight to an allocator.
+//!
+//! # Module ABI
+//!
+//! Each `.wasm` module must export:
+//! - `memory` — the module's linear memory
+//! - `alloc(len: i32) -> i32` — allocate `len` bytes, return the pointer
+//! - `transform(ptr: i32, len: i32) -> i32` — read the serialized
+//!   `LensDetail` JSON at `ptr`/`len`, write transformed JSON into memory
+//!   (via `alloc`), and return its pointer
+//! - `output_len() -> i32` — length in bytes of the buffer `transform`
+//!   last wrote
+
+use crate::error::SunwellError;
+use crate::lens::LensDetail;
+use crate::sunwell_err;
+use std::path::PathBuf;
+use wasmtime::{Config, Engine, Instance, Module, Store};
+
+/// Fuel budget for a single `transform` call — generous for the kind of
+/// small JSON rewrite these modules do, but low enough that a module stuck
+/// in an infinite loop traps in well under a second rather than hanging
+/// the calling thread forever.
+const TRANSFORM_FUEL: u64 = 50_000_000;
+
+/// Upper bound on the byte length a module may report from `output_len`.
+/// Transform output is a rewritten `LensDetail` JSON document — nowhere
+/// near this size — so a length beyond it is treated as a misbehaving
+/// module rather than trusted straight into a `vec![0u8; len]` allocation.
+const MAX_TRANSFORM_OUTPUT_BYTES: i32 = 64 * 1024 * 1024;
+
+/// A single transform lens, resolved to its `.wasm` file under the
+/// user's transforms directory (`~/.sunwell/transforms/<name>.wasm`).
+#[derive(Debug, Clone)]
+pub struct TransformLens {
+    pub name: String,
+    pub wasm_path: PathBuf,
+}
+
+impl TransformLens {
+    fn resolve(name: &str) -> Result<Self, SunwellError> {
+        let wasm_path = dirs::home_dir()
+            .ok_or_else(|| sunwell_err!(ConfigMissing, "Could not find home directory"))?
+            .join(".sunwell")
+            .join("transforms")
+            .join(format!("{}.wasm", name));
+
+        if !wasm_path.exists() {
+            return Err(
+                sunwell_err!(LensNotFound, "Transform lens '{}' not found", name).with_hints(vec![
+                    "Check the transform name and that its .wasm file is installed",
+                ]),
+            );
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            wasm_path,
+        })
+    }
+
+    /// Run this transform against `input` (a serialized `LensDetail`),
+    /// returning the transformed JSON. Sandboxed — no WASI context is
+    /// added, so the module has no filesystem or network access.
+    fn run(&self, input: &str) -> Result<String, SunwellError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' failed to initialize sandbox: {}",
+                self.name,
+                e
+            )
+        })?;
+        let module = Module::from_file(&engine, &self.wasm_path).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' failed to load: {}",
+                self.name,
+                e
+            )
+        })?;
+
+        let mut store = Store::new(&engine, ());
+        store.set_fuel(TRANSFORM_FUEL).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' failed to set fuel budget: {}",
+                self.name,
+                e
+            )
+        })?;
+        let instance = Instance::new(&mut store, &module, &[]).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' failed to instantiate: {}",
+                self.name,
+                e
+            )
+        })?;
+
+        let memory = instance.get_memory(&mut store, "memory").ok_or_else(|| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' does not export memory",
+                self.name
+            )
+        })?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .map_err(|_| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' does not export alloc",
+                    self.name
+                )
+            })?;
+        let transform = instance
+            .get_typed_func::<(i32, i32), i32>(&mut store, "transform")
+            .map_err(|_| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' does not export transform",
+                    self.name
+                )
+            })?;
+        let output_len = instance
+            .get_typed_func::<(), i32>(&mut store, "output_len")
+            .map_err(|_| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' does not export output_len",
+                    self.name
+                )
+            })?;
+
+        let input_bytes = input.as_bytes();
+        let input_ptr = alloc
+            .call(&mut store, input_bytes.len() as i32)
+            .map_err(|e| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' panicked in alloc: {}",
+                    self.name,
+                    e
+                )
+            })?;
+        memory
+            .write(&mut store, input_ptr as usize, input_bytes)
+            .map_err(|e| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' rejected input write: {}",
+                    self.name,
+                    e
+                )
+            })?;
+
+        let output_ptr = transform
+            .call(&mut store, (input_ptr, input_bytes.len() as i32))
+            .map_err(|e| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' panicked: {}",
+                    self.name,
+                    e
+                )
+            })?;
+        let len = output_len.call(&mut store, ()).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' panicked in output_len: {}",
+                self.name,
+                e
+            )
+        })?;
+
+        if !(0..=MAX_TRANSFORM_OUTPUT_BYTES).contains(&len) {
+            return Err(sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' reported an implausible output_len ({} bytes)",
+                self.name,
+                len
+            ));
+        }
+
+        let mut output_bytes = vec![0u8; len as usize];
+        memory
+            .read(&store, output_ptr as usize, &mut output_bytes)
+            .map_err(|e| {
+                sunwell_err!(
+                    LensTransformFailed,
+                    "Transform '{}' produced invalid output: {}",
+                    self.name,
+                    e
+                )
+            })?;
+
+        String::from_utf8(output_bytes).map_err(|e| {
+            sunwell_err!(
+                LensTransformFailed,
+                "Transform '{}' produced non-UTF8 output: {}",
+                self.name,
+                e
+            )
+        })
+    }
+}
+
+/// Load a lens, then run it through a pipeline of named transform
+/// lenses in order, feeding each transform's JSON output to the next.
+/// The result can be previewed as-is or saved via the existing
+/// `save_lens` once it's been converted back to `.lens` YAML.
+#[tauri::command]
+pub async fn apply_lens_transforms(
+    name: String,
+    transforms: Vec<String>,
+) -> Result<LensDetail, String> {
+    let detail = crate::lens::get_lens_detail(name.clone()).await?;
+    let mut current = serde_json::to_string(&detail).map_err(|e| {
+        sunwell_err!(LensTransformFailed, "Failed to serialize '{}': {}", name, e).to_json()
+    })?;
+
+    for transform_name in &transforms {
+        let transform = TransformLens::resolve(transform_name).map_err(|e| e.to_json())?;
+        current = transform.run(&current).map_err(|e| e.to_json())?;
+    }
+
+    serde_json::from_str(&current).map_err(|e| {
+        sunwell_err!(
+            LensTransformFailed,
+            "Final transform output did not match LensDetail: {}",
+            e
+        )
+        .to_json()
+    })
+}
@@ -0,0 +1,178 @@
+//! Lens file watcher — live-refresh the Lens Library UI on disk changes.
+//!
+//! Watches `~/.sunwell/lenses/` and the project's builtin `lenses/`
+//! directory with the `notify` crate, debouncing rapid bursts into a
+//! single `lens-library-changed` event per lens so the frontend can
+//! live-refresh `get_lens_library`/`get_lens_detail` instead of waiting
+//! for an explicit re-query. The debounce window also coalesces the
+//! temp-file-then-rename churn `save_lens` produces (it writes a temp
+//! file and shells out to the CLI) into one logical event, so editors
+//! don't flicker.
+
+use crate::error::SunwellError;
+use crate::sunwell_err;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How long to wait for a lens's events to go quiet before emitting.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Kind of change observed for a lens file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LensChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// Payload for the `lens-library-changed` Tauri event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensLibraryChanged {
+    pub name: String,
+    pub kind: LensChangeKind,
+}
+
+/// A running watcher. Dropping this stops watching (the `notify` watcher
+/// is torn down) and signals the debounce thread to exit.
+struct LensWatcherHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Owns the lens watcher's lifecycle, mirroring `PreviewManager`'s
+/// start/stop shape so it can live in `AppState` the same way.
+#[derive(Default)]
+pub struct LensWatcherManager {
+    handle: Option<LensWatcherHandle>,
+}
+
+impl LensWatcherManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start watching, or do nothing if already running.
+    pub fn start(&mut self, app: AppHandle) -> Result<(), SunwellError> {
+        if self.handle.is_some() {
+            return Ok(());
+        }
+
+        let (event_tx, event_rx) = mpsc::channel::<Event>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let _ = event_tx.send(event);
+            }
+        })
+        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to create lens watcher: {}", e))?;
+
+        for dir in watch_dirs() {
+            if dir.exists() {
+                watcher
+                    .watch(&dir, RecursiveMode::NonRecursive)
+                    .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to watch {}: {}", dir.display(), e))?;
+            }
+        }
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        thread::spawn(move || debounce_loop(app, event_rx, stop_rx));
+
+        self.handle = Some(LensWatcherHandle { _watcher: watcher, stop_tx });
+        Ok(())
+    }
+
+    /// Stop watching. A no-op if not running.
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.stop_tx.send(());
+        }
+    }
+}
+
+/// Directories the watcher covers: the user's editable lenses and the
+/// builtin `lenses/` directory under the current working directory.
+fn watch_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".sunwell").join("lenses"));
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        dirs.push(cwd.join("lenses"));
+    }
+    dirs
+}
+
+/// Extract a lens's name from a changed path, ignoring anything that
+/// isn't a `.lens` file (temp files, `.origin.json` sidecars, etc).
+fn lens_name_from_path(path: &Path) -> Option<String> {
+    if path.extension().and_then(|e| e.to_str()) != Some("lens") {
+        return None;
+    }
+    path.file_stem().and_then(|s| s.to_str()).map(String::from)
+}
+
+fn change_kind(kind: &EventKind) -> Option<LensChangeKind> {
+    match kind {
+        EventKind::Create(_) => Some(LensChangeKind::Created),
+        EventKind::Modify(_) => Some(LensChangeKind::Modified),
+        EventKind::Remove(_) => Some(LensChangeKind::Removed),
+        _ => None,
+    }
+}
+
+/// Coalesce raw filesystem events into one `lens-library-changed` event
+/// per lens, holding each lens's latest change kind until its events go
+/// quiet for `DEBOUNCE` before emitting.
+fn debounce_loop(app: AppHandle, event_rx: mpsc::Receiver<Event>, stop_rx: mpsc::Receiver<()>) {
+    let mut pending: HashMap<String, (LensChangeKind, Instant)> = HashMap::new();
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            return;
+        }
+
+        match event_rx.recv_timeout(DEBOUNCE) {
+            Ok(event) => {
+                let Some(kind) = change_kind(&event.kind) else { continue };
+                for path in &event.paths {
+                    if let Some(name) = lens_name_from_path(path) {
+                        pending.insert(name, (kind, Instant::now()));
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        let settled: Vec<String> =
+            pending.iter().filter(|(_, (_, seen))| seen.elapsed() >= DEBOUNCE).map(|(name, _)| name.clone()).collect();
+
+        for name in settled {
+            if let Some((kind, _)) = pending.remove(&name) {
+                let _ = app.emit("lens-library-changed", LensLibraryChanged { name, kind });
+            }
+        }
+    }
+}
+
+/// Start the lens file watcher, if it isn't already running.
+#[tauri::command]
+pub async fn start_lens_watcher(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.lens_watcher.lock().unwrap().start(app).map_err(|e| e.to_json())
+}
+
+/// Stop the lens file watcher, if it's running.
+#[tauri::command]
+pub async fn stop_lens_watcher(state: tauri::State<'_, crate::commands::AppState>) -> Result<(), String> {
+    state.lens_watcher.lock().unwrap().stop();
+    Ok(())
+}
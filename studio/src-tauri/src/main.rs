@@ -6,25 +6,61 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod agent;
+mod analysis_daemon;
+mod analysis_workload;
+mod audit_integrity;
+mod benchmark;
 mod briefing;
+mod capability;
 mod commands;
+mod coordinator;
 mod dag;
+mod dag_query;
+mod dag_store;
+mod demo;
+mod demo_transport;
 mod error;
+mod eval;
+mod eval_tools;
+mod file_watcher;
 mod heuristic_detect;
+mod index_error;
+mod indexer_job;
+mod indexer_rules;
+mod indexing;
+mod intelligence_integrity;
+mod intelligence_store;
+mod intelligence_watcher;
 mod interface;
+mod job_manager;
 mod lens;
+mod lens_manifest;
+mod lens_registry;
+mod lens_transform;
+mod lens_watcher;
 mod memory;
+mod memory_watcher;
+mod metrics;
 mod naaru;
+mod naaru_bench;
 mod preview;
 mod project;
 mod run_analysis;
+mod runtime_acl;
+mod runtime_version;
+mod sarif;
 mod security;
+mod self_benchmark;
 mod self_knowledge;
 mod surface;
+mod telemetry;
 mod util;
+mod validation_rules;
 mod weakness;
 mod weakness_types;
 mod workflow;
+mod workflow_bench;
+mod workflow_queue;
 mod workspace;
 mod writer;
 
@@ -65,21 +101,40 @@ pub struct StartupParams {
 }
 
 fn main() {
+    // RFC-101: Install the tracing subscriber before anything spans.
+    telemetry::init_telemetry();
+
+    // RFC-086 addendum: opt-in Prometheus metrics for previews/workflows —
+    // off by default so `metrics_snapshot` stays a cheap no-op unless an
+    // operator asks for it.
+    if std::env::var("SUNWELL_METRICS")
+        .map(|v| v == "1")
+        .unwrap_or(false)
+    {
+        metrics::init_metrics();
+    }
+
     // RFC-086: Parse CLI args before Tauri starts
     let args = CliArgs::parse();
     let startup = StartupParams {
         project: args.project,
         lens: args.lens,
         mode: args.mode,
-        plan: args.plan,  // RFC-090
+        plan: args.plan, // RFC-090
     };
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .manage(AppState::default())
+        .manage(indexing::IndexingState::default())
+        .manage(std::sync::Arc::new(workflow_queue::WorkflowQueue::default()))
         .setup(move |app| {
             // Emit startup params to frontend if any were provided (RFC-090: include plan)
-            if startup.project.is_some() || startup.lens.is_some() || startup.mode.is_some() || startup.plan.is_some() {
+            if startup.project.is_some()
+                || startup.lens.is_some()
+                || startup.mode.is_some()
+                || startup.plan.is_some()
+            {
                 let handle = app.handle().clone();
                 let params = startup.clone();
                 // Emit after a short delay to ensure frontend is ready
@@ -100,8 +155,11 @@ fn main() {
             // Goal execution
             commands::run_goal,
             commands::stop_agent,
+            commands::send_agent_approval,
+            commands::replay_agent_session,
             // Workspace resolution (RFC-043 addendum)
             commands::resolve_workspace_for_goal,
+            commands::clone_workspace_from_url,
             commands::get_default_workspace,
             commands::create_project,
             commands::get_workspace_settings,
@@ -110,12 +168,19 @@ fn main() {
             // Project management
             commands::get_recent_projects,
             commands::remove_recent_project,
+            commands::tag_recent_project,
+            commands::untag_recent_project,
+            commands::get_recent_projects_by_tag,
+            commands::get_recent_project_tags,
             commands::open_project,
             commands::get_project_info,
             // Project discovery & resume
             commands::scan_projects,
             commands::get_project_status,
             commands::resume_project,
+            commands::recover_jobs,
+            commands::get_project_environment,
+            commands::list_checkpoints,
             // Preview
             commands::launch_preview,
             commands::stop_preview,
@@ -126,6 +191,8 @@ fn main() {
             // File tree
             commands::list_project_files,
             commands::read_file_contents,
+            file_watcher::watch_project_files,
+            file_watcher::stop_watching_project_files,
             // Project lifecycle (delete, archive, iterate)
             commands::delete_project,
             commands::archive_project,
@@ -134,19 +201,46 @@ fn main() {
             // DAG / Pipeline view (RFC-056, RFC-090)
             dag::get_project_dag,
             dag::execute_dag_node,
+            dag::resume_dag_job,
+            dag::get_dag_schedule,
+            dag::get_downstream_impact,
             dag::refresh_backlog,
-            dag::load_plan_file,  // RFC-090: Load plan from CLI
+            dag::load_plan_file, // RFC-090: Load plan from CLI
+            // RFC-105 addendum: DAG query/filter mini-language
+            dag_query::query_project_dag,
+            // Workspace DAG Index (RFC-105 Phase 3)
+            dag::get_workspace_dag,
+            dag::refresh_workspace_index,
+            dag::scan_workspace,
+            // RFC-105 addendum: SQLite DAG Store — indexed cross-cutting queries
+            dag_store::query_goals_by_artifact,
+            dag_store::query_unverified_edges,
+            dag_store::query_goal_timeline,
+            dag_store::rebuild_dag_db,
             // Incremental Execution (RFC-074)
             dag::get_incremental_plan,
             dag::get_cache_stats,
             dag::get_artifact_impact,
             dag::clear_cache,
+            // DAG Planning & Incremental Execution Benchmarks (RFC-105 addendum)
+            benchmark::run_benchmark,
+            benchmark::run_benchmarks,
             // Memory / Simulacrum (RFC-013, RFC-014, RFC-084)
             memory::get_memory_stats,
             memory::list_sessions,
             memory::get_intelligence,
             memory::get_concept_graph,
+            memory::get_graph_analytics,
             memory::get_chunk_hierarchy,
+            memory::search_memory,
+            memory::semantic_search,
+            memory_watcher::start_memory_watch,
+            memory_watcher::stop_memory_watch,
+            intelligence_store::search_dead_ends,
+            intelligence_store::recent_decisions,
+            intelligence_store::check_intelligence_integrity,
+            intelligence_watcher::start_intelligence_watch,
+            intelligence_watcher::stop_intelligence_watch,
             // Saved prompts
             commands::get_saved_prompts,
             commands::save_prompt,
@@ -158,6 +252,8 @@ fn main() {
             weakness::start_cascade_execution,
             weakness::get_weakness_overlay,
             weakness::extract_contract,
+            weakness::run_cascade,
+            weakness::abort_cascade,
             // Lens Management (RFC-064)
             lens::list_lenses,
             lens::get_lens_detail,
@@ -169,18 +265,52 @@ fn main() {
             lens::save_lens,
             lens::delete_lens,
             lens::get_lens_versions,
+            lens::diff_lens_versions,
             lens::rollback_lens,
             lens::set_default_lens,
             lens::get_lens_content,
+            // Lens Lockfile — checksum-pinned resolution
+            lens::lock_project_lenses,
+            // Remote Lens Registry — publish and install with semver + integrity
+            lens_registry::publish_lens,
+            lens_registry::install_lens,
+            // WASM Transform Lenses — composable heuristic post-processing
+            lens_transform::apply_lens_transforms,
+            // Lens File Watcher — live-refresh on disk changes
+            lens_watcher::start_lens_watcher,
+            lens_watcher::stop_lens_watcher,
             // Run Analysis (RFC-066)
             commands::analyze_project_for_run,
             commands::run_project,
+            commands::get_run_session_logs,
             commands::stop_project_run,
+            commands::restart_project_run,
+            commands::list_run_sessions,
+            commands::get_run_session_status,
             commands::save_run_command,
+            commands::add_safe_run_command,
+            commands::set_run_command_alias,
+            commands::get_run_safety_config,
             // Project Intent Analysis (RFC-079)
             commands::analyze_project,
+            commands::cancel_analysis,
             commands::analyze_monorepo,
+            commands::install_monorepo,
+            commands::enumerate_monorepo_projects,
             commands::get_project_signals,
+            analysis_workload::run_analysis_workload,
+            // Codebase Indexing (RFC-108)
+            indexing::start_indexing_service,
+            indexing::stop_indexing_service,
+            indexing::pause_indexing_service,
+            indexing::resume_indexing_service,
+            indexing::query_index,
+            indexing::query_index_stream,
+            indexing::get_index_status,
+            indexing::rebuild_index,
+            indexing::set_index_settings,
+            indexing::get_index_metrics,
+            indexing::list_active_indexes,
             // Briefing System (RFC-071)
             briefing::get_briefing,
             briefing::has_briefing,
@@ -203,6 +333,9 @@ fn main() {
             naaru::naaru_subscribe,
             naaru::naaru_convergence,
             naaru::naaru_cancel,
+            naaru::naaru_cancel_all,
+            // Naaru Benchmark Harness (RFC-083 addendum)
+            naaru_bench::naaru_bench,
             // Self-Knowledge (RFC-085)
             self_knowledge::self_get_module_source,
             self_knowledge::self_find_symbol,
@@ -217,6 +350,18 @@ fn main() {
             self_knowledge::self_apply_proposal,
             self_knowledge::self_rollback_proposal,
             self_knowledge::self_get_summary,
+            // Self-Knowledge — streaming proposal execution (RFC-085 addendum)
+            self_knowledge::self_test_proposal_streaming,
+            self_knowledge::self_apply_proposal_streaming,
+            self_knowledge::cancel_proposal_session,
+            // Self-Knowledge — source watch mode (RFC-085 addendum)
+            self_knowledge::self_watch_source,
+            self_knowledge::self_unwatch_source,
+            // Self-Knowledge — proposal lockfile (RFC-085 addendum)
+            self_knowledge::self_verify_proposal_lock,
+            // Self-Knowledge — workload-driven benchmarking (RFC-085 addendum)
+            self_benchmark::self_run_benchmark,
+            self_benchmark::self_compare_benchmark,
             // Workflow Execution (RFC-086)
             workflow::route_workflow_intent,
             workflow::start_workflow,
@@ -225,6 +370,17 @@ fn main() {
             workflow::skip_workflow_step,
             workflow::list_workflow_chains,
             workflow::list_active_workflows,
+            // Workflow — workload-driven benchmarking (RFC-086 addendum)
+            workflow_bench::run_workflow_benchmark,
+            // Workflow — background queue with bounded concurrency (RFC-086 addendum)
+            workflow_queue::enqueue_workflow,
+            workflow_queue::list_queued_workflows,
+            workflow_queue::cancel_queued_workflow,
+            workflow_queue::resume_queue,
+            // Opt-in Prometheus metrics for previews/workflows (RFC-086 addendum)
+            metrics::metrics_snapshot,
+            // CLI version & capability handshake (RFC-109)
+            runtime_version::negotiate_runtime,
             // Writer Environment (RFC-086)
             writer::detect_diataxis,
             writer::validate_document,
@@ -234,14 +390,46 @@ fn main() {
             // Skill Graph (RFC-087)
             writer::get_skill_graph,
             writer::get_skill_execution_plan,
+            writer::execute_skill_plan,
             writer::get_skill_cache_stats,
             writer::clear_skill_cache,
+            // SARIF diagnostics export (RFC-113)
+            sarif::export_diagnostics,
             // Security-First Execution (RFC-089)
             security::analyze_dag_permissions,
             security::submit_security_approval,
             security::get_audit_log,
             security::verify_audit_integrity,
             security::scan_for_security_issues,
+            runtime_acl::revoke_dag_capability,
+            // Demo — Prism Principle (RFC-095)
+            demo::run_demo_streaming,
+            demo::cancel_demo,
+            demo::list_demo_tasks,
+            demo::run_demo_workload,
+            demo::run_demos_parallel,
+            // Demo session transport — capability-negotiated, long-lived (RFC-095 addendum)
+            demo_transport::start_demo_session,
+            demo_transport::pause_refinement,
+            demo_transport::request_intermediate_code,
+            demo_transport::set_judge_threshold,
+            demo_transport::stop_demo_session,
+            // Evaluation Framework (RFC-098)
+            eval::run_eval_streaming,
+            eval::run_eval_workload,
+            eval::run_eval_inprocess_baseline,
+            eval::list_eval_tasks,
+            eval::get_eval_history,
+            eval::get_eval_stats,
+            eval::export_eval_junit,
+            // Coordinator bridge — ATC view (RFC-100 Phase 4)
+            coordinator::get_coordinator_state,
+            coordinator::pause_worker,
+            coordinator::resume_worker,
+            coordinator::start_workers,
+            coordinator::get_state_dag,
+            coordinator::start_coordinator_stream,
+            coordinator::stop_coordinator_stream,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
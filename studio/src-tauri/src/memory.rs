@@ -7,7 +7,9 @@
 //! - RFC-084: Get ConceptGraph and ChunkHierarchy for visualization
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 // =============================================================================
 // Public Types
@@ -168,14 +170,17 @@ pub struct FailedApproach {
 // Tauri Commands
 // =============================================================================
 
-/// Get memory statistics for a project
-#[tauri::command]
-pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
-    let project_path = PathBuf::from(&path);
+/// Everything `compute_memory_stats_sync`/`compute_memory_stats_async` fill
+/// in except `learnings` (the one field read from a potentially large,
+/// ever-growing `.jsonl` log) — directory listings and single small-file
+/// reads, cheap enough that both the sync and async paths just do them the
+/// same (blocking) way.
+fn scan_memory_stats_except_learnings(path: &str) -> MemoryStats {
+    let project_path = PathBuf::from(path);
     let memory_path = project_path.join(".sunwell/memory");
 
     if !memory_path.exists() {
-        return Ok(MemoryStats::default());
+        return MemoryStats::default();
     }
 
     // Read directly from the filesystem (sunwell sessions stats outputs Rich tables, not JSON)
@@ -186,9 +191,13 @@ pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
             if path.is_file() && path.extension().map_or(false, |e| e == "json") {
-                if path.to_string_lossy().contains("hot") || path.to_string_lossy().contains("current") {
+                if path.to_string_lossy().contains("hot")
+                    || path.to_string_lossy().contains("current")
+                {
                     stats.hot_turns += 1;
-                } else if path.to_string_lossy().contains("cold") || path.to_string_lossy().contains("archive") {
+                } else if path.to_string_lossy().contains("cold")
+                    || path.to_string_lossy().contains("archive")
+                {
                     stats.cold_files += 1;
                 } else {
                     stats.warm_files += 1;
@@ -215,14 +224,6 @@ pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
         }
     }
 
-    // Count learnings from intelligence
-    let decisions_path = project_path.join(".sunwell/intelligence/decisions.jsonl");
-    if decisions_path.exists() {
-        if let Ok(content) = std::fs::read_to_string(&decisions_path) {
-            stats.learnings = content.lines().filter(|l| !l.is_empty()).count() as u32;
-        }
-    }
-
     // RFC-084: Count concept graph edges
     let unified_graph_path = project_path.join(".sunwell/memory/unified/graph.json");
     let graph_path = if unified_graph_path.exists() {
@@ -230,7 +231,7 @@ pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
     } else {
         project_path.join(".sunwell/memory/graph.json")
     };
-    
+
     if graph_path.exists() {
         if let Ok(content) = std::fs::read_to_string(&graph_path) {
             if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
@@ -241,17 +242,89 @@ pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
         }
     }
 
-    Ok(stats)
+    stats
 }
 
-/// List conversation sessions for a project
+/// Computes `MemoryStats` by walking `.sunwell/memory`/`.sunwell/intelligence`
+/// directly, counting `decisions.jsonl`'s lines synchronously. Used by
+/// `memory_watcher`'s debounce thread, which runs on a dedicated
+/// `std::thread` rather than a tokio worker, so the blocking read there is
+/// fine; the async `get_memory_stats` command uses
+/// `compute_memory_stats_async` instead.
+pub(crate) fn compute_memory_stats_sync(path: &str) -> MemoryStats {
+    let mut stats = scan_memory_stats_except_learnings(path);
+
+    let decisions_path = PathBuf::from(path).join(".sunwell/intelligence/decisions.jsonl");
+    if decisions_path.exists() {
+        if let Ok(content) = std::fs::read_to_string(&decisions_path) {
+            stats.learnings = content.lines().filter(|l| !l.is_empty()).count() as u32;
+        }
+    }
+
+    stats
+}
+
+/// Counts non-empty lines in `path` via `tokio::fs`/`BufReader::lines()`
+/// rather than `std::fs::read_to_string` + `.lines()`, so counting a large
+/// `decisions.jsonl` from an async command doesn't block its tokio worker
+/// thread or hold the whole file in memory at once.
+async fn count_nonempty_lines_async(path: &Path) -> u32 {
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    let Ok(file) = tokio::fs::File::open(path).await else {
+        return 0;
+    };
+    let mut lines = BufReader::new(file).lines();
+    let mut count = 0u32;
+    while let Ok(Some(line)) = lines.next_line().await {
+        if !line.is_empty() {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Same scan as `compute_memory_stats_sync`, but counts
+/// `decisions.jsonl`'s lines asynchronously so a direct (cache-miss) call
+/// from the async `get_memory_stats` command doesn't block the tokio
+/// runtime on a large log file. The other fields here are cheap
+/// directory listings and single small-file reads, so only that one count
+/// is worth duplicating an async path for; `memory_watcher`'s debounce
+/// thread keeps using `compute_memory_stats_sync` since it's a dedicated
+/// `std::thread`, not a tokio worker.
+async fn compute_memory_stats_async(path: &str) -> MemoryStats {
+    let mut stats = scan_memory_stats_except_learnings(path);
+
+    let decisions_path = PathBuf::from(path).join(".sunwell/intelligence/decisions.jsonl");
+    if decisions_path.exists() {
+        stats.learnings = count_nonempty_lines_async(&decisions_path).await;
+    }
+    stats
+}
+
+/// Get memory statistics for a project
 #[tauri::command]
-pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
-    let project_path = PathBuf::from(&path);
+pub async fn get_memory_stats(path: String) -> Result<MemoryStats, String> {
+    if let Some(snapshot) = crate::memory_watcher::cached_snapshot(&path) {
+        return Ok(snapshot.stats);
+    }
+    Ok(compute_memory_stats_async(&path).await)
+}
+
+/// Computes the session list by walking `.sunwell/memory`'s session
+/// directories directly. Shared the same way as `compute_memory_stats_sync`.
+///
+/// Unlike `get_intelligence`/`get_memory_stats`, this has no append-only
+/// `.jsonl` log to stream: each session contributes one small
+/// `metadata.json` read plus a directory listing, so there's no async
+/// streaming variant here — the blocking cost doesn't scale with history
+/// the way a growing `decisions.jsonl` does.
+pub(crate) fn compute_sessions_sync(path: &str) -> Vec<Session> {
+    let project_path = PathBuf::from(path);
     let memory_path = project_path.join(".sunwell/memory");
 
     if !memory_path.exists() {
-        return Ok(Vec::new());
+        return Vec::new();
     }
 
     let mut sessions = Vec::new();
@@ -261,7 +334,8 @@ pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
             if path.is_dir() {
-                let session_id = path.file_name()
+                let session_id = path
+                    .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or("unknown")
                     .to_string();
@@ -274,7 +348,9 @@ pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
                             (
                                 json.get("name").and_then(|v| v.as_str()).map(String::from),
                                 json.get("turn_count").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
-                                json.get("created_at").and_then(|v| v.as_str()).map(String::from),
+                                json.get("created_at")
+                                    .and_then(|v| v.as_str())
+                                    .map(String::from),
                             )
                         } else {
                             (None, 0, None)
@@ -285,9 +361,12 @@ pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
                 } else {
                     // Count .json files as turn estimate
                     let turns = std::fs::read_dir(&path)
-                        .map(|entries| entries.filter_map(|e| e.ok())
-                            .filter(|e| e.path().extension().map_or(false, |ext| ext == "json"))
-                            .count())
+                        .map(|entries| {
+                            entries
+                                .filter_map(|e| e.ok())
+                                .filter(|e| e.path().extension().map_or(false, |ext| ext == "json"))
+                                .count()
+                        })
                         .unwrap_or(0) as u32;
                     (None, turns, None)
                 };
@@ -302,34 +381,43 @@ pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
         }
     }
 
-    Ok(sessions)
+    sessions
+}
+
+/// List conversation sessions for a project
+#[tauri::command]
+pub async fn list_sessions(path: String) -> Result<Vec<Session>, String> {
+    if let Some(snapshot) = crate::memory_watcher::cached_snapshot(&path) {
+        return Ok(snapshot.sessions);
+    }
+    Ok(compute_sessions_sync(&path))
 }
 
 // =============================================================================
 // RFC-084: ConceptGraph and ChunkHierarchy Commands
 // =============================================================================
 
-/// Get ConceptGraph for visualization (RFC-084)
-#[tauri::command]
-pub async fn get_concept_graph(path: String) -> Result<ConceptGraph, String> {
-    let project_path = PathBuf::from(&path);
-    
+/// Computes the `ConceptGraph` by reading `graph.json` directly. Shared
+/// the same way as `compute_memory_stats_sync`.
+pub(crate) fn compute_concept_graph_sync(path: &str) -> ConceptGraph {
+    let project_path = PathBuf::from(path);
+
     // Try unified store first (RFC-014 format)
     let unified_graph_path = project_path.join(".sunwell/memory/unified/graph.json");
     if unified_graph_path.exists() {
         if let Ok(content) = std::fs::read_to_string(&unified_graph_path) {
             if let Ok(graph) = serde_json::from_str::<ConceptGraph>(&content) {
-                return Ok(graph);
+                return graph;
             }
         }
     }
-    
+
     // Try legacy format
     let graph_path = project_path.join(".sunwell/memory/graph.json");
     if graph_path.exists() {
         if let Ok(content) = std::fs::read_to_string(&graph_path) {
             if let Ok(graph) = serde_json::from_str::<ConceptGraph>(&content) {
-                return Ok(graph);
+                return graph;
             }
             // Try parsing as nested structure
             if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
@@ -338,24 +426,313 @@ pub async fn get_concept_graph(path: String) -> Result<ConceptGraph, String> {
                         .iter()
                         .filter_map(|v| serde_json::from_value(v.clone()).ok())
                         .collect();
-                    return Ok(ConceptGraph { edges });
+                    return ConceptGraph { edges };
                 }
             }
         }
     }
-    
+
     // No graph found - return empty
-    Ok(ConceptGraph::default())
+    ConceptGraph::default()
 }
 
-/// Get ChunkHierarchy for visualization (RFC-084)
+/// Get ConceptGraph for visualization (RFC-084)
 #[tauri::command]
-pub async fn get_chunk_hierarchy(path: String) -> Result<ChunkHierarchy, String> {
-    let project_path = PathBuf::from(&path);
+pub async fn get_concept_graph(path: String) -> Result<ConceptGraph, String> {
+    if let Some(snapshot) = crate::memory_watcher::cached_snapshot(&path) {
+        return Ok(snapshot.graph);
+    }
+    Ok(compute_concept_graph_sync(&path))
+}
+
+// =============================================================================
+// RFC-115: Concept Graph Analytics
+// =============================================================================
+//
+// `get_concept_graph` hands Studio the raw `edges: Vec<ConceptEdge>` wire
+// dump; `get_graph_analytics` derives structure from it instead: node
+// importance via weighted PageRank, `Contradicts` components (so two
+// conflicting notes that both feed a third show up as one cluster, not a
+// dangling pair), and `Supersedes`/`Updates` chains resolved down to their
+// terminal node.
+
+/// A node's weighted-PageRank importance within the graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeImportance {
+    pub node_id: String,
+    pub score: f32,
+}
+
+/// A connected component of mutually `Contradicts`-linked nodes.
+/// `favored_node_id` is the member with the highest total `Supports`
+/// confidence of any node in the graph — `None` if no member has any
+/// supporting evidence (a `Contradicts` edge alone doesn't say which side
+/// is right, only that they conflict).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContradictionCluster {
+    pub node_ids: Vec<String>,
+    pub favored_node_id: Option<String>,
+}
+
+/// Derived analytics over a `ConceptGraph` (RFC-115).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphAnalytics {
+    pub graph: ConceptGraph,
+    pub importance: Vec<NodeImportance>,
+    pub contradictions: Vec<ContradictionCluster>,
+    /// stale node id -> its terminal (non-superseded) replacement
+    pub superseded_by: HashMap<String, String>,
+    /// true if a `Supersedes`/`Updates` chain looped back on itself; such
+    /// nodes are left out of `superseded_by` rather than resolved wrong
+    pub supersession_cycle: bool,
+    pub node_count: u32,
+    pub edge_count: u32,
+}
+
+/// Weighted PageRank over `edges` (`source_id -> target_id`, weighted by
+/// `confidence`): `d=0.85`, up to 30 iterations or until the L1 delta
+/// between successive rank vectors drops below `1e-6`, with a dangling
+/// node's (no outgoing edges) mass redistributed uniformly each pass.
+fn compute_pagerank(edges: &[ConceptEdge]) -> Vec<NodeImportance> {
+    const DAMPING: f32 = 0.85;
+    const MAX_ITERS: usize = 30;
+    const CONVERGENCE: f32 = 1e-6;
+
+    let mut nodes: Vec<String> = Vec::new();
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+    for edge in edges {
+        for id in [&edge.source_id, &edge.target_id] {
+            if !index_of.contains_key(id) {
+                index_of.insert(id.clone(), nodes.len());
+                nodes.push(id.clone());
+            }
+        }
+    }
+
+    let n = nodes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut out_edges: Vec<Vec<(usize, f32)>> = vec![Vec::new(); n];
+    let mut out_weight_sum: Vec<f32> = vec![0.0; n];
+    for edge in edges {
+        let from = index_of[&edge.source_id];
+        let to = index_of[&edge.target_id];
+        let weight = edge.confidence.max(0.0001);
+        out_edges[from].push((to, weight));
+        out_weight_sum[from] += weight;
+    }
+
+    let mut ranks = vec![1.0f32 / n as f32; n];
+    for _ in 0..MAX_ITERS {
+        let dangling_mass: f32 = (0..n)
+            .filter(|&i| out_edges[i].is_empty())
+            .map(|i| ranks[i])
+            .sum();
+
+        let mut next = vec![(1.0 - DAMPING) / n as f32 + DAMPING * dangling_mass / n as f32; n];
+        for from in 0..n {
+            if out_weight_sum[from] <= 0.0 {
+                continue;
+            }
+            for &(to, weight) in &out_edges[from] {
+                next[to] += DAMPING * ranks[from] * weight / out_weight_sum[from];
+            }
+        }
+
+        let delta: f32 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+        ranks = next;
+        if delta < CONVERGENCE {
+            break;
+        }
+    }
+
+    nodes
+        .into_iter()
+        .zip(ranks)
+        .map(|(node_id, score)| NodeImportance { node_id, score })
+        .collect()
+}
+
+/// Minimal union-find for grouping nodes into `Contradicts` components.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra != rb {
+            self.parent[ra] = rb;
+        }
+    }
+}
+
+/// Total `Supports` confidence each node has received — used only to pick
+/// a `favored_node_id` within a contradiction cluster, not as a
+/// standalone importance score (that's what PageRank is for).
+fn node_support_scores(edges: &[ConceptEdge]) -> HashMap<String, f32> {
+    let mut scores: HashMap<String, f32> = HashMap::new();
+    for edge in edges {
+        if matches!(edge.relation, RelationType::Supports) {
+            *scores.entry(edge.target_id.clone()).or_insert(0.0) += edge.confidence;
+        }
+    }
+    scores
+}
+
+fn compute_contradiction_clusters(edges: &[ConceptEdge]) -> Vec<ContradictionCluster> {
+    let contradicts: Vec<&ConceptEdge> = edges
+        .iter()
+        .filter(|edge| matches!(edge.relation, RelationType::Contradicts))
+        .collect();
+    if contradicts.is_empty() {
+        return Vec::new();
+    }
+
+    let mut nodes: Vec<String> = Vec::new();
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+    for edge in &contradicts {
+        for id in [&edge.source_id, &edge.target_id] {
+            if !index_of.contains_key(id) {
+                index_of.insert(id.clone(), nodes.len());
+                nodes.push(id.clone());
+            }
+        }
+    }
+
+    let mut uf = UnionFind::new(nodes.len());
+    for edge in &contradicts {
+        uf.union(index_of[&edge.source_id], index_of[&edge.target_id]);
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..nodes.len() {
+        let root = uf.find(i);
+        groups.entry(root).or_default().push(i);
+    }
+
+    let support = node_support_scores(edges);
+    groups
+        .into_values()
+        .map(|member_indices| {
+            let node_ids: Vec<String> = member_indices.iter().map(|&i| nodes[i].clone()).collect();
+            let favored_node_id = node_ids
+                .iter()
+                .filter(|id| support.contains_key(id.as_str()))
+                .max_by(|a, b| {
+                    support[a.as_str()]
+                        .partial_cmp(&support[b.as_str()])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .cloned();
+            ContradictionCluster {
+                node_ids,
+                favored_node_id,
+            }
+        })
+        .collect()
+}
+
+/// Resolves `Supersedes`/`Updates` chains (edge `source_id` supersedes
+/// `target_id`) down to each stale node's terminal replacement. If two
+/// edges claim to supersede the same node, the most recently seen one
+/// wins — manifests aren't expected to declare more than one. Returns the
+/// resolved map plus whether a cycle was detected; cyclic nodes are
+/// omitted from the map rather than resolved to a wrong answer.
+fn resolve_supersession(edges: &[ConceptEdge]) -> (HashMap<String, String>, bool) {
+    let mut superseder_of: HashMap<String, String> = HashMap::new();
+    for edge in edges {
+        if matches!(
+            edge.relation,
+            RelationType::Supersedes | RelationType::Updates
+        ) {
+            superseder_of.insert(edge.target_id.clone(), edge.source_id.clone());
+        }
+    }
+
+    let mut stale_to_current = HashMap::new();
+    let mut any_cycle = false;
+
+    for stale_id in superseder_of.keys() {
+        let mut current = stale_id.clone();
+        let mut visited = HashSet::new();
+        let mut cyclic = false;
+        loop {
+            if !visited.insert(current.clone()) {
+                cyclic = true;
+                break;
+            }
+            match superseder_of.get(&current) {
+                Some(next) => current = next.clone(),
+                None => break,
+            }
+        }
+
+        if cyclic {
+            any_cycle = true;
+        } else if current != *stale_id {
+            stale_to_current.insert(stale_id.clone(), current);
+        }
+    }
+
+    (stale_to_current, any_cycle)
+}
+
+/// Get derived concept graph analytics — importance, contradictions, and
+/// resolved supersession — for visualization (RFC-115).
+#[tauri::command]
+pub async fn get_graph_analytics(path: String) -> Result<GraphAnalytics, String> {
+    let graph = get_concept_graph(path).await?;
+
+    let mut node_ids: HashSet<&str> = HashSet::new();
+    for edge in &graph.edges {
+        node_ids.insert(edge.source_id.as_str());
+        node_ids.insert(edge.target_id.as_str());
+    }
+    let node_count = node_ids.len() as u32;
+    let edge_count = graph.edges.len() as u32;
+
+    let importance = compute_pagerank(&graph.edges);
+    let contradictions = compute_contradiction_clusters(&graph.edges);
+    let (superseded_by, supersession_cycle) = resolve_supersession(&graph.edges);
+
+    Ok(GraphAnalytics {
+        graph,
+        importance,
+        contradictions,
+        superseded_by,
+        supersession_cycle,
+        node_count,
+        edge_count,
+    })
+}
+
+/// Computes the `ChunkHierarchy` by reading `.sunwell/memory/chunks`
+/// directly. Shared the same way as `compute_memory_stats_sync`.
+pub(crate) fn compute_chunk_hierarchy_sync(path: &str) -> ChunkHierarchy {
+    let project_path = PathBuf::from(path);
     let chunks_path = project_path.join(".sunwell/memory/chunks");
-    
+
     let mut hierarchy = ChunkHierarchy::default();
-    
+
     // Read each tier
     for tier in ["hot", "warm", "cold"] {
         let tier_path = chunks_path.join(tier);
@@ -368,59 +745,72 @@ pub async fn get_chunk_hierarchy(path: String) -> Result<ChunkHierarchy, String>
                             if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
                                 // Parse chunk from JSON
                                 let chunk = Chunk {
-                                    id: json.get("id")
+                                    id: json
+                                        .get("id")
                                         .and_then(|v| v.as_str())
                                         .unwrap_or("")
                                         .to_string(),
-                                    chunk_type: match json.get("chunk_type")
+                                    chunk_type: match json
+                                        .get("chunk_type")
                                         .and_then(|v| v.as_str())
-                                        .unwrap_or("micro") 
+                                        .unwrap_or("micro")
                                     {
                                         "mini" => ChunkType::Mini,
                                         "macro" => ChunkType::Macro,
                                         _ => ChunkType::Micro,
                                     },
                                     turn_range: {
-                                        let range = json.get("turn_range")
-                                            .and_then(|v| v.as_array());
+                                        let range =
+                                            json.get("turn_range").and_then(|v| v.as_array());
                                         if let Some(arr) = range {
-                                            let start = arr.get(0)
-                                                .and_then(|v| v.as_u64())
-                                                .unwrap_or(0) as u32;
-                                            let end = arr.get(1)
-                                                .and_then(|v| v.as_u64())
-                                                .unwrap_or(0) as u32;
+                                            let start =
+                                                arr.get(0).and_then(|v| v.as_u64()).unwrap_or(0)
+                                                    as u32;
+                                            let end =
+                                                arr.get(1).and_then(|v| v.as_u64()).unwrap_or(0)
+                                                    as u32;
                                             (start, end)
                                         } else {
                                             (0, 0)
                                         }
                                     },
-                                    summary: json.get("summary")
+                                    summary: json
+                                        .get("summary")
                                         .and_then(|v| v.as_str())
                                         .map(String::from),
-                                    themes: json.get("themes")
+                                    themes: json
+                                        .get("themes")
                                         .and_then(|v| v.as_array())
-                                        .map(|arr| arr.iter()
-                                            .filter_map(|v| v.as_str().map(String::from))
-                                            .collect())
+                                        .map(|arr| {
+                                            arr.iter()
+                                                .filter_map(|v| v.as_str().map(String::from))
+                                                .collect()
+                                        })
                                         .unwrap_or_default(),
-                                    key_facts: json.get("key_facts")
+                                    key_facts: json
+                                        .get("key_facts")
                                         .and_then(|v| v.as_array())
-                                        .map(|arr| arr.iter()
-                                            .filter_map(|v| v.as_str().map(String::from))
-                                            .collect())
+                                        .map(|arr| {
+                                            arr.iter()
+                                                .filter_map(|v| v.as_str().map(String::from))
+                                                .collect()
+                                        })
                                         .unwrap_or_default(),
-                                    token_count: json.get("token_count")
+                                    token_count: json
+                                        .get("token_count")
                                         .and_then(|v| v.as_u64())
-                                        .unwrap_or(0) as u32,
-                                    timestamp_start: json.get("timestamp_start")
+                                        .unwrap_or(0)
+                                        as u32,
+                                    timestamp_start: json
+                                        .get("timestamp_start")
                                         .and_then(|v| v.as_str())
                                         .map(String::from),
-                                    timestamp_end: json.get("timestamp_end")
+                                    timestamp_end: json
+                                        .get("timestamp_end")
                                         .and_then(|v| v.as_str())
                                         .map(String::from),
                                 };
-                                
+
                                 match tier {
                                     "hot" => hierarchy.hot.push(chunk),
                                     "warm" => hierarchy.warm.push(chunk),
@@ -434,19 +824,29 @@ pub async fn get_chunk_hierarchy(path: String) -> Result<ChunkHierarchy, String>
             }
         }
     }
-    
+
     // Sort by turn range for consistent ordering
     hierarchy.hot.sort_by_key(|c| c.turn_range.0);
     hierarchy.warm.sort_by_key(|c| c.turn_range.0);
     hierarchy.cold.sort_by_key(|c| c.turn_range.0);
-    
-    Ok(hierarchy)
+
+    hierarchy
 }
 
-/// Get intelligence data (decisions and failures)
+/// Get ChunkHierarchy for visualization (RFC-084)
 #[tauri::command]
-pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String> {
-    let project_path = PathBuf::from(&path);
+pub async fn get_chunk_hierarchy(path: String) -> Result<ChunkHierarchy, String> {
+    if let Some(snapshot) = crate::memory_watcher::cached_snapshot(&path) {
+        return Ok(snapshot.chunk_hierarchy);
+    }
+    Ok(compute_chunk_hierarchy_sync(&path))
+}
+
+/// Computes the `IntelligenceData` by reading `.sunwell/intelligence` and
+/// `.sunwell/learnings` directly. Shared the same way as
+/// `compute_memory_stats_sync`.
+pub(crate) fn compute_intelligence_sync(path: &str) -> IntelligenceData {
+    let project_path = PathBuf::from(path);
     let intel_path = project_path.join(".sunwell/intelligence");
 
     let mut data = IntelligenceData::default();
@@ -461,10 +861,25 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                 }
                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
                     data.decisions.push(Decision {
-                        id: json.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        decision: json.get("decision").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        rationale: json.get("rationale").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        created_at: json.get("created_at").and_then(|v| v.as_str()).map(String::from),
+                        id: json
+                            .get("id")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        decision: json
+                            .get("decision")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        rationale: json
+                            .get("rationale")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        created_at: json
+                            .get("created_at")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
                         scope: json.get("scope").and_then(|v| v.as_str()).map(String::from),
                     });
                 }
@@ -483,11 +898,29 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                 }
                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
                     data.failures.push(FailedApproach {
-                        id: json.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        approach: json.get("approach").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        reason: json.get("reason").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        created_at: json.get("created_at").and_then(|v| v.as_str()).map(String::from),
-                        context: json.get("context").and_then(|v| v.as_str()).map(String::from),
+                        id: json
+                            .get("id")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        approach: json
+                            .get("approach")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        reason: json
+                            .get("reason")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        created_at: json
+                            .get("created_at")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                        context: json
+                            .get("context")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
                     });
                 }
             }
@@ -506,12 +939,33 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                 }
                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
                     data.learnings.push(Learning {
-                        id: json.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        fact: json.get("fact").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        category: json.get("category").and_then(|v| v.as_str()).unwrap_or("pattern").to_string(),
-                        confidence: json.get("confidence").and_then(|v| v.as_f64()).unwrap_or(0.7) as f32,
-                        source_file: json.get("source_file").and_then(|v| v.as_str()).map(String::from),
-                        created_at: json.get("created_at").and_then(|v| v.as_str()).map(String::from),
+                        id: json
+                            .get("id")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        fact: json
+                            .get("fact")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        category: json
+                            .get("category")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("pattern")
+                            .to_string(),
+                        confidence: json
+                            .get("confidence")
+                            .and_then(|v| v.as_f64())
+                            .unwrap_or(0.7) as f32,
+                        source_file: json
+                            .get("source_file")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                        created_at: json
+                            .get("created_at")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
                     });
                 }
             }
@@ -527,13 +981,19 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                 if path.extension().map_or(false, |ext| ext == "json") {
                     if let Ok(content) = std::fs::read_to_string(&path) {
                         // Parse as JSON array of learning records
-                        if let Ok(json_array) = serde_json::from_str::<serde_json::Value>(&content) {
+                        if let Ok(json_array) = serde_json::from_str::<serde_json::Value>(&content)
+                        {
                             if let Some(arr) = json_array.as_array() {
                                 for json in arr {
                                     // Naaru format: type, goal, task_id, task_description, output, timestamp
-                                    let task_id = json.get("task_id").and_then(|v| v.as_str()).unwrap_or("");
-                                    let description = json.get("task_description").and_then(|v| v.as_str()).unwrap_or("");
-                                    let output = json.get("output").and_then(|v| v.as_str()).unwrap_or("");
+                                    let task_id =
+                                        json.get("task_id").and_then(|v| v.as_str()).unwrap_or("");
+                                    let description = json
+                                        .get("task_description")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let output =
+                                        json.get("output").and_then(|v| v.as_str()).unwrap_or("");
                                     let timestamp = json.get("timestamp").and_then(|v| v.as_str());
 
                                     // Convert Naaru learning format to our Learning struct
@@ -547,7 +1007,7 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                                             created_at: timestamp.map(String::from),
                                         });
                                     }
-                                    
+
                                     // If output contains useful info, create a learning from it
                                     if output.len() > 20 && output.len() < 200 {
                                         data.learnings.push(Learning {
@@ -580,10 +1040,24 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
                 }
                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
                     data.dead_ends.push(DeadEnd {
-                        approach: json.get("approach").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        reason: json.get("reason").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                        context: json.get("context").and_then(|v| v.as_str()).map(String::from),
-                        created_at: json.get("created_at").and_then(|v| v.as_str()).map(String::from),
+                        approach: json
+                            .get("approach")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        reason: json
+                            .get("reason")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string(),
+                        context: json
+                            .get("context")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                        created_at: json
+                            .get("created_at")
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
                     });
                 }
             }
@@ -591,7 +1065,860 @@ pub async fn get_intelligence(path: String) -> Result<IntelligenceData, String>
         }
     }
 
-    Ok(data)
+    data
+}
+
+/// Reads `path` one line at a time via `tokio::fs`/`BufReader::lines()`
+/// rather than `std::fs::read_to_string` + `.lines()`, so a call from an
+/// async command never blocks its tokio worker thread on a large
+/// `.jsonl` file and never holds more than one line in memory at once.
+/// When `max_records` is set, only the last `max_records` parsed values
+/// are kept (oldest dropped as newer ones arrive), approximating "read
+/// from the tail" without a second pass or a seek-from-end (line lengths
+/// are irregular, so byte-offset seeking can't land on a line boundary).
+async fn read_jsonl_capped(path: &Path, max_records: Option<usize>) -> Vec<serde_json::Value> {
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    let Ok(file) = tokio::fs::File::open(path).await else {
+        return Vec::new();
+    };
+    let mut lines = BufReader::new(file).lines();
+
+    let mut values: std::collections::VecDeque<serde_json::Value> =
+        std::collections::VecDeque::new();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        values.push_back(value);
+        if let Some(cap) = max_records {
+            while values.len() > cap {
+                values.pop_front();
+            }
+        }
+    }
+    values.into_iter().collect()
+}
+
+/// Same parsing as `compute_intelligence_sync`, but reads each `.jsonl`
+/// file asynchronously via `read_jsonl_capped` instead of
+/// `std::fs::read_to_string` so a direct (cache-miss) call from the async
+/// `get_intelligence` command doesn't block the tokio runtime on a large
+/// decisions/failures/dead-ends log. `memory_watcher`'s debounce thread
+/// keeps using `compute_intelligence_sync` instead — it's a dedicated
+/// `std::thread`, not a tokio worker, so blocking there doesn't stall
+/// other async tasks, and it has no `max_records` need since it always
+/// materializes the full snapshot.
+async fn compute_intelligence_async(path: &str, max_records: Option<usize>) -> IntelligenceData {
+    let project_path = PathBuf::from(path);
+    let intel_path = project_path.join(".sunwell/intelligence");
+
+    let mut data = IntelligenceData::default();
+
+    for value in read_jsonl_capped(&intel_path.join("decisions.jsonl"), max_records).await {
+        data.decisions.push(Decision {
+            id: value
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            decision: value
+                .get("decision")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            rationale: value
+                .get("rationale")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            created_at: value
+                .get("created_at")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            scope: value
+                .get("scope")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        });
+    }
+    data.total_decisions = data.decisions.len() as u32;
+
+    for value in read_jsonl_capped(&intel_path.join("failures.jsonl"), max_records).await {
+        data.failures.push(FailedApproach {
+            id: value
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            approach: value
+                .get("approach")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            reason: value
+                .get("reason")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            created_at: value
+                .get("created_at")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            context: value
+                .get("context")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        });
+    }
+    data.total_failures = data.failures.len() as u32;
+
+    for value in read_jsonl_capped(&intel_path.join("learnings.jsonl"), max_records).await {
+        data.learnings.push(Learning {
+            id: value
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            fact: value
+                .get("fact")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            category: value
+                .get("category")
+                .and_then(|v| v.as_str())
+                .unwrap_or("pattern")
+                .to_string(),
+            confidence: value
+                .get("confidence")
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.7) as f32,
+            source_file: value
+                .get("source_file")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            created_at: value
+                .get("created_at")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        });
+    }
+
+    // Source 2: .sunwell/learnings/*.json (JSON array format from Naaru) —
+    // left as a direct `std::fs` read, matching `compute_intelligence_sync`:
+    // it's a handful of small files, not an append-only log that grows
+    // unbounded, so streaming it wouldn't help.
+    let naaru_learnings_dir = project_path.join(".sunwell/learnings");
+    if naaru_learnings_dir.exists() {
+        if let Ok(entries) = std::fs::read_dir(&naaru_learnings_dir) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                let entry_path = entry.path();
+                if entry_path.extension().map_or(false, |ext| ext == "json") {
+                    if let Ok(content) = std::fs::read_to_string(&entry_path) {
+                        if let Ok(json_array) = serde_json::from_str::<serde_json::Value>(&content)
+                        {
+                            if let Some(arr) = json_array.as_array() {
+                                for json in arr {
+                                    let task_id =
+                                        json.get("task_id").and_then(|v| v.as_str()).unwrap_or("");
+                                    let description = json
+                                        .get("task_description")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let output =
+                                        json.get("output").and_then(|v| v.as_str()).unwrap_or("");
+                                    let timestamp = json.get("timestamp").and_then(|v| v.as_str());
+
+                                    if !task_id.is_empty() {
+                                        data.learnings.push(Learning {
+                                            id: task_id.to_string(),
+                                            fact: format!("Completed: {}", description),
+                                            category: "task_completion".to_string(),
+                                            confidence: 1.0,
+                                            source_file: Some(task_id.to_string()),
+                                            created_at: timestamp.map(String::from),
+                                        });
+                                    }
+
+                                    if output.len() > 20 && output.len() < 200 {
+                                        data.learnings.push(Learning {
+                                            id: format!("{}-output", task_id),
+                                            fact: output.chars().take(150).collect::<String>(),
+                                            category: "code".to_string(),
+                                            confidence: 0.8,
+                                            source_file: Some(task_id.to_string()),
+                                            created_at: timestamp.map(String::from),
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    data.total_learnings = data.learnings.len() as u32;
+
+    for value in read_jsonl_capped(&intel_path.join("dead_ends.jsonl"), max_records).await {
+        data.dead_ends.push(DeadEnd {
+            approach: value
+                .get("approach")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            reason: value
+                .get("reason")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            context: value
+                .get("context")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            created_at: value
+                .get("created_at")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        });
+    }
+    data.total_dead_ends = data.dead_ends.len() as u32;
+
+    data
+}
+
+/// Get intelligence data (decisions and failures). `max_records` caps how
+/// many of the most recent entries are read from each `.jsonl` log
+/// (`None` reads all of them) — ignored on a cache hit, since a watched
+/// snapshot is already fully materialized.
+#[tauri::command]
+pub async fn get_intelligence(
+    path: String,
+    max_records: Option<usize>,
+) -> Result<IntelligenceData, String> {
+    if let Some(snapshot) = crate::memory_watcher::cached_snapshot(&path) {
+        return Ok(snapshot.intelligence);
+    }
+    Ok(compute_intelligence_async(&path, max_records).await)
+}
+
+// =============================================================================
+// RFC-114: Full-Text Search (BM25)
+// =============================================================================
+//
+// `search_memory` indexes every artifact `get_memory_stats`/`list_sessions`/
+// `get_intelligence`/`get_chunk_hierarchy` already parse — `Session` names,
+// `Learning.fact`, `Decision.decision`/`rationale`, `FailedApproach`/
+// `DeadEnd.reason`, and `Chunk.summary`/`themes`/`key_facts` — into an
+// in-memory inverted index, scored with BM25 and a bounded-edit-distance
+// fallback for typos. The index is built on first call and cached per
+// project path, keyed by a signature derived from the watched
+// directories' latest mtime so an agent run that adds new artifacts
+// invalidates it automatically instead of serving stale hits forever.
+
+const BM25_K1: f32 = 1.2;
+const BM25_B: f32 = 0.75;
+
+/// Which surfaced artifact a search hit came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSourceKind {
+    Session,
+    Learning,
+    Decision,
+    Failure,
+    DeadEnd,
+    Chunk,
+}
+
+/// Restricts a search to artifacts matching all of the given (optional)
+/// predicates; `None` means "don't filter on this dimension".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchFilters {
+    pub category: Option<String>,
+    pub chunk_tier: Option<String>,
+    pub source_kind: Option<SearchSourceKind>,
+}
+
+/// One ranked search result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub doc_id: String,
+    pub source_kind: SearchSourceKind,
+    pub score: f32,
+    pub snippet: String,
+}
+
+/// Field weights applied before BM25 scoring — a chunk's human-written
+/// summary is a denser signal than its raw key-fact bullets, so it counts
+/// for more per occurrence.
+fn field_weight(field: &str) -> f32 {
+    match field {
+        "summary" => 2.0,
+        "fact" | "decision" | "approach" => 1.5,
+        "rationale" | "reason" => 1.2,
+        _ => 1.0,
+    }
+}
+
+struct RawDoc {
+    doc_id: String,
+    source_kind: SearchSourceKind,
+    category: Option<String>,
+    chunk_tier: Option<String>,
+    fields: Vec<(&'static str, String)>,
+}
+
+struct IndexedDoc {
+    doc_id: String,
+    source_kind: SearchSourceKind,
+    category: Option<String>,
+    chunk_tier: Option<String>,
+    snippet: String,
+    token_count: f32,
+}
+
+struct SearchIndex {
+    signature: u128,
+    docs: Vec<IndexedDoc>,
+    /// term -> list of (doc index, field-weighted term frequency)
+    postings: HashMap<String, Vec<(usize, f32)>>,
+    avgdl: f32,
+    /// Sorted so fuzzy expansion can early-exit; also lets Debug output
+    /// stay stable across runs for the same on-disk content.
+    vocab: Vec<String>,
+}
+
+static SEARCH_INDEX_CACHE: OnceLock<Mutex<HashMap<String, SearchIndex>>> = OnceLock::new();
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+/// Truncates to a snippet short enough to render inline in a results list.
+fn snippet_of(text: &str) -> String {
+    const MAX_CHARS: usize = 160;
+    if text.chars().count() <= MAX_CHARS {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(MAX_CHARS).collect::<String>())
+    }
+}
+
+/// Gathers every searchable artifact by reusing the existing read
+/// commands rather than re-walking `.sunwell` a second way.
+async fn gather_raw_docs(path: &str) -> Vec<RawDoc> {
+    let mut docs = Vec::new();
+
+    if let Ok(sessions) = list_sessions(path.to_string()).await {
+        for session in sessions {
+            if let Some(name) = session.name {
+                docs.push(RawDoc {
+                    doc_id: format!("session:{}", session.id),
+                    source_kind: SearchSourceKind::Session,
+                    category: None,
+                    chunk_tier: None,
+                    fields: vec![("name", name)],
+                });
+            }
+        }
+    }
+
+    if let Ok(intel) = get_intelligence(path.to_string(), None).await {
+        for learning in intel.learnings {
+            docs.push(RawDoc {
+                doc_id: format!("learning:{}", learning.id),
+                source_kind: SearchSourceKind::Learning,
+                category: Some(learning.category),
+                chunk_tier: None,
+                fields: vec![("fact", learning.fact)],
+            });
+        }
+        for decision in intel.decisions {
+            docs.push(RawDoc {
+                doc_id: format!("decision:{}", decision.id),
+                source_kind: SearchSourceKind::Decision,
+                category: decision.scope,
+                chunk_tier: None,
+                fields: vec![
+                    ("decision", decision.decision),
+                    ("rationale", decision.rationale),
+                ],
+            });
+        }
+        for failure in intel.failures {
+            docs.push(RawDoc {
+                doc_id: format!("failure:{}", failure.id),
+                source_kind: SearchSourceKind::Failure,
+                category: None,
+                chunk_tier: None,
+                fields: vec![("approach", failure.approach), ("reason", failure.reason)],
+            });
+        }
+        for (i, dead_end) in intel.dead_ends.into_iter().enumerate() {
+            docs.push(RawDoc {
+                doc_id: format!("dead_end:{}", i),
+                source_kind: SearchSourceKind::DeadEnd,
+                category: None,
+                chunk_tier: None,
+                fields: vec![("approach", dead_end.approach), ("reason", dead_end.reason)],
+            });
+        }
+    }
+
+    if let Ok(hierarchy) = get_chunk_hierarchy(path.to_string()).await {
+        let tiers = [
+            ("hot", hierarchy.hot),
+            ("warm", hierarchy.warm),
+            ("cold", hierarchy.cold),
+        ];
+        for (tier, chunks) in tiers {
+            for chunk in chunks {
+                let mut fields = Vec::new();
+                if let Some(summary) = chunk.summary {
+                    fields.push(("summary", summary));
+                }
+                if !chunk.themes.is_empty() {
+                    fields.push(("themes", chunk.themes.join(" ")));
+                }
+                if !chunk.key_facts.is_empty() {
+                    fields.push(("key_facts", chunk.key_facts.join(" ")));
+                }
+                docs.push(RawDoc {
+                    doc_id: format!("chunk:{}", chunk.id),
+                    source_kind: SearchSourceKind::Chunk,
+                    category: None,
+                    chunk_tier: Some(tier.to_string()),
+                    fields,
+                });
+            }
+        }
+    }
+
+    docs
+}
+
+fn build_index(docs: Vec<RawDoc>, signature: u128) -> SearchIndex {
+    let mut indexed_docs = Vec::with_capacity(docs.len());
+    let mut postings: HashMap<String, Vec<(usize, f32)>> = HashMap::new();
+    let mut total_tokens = 0f32;
+
+    for (doc_idx, doc) in docs.into_iter().enumerate() {
+        let mut term_weights: HashMap<String, f32> = HashMap::new();
+        let mut token_count = 0f32;
+        let mut snippet_source = String::new();
+
+        for (field, text) in &doc.fields {
+            if snippet_source.is_empty() && !text.trim().is_empty() {
+                snippet_source = text.clone();
+            }
+            let weight = field_weight(field);
+            for term in tokenize(text) {
+                *term_weights.entry(term).or_insert(0.0) += weight;
+                token_count += 1.0;
+            }
+        }
+
+        for (term, weight) in term_weights {
+            postings.entry(term).or_default().push((doc_idx, weight));
+        }
+
+        total_tokens += token_count;
+        indexed_docs.push(IndexedDoc {
+            doc_id: doc.doc_id,
+            source_kind: doc.source_kind,
+            category: doc.category,
+            chunk_tier: doc.chunk_tier,
+            snippet: snippet_of(&snippet_source),
+            token_count,
+        });
+    }
+
+    let avgdl = if indexed_docs.is_empty() {
+        0.0
+    } else {
+        total_tokens / indexed_docs.len() as f32
+    };
+    let mut vocab: Vec<String> = postings.keys().cloned().collect();
+    vocab.sort();
+
+    SearchIndex {
+        signature,
+        docs: indexed_docs,
+        postings,
+        avgdl,
+        vocab,
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dp[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+        }
+    }
+    dp[a.len()][b.len()]
+}
+
+/// Expands one query term to every index term within a bounded edit
+/// distance — 0 for 1-2 char terms (a typo there is most of the word),
+/// 1 for 3-5 chars, 2 beyond that — pairing each with a match weight: 1.0
+/// for an exact hit, discounted per edit for a fuzzy one so close-but-not-
+/// exact matches rank below literal hits.
+fn expand_term(term: &str, vocab: &[String]) -> Vec<(String, f32)> {
+    let max_distance = match term.len() {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    };
+
+    vocab
+        .iter()
+        .filter_map(|candidate| {
+            if candidate == term {
+                return Some((candidate.clone(), 1.0));
+            }
+            if max_distance == 0 {
+                return None;
+            }
+            let len_diff = (candidate.len() as i64 - term.len() as i64).unsigned_abs() as usize;
+            if len_diff > max_distance {
+                return None;
+            }
+            let distance = levenshtein(term, candidate);
+            if distance > 0 && distance <= max_distance {
+                Some((candidate.clone(), 1.0 - 0.35 * distance as f32))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn matches_filters(doc: &IndexedDoc, filters: &SearchFilters) -> bool {
+    if let Some(kind) = filters.source_kind {
+        if doc.source_kind != kind {
+            return false;
+        }
+    }
+    if let Some(category) = &filters.category {
+        if doc.category.as_deref() != Some(category.as_str()) {
+            return false;
+        }
+    }
+    if let Some(tier) = &filters.chunk_tier {
+        if doc.chunk_tier.as_deref() != Some(tier.as_str()) {
+            return false;
+        }
+    }
+    true
+}
+
+fn bm25_search(
+    index: &SearchIndex,
+    query: &str,
+    filters: &SearchFilters,
+    top_k: usize,
+) -> Vec<SearchHit> {
+    let n = index.docs.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut scores: HashMap<usize, f32> = HashMap::new();
+    for raw_term in tokenize(query) {
+        for (term, term_weight) in expand_term(&raw_term, &index.vocab) {
+            let Some(postings) = index.postings.get(&term) else {
+                continue;
+            };
+            let df = postings.len() as f32;
+            let idf = ((n as f32 - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+            for &(doc_idx, tf) in postings {
+                let doc_len = index.docs[doc_idx].token_count;
+                let denom = tf + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / index.avgdl.max(1.0));
+                let term_score = idf * (tf * (BM25_K1 + 1.0)) / denom.max(f32::EPSILON);
+                *scores.entry(doc_idx).or_insert(0.0) += term_score * term_weight;
+            }
+        }
+    }
+
+    let mut hits: Vec<SearchHit> = scores
+        .into_iter()
+        .filter_map(|(doc_idx, score)| {
+            let doc = &index.docs[doc_idx];
+            if !matches_filters(doc, filters) {
+                return None;
+            }
+            Some(SearchHit {
+                doc_id: doc.doc_id.clone(),
+                source_kind: doc.source_kind,
+                score,
+                snippet: doc.snippet.clone(),
+            })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    hits.truncate(top_k);
+    hits
+}
+
+/// A signature that changes whenever the watched directories' content
+/// does, derived from their most-recently-modified file — cheap enough to
+/// recompute on every call, so the index only gets rebuilt when something
+/// actually changed since the last search.
+fn index_signature(path: &str) -> u128 {
+    let project_path = PathBuf::from(path);
+    let mut latest = std::time::UNIX_EPOCH;
+    for dir in [
+        project_path.join(".sunwell/memory"),
+        project_path.join(".sunwell/intelligence"),
+        project_path.join(".sunwell/learnings"),
+    ] {
+        latest = latest_mtime(&dir, latest);
+    }
+    latest
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+fn latest_mtime(dir: &Path, mut acc: std::time::SystemTime) -> std::time::SystemTime {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return acc;
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let entry_path = entry.path();
+        if let Ok(meta) = entry.metadata() {
+            if let Ok(modified) = meta.modified() {
+                if modified > acc {
+                    acc = modified;
+                }
+            }
+        }
+        if entry_path.is_dir() {
+            acc = latest_mtime(&entry_path, acc);
+        }
+    }
+    acc
+}
+
+/// Full-text search over every memory artifact this module surfaces,
+/// ranked by BM25 with typo-tolerant term expansion (RFC-114).
+#[tauri::command]
+pub async fn search_memory(
+    path: String,
+    query: String,
+    filters: SearchFilters,
+) -> Result<Vec<SearchHit>, String> {
+    const TOP_K: usize = 20;
+
+    let signature = index_signature(&path);
+    let cache = SEARCH_INDEX_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+    {
+        let cached = cache.lock().unwrap();
+        if let Some(index) = cached.get(&path) {
+            if index.signature == signature {
+                return Ok(bm25_search(index, &query, &filters, TOP_K));
+            }
+        }
+    }
+
+    let docs = gather_raw_docs(&path).await;
+    let index = build_index(docs, signature);
+    let hits = bm25_search(&index, &query, &filters, TOP_K);
+    cache.lock().unwrap().insert(path, index);
+    Ok(hits)
+}
+
+// =============================================================================
+// RFC-116: Semantic Vector Search over ChunkHierarchy
+// =============================================================================
+//
+// `search_memory` (RFC-114) is lexical — it can't find a macro-chunk about
+// retry handling from a query like "how did we handle auth retries" if the
+// chunk never uses those exact words. `semantic_search` complements it
+// with cosine-similarity recall over persisted per-chunk embeddings,
+// using the same pluggable `writer::Embedder` RFC-110 introduced for
+// Diataxis detection rather than standing up a second embedding backend.
+
+/// One chunk's persisted embedding, stored alongside its
+/// `.sunwell/memory/chunks/<tier>/<id>.json` as `<id>.embedding`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredEmbedding {
+    model: String,
+    vector: Vec<f32>,
+}
+
+/// One semantic search hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchHit {
+    pub chunk_id: String,
+    pub tier: String,
+    pub score: f64,
+    pub summary: Option<String>,
+}
+
+/// Result of a `semantic_search` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchResult {
+    pub hits: Vec<SemanticSearchHit>,
+    /// True if no embedder is configured, or if one or more chunks in the
+    /// hierarchy have no stored vector yet — the UI should offer a
+    /// reindex action rather than treat an empty/partial result as "no
+    /// matches".
+    pub embeddings_missing: bool,
+}
+
+static QUERY_EMBEDDING_CACHE: OnceLock<Mutex<HashMap<String, Vec<f32>>>> = OnceLock::new();
+
+/// Embeds `query`, caching by exact string so repeated searches (e.g. as
+/// the user refines filters) don't re-embed identical text.
+fn query_embedding(embedder: &dyn crate::writer::Embedder, query: &str) -> Option<Vec<f32>> {
+    let cache = QUERY_EMBEDDING_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(vector) = cache.lock().unwrap().get(query) {
+        return Some(vector.clone());
+    }
+    let vector = embedder.embed(query)?;
+    cache
+        .lock()
+        .unwrap()
+        .insert(query.to_string(), vector.clone());
+    Some(vector)
+}
+
+fn chunk_embedding_path(project_path: &Path, tier: &str, chunk_id: &str) -> PathBuf {
+    project_path
+        .join(".sunwell/memory/chunks")
+        .join(tier)
+        .join(format!("{}.embedding", chunk_id))
+}
+
+/// Loads a chunk's persisted embedding, or `None` if it hasn't been
+/// indexed yet. The stored `model` id isn't reconciled against whichever
+/// embedder is currently configured — mixing models without a reindex
+/// would silently produce meaningless similarity scores, a gap worth
+/// closing once a second `Embedder` impl actually exists.
+fn load_chunk_embedding(project_path: &Path, tier: &str, chunk_id: &str) -> Option<Vec<f32>> {
+    let content =
+        std::fs::read_to_string(chunk_embedding_path(project_path, tier, chunk_id)).ok()?;
+    serde_json::from_str::<StoredEmbedding>(&content)
+        .ok()
+        .map(|stored| stored.vector)
+}
+
+/// Semantic (embedding cosine-similarity) search over `ChunkHierarchy`
+/// (RFC-116). Falls back to an empty, `embeddings_missing` result when no
+/// embedder is configured or a chunk hasn't been indexed yet, so the UI
+/// can prompt a reindex instead of reading "no matches" as authoritative.
+#[tauri::command]
+pub async fn semantic_search(
+    path: String,
+    query: String,
+    top_k: usize,
+) -> Result<SemanticSearchResult, String> {
+    let Some(embedder) = crate::writer::embedder() else {
+        return Ok(SemanticSearchResult {
+            hits: Vec::new(),
+            embeddings_missing: true,
+        });
+    };
+    let Some(query_vector) = query_embedding(embedder, &query) else {
+        return Ok(SemanticSearchResult {
+            hits: Vec::new(),
+            embeddings_missing: true,
+        });
+    };
+
+    let hierarchy = get_chunk_hierarchy(path.clone()).await?;
+    let project_path = PathBuf::from(&path);
+    let tiers = [
+        ("hot", &hierarchy.hot),
+        ("warm", &hierarchy.warm),
+        ("cold", &hierarchy.cold),
+    ];
+
+    let mut hits = Vec::new();
+    let mut embeddings_missing = false;
+    for (tier, chunks) in tiers {
+        for chunk in chunks {
+            match load_chunk_embedding(&project_path, tier, &chunk.id) {
+                Some(vector) => hits.push(SemanticSearchHit {
+                    chunk_id: chunk.id.clone(),
+                    tier: tier.to_string(),
+                    score: crate::writer::cosine_similarity(&query_vector, &vector),
+                    summary: chunk.summary.clone(),
+                }),
+                None => embeddings_missing = true,
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    hits.truncate(top_k);
+
+    Ok(SemanticSearchResult {
+        hits,
+        embeddings_missing,
+    })
+}
+
+// =============================================================================
+// RFC-117: Watched Snapshot Cache
+// =============================================================================
+//
+// `memory_watcher` recomputes all five read commands together on a
+// debounced filesystem change and caches the result as one `MemorySnapshot`,
+// so `get_memory_stats`/`list_sessions`/`get_concept_graph`/
+// `get_chunk_hierarchy`/`get_intelligence` can each serve a cache hit
+// without re-scanning `.sunwell`. `compute_*_sync` are the same functions
+// the watcher calls to build it.
+
+/// A recomputed-together snapshot of everything `memory_watcher` refreshes
+/// on a debounced filesystem change. Kept as a single struct (rather than
+/// caching each field separately) so a refresh is all-or-nothing — callers
+/// never see stats from one moment paired with sessions from another.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySnapshot {
+    pub stats: MemoryStats,
+    pub sessions: Vec<Session>,
+    pub intelligence: IntelligenceData,
+    pub graph: ConceptGraph,
+    pub chunk_hierarchy: ChunkHierarchy,
 }
 
 // =============================================================================
@@ -624,7 +1951,7 @@ mod tests {
     #[tokio::test]
     async fn test_empty_project_returns_empty_intelligence() {
         let tmp = TempDir::new().unwrap();
-        let result = get_intelligence(tmp.path().to_string_lossy().to_string()).await;
+        let result = get_intelligence(tmp.path().to_string_lossy().to_string(), None).await;
         assert!(result.is_ok());
         let data = result.unwrap();
         assert_eq!(data.total_decisions, 0);
@@ -640,9 +1967,10 @@ mod tests {
             intel_dir.join("decisions.jsonl"),
             r#"{"id": "d1", "decision": "Use async/await", "rationale": "Better for I/O bound work"}
 {"id": "d2", "decision": "Add caching", "rationale": "Improve performance"}"#,
-        ).unwrap();
+        )
+        .unwrap();
 
-        let result = get_intelligence(tmp.path().to_string_lossy().to_string()).await;
+        let result = get_intelligence(tmp.path().to_string_lossy().to_string(), None).await;
         assert!(result.is_ok());
         let data = result.unwrap();
         assert_eq!(data.total_decisions, 2);
@@ -657,9 +1985,10 @@ mod tests {
         std::fs::write(
             intel_dir.join("failures.jsonl"),
             r#"{"id": "f1", "approach": "Sync implementation", "reason": "Too slow"}"#,
-        ).unwrap();
+        )
+        .unwrap();
 
-        let result = get_intelligence(tmp.path().to_string_lossy().to_string()).await;
+        let result = get_intelligence(tmp.path().to_string_lossy().to_string(), None).await;
         assert!(result.is_ok());
         let data = result.unwrap();
         assert_eq!(data.total_failures, 1);
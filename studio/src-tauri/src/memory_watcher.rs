@@ -0,0 +1,228 @@
+//! Incremental memory-indexing watcher (RFC-117).
+//!
+//! `get_memory_stats`/`list_sessions`/`get_concept_graph`/
+//! `get_chunk_hierarchy`/`get_intelligence` each do a full synchronous
+//! rescan of `.sunwell/memory`/`.sunwell/intelligence`/`.sunwell/learnings`
+//! on every call. `MemoryWatcherManager` watches those three directories
+//! with the `notify` crate, and once a debounced burst settles, recomputes
+//! all five via their `compute_*_sync` helpers (`memory.rs`) in one pass
+//! and caches the result as a `MemorySnapshot`, emitting a `memory-updated`
+//! event so the frontend can re-fetch instead of polling. The five public
+//! commands check `cached_snapshot` first and only fall back to a direct
+//! scan when no watcher is running (or hasn't produced a snapshot yet).
+//!
+//! The debounce thread is plain `std::thread`, not async — every
+//! `compute_*_sync` helper is itself synchronous `std::fs` I/O, so no
+//! async-bridging machinery (no `tokio::spawn`, no `block_on`) is needed to
+//! call them from it, mirroring how `file_watcher`/`lens_watcher` keep
+//! their debounce threads fully synchronous.
+
+use crate::error::SunwellError;
+use crate::memory::{self, MemorySnapshot};
+use crate::sunwell_err;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How long to wait for a project's events to go quiet before recomputing.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+static CACHE: OnceLock<Mutex<HashMap<String, MemorySnapshot>>> = OnceLock::new();
+
+fn cache() -> &'static Mutex<HashMap<String, MemorySnapshot>> {
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The most recently computed snapshot for `project_path`, if a watcher has
+/// produced one. `None` means "no watcher running yet (or none at all)" —
+/// callers fall back to a direct scan in that case.
+pub(crate) fn cached_snapshot(project_path: &str) -> Option<MemorySnapshot> {
+    cache().lock().unwrap().get(project_path).cloned()
+}
+
+fn update_cache(project_path: &str, snapshot: MemorySnapshot) {
+    cache()
+        .lock()
+        .unwrap()
+        .insert(project_path.to_string(), snapshot);
+}
+
+fn clear_cache(project_path: &str) {
+    cache().lock().unwrap().remove(project_path);
+}
+
+/// Recomputes all five read commands together via their `compute_*_sync`
+/// helpers, so a cache hit never mixes stats from one moment with sessions
+/// from another.
+fn recompute_snapshot(project_path: &str) -> MemorySnapshot {
+    MemorySnapshot {
+        stats: memory::compute_memory_stats_sync(project_path),
+        sessions: memory::compute_sessions_sync(project_path),
+        intelligence: memory::compute_intelligence_sync(project_path),
+        graph: memory::compute_concept_graph_sync(project_path),
+        chunk_hierarchy: memory::compute_chunk_hierarchy_sync(project_path),
+    }
+}
+
+/// A running watcher for one project. Dropping this stops watching (the
+/// `notify` watcher is torn down) and signals the debounce thread to exit.
+struct MemoryWatchHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Tracks at most one live memory watcher per project path, mirroring
+/// `ProjectFileWatcherManager`'s start/stop shape.
+#[derive(Default)]
+pub struct MemoryWatcherManager {
+    handles: Mutex<HashMap<String, MemoryWatchHandle>>,
+}
+
+impl MemoryWatcherManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start watching `project_path`'s memory/intelligence directories,
+    /// seeding the cache with an initial snapshot. A no-op if already
+    /// watching this project.
+    pub fn start(&self, project_path: String, app: AppHandle) -> Result<(), SunwellError> {
+        let mut handles = self.handles.lock().unwrap();
+        if handles.contains_key(&project_path) {
+            return Ok(());
+        }
+
+        let (event_tx, event_rx) = mpsc::channel::<()>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = event_tx.send(());
+            }
+        })
+        .map_err(|e| {
+            sunwell_err!(
+                RuntimeStateInvalid,
+                "Failed to create memory watcher: {}",
+                e
+            )
+        })?;
+
+        for dir in watch_dirs(&project_path) {
+            if dir.exists() {
+                watcher.watch(&dir, RecursiveMode::Recursive).map_err(|e| {
+                    sunwell_err!(
+                        RuntimeStateInvalid,
+                        "Failed to watch {}: {}",
+                        dir.display(),
+                        e
+                    )
+                })?;
+            }
+        }
+
+        update_cache(&project_path, recompute_snapshot(&project_path));
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let thread_project_path = project_path.clone();
+        thread::spawn(move || debounce_loop(thread_project_path, app, event_rx, stop_rx));
+
+        handles.insert(
+            project_path,
+            MemoryWatchHandle {
+                _watcher: watcher,
+                stop_tx,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop watching `project_path` and drop its cached snapshot. A no-op
+    /// if not currently watched.
+    pub fn stop(&self, project_path: &str) {
+        if let Some(handle) = self.handles.lock().unwrap().remove(project_path) {
+            let _ = handle.stop_tx.send(());
+        }
+        clear_cache(project_path);
+    }
+}
+
+/// Directories that feed `MemorySnapshot`: session/chunk storage, the
+/// decisions/failures/dead-ends JSONL files, and the Naaru learnings
+/// sidecar directory.
+fn watch_dirs(project_path: &str) -> Vec<PathBuf> {
+    let root = PathBuf::from(project_path).join(".sunwell");
+    vec![
+        root.join("memory"),
+        root.join("intelligence"),
+        root.join("learnings"),
+    ]
+}
+
+/// Waits for a project's events to go quiet for `DEBOUNCE` before
+/// recomputing the whole snapshot in one pass and emitting
+/// `memory-updated`. Unlike `file_watcher`/`lens_watcher`, individual
+/// changed paths aren't tracked — any event under the watched directories
+/// just marks the whole project dirty, since every `compute_*_sync` helper
+/// already rescans its own directory in full.
+fn debounce_loop(
+    project_path: String,
+    app: AppHandle,
+    event_rx: mpsc::Receiver<()>,
+    stop_rx: mpsc::Receiver<()>,
+) {
+    let mut dirty_since: Option<Instant> = None;
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            return;
+        }
+
+        match event_rx.recv_timeout(DEBOUNCE) {
+            Ok(()) => dirty_since = Some(Instant::now()),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        let Some(since) = dirty_since else {
+            continue;
+        };
+        if since.elapsed() < DEBOUNCE {
+            continue;
+        }
+        dirty_since = None;
+
+        let snapshot = recompute_snapshot(&project_path);
+        update_cache(&project_path, snapshot);
+        let _ = app.emit("memory-updated", &project_path);
+    }
+}
+
+/// Start watching `project_path`'s memory and intelligence directories,
+/// caching a recomputed `MemorySnapshot` on every settled burst and
+/// emitting `memory-updated`. A no-op if already watching this project.
+#[tauri::command]
+pub async fn start_memory_watch(
+    project_path: String,
+    app: AppHandle,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state
+        .memory_watcher
+        .start(project_path, app)
+        .map_err(|e| e.to_json())
+}
+
+/// Stop watching `project_path`'s memory/intelligence directories, if
+/// watched, dropping its cached snapshot so subsequent reads fall back to
+/// a direct scan.
+#[tauri::command]
+pub async fn stop_memory_watch(
+    project_path: String,
+    state: tauri::State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.memory_watcher.stop(&project_path);
+    Ok(())
+}
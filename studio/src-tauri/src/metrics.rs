@@ -0,0 +1,243 @@
+//! Opt-In Prometheus Metrics for Previews, Workflows, Security, and
+//! Cascades (RFC-086 addendum, extended by RFC-109 addendum)
+//!
+//! Like pict-rs's `init_metrics` / kittybox's `metrics.rs`: a process-global
+//! recorder, installed once at startup via [`init_metrics`], that `preview`,
+//! `workflow`, `security`, and `weakness` report into. Recording is gated
+//! behind a cheap
+//! `AtomicBool` (mirroring `telemetry`'s `otel`-feature gate, but a runtime
+//! flag rather than a compile-time one, since operators should be able to
+//! opt in without a rebuild) so every `record_*` call is a single relaxed
+//! load and an early return when metrics haven't been installed — no
+//! allocation, no lock, until someone actually wants the data.
+//!
+//! There's no existing `prometheus`/`metrics` crate dependency in this tree,
+//! so the counters/histograms and their text exposition are hand-rolled
+//! here rather than pulled in from one — the format this module emits from
+//! [`metrics_snapshot`] is the same `# TYPE ... \nname{labels} value` text
+//! Prometheus's `/metrics` scrape endpoint expects, just produced by a
+//! `HashMap` instead of a library.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Install the metrics recorder. Call once at startup (e.g. from `main`,
+/// alongside `telemetry::init_telemetry`); calling it more than once is
+/// harmless. Before this is called, every `record_*` call below is a single
+/// `Ordering::Relaxed` load and nothing else.
+pub fn init_metrics() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Bucket upper bounds (seconds) shared by both histograms — wide enough to
+/// cover a near-instant content re-read and a slow framework cold start or
+/// multi-step chain.
+const DURATION_BUCKETS_S: &[f64] = &[0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+#[derive(Default)]
+struct Histogram {
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value_s: f64) {
+        if self.bucket_counts.is_empty() {
+            self.bucket_counts = vec![0; DURATION_BUCKETS_S.len()];
+        }
+        for (i, &bound) in DURATION_BUCKETS_S.iter().enumerate() {
+            if value_s <= bound {
+                self.bucket_counts[i] += 1;
+            }
+        }
+        self.sum += value_s;
+        self.count += 1;
+    }
+}
+
+/// A counter or histogram keyed by (metric name, single label value) — both
+/// instrumented metrics here only ever carry one label (`view_type` or
+/// `chain_name`), so a composite-tuple key is simpler than a general label
+/// set.
+type CounterKey = (&'static str, String);
+
+struct MetricsState {
+    counters: Mutex<HashMap<CounterKey, u64>>,
+    histograms: Mutex<HashMap<&'static str, Histogram>>,
+}
+
+fn state() -> &'static MetricsState {
+    static STATE: OnceLock<MetricsState> = OnceLock::new();
+    STATE.get_or_init(|| MetricsState {
+        counters: Mutex::new(HashMap::new()),
+        histograms: Mutex::new(HashMap::new()),
+    })
+}
+
+fn incr(name: &'static str, label_value: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let mut counters = state().counters.lock().unwrap();
+    *counters.entry((name, label_value.to_string())).or_insert(0) += 1;
+}
+
+fn observe(name: &'static str, value_s: f64) {
+    if !is_enabled() {
+        return;
+    }
+    let mut histograms = state().histograms.lock().unwrap();
+    histograms.entry(name).or_default().observe(value_s);
+}
+
+// =============================================================================
+// PREVIEW — `PreviewManager::launch`/`stop`
+// =============================================================================
+
+pub fn record_preview_started(view_type: &str) {
+    incr("preview_started_total", view_type);
+}
+
+pub fn record_preview_stopped(view_type: &str) {
+    incr("preview_stopped_total", view_type);
+}
+
+/// `PreviewManager::launch_web_app`'s `spawn_framework_process` call, timed
+/// from just before spawn to the port being ready to serve.
+pub fn record_preview_startup_latency(seconds: f64) {
+    observe("preview_startup_latency_seconds", seconds);
+}
+
+// =============================================================================
+// WORKFLOW — `start_workflow`/`resume_workflow`
+// =============================================================================
+
+pub fn record_workflow_chain_started(chain_name: &str) {
+    incr("workflow_chain_started_total", chain_name);
+}
+
+pub fn record_workflow_chain_completed(chain_name: &str) {
+    incr("workflow_chain_completed_total", chain_name);
+}
+
+pub fn record_workflow_chain_failed(chain_name: &str) {
+    incr("workflow_chain_failed_total", chain_name);
+}
+
+/// One `WorkflowStep.duration_s`, observed for every completed step of a
+/// chain run.
+pub fn record_workflow_step_duration(seconds: f64) {
+    observe("workflow_step_duration_seconds", seconds);
+}
+
+// =============================================================================
+// SECURITY — `scan_for_security_issues`
+// =============================================================================
+
+/// One `SecurityViolation` detected by `scan_for_security_issues`, labeled
+/// by its `violation_type` (e.g. `credential_leak`, `path_traversal`).
+pub fn record_security_violation(violation_type: &str) {
+    incr("security_violations_total", violation_type);
+}
+
+// =============================================================================
+// CASCADE — `execute_cascade_fix`/`start_cascade_execution`
+// =============================================================================
+
+/// One `WaveConfidence.confidence` from a completed cascade wave.
+pub fn record_cascade_wave_confidence(confidence: f32) {
+    observe("cascade_wave_confidence", confidence as f64);
+}
+
+// =============================================================================
+// EXPOSITION
+// =============================================================================
+
+/// Render everything recorded so far as Prometheus text exposition format.
+/// Returns an empty string if `init_metrics` was never called — there's
+/// nothing to report, and the caller shouldn't mistake that for a scrape
+/// failure.
+#[tauri::command]
+pub async fn metrics_snapshot() -> String {
+    if !is_enabled() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    render_counters(&mut out);
+    render_histograms(&mut out);
+    out
+}
+
+fn render_counters(out: &mut String) {
+    let counters = state().counters.lock().unwrap();
+    let mut by_metric: HashMap<&'static str, Vec<(&String, &u64)>> = HashMap::new();
+    for ((name, label), count) in counters.iter() {
+        by_metric.entry(name).or_default().push((label, count));
+    }
+
+    let mut names: Vec<&&'static str> = by_metric.keys().collect();
+    names.sort();
+    for name in names {
+        let label_key = label_name_for(name);
+        out.push_str(&format!("# TYPE {} counter\n", name));
+        let mut entries = by_metric[name].clone();
+        entries.sort_by_key(|(label, _)| (*label).clone());
+        for (label, count) in entries {
+            out.push_str(&format!(
+                "{}{{{}=\"{}\"}} {}\n",
+                name, label_key, label, count
+            ));
+        }
+    }
+}
+
+fn render_histograms(out: &mut String) {
+    let histograms = state().histograms.lock().unwrap();
+    let mut names: Vec<&&'static str> = histograms.keys().collect();
+    names.sort();
+
+    for name in names {
+        let histogram = &histograms[*name];
+        out.push_str(&format!("# TYPE {} histogram\n", name));
+        // `observe` already increments every bucket an observation falls
+        // under-or-at, so `bucket_counts[i]` is already the cumulative
+        // "count <= bound[i]" Prometheus expects — no re-summing needed.
+        for (bound, bucket_count) in DURATION_BUCKETS_S
+            .iter()
+            .zip(histogram.bucket_counts.iter())
+        {
+            out.push_str(&format!(
+                "{}_bucket{{le=\"{}\"}} {}\n",
+                name, bound, bucket_count
+            ));
+        }
+        out.push_str(&format!(
+            "{}_bucket{{le=\"+Inf\"}} {}\n",
+            name, histogram.count
+        ));
+        out.push_str(&format!("{}_sum {}\n", name, histogram.sum));
+        out.push_str(&format!("{}_count {}\n", name, histogram.count));
+    }
+}
+
+/// The label name a counter's single label value is reported under — both
+/// instrumented counters happen to share a natural label name per metric
+/// family.
+fn label_name_for(metric_name: &str) -> &'static str {
+    if metric_name.starts_with("preview_") {
+        "view_type"
+    } else if metric_name.starts_with("security_") {
+        "violation_type"
+    } else {
+        "chain_name"
+    }
+}
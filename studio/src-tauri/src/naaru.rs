@@ -9,12 +9,18 @@
 
 use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
+use crate::telemetry::{self, CommandTimer};
 use crate::util::parse_json_safe;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::OnceLock;
 use tauri::{Emitter, Window};
-use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
+use tokio::io::AsyncReadExt;
+use tokio::process::{Child, Command};
+use tokio::sync::Mutex;
+use tracing::Instrument;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // TYPES (match Python exactly)
@@ -112,6 +118,28 @@ pub struct ProcessOutput {
     pub artifacts: Vec<String>,
     pub events: Vec<NaaruEvent>,
     pub routing: Option<RoutingDecision>,
+    /// Every tool call dispatched across this request's tool-calling loop,
+    /// in call order.
+    #[serde(default)]
+    pub tool_results: Vec<ToolResult>,
+}
+
+/// One tool call dispatched (or held for confirmation) during a
+/// `naaru_process` tool-calling loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolResult {
+    pub name: String,
+    pub arguments: serde_json::Value,
+    /// The value fed back to the model as the tool's result. `None` for a
+    /// `may_`-prefixed call that is still waiting on user confirmation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// True for a `may_`-prefixed tool call that was surfaced for user
+    /// confirmation rather than executed automatically; the loop stops as
+    /// soon as one of these is hit.
+    #[serde(default)]
+    pub pending_approval: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,62 +152,103 @@ pub struct ConvergenceSlot {
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
-// TAURI COMMANDS
+// PROCESS REGISTRY
 // ═══════════════════════════════════════════════════════════════════════════════
 
-/// Process any input through unified Naaru (RFC-083).
-///
-/// THE entry point. All UI interaction goes through here.
-///
-/// # Arguments
-/// * `input` - ProcessInput with content and options
-///
-/// # Returns
-/// ProcessOutput with response, routing, and composition
-#[tauri::command]
-pub async fn naaru_process(input: ProcessInput) -> Result<ProcessOutput, String> {
-    // Build CLI command
-    let mode_str = match input.mode {
-        ProcessMode::Auto => "auto",
-        ProcessMode::Chat => "chat",
-        ProcessMode::Agent => "agent",
-        ProcessMode::Interface => "interface",
-    };
+/// Every `sunwell` subprocess currently spawned by `naaru_process`/
+/// `naaru_subscribe`, keyed by job ID, so `naaru_cancel` can find one and
+/// kill it. Entries are removed once the process exits, whether that's a
+/// normal completion or a cancellation.
+static JOB_REGISTRY: OnceLock<Mutex<HashMap<String, Child>>> = OnceLock::new();
 
-    // Call sunwell naaru process
-    let output = Command::new("sunwell")
-        .args([
-            "naaru",
-            "process",
-            &input.content,
-            "--mode",
-            mode_str,
-            "--page-type",
-            &input.page_type,
-            "--json",
-        ])
-        .output()
-        .await
+fn job_registry() -> &'static Mutex<HashMap<String, Child>> {
+    JOB_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Hard ceiling on how many tool-calling turns `naaru_process` will drive
+/// before giving up and returning whatever it has, even if the model keeps
+/// requesting tools. Keeps a misbehaving model from looping forever.
+const MAX_TOOL_STEPS: u32 = 8;
+
+/// Prefix marking a tool call as side-effecting. These are never executed
+/// automatically — they're surfaced to the caller as a pending `ToolResult`
+/// so the UI can prompt the user for confirmation before resubmitting.
+const MAY_PREFIX: &str = "may_";
+
+/// Spawn one `sunwell naaru process` invocation (registered under `job_id`
+/// so `naaru_cancel` can kill it mid-turn) and return its captured
+/// stdout/stderr plus exit status.
+async fn spawn_naaru_turn(
+    content: &str,
+    mode_str: &str,
+    page_type: &str,
+    history: &[ConversationMessage],
+    job_id: &str,
+) -> Result<(String, String, std::process::ExitStatus), String> {
+    let mut args = vec![
+        "naaru".to_string(),
+        "process".to_string(),
+        content.to_string(),
+        "--mode".to_string(),
+        mode_str.to_string(),
+        "--page-type".to_string(),
+        page_type.to_string(),
+        "--json".to_string(),
+    ];
+    if !history.is_empty() {
+        let history_json = serde_json::to_string(history)
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to encode conversation history: {}", e).to_json())?;
+        args.push("--history".to_string());
+        args.push(history_json);
+    }
+
+    let mut child = Command::new("sunwell")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
         .map_err(|e| {
             SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
                 .with_hints(vec!["Check if sunwell CLI is installed"])
                 .to_json()
         })?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(sunwell_err!(SkillExecutionFailed, "Naaru process failed: {}", stderr)
-            .with_hints(vec!["Check the input content", "Verify model availability"])
-            .to_json());
-    }
+    let mut child_stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| sunwell_err!(RuntimeProcessFailed, "Failed to capture stdout").to_json())?;
+    let mut child_stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| sunwell_err!(RuntimeProcessFailed, "Failed to capture stderr").to_json())?;
+
+    job_registry().lock().await.insert(job_id.to_string(), child);
+
+    let mut stdout_buf = String::new();
+    let mut stderr_buf = String::new();
+    let (stdout_result, stderr_result) = tokio::join!(
+        child_stdout.read_to_string(&mut stdout_buf),
+        child_stderr.read_to_string(&mut stderr_buf)
+    );
+    stdout_result.map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e).to_json())?;
+    stderr_result.map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e).to_json())?;
+
+    let status = match job_registry().lock().await.remove(job_id) {
+        Some(mut child) => child
+            .wait()
+            .await
+            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e).to_json())?,
+        None => return Err(sunwell_err!(RuntimeProcessFailed, "Naaru process was cancelled").to_json()),
+    };
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok((stdout_buf, stderr_buf, status))
+}
 
-    // Try to parse the last JSON object (the final output)
-    // The output may contain multiple JSON lines if streaming
+/// Parse one turn's raw NDJSON stdout into a `ProcessOutput`, the same way
+/// a single-shot `naaru_process` call always has.
+fn parse_process_output(stdout: &str) -> ProcessOutput {
     let lines: Vec<&str> = stdout.lines().collect();
 
-    // Find the last valid JSON that looks like a ProcessOutput
     let mut response = String::new();
     let mut route_type = "conversation".to_string();
     let mut confidence = 0.0;
@@ -193,7 +262,6 @@ pub async fn naaru_process(input: ProcessInput) -> Result<ProcessOutput, String>
         if let Ok(event) = parse_json_safe::<NaaruEvent>(line) {
             events.push(event.clone());
 
-            // Extract data from events
             match event.event_type.as_str() {
                 "model_tokens" => {
                     if let Some(content) = event.data.get("content").and_then(|v| v.as_str()) {
@@ -221,18 +289,13 @@ pub async fn naaru_process(input: ProcessInput) -> Result<ProcessOutput, String>
                 _ => {}
             }
         } else if let Ok(output) = parse_json_safe::<ProcessOutput>(line) {
-            // Found a complete ProcessOutput - use it directly
-            return Ok(output);
+            // A complete ProcessOutput short-circuits the accumulated fields.
+            return output;
         }
     }
 
-    // Build response from accumulated events
-    Ok(ProcessOutput {
-        response: if response.is_empty() {
-            "I'm here to help.".to_string()
-        } else {
-            response
-        },
+    ProcessOutput {
+        response: if response.is_empty() { "I'm here to help.".to_string() } else { response },
         route_type,
         confidence,
         composition,
@@ -240,40 +303,381 @@ pub async fn naaru_process(input: ProcessInput) -> Result<ProcessOutput, String>
         artifacts,
         events,
         routing,
-    })
+        tool_results: Vec::new(),
+    }
+}
+
+/// Pull every `tool_call` event's `{name, arguments}` out of `events`, in
+/// the order the CLI emitted them.
+fn extract_tool_calls(events: &[NaaruEvent]) -> Vec<(String, serde_json::Value)> {
+    events
+        .iter()
+        .filter(|e| e.event_type == "tool_call")
+        .filter_map(|e| {
+            let name = e.data.get("name").and_then(|v| v.as_str())?.to_string();
+            let arguments = e.data.get("arguments").cloned().unwrap_or(serde_json::Value::Null);
+            Some((name, arguments))
+        })
+        .collect()
+}
+
+/// Resolve a tool-provided relative path against `root`, rejecting anything
+/// that would escape it (e.g. an absolute path, or `../../etc/passwd`) —
+/// same containment check as `eval_tools::resolve_sandboxed_path`, since
+/// this dispatches the same class of model-supplied path, just auto-run
+/// instead of confirmation-gated.
+fn resolve_sandboxed_path(root: &std::path::Path, relative: &str) -> Result<PathBuf, String> {
+    let joined = root.join(relative);
+    let normalized = path_clean(&joined);
+    if !normalized.starts_with(root) {
+        return Err(format!("Path '{}' escapes the workspace root", relative));
+    }
+    Ok(normalized)
+}
+
+/// Lexically normalize a path (collapse `.`/`..` components) without
+/// requiring the path to exist.
+fn path_clean(path: &std::path::Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Execute a pure "query" tool call directly (anything not `may_`-prefixed)
+/// against `workspace`, returning the text to feed back to the model.
+///
+/// Side-effecting tools never reach here — `naaru_process` surfaces those
+/// as a pending `ToolResult` instead of dispatching them. Query tools run
+/// with no user confirmation step at all, so `path` must be sandboxed the
+/// same way `eval_tools::dispatch_tool` sandboxes its own file tools.
+fn dispatch_query_tool(workspace: Option<&str>, name: &str, args: &serde_json::Value) -> Result<String, String> {
+    let root = workspace.map(PathBuf::from).unwrap_or_else(|| PathBuf::from("."));
+
+    match name {
+        "read_file" => {
+            let path = args.get("path").and_then(|v| v.as_str()).ok_or("read_file requires 'path'")?;
+            let target = resolve_sandboxed_path(&root, path)?;
+            std::fs::read_to_string(target).map_err(|e| format!("Failed to read {}: {}", path, e))
+        }
+        "list_dir" => {
+            let path = args.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+            let target = resolve_sandboxed_path(&root, path)?;
+            let entries = std::fs::read_dir(target).map_err(|e| format!("Failed to list {}: {}", path, e))?;
+            Ok(entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        other => Err(format!("Unknown query tool '{}'", other)),
+    }
+}
+
+// =============================================================================
+// Framed Event Stream
+// =============================================================================
+
+/// LSP-style header introducing a framed message: `Content-Length: <n>\r\n\r\n`
+/// followed by exactly `n` bytes of UTF-8 JSON. Used by `naaru_subscribe` so
+/// a `composition_ready` payload with embedded newlines (or one that spans
+/// two `read` calls) doesn't get truncated the way plain line-splitting would.
+const CONTENT_LENGTH_PREFIX: &[u8] = b"Content-Length:";
+
+/// Whether `buf`'s leading bytes could still become `CONTENT_LENGTH_PREFIX`.
+enum HeaderPrefixState {
+    /// `buf` already starts with the full prefix.
+    Confirmed,
+    /// `buf` is a strict prefix of `CONTENT_LENGTH_PREFIX` — need more bytes
+    /// before this can be decided either way.
+    NeedMoreData,
+    /// `buf` diverges from the prefix; this stream is line-oriented.
+    NotAMatch,
+}
+
+fn header_prefix_state(buf: &[u8]) -> HeaderPrefixState {
+    if buf.len() >= CONTENT_LENGTH_PREFIX.len() {
+        if buf[..CONTENT_LENGTH_PREFIX.len()] == *CONTENT_LENGTH_PREFIX {
+            HeaderPrefixState::Confirmed
+        } else {
+            HeaderPrefixState::NotAMatch
+        }
+    } else if CONTENT_LENGTH_PREFIX.starts_with(buf) {
+        HeaderPrefixState::NeedMoreData
+    } else {
+        HeaderPrefixState::NotAMatch
+    }
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// Reads `NaaruEvent`s from a byte stream that may use either LSP-style
+/// `Content-Length` framing or plain newline-delimited JSON, buffering
+/// across `read` calls so a message split across two reads still parses.
+struct FramedEventReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+}
+
+impl<R: tokio::io::AsyncRead + Unpin> FramedEventReader<R> {
+    fn new(reader: R) -> Self {
+        Self { reader, buf: Vec::new() }
+    }
+
+    /// Pull more bytes from the underlying stream. `false` means EOF.
+    async fn fill(&mut self) -> bool {
+        let mut chunk = [0u8; 8192];
+        match self.reader.read(&mut chunk).await {
+            Ok(0) | Err(_) => false,
+            Ok(n) => {
+                self.buf.extend_from_slice(&chunk[..n]);
+                true
+            }
+        }
+    }
+
+    /// Read the next event, or `None` once the stream is exhausted. A line
+    /// or frame that fails to parse as a `NaaruEvent` is skipped rather than
+    /// ending the stream, matching the old line-oriented reader's behavior.
+    async fn next_event(&mut self) -> Option<NaaruEvent> {
+        loop {
+            match header_prefix_state(&self.buf) {
+                HeaderPrefixState::NeedMoreData => {
+                    if !self.fill().await {
+                        return None;
+                    }
+                }
+                HeaderPrefixState::Confirmed => {
+                    let Some(header_end) = find_subslice(&self.buf, b"\r\n\r\n") else {
+                        if !self.fill().await {
+                            return None;
+                        }
+                        continue;
+                    };
+
+                    let header = String::from_utf8_lossy(&self.buf[..header_end]).to_string();
+                    let length: usize = header
+                        .trim_start_matches("Content-Length:")
+                        .trim()
+                        .parse()
+                        .unwrap_or(0);
+                    let body_start = header_end + 4;
+                    let body_end = body_start + length;
+
+                    if self.buf.len() < body_end {
+                        if !self.fill().await {
+                            return None;
+                        }
+                        continue;
+                    }
+
+                    let body = self.buf[body_start..body_end].to_vec();
+                    self.buf.drain(..body_end);
+                    return serde_json::from_slice(&body).ok();
+                }
+                HeaderPrefixState::NotAMatch => {
+                    let Some(newline) = self.buf.iter().position(|&b| b == b'\n') else {
+                        if !self.fill().await {
+                            return None;
+                        }
+                        continue;
+                    };
+
+                    let line: Vec<u8> = self.buf.drain(..=newline).collect();
+                    let trimmed = String::from_utf8_lossy(&line).trim().to_string();
+                    if trimmed.is_empty() {
+                        continue;
+                    }
+                    match parse_json_safe::<NaaruEvent>(&trimmed) {
+                        Ok(event) => return Some(event),
+                        Err(_) => continue,
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn naaru_event(event_type: &str, data: serde_json::Value) -> NaaruEvent {
+    NaaruEvent {
+        event_type: event_type.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        data,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// TAURI COMMANDS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Process any input through unified Naaru (RFC-083).
+///
+/// THE entry point. All UI interaction goes through here.
+///
+/// Drives a multi-turn tool-calling loop: each turn's `tool_call` events are
+/// dispatched (pure "query" tools run immediately; `may_`-prefixed
+/// side-effecting tools are surfaced as a pending `ToolResult` instead, and
+/// the loop stops there for the UI to confirm), their results are folded
+/// into `conversation_history`, and a follow-up turn is sent until the CLI
+/// returns a turn with no pending tool calls or `MAX_TOOL_STEPS` is hit.
+///
+/// # Arguments
+/// * `input` - ProcessInput with content and options
+/// * `job_id` - Optional ID the caller can later pass to `naaru_cancel` to
+///   abort this run. If omitted, an internal ID is used and the run can't
+///   be cancelled.
+///
+/// # Returns
+/// ProcessOutput with response, routing, composition, and every tool call
+/// dispatched along the way.
+#[tracing::instrument(skip(input, job_id), fields(mode = ?input.mode, page_type = %input.page_type, route_type, spawn_ms, wall_clock_ms))]
+#[tauri::command]
+pub async fn naaru_process(input: ProcessInput, job_id: Option<String>) -> Result<ProcessOutput, String> {
+    let mode_str = match input.mode {
+        ProcessMode::Auto => "auto",
+        ProcessMode::Chat => "chat",
+        ProcessMode::Agent => "agent",
+        ProcessMode::Interface => "interface",
+    };
+
+    let effective_job_id = job_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let mut history = input.conversation_history.clone();
+    let mut tool_results: Vec<ToolResult> = Vec::new();
+    let timer = CommandTimer::start();
+
+    for _step in 0..MAX_TOOL_STEPS {
+        let (stdout_buf, stderr_buf, status) =
+            spawn_naaru_turn(&input.content, mode_str, &input.page_type, &history, &effective_job_id).await?;
+        tracing::Span::current().record("spawn_ms", timer.elapsed_ms() as u64);
+
+        if !status.success() {
+            let err = sunwell_err!(SkillExecutionFailed, "Naaru process failed: {}", stderr_buf)
+                .with_hints(vec!["Check the input content", "Verify model availability"]);
+            telemetry::record_failure(&err);
+            return Err(err.to_json());
+        }
+
+        let mut output = parse_process_output(&stdout_buf);
+        let tool_calls = extract_tool_calls(&output.events);
+        tracing::Span::current().record("route_type", output.route_type.as_str());
+
+        if tool_calls.is_empty() {
+            output.tool_results = tool_results;
+            tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+            return Ok(output);
+        }
+
+        let mut awaiting_confirmation = false;
+        for (name, arguments) in tool_calls {
+            if name.starts_with(MAY_PREFIX) {
+                output.events.push(naaru_event(
+                    "tool_call_pending",
+                    serde_json::json!({"name": name, "arguments": arguments}),
+                ));
+                tool_results.push(ToolResult { name, arguments, output: None, pending_approval: true });
+                awaiting_confirmation = true;
+                break;
+            }
+
+            let result = dispatch_query_tool(input.workspace.as_deref(), &name, &arguments)
+                .unwrap_or_else(|e| format!("Tool failed: {}", e));
+
+            output.events.push(naaru_event(
+                "tool_result",
+                serde_json::json!({"name": name, "arguments": arguments, "output": result}),
+            ));
+            history.push(ConversationMessage {
+                role: "assistant".to_string(),
+                content: format!("Called tool `{}` with {}", name, arguments),
+            });
+            history.push(ConversationMessage { role: "tool".to_string(), content: result.clone() });
+            tool_results.push(ToolResult { name, arguments, output: Some(result), pending_approval: false });
+        }
+
+        if awaiting_confirmation {
+            output.tool_results = tool_results;
+            tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+            return Ok(output);
+        }
+    }
+
+    let err = sunwell_err!(
+        SkillExecutionFailed,
+        "Naaru tool-calling loop did not converge after {} steps",
+        MAX_TOOL_STEPS
+    );
+    telemetry::record_failure(&err);
+    Err(err.to_json())
 }
 
 /// Subscribe to real-time Naaru events.
 ///
 /// Opens event stream and emits to window.
+///
+/// # Arguments
+/// * `job_id` - Optional ID the caller can later pass to `naaru_cancel` to
+///   abort the stream early.
+#[tracing::instrument(skip(window, job_id), fields(job_id, spawn_ms, time_to_first_event_ms))]
 #[tauri::command]
-pub async fn naaru_subscribe(window: Window) -> Result<(), String> {
+pub async fn naaru_subscribe(window: Window, job_id: Option<String>) -> Result<(), String> {
+    let timer = CommandTimer::start();
+
     // Start sunwell in streaming mode
     let mut child = Command::new("sunwell")
         .args(["naaru", "process", "--stream", "--json", ""])
         .stdout(Stdio::piped())
         .spawn()
         .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
+            let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"]);
+            telemetry::record_failure(&err);
+            err.to_json()
         })?;
+    tracing::Span::current().record("spawn_ms", timer.elapsed_ms() as u64);
+
+    let stdout = child.stdout.take().ok_or_else(|| {
+        let err = sunwell_err!(RuntimeProcessFailed, "Failed to capture stdout");
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
+    let mut reader = FramedEventReader::new(stdout);
+
+    let effective_job_id = job_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    tracing::Span::current().record("job_id", effective_job_id.as_str());
+    job_registry().lock().await.insert(effective_job_id.clone(), child);
+
+    // Spawn task to read and emit events, then reap the process once the
+    // stream ends (naturally or via `naaru_cancel` closing its stdout).
+    // `.instrument` carries this command's span into the detached task so
+    // the `time_to_first_event_ms` field lands on the right span.
+    let span = tracing::Span::current();
+    tokio::spawn(
+        async move {
+            let mut timer = timer;
+            while let Some(event) = reader.next_event().await {
+                timer.mark_first_event();
+                let _ = window.emit("naaru_event", event);
+            }
 
-    let stdout = child
-        .stdout
-        .take()
-        .ok_or_else(|| sunwell_err!(RuntimeProcessFailed, "Failed to capture stdout").to_json())?;
-    let reader = BufReader::new(stdout);
-    let mut lines = reader.lines();
+            if let Some(ms) = timer.time_to_first_event_ms() {
+                tracing::Span::current().record("time_to_first_event_ms", ms as u64);
+            }
 
-    // Spawn task to read and emit events
-    tokio::spawn(async move {
-        while let Ok(Some(line)) = lines.next_line().await {
-            if let Ok(event) = parse_json_safe::<NaaruEvent>(&line) {
-                let _ = window.emit("naaru_event", event);
+            if let Some(mut child) = job_registry().lock().await.remove(&effective_job_id) {
+                let _ = child.wait().await;
             }
         }
-    });
+        .instrument(span),
+    );
 
     Ok(())
 }
@@ -285,32 +689,63 @@ pub async fn naaru_subscribe(window: Window) -> Result<(), String> {
 ///
 /// # Returns
 /// ConvergenceSlot or null if not found
+#[tracing::instrument(skip(slot), fields(slot = %slot, wall_clock_ms))]
 #[tauri::command]
 pub async fn naaru_convergence(slot: String) -> Result<Option<ConvergenceSlot>, String> {
+    let timer = CommandTimer::start();
     let output = Command::new("sunwell")
         .args(["naaru", "convergence", "--slot", &slot, "--json"])
         .output()
         .await
         .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
+            let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"]);
+            telemetry::record_failure(&err);
+            err.to_json()
         })?;
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
 
     if !output.status.success() {
         return Ok(None);
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&stdout)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse convergence: {}", e).to_json())
+    parse_json_safe(&stdout).map_err(|e| {
+        let err = sunwell_err!(ConfigInvalid, "Failed to parse convergence: {}", e);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })
 }
 
-/// Cancel current processing.
+/// Cancel a running Naaru subprocess by job ID.
+///
+/// Looks up `job_id` in the process registry and kills it via
+/// `Child::start_kill` (SIGKILL on Unix, `TerminateProcess` on Windows —
+/// tokio's portable equivalent of sending SIGINT, without depending on a
+/// Unix-only signals crate). The registry entry itself is cleaned up by
+/// the run that owns it once it observes the process exit, not here.
+/// A job ID that isn't tracked (already finished, or never given one) is
+/// treated as already-cancelled rather than an error.
+#[tauri::command]
+pub async fn naaru_cancel(job_id: String) -> Result<(), String> {
+    let mut registry = job_registry().lock().await;
+    if let Some(child) = registry.get_mut(&job_id) {
+        child
+            .start_kill()
+            .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e).to_json())?;
+    }
+    Ok(())
+}
+
+/// Kill and drain every subprocess currently tracked by the registry —
+/// for app shutdown, so no `sunwell` child process outlives the window.
 #[tauri::command]
-pub async fn naaru_cancel() -> Result<(), String> {
-    // Send SIGINT to any running sunwell processes
-    // For now, this is a no-op - proper cancellation requires process management
+pub async fn naaru_cancel_all() -> Result<(), String> {
+    let mut registry = job_registry().lock().await;
+    for child in registry.values_mut() {
+        let _ = child.start_kill();
+    }
+    registry.clear();
     Ok(())
 }
 
@@ -331,6 +766,54 @@ mod tests {
         assert_eq!(input.timeout, 300.0);
     }
 
+    #[tokio::test]
+    async fn test_framed_reader_reads_content_length_messages() {
+        let event = r#"{"type": "model_tokens", "timestamp": "t", "data": {}}"#;
+        let stream = format!("Content-Length: {}\r\n\r\n{}", event.len(), event);
+        let mut reader = FramedEventReader::new(std::io::Cursor::new(stream.into_bytes()));
+
+        let parsed = reader.next_event().await.unwrap();
+        assert_eq!(parsed.event_type, "model_tokens");
+        assert!(reader.next_event().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_framed_reader_handles_embedded_newlines() {
+        let event = r#"{"type": "composition_ready", "timestamp": "t", "data": {"text": "line one\nline two"}}"#;
+        let stream = format!("Content-Length: {}\r\n\r\n{}", event.len(), event);
+        let mut reader = FramedEventReader::new(std::io::Cursor::new(stream.into_bytes()));
+
+        let parsed = reader.next_event().await.unwrap();
+        assert_eq!(parsed.data["text"], "line one\nline two");
+    }
+
+    #[tokio::test]
+    async fn test_framed_reader_falls_back_to_line_oriented() {
+        let stream = "{\"type\": \"model_tokens\", \"timestamp\": \"t\", \"data\": {}}\n";
+        let mut reader = FramedEventReader::new(std::io::Cursor::new(stream.as_bytes().to_vec()));
+
+        let parsed = reader.next_event().await.unwrap();
+        assert_eq!(parsed.event_type, "model_tokens");
+    }
+
+    #[tokio::test]
+    async fn test_framed_reader_reads_multiple_framed_messages() {
+        let first = r#"{"type": "a", "timestamp": "t", "data": {}}"#;
+        let second = r#"{"type": "b", "timestamp": "t", "data": {}}"#;
+        let stream = format!(
+            "Content-Length: {}\r\n\r\n{}Content-Length: {}\r\n\r\n{}",
+            first.len(),
+            first,
+            second.len(),
+            second
+        );
+        let mut reader = FramedEventReader::new(std::io::Cursor::new(stream.into_bytes()));
+
+        assert_eq!(reader.next_event().await.unwrap().event_type, "a");
+        assert_eq!(reader.next_event().await.unwrap().event_type, "b");
+        assert!(reader.next_event().await.is_none());
+    }
+
     #[test]
     fn test_process_mode_serialization() {
         assert_eq!(
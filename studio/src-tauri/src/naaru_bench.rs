@@ -0,0 +1,177 @@
+//! Workload-Driven Benchmark Harness for the Naaru Process Pipeline
+//! (RFC-083 addendum)
+//!
+//! Builds on `naaru_process`/`predict_composition` the same way
+//! `benchmark.rs` builds on the RFC-074/RFC-105 DAG pipeline and
+//! `self_benchmark.rs` builds on `self_knowledge`: a JSON "workload" file
+//! describes an ordered list of named cases, each either a full
+//! `naaru_process` turn (with an expected `route_type`/`confidence`) or a
+//! speculative `predict_composition` call. `naaru_bench` replays every
+//! case and folds the outcomes into latency percentiles and a
+//! route-accuracy score, so a regression in routing quality or latency
+//! shows up as a diffable report rather than silently drifting.
+//!
+//! There's no CLI-side counterpart to this command in this tree (the
+//! `sunwell` Python CLI this module shells out to isn't part of this
+//! checkout), so only the in-app Tauri surface is implemented here; a CI
+//! runner would invoke it the same way the Tauri frontend does, via
+//! `naaru_bench`.
+
+use crate::interface::predict_composition;
+use crate::naaru::{naaru_process, ProcessInput};
+use crate::util::parse_json_safe;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// One case's operation: either a full process turn or a speculative
+/// composition prediction, modeled on `benchmark::WorkloadStep`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BenchOperation {
+    /// Run `naaru_process` and optionally assert on its routing.
+    Process {
+        input: ProcessInput,
+        /// Expected `ProcessOutput::route_type`, checked if present.
+        #[serde(default)]
+        expected_route_type: Option<String>,
+        /// Minimum acceptable `ProcessOutput::confidence`, checked if present.
+        #[serde(default)]
+        expected_min_confidence: Option<f64>,
+    },
+    /// Run `predict_composition` (no routing assertions — it's the fast,
+    /// speculative tier, not the full router).
+    Compose { input: String, current_page: Option<String> },
+}
+
+/// One named case within a workload's ordered list.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchCase {
+    pub name: String,
+    #[serde(flatten)]
+    pub operation: BenchOperation,
+}
+
+/// A workload file: a named, ordered list of process-pipeline cases to
+/// replay, modeled on `self_benchmark::SelfBenchmarkWorkload`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NaaruBenchWorkload {
+    pub name: String,
+    pub cases: Vec<BenchCase>,
+}
+
+/// Outcome of replaying a single case.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CaseResult {
+    pub name: String,
+    pub wall_clock_ms: u128,
+    pub route_type: Option<String>,
+    pub confidence: Option<f64>,
+    /// `None` when the case had no `expected_route_type` to check against.
+    pub route_correct: Option<bool>,
+    /// `None` when the case had no `expected_min_confidence` to check against.
+    pub confidence_met: Option<bool>,
+    pub error: Option<String>,
+}
+
+/// Aggregated report from replaying a workload.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NaaruBenchReport {
+    pub workload_name: String,
+    pub cases: Vec<CaseResult>,
+    pub p50_latency_ms: f64,
+    pub p90_latency_ms: f64,
+    pub p99_latency_ms: f64,
+    /// Fraction of cases with an `expected_route_type` that routed
+    /// correctly. `1.0` when no case declared an expectation.
+    pub route_accuracy: f64,
+    pub total_wall_clock_ms: u128,
+}
+
+/// Replay every case of a workload file (in order) through the process
+/// pipeline, and return a latency/route-accuracy report that can be
+/// diffed against a prior run to catch routing or latency regressions.
+#[tauri::command]
+pub async fn naaru_bench(workload_path: String) -> Result<NaaruBenchReport, String> {
+    let content = std::fs::read_to_string(&workload_path)
+        .map_err(|e| format!("Failed to read workload file {}: {}", workload_path, e))?;
+    let workload: NaaruBenchWorkload =
+        parse_json_safe(&content).map_err(|e| format!("Failed to parse workload file: {}", e))?;
+
+    Ok(run_workload(&workload).await)
+}
+
+async fn run_workload(workload: &NaaruBenchWorkload) -> NaaruBenchReport {
+    let mut cases = Vec::with_capacity(workload.cases.len());
+    let mut latencies_ms: Vec<u64> = Vec::new();
+    let mut checked_routes = 0u32;
+    let mut correct_routes = 0u32;
+
+    for case in &workload.cases {
+        let started = Instant::now();
+        let result = run_case(case).await;
+        let wall_clock_ms = started.elapsed().as_millis();
+        latencies_ms.push(wall_clock_ms as u64);
+
+        let mut case_result = result.unwrap_or_else(|e| CaseResult { error: Some(e), ..Default::default() });
+        case_result.name = case.name.clone();
+        case_result.wall_clock_ms = wall_clock_ms;
+
+        if let Some(correct) = case_result.route_correct {
+            checked_routes += 1;
+            if correct {
+                correct_routes += 1;
+            }
+        }
+        cases.push(case_result);
+    }
+
+    latencies_ms.sort_unstable();
+    let route_accuracy = if checked_routes == 0 { 1.0 } else { correct_routes as f64 / checked_routes as f64 };
+    let total_wall_clock_ms = cases.iter().map(|c| c.wall_clock_ms).sum();
+
+    NaaruBenchReport {
+        workload_name: workload.name.clone(),
+        cases,
+        p50_latency_ms: percentile(&latencies_ms, 50.0),
+        p90_latency_ms: percentile(&latencies_ms, 90.0),
+        p99_latency_ms: percentile(&latencies_ms, 99.0),
+        route_accuracy,
+        total_wall_clock_ms,
+    }
+}
+
+async fn run_case(case: &BenchCase) -> Result<CaseResult, String> {
+    match &case.operation {
+        BenchOperation::Process { input, expected_route_type, expected_min_confidence } => {
+            let output = naaru_process(input.clone(), None).await?;
+
+            let route_correct = expected_route_type.as_ref().map(|expected| output.route_type == *expected);
+            let confidence_met = expected_min_confidence.map(|min| output.confidence >= min);
+
+            Ok(CaseResult {
+                route_type: Some(output.route_type),
+                confidence: Some(output.confidence),
+                route_correct,
+                confidence_met,
+                ..Default::default()
+            })
+        }
+        BenchOperation::Compose { input, current_page } => {
+            let composition = predict_composition(input.clone(), current_page.clone()).await?;
+            Ok(CaseResult { confidence: composition.map(|c| c.confidence), ..Default::default() })
+        }
+    }
+}
+
+/// Nearest-rank percentile over an already-sorted sample — matches
+/// `self_benchmark::percentile`'s choice of interpolation-free ranking for
+/// small benchmark sample sizes.
+fn percentile(sorted_ms: &[u64], p: f64) -> f64 {
+    if sorted_ms.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * (sorted_ms.len() as f64 - 1.0)).round() as usize;
+    sorted_ms[rank.min(sorted_ms.len() - 1)] as f64
+}
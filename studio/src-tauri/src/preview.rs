@@ -5,12 +5,36 @@
 //! - CLI tools: Pre-filled terminal command
 //! - Prose: Formatted reader view
 //! - Dialogues: Interactive dialogue player
+//!
+//! `launch`'s optional `watch` flag adds live hot-reload, mirroring Deno's
+//! `file_watcher`: a recursive `notify` watcher on `project.path` debounces
+//! bursts of filesystem events (see `file_watcher::DEBOUNCE` for the same
+//! shape) and, once they go quiet, calls `reload` — which restarts the
+//! framework process on the same port for `WebView`/`RemoteWebView`
+//! sessions, or just re-reads the matching `find_*_content` for
+//! `Prose`/`Fountain`/`Dialogue` ones — and emits a `preview-reloaded` event
+//! with the refreshed `PreviewSession`.
 
 use crate::error::{ErrorCode, SunwellError};
 use crate::project::{Project, ProjectType};
+use notify::{RecommendedWatcher, Watcher};
 use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader};
 use std::net::TcpListener;
 use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How long to wait for a tunnel helper to announce its public URL before
+/// giving up and falling back to the plain local preview.
+const TUNNEL_URL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a burst of file-change events must go quiet before triggering a
+/// hot reload, matching `file_watcher`'s own debounce shape.
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(200);
 
 /// Type of preview view.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +52,25 @@ pub enum ViewType {
     Dialogue,
     /// Generic file viewer
     Generic,
+    /// Embedded web browser pointed at a shareable tunnel URL rather than
+    /// `localhost`, so the frontend can badge the preview as "public".
+    RemoteWebView,
+}
+
+impl ViewType {
+    /// Label used for the `view_type` metric label in `metrics::record_preview_*` —
+    /// same spelling as the `#[serde(rename_all = "snake_case")]` wire format.
+    fn metric_label(&self) -> &'static str {
+        match self {
+            ViewType::WebView => "web_view",
+            ViewType::Terminal => "terminal",
+            ViewType::Prose => "prose",
+            ViewType::Fountain => "fountain",
+            ViewType::Dialogue => "dialogue",
+            ViewType::Generic => "generic",
+            ViewType::RemoteWebView => "remote_web_view",
+        }
+    }
 }
 
 /// An active preview session.
@@ -43,6 +86,148 @@ pub struct PreviewSession {
     pub command: Option<String>,
     /// Port being used
     pub port: Option<u16>,
+    /// Public, shareable URL from an outbound tunnel, when one was
+    /// requested and came up in time. `None` if no tunnel was requested, or
+    /// if it timed out and the session fell back to `url` alone.
+    pub public_url: Option<String>,
+}
+
+/// Tunnel helper binaries `TunnelConfig::provider` is allowed to name.
+/// `launch_preview`'s `tunnel` argument comes straight from the frontend, so
+/// this is an allowlist in the same spirit as
+/// `run_analysis::SAFE_COMMAND_PREFIXES` — it just governs a single
+/// `Command::new` rather than a shell-lexed string.
+const SAFE_TUNNEL_PROVIDERS: &[&str] = &["cloudflared", "ngrok", "lt"];
+
+/// Requests a shareable remote tunnel for a web preview, as VS Code's
+/// code-tunnel does for `code tunnel`: a helper process is pointed at the
+/// local preview port and prints a public HTTPS URL once the tunnel is up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TunnelConfig {
+    /// Tunnel helper binary to spawn (e.g. `"cloudflared"`, `"ngrok"`),
+    /// invoked as `{provider} {args...} 127.0.0.1:{port}`. Must be one of
+    /// `SAFE_TUNNEL_PROVIDERS` — see `validate`.
+    pub provider: String,
+    /// Extra args inserted before the local-address target. Each must look
+    /// like a `-`/`--` flag (see `validate`) so this can't be used to smuggle
+    /// in an arbitrary positional argument.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl TunnelConfig {
+    /// Reject a `provider` outside `SAFE_TUNNEL_PROVIDERS`, or an `args`
+    /// entry that isn't a plain flag, before `DefaultTunnelProvider::start`
+    /// is allowed to spawn anything.
+    fn validate(&self) -> Result<(), String> {
+        if !SAFE_TUNNEL_PROVIDERS.contains(&self.provider.as_str()) {
+            return Err(format!(
+                "Tunnel provider '{}' is not supported (expected one of: {})",
+                self.provider,
+                SAFE_TUNNEL_PROVIDERS.join(", ")
+            ));
+        }
+        for arg in &self.args {
+            let is_safe_flag = arg.starts_with('-')
+                && arg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_=.:/".contains(c));
+            if !is_safe_flag {
+                return Err(format!("Tunnel arg '{}' is not a recognized flag", arg));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Starts a tunnel helper pointed at a local port and resolves the public
+/// URL it reports, so `launch_web_app` isn't hard-wired to one specific
+/// tunnel binary. `DefaultTunnelProvider` is the only implementation today,
+/// but the trait is the extension point for e.g. a built-in Sunwell relay
+/// later without touching `launch_web_app` itself.
+pub trait TunnelProvider {
+    /// Spawn the helper pointed at `127.0.0.1:{port}` and block (bounded by
+    /// `TUNNEL_URL_TIMEOUT`) until its public URL is parsed from stdout.
+    /// Returns the running `Child` alongside the parsed URL so the caller
+    /// can hold onto both for teardown.
+    fn start(&self, port: u16) -> Result<(Child, String), String>;
+}
+
+/// Default `TunnelProvider`: spawns `config.provider` as a subprocess and
+/// treats the first `https://` token printed to its stdout as the public
+/// hostname — the same announcement shape `code-tunnel` itself prints.
+pub struct DefaultTunnelProvider {
+    config: TunnelConfig,
+}
+
+impl DefaultTunnelProvider {
+    pub fn new(config: TunnelConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl TunnelProvider for DefaultTunnelProvider {
+    fn start(&self, port: u16) -> Result<(Child, String), String> {
+        self.config.validate()?;
+
+        let mut process = Command::new(&self.config.provider)
+            .args(&self.config.args)
+            .arg(format!("127.0.0.1:{}", port))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                    .with_hints(vec![
+                        "Check the tunnel provider binary is installed and on PATH",
+                    ])
+                    .to_json()
+            })?;
+
+        let stdout = process
+            .stdout
+            .take()
+            .ok_or("Tunnel process has no stdout")?;
+
+        match wait_for_tunnel_url(stdout, TUNNEL_URL_TIMEOUT) {
+            Ok(url) => Ok((process, url)),
+            Err(e) => {
+                let _ = process.kill();
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Pull the first `https://`-prefixed whitespace-delimited token out of a
+/// tunnel helper's stdout line.
+fn parse_tunnel_url(line: &str) -> Option<String> {
+    line.split_whitespace()
+        .find(|token| token.starts_with("https://"))
+        .map(str::to_string)
+}
+
+/// Read lines from `stdout` on a dedicated thread until one contains a
+/// `parse_tunnel_url` match, or `timeout` elapses first. Split out of
+/// `DefaultTunnelProvider::start` so the timeout/no-match path can be
+/// exercised with an in-memory reader instead of a real subprocess.
+fn wait_for_tunnel_url(
+    stdout: impl std::io::Read + Send + 'static,
+    timeout: Duration,
+) -> Result<String, String> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(url) = parse_tunnel_url(&line) {
+                let _ = tx.send(url);
+                return;
+            }
+        }
+    });
+
+    rx.recv_timeout(timeout)
+        .map_err(|_| "Timed out waiting for the tunnel to report a public URL".to_string())
 }
 
 /// Web framework detection.
@@ -55,29 +240,77 @@ pub enum Framework {
     Unknown,
 }
 
+/// A running hot-reload watcher. Dropping (or explicitly stopping) this
+/// tears down the `notify` watcher and signals the debounce thread to exit,
+/// mirroring `file_watcher::ProjectWatchHandle`.
+struct PreviewWatchHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Payload for the `preview-reloaded` event, emitted after a hot reload
+/// restarts the server process (`WebView`/`RemoteWebView`) or refreshes
+/// `content` (`Prose`/`Fountain`/`Dialogue`).
+#[derive(Debug, Clone, Serialize)]
+struct PreviewReloadedEvent {
+    session: PreviewSession,
+}
+
 /// Manages preview sessions.
 pub struct PreviewManager {
     /// Currently running server process
     server_process: Option<Child>,
+    /// Outbound tunnel helper process, when the current web preview was
+    /// launched with a `TunnelConfig`.
+    tunnel_process: Option<Child>,
     /// Current session info
     current_session: Option<PreviewSession>,
+    /// The project the current session was launched for, kept around so a
+    /// hot reload can re-detect the framework or re-read content without
+    /// the caller supplying it again.
+    watched_project: Option<Project>,
+    /// Live hot-reload watcher for the current session, if `launch` was
+    /// called with `watch: true`.
+    watch_handle: Option<PreviewWatchHandle>,
+    /// Single-flight guard so an overlapping burst of file-change events
+    /// can't trigger two concurrent reloads (e.g. two respawned servers
+    /// fighting over the same port).
+    reloading: Arc<AtomicBool>,
 }
 
 impl PreviewManager {
     pub fn new() -> Self {
         Self {
             server_process: None,
+            tunnel_process: None,
             current_session: None,
+            watched_project: None,
+            watch_handle: None,
+            reloading: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    /// Launch a preview for the given project.
-    pub fn launch(&mut self, project: &Project) -> Result<PreviewSession, String> {
+    /// Launch a preview for the given project. `tunnel`, if set, is only
+    /// consulted for `launch_web_app` — it's ignored for every other
+    /// preview kind, which have no server process to tunnel to. `watch`, if
+    /// true, starts a recursive file watcher on `project.path` that hot-
+    /// reloads the session on change (see the module doc comment); `app` is
+    /// used both to emit `preview-reloaded` events and, from the watcher's
+    /// own thread, to reach back into `AppState` for the reload itself.
+    pub fn launch(
+        &mut self,
+        project: &Project,
+        tunnel: Option<TunnelConfig>,
+        watch: bool,
+        app: AppHandle,
+    ) -> Result<PreviewSession, String> {
         // Stop any existing preview
         self.stop()?;
 
         let session = match project.project_type {
-            ProjectType::CodeWeb | ProjectType::CodePython => self.launch_web_app(project)?,
+            ProjectType::CodeWeb | ProjectType::CodePython => {
+                self.launch_web_app(project, tunnel)?
+            }
             ProjectType::CodeCli => self.launch_cli(project)?,
             ProjectType::Novel => self.launch_prose_reader(project)?,
             ProjectType::Screenplay => self.launch_fountain_viewer(project)?,
@@ -85,12 +318,25 @@ impl PreviewManager {
             _ => self.launch_generic(project)?,
         };
 
+        crate::metrics::record_preview_started(session.view_type.metric_label());
+
         self.current_session = Some(session.clone());
+        self.watched_project = Some(project.clone());
+
+        if watch {
+            if let Err(e) = self.start_watching(&project.path, app) {
+                eprintln!("Failed to start preview file watcher: {}", e);
+            }
+        }
+
         Ok(session)
     }
 
     /// Stop the current preview.
     pub fn stop(&mut self) -> Result<(), String> {
+        if let Some(session) = &self.current_session {
+            crate::metrics::record_preview_stopped(session.view_type.metric_label());
+        }
         if let Some(mut process) = self.server_process.take() {
             process.kill().map_err(|e| {
                 SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
@@ -98,86 +344,125 @@ impl PreviewManager {
                     .to_json()
             })?;
         }
+        if let Some(mut tunnel) = self.tunnel_process.take() {
+            let _ = tunnel.kill();
+        }
+        if let Some(handle) = self.watch_handle.take() {
+            let _ = handle.stop_tx.send(());
+        }
+        self.watched_project = None;
         self.current_session = None;
         Ok(())
     }
 
-    /// Launch a web application preview.
-    fn launch_web_app(&mut self, project: &Project) -> Result<PreviewSession, String> {
-        let framework = self.detect_framework(&project.path);
-        let port = find_free_port()?;
-
-        let process = match framework {
-            Framework::Flask => Command::new("python")
-                .args(["-m", "flask", "run", "--port", &port.to_string()])
-                .current_dir(&project.path)
-                .env("FLASK_APP", "app.py")
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .map_err(|e| {
-                    SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                        .with_hints(vec!["Check if Flask is installed", "Run 'pip install flask'"])
-                        .to_json()
-                })?,
-            Framework::FastAPI => Command::new("uvicorn")
-                .args(["main:app", "--port", &port.to_string()])
-                .current_dir(&project.path)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .map_err(|e| {
-                    SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                        .with_hints(vec!["Check if uvicorn is installed", "Run 'pip install uvicorn'"])
-                        .to_json()
-                })?,
-            Framework::Django => Command::new("python")
-                .args(["manage.py", "runserver", &format!("127.0.0.1:{}", port)])
-                .current_dir(&project.path)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .map_err(|e| {
-                    SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                        .with_hints(vec!["Check if Django is configured", "Run 'python manage.py check'"])
-                        .to_json()
-                })?,
-            Framework::Express => Command::new("npm")
-                .args(["start"])
-                .current_dir(&project.path)
-                .env("PORT", port.to_string())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .map_err(|e| {
-                    SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                        .with_hints(vec!["Check if npm is installed", "Run 'npm install' first"])
-                        .to_json()
-                })?,
-            Framework::Unknown => {
-                // Try generic Python approach
-                Command::new("python")
-                    .args(["app.py"])
-                    .current_dir(&project.path)
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .spawn()
-                    .map_err(|e| {
-                        SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                            .with_hints(vec!["Check if app.py exists", "Check Python is installed"])
-                            .to_json()
-                    })?
+    /// Start a recursive `notify` watcher on `project_path`, debouncing
+    /// bursts of events over `RELOAD_DEBOUNCE` before calling `reload` on a
+    /// dedicated thread.
+    fn start_watching(
+        &mut self,
+        project_path: &std::path::Path,
+        app: AppHandle,
+    ) -> Result<(), String> {
+        let (event_tx, event_rx) = mpsc::channel::<notify::Event>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res {
+                let _ = event_tx.send(event);
             }
+        })
+        .map_err(|e| e.to_string())?;
+
+        watcher
+            .watch(project_path, notify::RecursiveMode::Recursive)
+            .map_err(|e| e.to_string())?;
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let reloading = self.reloading.clone();
+        thread::spawn(move || reload_debounce_loop(app, reloading, event_rx, stop_rx));
+
+        self.watch_handle = Some(PreviewWatchHandle {
+            _watcher: watcher,
+            stop_tx,
+        });
+        Ok(())
+    }
+
+    /// Re-run the current session in place: restart the server process on
+    /// the same port for `WebView`/`RemoteWebView` (the tunnel, if any, is
+    /// left running — its target port hasn't changed), or re-read content
+    /// for `Prose`/`Fountain`/`Dialogue`. A no-op for every other view type,
+    /// and if no session is being watched at all.
+    fn reload(&mut self, app: &AppHandle) -> Result<(), String> {
+        let (Some(project), Some(mut session)) =
+            (self.watched_project.clone(), self.current_session.clone())
+        else {
+            return Ok(());
         };
 
+        match session.view_type {
+            ViewType::WebView | ViewType::RemoteWebView => {
+                let port = session
+                    .port
+                    .ok_or("Preview session has no port to reload")?;
+                if let Some(mut process) = self.server_process.take() {
+                    let _ = process.kill();
+                }
+                let framework = self.detect_framework(&project.path);
+                self.server_process = Some(spawn_framework_process(&framework, &project, port)?);
+            }
+            ViewType::Prose => session.content = Some(self.find_prose_content(&project.path)?),
+            ViewType::Fountain => {
+                session.content = Some(self.find_fountain_content(&project.path)?)
+            }
+            ViewType::Dialogue => {
+                session.content = Some(self.find_dialogue_content(&project.path)?)
+            }
+            ViewType::Terminal | ViewType::Generic => return Ok(()),
+        }
+
+        self.current_session = Some(session.clone());
+        let _ = app.emit("preview-reloaded", PreviewReloadedEvent { session });
+        Ok(())
+    }
+
+    /// Launch a web application preview. When `tunnel` is set, also starts
+    /// an outbound tunnel pointed at the app's port and returns its public
+    /// URL alongside the local one; a tunnel that fails to come up in time
+    /// doesn't fail the whole preview, it just falls back to the local URL.
+    fn launch_web_app(
+        &mut self,
+        project: &Project,
+        tunnel: Option<TunnelConfig>,
+    ) -> Result<PreviewSession, String> {
+        let framework = self.detect_framework(&project.path);
+        let port = find_free_port()?;
+        let spawn_started = Instant::now();
+        let process = spawn_framework_process(&framework, project, port)?;
+        crate::metrics::record_preview_startup_latency(spawn_started.elapsed().as_secs_f64());
+
         self.server_process = Some(process);
 
+        let local_url = format!("http://localhost:{}", port);
+        let (view_type, public_url) = match tunnel {
+            Some(config) => match DefaultTunnelProvider::new(config).start(port) {
+                Ok((child, url)) => {
+                    self.tunnel_process = Some(child);
+                    (ViewType::RemoteWebView, Some(url))
+                }
+                Err(e) => {
+                    eprintln!("Tunnel setup failed, falling back to local preview: {}", e);
+                    (ViewType::WebView, None)
+                }
+            },
+            None => (ViewType::WebView, None),
+        };
+
         Ok(PreviewSession {
-            url: Some(format!("http://localhost:{}", port)),
+            url: Some(local_url),
             content: None,
-            view_type: ViewType::WebView,
+            view_type,
             command: None,
             port: Some(port),
+            public_url,
         })
     }
 
@@ -197,6 +482,7 @@ impl PreviewManager {
             view_type: ViewType::Terminal,
             command: Some(cmd),
             port: None,
+            public_url: None,
         })
     }
 
@@ -211,6 +497,7 @@ impl PreviewManager {
             view_type: ViewType::Prose,
             command: None,
             port: None,
+            public_url: None,
         })
     }
 
@@ -224,6 +511,7 @@ impl PreviewManager {
             view_type: ViewType::Fountain,
             command: None,
             port: None,
+            public_url: None,
         })
     }
 
@@ -237,6 +525,7 @@ impl PreviewManager {
             view_type: ViewType::Dialogue,
             command: None,
             port: None,
+            public_url: None,
         })
     }
 
@@ -244,10 +533,15 @@ impl PreviewManager {
     fn launch_generic(&self, project: &Project) -> Result<PreviewSession, String> {
         Ok(PreviewSession {
             url: None,
-            content: Some(format!("Project: {}\nPath: {}", project.name, project.path.display())),
+            content: Some(format!(
+                "Project: {}\nPath: {}",
+                project.name,
+                project.path.display()
+            )),
             view_type: ViewType::Generic,
             command: None,
             port: None,
+            public_url: None,
         })
     }
 
@@ -302,7 +596,11 @@ impl PreviewManager {
         if let Ok(entries) = std::fs::read_dir(path) {
             for entry in entries.flatten() {
                 let entry_path = entry.path();
-                if entry_path.extension().map(|e| e == "fountain").unwrap_or(false) {
+                if entry_path
+                    .extension()
+                    .map(|e| e == "fountain")
+                    .unwrap_or(false)
+                {
                     if let Ok(content) = std::fs::read_to_string(&entry_path) {
                         return Ok(content);
                     }
@@ -336,6 +634,87 @@ impl Default for PreviewManager {
     }
 }
 
+/// Spawn `project`'s web server on `port` for the given `framework`, shared
+/// by `launch_web_app` (fresh start) and `PreviewManager::reload` (hot
+/// restart on file change, reusing the same port).
+fn spawn_framework_process(
+    framework: &Framework,
+    project: &Project,
+    port: u16,
+) -> Result<Child, String> {
+    match framework {
+        Framework::Flask => Command::new("python")
+            .args(["-m", "flask", "run", "--port", &port.to_string()])
+            .current_dir(&project.path)
+            .env("FLASK_APP", "app.py")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                    .with_hints(vec![
+                        "Check if Flask is installed",
+                        "Run 'pip install flask'",
+                    ])
+                    .to_json()
+            }),
+        Framework::FastAPI => Command::new("uvicorn")
+            .args(["main:app", "--port", &port.to_string()])
+            .current_dir(&project.path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                    .with_hints(vec![
+                        "Check if uvicorn is installed",
+                        "Run 'pip install uvicorn'",
+                    ])
+                    .to_json()
+            }),
+        Framework::Django => Command::new("python")
+            .args(["manage.py", "runserver", &format!("127.0.0.1:{}", port)])
+            .current_dir(&project.path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                    .with_hints(vec![
+                        "Check if Django is configured",
+                        "Run 'python manage.py check'",
+                    ])
+                    .to_json()
+            }),
+        Framework::Express => Command::new("npm")
+            .args(["start"])
+            .current_dir(&project.path)
+            .env("PORT", port.to_string())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                    .with_hints(vec!["Check if npm is installed", "Run 'npm install' first"])
+                    .to_json()
+            }),
+        Framework::Unknown => {
+            // Try generic Python approach
+            Command::new("python")
+                .args(["app.py"])
+                .current_dir(&project.path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .map_err(|e| {
+                    SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                        .with_hints(vec!["Check if app.py exists", "Check Python is installed"])
+                        .to_json()
+                })
+        }
+    }
+}
+
 /// Find a free TCP port.
 fn find_free_port() -> Result<u16, String> {
     let listener = TcpListener::bind("127.0.0.1:0").map_err(|e| {
@@ -343,10 +722,129 @@ fn find_free_port() -> Result<u16, String> {
             .with_hints(vec!["Check if the port range is available"])
             .to_json()
     })?;
-    let port = listener.local_addr().map_err(|e| {
-        SunwellError::from_error(ErrorCode::NetworkUnreachable, e)
-            .with_hints(vec!["Network configuration issue"])
-            .to_json()
-    })?.port();
+    let port = listener
+        .local_addr()
+        .map_err(|e| {
+            SunwellError::from_error(ErrorCode::NetworkUnreachable, e)
+                .with_hints(vec!["Network configuration issue"])
+                .to_json()
+        })?
+        .port();
     Ok(port)
 }
+
+/// Debounce loop for a preview's file watcher, run on a dedicated thread.
+/// Unlike `file_watcher`'s debounce loop, which tracks per-path change kinds
+/// for a `file-tree-changed` diff, this only cares that *something* changed
+/// since the last reload — so any event occurring within `RELOAD_DEBOUNCE`
+/// of the last just resets a single deadline.
+fn reload_debounce_loop(
+    app: AppHandle,
+    reloading: Arc<AtomicBool>,
+    event_rx: mpsc::Receiver<notify::Event>,
+    stop_rx: mpsc::Receiver<()>,
+) {
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            break;
+        }
+
+        match event_rx.recv_timeout(RELOAD_DEBOUNCE) {
+            Ok(_event) => {
+                deadline = Some(Instant::now() + RELOAD_DEBOUNCE);
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if let Some(d) = deadline {
+                    if Instant::now() >= d {
+                        deadline = None;
+                        trigger_reload(&app, &reloading);
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+/// Reach back into the shared `PreviewManager` from the watcher's own
+/// thread (outside any Tauri command context) to run the actual reload,
+/// guarded so an overlapping burst of events can't start a second reload
+/// while one is already restarting the server.
+fn trigger_reload(app: &AppHandle, reloading: &Arc<AtomicBool>) {
+    if reloading.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let state = app.state::<crate::commands::AppState>();
+    let result = match state.preview.lock() {
+        Ok(mut preview) => preview.reload(app),
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("Preview hot reload failed: {}", e);
+    }
+
+    reloading.store(false, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tunnel_url_finds_first_https_token() {
+        let line = "Your tunnel is live at https://example.trycloudflare.com ready";
+        assert_eq!(
+            parse_tunnel_url(line),
+            Some("https://example.trycloudflare.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_tunnel_url_returns_none_without_https() {
+        assert_eq!(parse_tunnel_url("still starting up..."), None);
+    }
+
+    #[test]
+    fn test_wait_for_tunnel_url_returns_parsed_url() {
+        let stdout = std::io::Cursor::new(b"connecting...\nhttps://foo.ngrok.io\n".to_vec());
+        let result = wait_for_tunnel_url(stdout, Duration::from_secs(1));
+        assert_eq!(result, Ok("https://foo.ngrok.io".to_string()));
+    }
+
+    #[test]
+    fn test_wait_for_tunnel_url_times_out_without_url() {
+        let stdout = std::io::Cursor::new(b"connecting...\nstill no url here\n".to_vec());
+        let result = wait_for_tunnel_url(stdout, Duration::from_millis(50));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tunnel_config_validate_accepts_known_provider_and_flags() {
+        let config = TunnelConfig {
+            provider: "cloudflared".to_string(),
+            args: vec!["--url".to_string(), "--no-autoupdate".to_string()],
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_tunnel_config_validate_rejects_unknown_provider() {
+        let config = TunnelConfig {
+            provider: "bash".to_string(),
+            args: vec![],
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_tunnel_config_validate_rejects_non_flag_arg() {
+        let config = TunnelConfig {
+            provider: "ngrok".to_string(),
+            args: vec!["; rm -rf /".to_string()],
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -3,10 +3,16 @@
 //! Detects project type from files and structure to enable
 //! adaptive UI layouts.
 
+use crate::indexer_rules::{build_ruleset, RuleSet};
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 /// Type of project being worked on.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -54,6 +60,11 @@ pub struct RecentProject {
     pub project_type: ProjectType,
     pub description: String,
     pub last_opened: u64,
+    /// User-assigned labels (e.g. "work", "archived", "client-x") for
+    /// grouping projects on the home screen. Absent in older `recent.json`
+    /// files, so it defaults to empty on load.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Detects project type from files and structure.
@@ -223,6 +234,122 @@ impl ProjectDetector {
             .map(|entries| entries.count())
             .unwrap_or(0)
     }
+
+    /// Deep variant of `detect`: runs the full parallel `walk` first, and
+    /// falls back to per-extension ratios (e.g. mostly `.py` vs mostly
+    /// `.md`) when the fast marker-file check in `detect_type` can't tell
+    /// anything apart and lands on `General`. Marker-based detection still
+    /// wins when it has an answer — `.py`-heavy repos with a `Cargo.toml`
+    /// at the root are still Rust projects.
+    pub async fn detect_deep(&self, path: &Path) -> Result<Project, String> {
+        if !path.exists() {
+            return Err(format!("Path does not exist: {}", path.display()));
+        }
+
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("Untitled").to_string();
+
+        let mut project_type = self.detect_type(path);
+        let walk_result = self.walk(path, |_, _| {}).await;
+        if project_type == ProjectType::General {
+            if let Some(from_extensions) = detect_type_from_counts(&walk_result.per_type_counts) {
+                project_type = from_extensions;
+            }
+        }
+
+        let id = generate_project_id(path);
+
+        Ok(Project {
+            id,
+            path: path.to_path_buf(),
+            name,
+            project_type,
+            description: None,
+            files_count: walk_result.files_count as usize,
+            last_modified: None,
+        })
+    }
+
+    /// Walk `root` recursively on a bounded pool of worker tasks (one per
+    /// directory in flight, capped at the machine's available parallelism
+    /// to avoid exhausting file descriptors), filtering every entry through
+    /// the same gitignore-aware rules `indexing.rs` uses, and return
+    /// aggregate file counts, byte totals, per-extension counts, and a
+    /// per-directory size rollup. `on_progress(files_so_far, bytes_so_far)`
+    /// is called after every file so a caller (e.g. `indexing::run_build`)
+    /// can mirror live numbers into `IndexStatus` while the walk runs.
+    ///
+    /// Modeled on Spacedrive's task-system indexer: workers pop directories
+    /// off a shared work set (here, a `tokio::task::JoinSet` that each
+    /// worker feeds by pushing its subdirectories back in) rather than
+    /// walking depth-first on one task, so a wide directory tree scans in
+    /// roughly `dirs / cores` wall-clock instead of `dirs * avg_dir_time`.
+    /// Symlink cycles are broken by tracking visited canonicalized
+    /// directories; a directory's size is only finalized (and rolled up
+    /// into its parent) once every one of its subdirectories has finished.
+    pub async fn walk(
+        &self,
+        root: &Path,
+        on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> WalkResult {
+        let root = root.to_path_buf();
+        let rule_set = Arc::new(build_ruleset(&root, &[]).unwrap_or_default());
+        let visited = Arc::new(Mutex::new(HashSet::new()));
+        let agg = Arc::new(Mutex::new(HashMap::<PathBuf, DirAgg>::new()));
+        let dir_sizes = Arc::new(Mutex::new(HashMap::<PathBuf, u64>::new()));
+        let per_type_counts = Arc::new(Mutex::new(HashMap::<String, u64>::new()));
+        let files_count = Arc::new(AtomicU64::new(0));
+        let total_bytes = Arc::new(AtomicU64::new(0));
+        let on_progress = Arc::new(on_progress);
+
+        let permits = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+        let semaphore = Arc::new(Semaphore::new(permits));
+
+        agg.lock().unwrap().insert(
+            root.clone(),
+            DirAgg { parent: None, bytes: 0, pending_children: 0, scan_done: false },
+        );
+
+        let mut join_set = JoinSet::new();
+        join_set.spawn(scan_dir(
+            root.clone(),
+            root.clone(),
+            rule_set.clone(),
+            visited.clone(),
+            semaphore.clone(),
+            agg.clone(),
+            dir_sizes.clone(),
+            per_type_counts.clone(),
+            files_count.clone(),
+            total_bytes.clone(),
+            on_progress.clone(),
+        ));
+
+        while let Some(joined) = join_set.join_next().await {
+            let Ok(subdirs) = joined else { continue };
+            for subdir in subdirs {
+                join_set.spawn(scan_dir(
+                    root.clone(),
+                    subdir,
+                    rule_set.clone(),
+                    visited.clone(),
+                    semaphore.clone(),
+                    agg.clone(),
+                    dir_sizes.clone(),
+                    per_type_counts.clone(),
+                    files_count.clone(),
+                    total_bytes.clone(),
+                    on_progress.clone(),
+                ));
+            }
+        }
+
+        WalkResult {
+            files_count: files_count.load(Ordering::Relaxed),
+            total_bytes: total_bytes.load(Ordering::Relaxed),
+            per_type_counts: Arc::try_unwrap(per_type_counts).map(|m| m.into_inner().unwrap()).unwrap_or_default(),
+            dir_sizes: Arc::try_unwrap(dir_sizes).map(|m| m.into_inner().unwrap()).unwrap_or_default(),
+        }
+    }
 }
 
 impl Default for ProjectDetector {
@@ -231,9 +358,286 @@ impl Default for ProjectDetector {
     }
 }
 
+// =============================================================================
+// Parallel Workspace Walker
+// =============================================================================
+
+/// Aggregate result of `ProjectDetector::walk`.
+#[derive(Debug, Clone, Default)]
+pub struct WalkResult {
+    pub files_count: u64,
+    pub total_bytes: u64,
+    /// Lower-cased file extension (no leading dot) to file count.
+    pub per_type_counts: HashMap<String, u64>,
+    /// Total bytes under each directory, including itself — present for
+    /// every directory the walk descended into.
+    pub dir_sizes: HashMap<PathBuf, u64>,
+}
+
+/// In-flight bookkeeping for one directory's size rollup, keyed by path in
+/// a shared `Mutex<HashMap<..>>`. A directory is only removed (and its
+/// final size recorded into `dir_sizes`) once `scan_done` is true and every
+/// child it spawned has itself finished.
+#[derive(Debug, Default)]
+struct DirAgg {
+    parent: Option<PathBuf>,
+    /// Own files' bytes, plus finished children's subtree bytes as they
+    /// roll in.
+    bytes: u64,
+    pending_children: usize,
+    scan_done: bool,
+}
+
+/// Classify a project from per-extension file counts when marker-file
+/// detection found nothing — e.g. a plain directory of `.py` scripts with
+/// no `pyproject.toml`. Requires the dominant extension to account for a
+/// clear majority of counted files, so a handful of stray scripts in an
+/// otherwise-mixed directory don't get misclassified.
+fn detect_type_from_counts(per_type_counts: &HashMap<String, u64>) -> Option<ProjectType> {
+    let total: u64 = per_type_counts.values().sum();
+    if total == 0 {
+        return None;
+    }
+
+    let (dominant_ext, dominant_count) = per_type_counts.iter().max_by_key(|(_, count)| **count)?;
+    if (*dominant_count as f64) / (total as f64) < 0.5 {
+        return None;
+    }
+
+    match dominant_ext.as_str() {
+        "py" => Some(ProjectType::CodePython),
+        "js" | "jsx" | "ts" | "tsx" => Some(ProjectType::CodeJs),
+        "rs" => Some(ProjectType::CodeRust),
+        "go" => Some(ProjectType::CodeGo),
+        "fountain" => Some(ProjectType::Screenplay),
+        "yarn" | "ink" => Some(ProjectType::GameDialogue),
+        _ => None,
+    }
+}
+
+/// Finalize `dir`'s rollup and, if that leaves its parent with no more
+/// pending children, bubble the finalize up the tree. Only ever called
+/// once per directory, either directly by `scan_dir` (leaf, unreadable, or
+/// symlink-cycle directories) or from within this function as it bubbles
+/// upward.
+fn finish_dir(agg: &Mutex<HashMap<PathBuf, DirAgg>>, dir_sizes: &Mutex<HashMap<PathBuf, u64>>, dir: PathBuf) {
+    let mut current = dir;
+    loop {
+        let (bytes, parent) = {
+            let mut a = agg.lock().unwrap();
+            let entry = a.remove(&current).expect("directory registered before it was finished");
+            (entry.bytes, entry.parent)
+        };
+        dir_sizes.lock().unwrap().insert(current.clone(), bytes);
+
+        let Some(parent) = parent else { break };
+        let parent_ready = {
+            let mut a = agg.lock().unwrap();
+            let entry = a.get_mut(&parent).expect("parent registered before its child");
+            entry.bytes += bytes;
+            entry.pending_children -= 1;
+            entry.pending_children == 0 && entry.scan_done
+        };
+        if !parent_ready {
+            break;
+        }
+        current = parent;
+    }
+}
+
+/// Scan one directory: read its entries, filter through `rule_set`,
+/// accumulate file counts/bytes/extensions, and return the accepted
+/// subdirectories for the caller (`ProjectDetector::walk`'s driving loop)
+/// to spawn as further `scan_dir` tasks. Finalizes this directory's own
+/// rollup immediately if it has no accepted subdirectories.
+#[allow(clippy::too_many_arguments)]
+async fn scan_dir(
+    root: PathBuf,
+    dir: PathBuf,
+    rule_set: Arc<RuleSet>,
+    visited: Arc<Mutex<HashSet<PathBuf>>>,
+    semaphore: Arc<Semaphore>,
+    agg: Arc<Mutex<HashMap<PathBuf, DirAgg>>>,
+    dir_sizes: Arc<Mutex<HashMap<PathBuf, u64>>>,
+    per_type_counts: Arc<Mutex<HashMap<String, u64>>>,
+    files_count: Arc<AtomicU64>,
+    total_bytes: Arc<AtomicU64>,
+    on_progress: Arc<impl Fn(u64, u64) + Send + Sync + 'static>,
+) -> Vec<PathBuf> {
+    let _permit = semaphore.acquire_owned().await.expect("walker semaphore is never closed");
+
+    let canonical = dir.canonicalize().unwrap_or_else(|_| dir.clone());
+    let already_visited = !visited.lock().unwrap().insert(canonical);
+    if already_visited {
+        finish_dir(&agg, &dir_sizes, dir);
+        return Vec::new();
+    }
+
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        finish_dir(&agg, &dir_sizes, dir);
+        return Vec::new();
+    };
+    let entries: Vec<_> = entries.flatten().collect();
+
+    let dir_entry_names: Vec<String> = entries
+        .iter()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| e.file_name().to_str().map(str::to_string))
+        .collect();
+
+    let mut own_bytes = 0u64;
+    let mut accepted_subdirs = Vec::new();
+
+    for entry in &entries {
+        let path = entry.path();
+        let is_dir = path.is_dir();
+        let relative = path.strip_prefix(&root).unwrap_or(&path);
+
+        if !rule_set.is_indexable(relative, is_dir, Some(&dir_entry_names)) {
+            continue;
+        }
+
+        if is_dir {
+            accepted_subdirs.push(path);
+        } else if let Ok(metadata) = entry.metadata() {
+            let size = metadata.len();
+            own_bytes += size;
+            total_bytes.fetch_add(size, Ordering::Relaxed);
+            let files_so_far = files_count.fetch_add(1, Ordering::Relaxed) + 1;
+
+            if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+                *per_type_counts.lock().unwrap().entry(ext.to_lowercase()).or_insert(0) += 1;
+            }
+
+            on_progress(files_so_far, total_bytes.load(Ordering::Relaxed));
+        }
+    }
+
+    {
+        let mut a = agg.lock().unwrap();
+        for subdir in &accepted_subdirs {
+            a.entry(subdir.clone())
+                .or_insert_with(|| DirAgg { parent: Some(dir.clone()), ..Default::default() });
+        }
+        let entry = a.entry(dir.clone()).or_default();
+        entry.bytes = own_bytes;
+        entry.pending_children = accepted_subdirs.len();
+        entry.scan_done = true;
+    }
+
+    if accepted_subdirs.is_empty() {
+        finish_dir(&agg, &dir_sizes, dir);
+    }
+
+    accepted_subdirs
+}
+
+// =============================================================================
+// Monorepo Enumeration
+// =============================================================================
+
+/// How far down `enumerate_projects` will walk below the root.
+const ENUMERATE_MAX_DEPTH: usize = 4;
+
+/// Directories never descended into while enumerating a monorepo —
+/// dependency/build output, plus Sunwell Studio's own directories (already
+/// excluded from workspace detection in `workspace.rs`).
+const ENUMERATE_SKIP_DIRS: &[&str] =
+    &["node_modules", "target", ".git", "src-tauri", "studio", "sunwell"];
+
+/// Marker files that indicate a directory is its own package.
+const PROJECT_MARKER_FILES: &[&str] =
+    &["package.json", "Cargo.toml", "pyproject.toml", "setup.py", "go.mod"];
+
+/// Lockfiles that indicate a package is independently installable rather
+/// than just inheriting a parent workspace's lockfile.
+const PROJECT_LOCKFILES: &[&str] = &[
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Cargo.lock",
+    "poetry.lock",
+    "go.sum",
+];
+
+/// One package discovered while walking a monorepo root downward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedProject {
+    pub path: PathBuf,
+    pub project_type: ProjectType,
+    /// Suggested working directory for running this package, relative to
+    /// the root that was walked.
+    pub working_dir: String,
+    /// Confidence this is an independently runnable package. Nested
+    /// packages that lack their own lockfile are down-weighted, since they
+    /// likely just inherit a parent workspace's.
+    pub confidence: f64,
+}
+
+/// Walk `root` downward (bounded depth, skipping `node_modules`, `target`,
+/// `.git`, and the Studio directories already excluded elsewhere) and
+/// return every discovered package with its detected type and a suggested
+/// working directory relative to `root`.
+pub fn enumerate_projects(root: &Path) -> Vec<DetectedProject> {
+    let mut results = Vec::new();
+    walk_for_projects(root, root, 0, &mut results);
+    results
+}
+
+fn walk_for_projects(root: &Path, dir: &Path, depth: usize, results: &mut Vec<DetectedProject>) {
+    if depth > ENUMERATE_MAX_DEPTH {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    let detector = ProjectDetector::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if dir_name.starts_with('.') || ENUMERATE_SKIP_DIRS.contains(&dir_name) {
+            continue;
+        }
+
+        if PROJECT_MARKER_FILES.iter().any(|marker| path.join(marker).exists()) {
+            let has_own_lockfile = PROJECT_LOCKFILES.iter().any(|lock| path.join(lock).exists());
+            let confidence = if depth == 0 {
+                1.0
+            } else if has_own_lockfile {
+                0.9
+            } else {
+                0.5
+            };
+
+            let working_dir = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+
+            results.push(DetectedProject {
+                path: path.clone(),
+                project_type: detector.detect_type(&path),
+                working_dir,
+                confidence,
+            });
+        }
+
+        walk_for_projects(root, &path, depth + 1, results);
+    }
+}
+
 /// Generate a stable project ID from the path.
 /// Uses a hash of the absolute path for consistency.
-fn generate_project_id(path: &Path) -> String {
+pub(crate) fn generate_project_id(path: &Path) -> String {
     let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
     let path_str = canonical.to_string_lossy();
     
@@ -4,6 +4,8 @@
 //! `schemas/run-analysis.schema.json`.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Confidence level of the analysis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,6 +41,26 @@ pub struct RunCommand {
     /// When to use this alternative (e.g., "for production build")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub when: Option<String>,
+    /// `when` parsed as a structured, evaluable guard (see [`Condition`]).
+    /// Not serialized — it's re-derived from `when` on construction, and a
+    /// `when` string that fails to parse just leaves this `None`, which
+    /// `RunAnalysis::select` treats as "never matches" rather than an error.
+    #[serde(skip)]
+    pub condition: Option<Condition>,
+}
+
+impl RunCommand {
+    /// Construct a `RunCommand`, parsing `when` into a structured
+    /// [`Condition`] (cargo `cfg(...)`-style grammar) if it parses as one.
+    pub fn new(command: String, description: String, when: Option<String>) -> Self {
+        let condition = when.as_deref().and_then(parse_condition);
+        Self {
+            command,
+            description,
+            when,
+            condition,
+        }
+    }
 }
 
 /// A prerequisite that must be satisfied before running.
@@ -85,7 +107,13 @@ pub struct RunAnalysis {
     
     /// Prerequisites that must be satisfied before running
     pub prerequisites: Vec<Prerequisite>,
-    
+
+    /// Environment variables the project's own config (e.g. cargo's
+    /// `.cargo/config.toml` `[env]` table) says should be set before
+    /// running this command.
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+
     /// Expected port the dev server will run on
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expected_port: Option<u16>,
@@ -124,10 +152,189 @@ pub struct RunSession {
     /// Port the server is running on (if applicable)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub port: Option<u16>,
+    /// URL the app is expected to be reachable at, once its port is detected
+    /// from the process's own output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_url: Option<String>,
     /// Unix timestamp when the session started
     pub started_at: u64,
 }
 
+// =============================================================================
+// Run Guards — Conditional Alternatives
+// =============================================================================
+
+/// A structured, evaluable guard on a [`RunCommand`], parsed from its `when`
+/// string using cargo's `cfg(...)` grammar: bare identifiers, `key = "value"`
+/// predicates, and the `all(...)`/`any(...)`/`not(...)` combinators.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// A bare identifier predicate, e.g. `windows`, `docker`, `ci`.
+    Ident(String),
+    /// A `key = "value"` predicate, e.g. `os = "windows"`, `env = "CI"`.
+    KeyValue(String, String),
+    /// True if every sub-condition is true.
+    All(Vec<Condition>),
+    /// True if any sub-condition is true.
+    Any(Vec<Condition>),
+    /// True if the sub-condition is false.
+    Not(Box<Condition>),
+}
+
+/// Context a [`Condition`] is evaluated against. Gathered once up front so
+/// `Condition::evaluate` stays a pure function with no filesystem or
+/// environment access of its own.
+#[derive(Debug, Clone, Default)]
+pub struct RunContext {
+    /// The current OS, e.g. `"windows"`, `"macos"`, `"linux"`.
+    pub os: String,
+    /// Marker files present in the project root (e.g. `"Dockerfile"`, `".env"`).
+    pub markers: std::collections::HashSet<String>,
+    /// Environment variable names that are currently set.
+    pub env_set: std::collections::HashSet<String>,
+}
+
+impl Condition {
+    /// Evaluate this condition against `ctx`. Side-effect-free: looks up
+    /// only what `ctx` already gathered.
+    pub fn evaluate(&self, ctx: &RunContext) -> bool {
+        match self {
+            Condition::Ident(name) => match name.as_str() {
+                "windows" => ctx.os == "windows",
+                "macos" => ctx.os == "macos",
+                "linux" => ctx.os == "linux",
+                "unix" => ctx.os == "linux" || ctx.os == "macos",
+                "ci" => ctx.env_set.contains("CI"),
+                other => ctx.markers.contains(other) || ctx.env_set.contains(other),
+            },
+            Condition::KeyValue(key, value) => match key.as_str() {
+                "os" => ctx.os == *value,
+                "marker" => ctx.markers.contains(value),
+                "env" => ctx.env_set.contains(value),
+                _ => false,
+            },
+            Condition::All(parts) => parts.iter().all(|c| c.evaluate(ctx)),
+            Condition::Any(parts) => parts.iter().any(|c| c.evaluate(ctx)),
+            Condition::Not(inner) => !inner.evaluate(ctx),
+        }
+    }
+}
+
+/// Parse a guard string with cargo's `cfg(...)` grammar. Returns `None` on
+/// anything that doesn't parse cleanly — callers should treat that as "never
+/// matches" rather than erroring the whole analysis.
+pub fn parse_condition(input: &str) -> Option<Condition> {
+    let mut parser = ConditionParser {
+        chars: input.chars().peekable(),
+    };
+    let condition = parser.parse_expr()?;
+    parser.skip_ws();
+    if parser.chars.next().is_some() {
+        return None; // trailing garbage after a complete expression
+    }
+    Some(condition)
+}
+
+struct ConditionParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> ConditionParser<'a> {
+    fn skip_ws(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Option<()> {
+        self.skip_ws();
+        if self.chars.peek() == Some(&ch) {
+            self.chars.next();
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn parse_ident(&mut self) -> Option<String> {
+        self.skip_ws();
+        let mut ident = String::new();
+        while matches!(self.chars.peek(), Some(c) if c.is_alphanumeric() || *c == '_' || *c == '-') {
+            ident.push(self.chars.next().unwrap());
+        }
+        if ident.is_empty() {
+            None
+        } else {
+            Some(ident)
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => break,
+                c => value.push(c),
+            }
+        }
+        Some(value)
+    }
+
+    fn parse_expr(&mut self) -> Option<Condition> {
+        let name = self.parse_ident()?;
+
+        if matches!(name.as_str(), "all" | "any" | "not") {
+            self.expect('(')?;
+            let mut parts = vec![self.parse_expr()?];
+            self.skip_ws();
+            while self.chars.peek() == Some(&',') {
+                self.chars.next();
+                parts.push(self.parse_expr()?);
+                self.skip_ws();
+            }
+            self.expect(')')?;
+
+            return match name.as_str() {
+                "all" => Some(Condition::All(parts)),
+                "any" => Some(Condition::Any(parts)),
+                "not" if parts.len() == 1 => {
+                    Some(Condition::Not(Box::new(parts.into_iter().next().unwrap())))
+                }
+                _ => None, // `not(...)` with != 1 argument
+            };
+        }
+
+        self.skip_ws();
+        if self.chars.peek() == Some(&'=') {
+            self.chars.next();
+            self.skip_ws();
+            let value = self.parse_string()?;
+            Some(Condition::KeyValue(name, value))
+        } else {
+            Some(Condition::Ident(name))
+        }
+    }
+}
+
+impl RunAnalysis {
+    /// Select the run command to use for `ctx`: the first alternative whose
+    /// guard condition evaluates true, in list order, or the primary command
+    /// if none match. The primary command isn't itself stored as a
+    /// `RunCommand` (it's flattened onto `RunAnalysis` as `command`/
+    /// `command_description`), so the fallback is synthesized as one.
+    pub fn select(&self, ctx: &RunContext) -> RunCommand {
+        for alt in &self.alternatives {
+            if let Some(condition) = &alt.condition {
+                if condition.evaluate(ctx) {
+                    return alt.clone();
+                }
+            }
+        }
+        RunCommand::new(self.command.clone(), self.command_description.clone(), None)
+    }
+}
+
 // =============================================================================
 // Command Safety Validation
 // =============================================================================
@@ -156,46 +363,385 @@ const SAFE_COMMAND_PREFIXES: &[&str] = &[
     "mix", "elixir",
     // Java
     "java", "javac",
+    // Version control (workspace cloning)
+    "git",
 ];
 
-/// Dangerous patterns that should never appear in commands.
-const DANGEROUS_PATTERNS: &[&str] = &[
-    "rm ", "rm\t", "rmdir",
-    "sudo", "su ",
-    "&&", "||", ";", "|",
-    ">", "<", ">>", "<<",
-    "`", "$(", "${",
-    "eval", "exec", "source",
-    "curl ", "wget ",
-    "chmod", "chown",
-    "kill", "pkill",
-];
+/// One token from a shell-lexed command line.
+#[derive(Debug, Clone, PartialEq)]
+struct ShellToken {
+    /// Literal value with quotes stripped and escapes resolved.
+    text: String,
+    /// Whether this token *is* a bare, unquoted metacharacter sequence
+    /// (e.g. `&&`, `;`, `>`) rather than a regular argument.
+    is_unquoted_metachar: bool,
+    /// Whether this token contains a backtick or `$(` that the shell would
+    /// still expand — tracked even inside double quotes, since double
+    /// quotes don't suppress command substitution.
+    has_unsafe_expansion: bool,
+}
+
+/// Split a command string into tokens the way a shell would: respecting
+/// single/double quotes and backslash escapes, and splitting bare shell
+/// metacharacters (`|`, `&&`, `||`, `;`, `>`, `<`, backtick, `$(`) into
+/// their own tokens even without surrounding whitespace.
+///
+/// Returns an error on an unterminated quote or a trailing backslash.
+fn tokenize_shell(command: &str) -> Result<Vec<ShellToken>, String> {
+    let chars: Vec<char> = command.chars().collect();
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut has_unsafe_expansion = false;
+    let mut token_open = false;
+    let mut i = 0;
+
+    fn flush(tokens: &mut Vec<ShellToken>, text: &mut String, has_unsafe_expansion: &mut bool, token_open: &mut bool) {
+        if *token_open {
+            tokens.push(ShellToken {
+                text: std::mem::take(text),
+                is_unquoted_metachar: false,
+                has_unsafe_expansion: *has_unsafe_expansion,
+            });
+            *has_unsafe_expansion = false;
+            *token_open = false;
+        }
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' | '\n' => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                i += 1;
+            }
+            '\'' => {
+                token_open = true;
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('\'') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(ch) => {
+                            text.push(*ch);
+                            i += 1;
+                        }
+                        None => return Err("Unterminated single quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                token_open = true;
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some('\\') if matches!(chars.get(i + 1), Some('"') | Some('\\')) => {
+                            text.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some('`') => {
+                            has_unsafe_expansion = true;
+                            text.push('`');
+                            i += 1;
+                        }
+                        Some('$') if chars.get(i + 1) == Some(&'(') => {
+                            has_unsafe_expansion = true;
+                            text.push_str("$(");
+                            i += 2;
+                        }
+                        Some(ch) => {
+                            text.push(*ch);
+                            i += 1;
+                        }
+                        None => return Err("Unterminated double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                token_open = true;
+                i += 1;
+                match chars.get(i) {
+                    Some(ch) => {
+                        text.push(*ch);
+                        i += 1;
+                    }
+                    None => return Err("Trailing backslash".to_string()),
+                }
+            }
+            '&' if chars.get(i + 1) == Some(&'&') => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token("&&"));
+                i += 2;
+            }
+            '|' if chars.get(i + 1) == Some(&'|') => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token("||"));
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'>') => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token(">>"));
+                i += 2;
+            }
+            '<' if chars.get(i + 1) == Some(&'<') => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token("<<"));
+                i += 2;
+            }
+            '|' | ';' | '>' | '<' | '`' => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token(&c.to_string()));
+                i += 1;
+            }
+            '$' if chars.get(i + 1) == Some(&'(') => {
+                flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+                tokens.push(metachar_token("$("));
+                i += 2;
+            }
+            ch => {
+                token_open = true;
+                text.push(ch);
+                i += 1;
+            }
+        }
+    }
+
+    flush(&mut tokens, &mut text, &mut has_unsafe_expansion, &mut token_open);
+    Ok(tokens)
+}
+
+fn metachar_token(text: &str) -> ShellToken {
+    ShellToken {
+        text: text.to_string(),
+        is_unquoted_metachar: true,
+        has_unsafe_expansion: false,
+    }
+}
+
+/// Whether a token is a leading `NAME=value` environment assignment, e.g.
+/// `FOO=bar` preceding the real binary (as in `FOO=bar npm run dev`).
+fn is_env_assignment(token: &str) -> bool {
+    let Some((name, _)) = token.split_once('=') else {
+        return false;
+    };
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Tokenize and structurally validate a command against an allowlist.
+///
+/// Leading `NAME=value` assignments are allowed and skipped over to find
+/// the real binary, which must be in `allowlist`. Any unquoted shell
+/// metacharacter token, or a backtick/`$(` that survives inside double
+/// quotes, rejects the command. Returns the normalized argv (assignments
+/// included) so callers can spawn without invoking a shell.
+fn validate_against_allowlist(command: &str, allowlist: &[&str]) -> Result<Vec<String>, String> {
+    let tokens = tokenize_shell(command.trim())?;
+
+    if tokens.is_empty() {
+        return Err("Empty command".to_string());
+    }
+
+    for token in &tokens {
+        if token.is_unquoted_metachar {
+            return Err(format!(
+                "Command contains disallowed shell metacharacter: {}",
+                token.text
+            ));
+        }
+        if token.has_unsafe_expansion {
+            return Err(
+                "Command contains a command substitution the shell would still expand"
+                    .to_string(),
+            );
+        }
+    }
+
+    let mut binary = None;
+    for token in &tokens {
+        if binary.is_none() && is_env_assignment(&token.text) {
+            continue;
+        }
+        binary = Some(token.text.clone());
+        break;
+    }
+    let binary = binary.ok_or_else(|| "Empty command".to_string())?;
+
+    if !allowlist.contains(&binary.as_str()) {
+        return Err(format!("Command '{}' not in allowlist", binary));
+    }
+
+    Ok(tokens.into_iter().map(|t| t.text).collect())
+}
 
 /// Validate a command against the safety allowlist.
-/// 
+///
 /// Returns `Ok(())` if valid, `Err(reason)` if invalid.
 pub fn validate_command_safety(command: &str) -> Result<(), String> {
-    let command = command.trim();
-    if command.is_empty() {
+    validate_against_allowlist(command, SAFE_COMMAND_PREFIXES).map(|_| ())
+}
+
+/// Validate a command against the safety allowlist, returning the
+/// normalized argv (quotes stripped, escapes resolved) so the caller can
+/// spawn it directly without invoking a shell.
+pub fn validate_command_safety_argv(command: &str) -> Result<Vec<String>, String> {
+    validate_against_allowlist(command, SAFE_COMMAND_PREFIXES)
+}
+
+/// Split an already-validated command into argv the way a shell would
+/// (respecting quotes and escapes), without re-checking it against an
+/// allowlist. For spawning a command that was already validated (e.g. by
+/// `validate_command_safety_with_config`) via a broader allowlist than the
+/// base one.
+pub fn tokenize_command(command: &str) -> Result<Vec<String>, String> {
+    let tokens = tokenize_shell(command.trim())?;
+    if tokens.is_empty() {
         return Err("Empty command".to_string());
     }
-    
-    let binary = command.split_whitespace().next().unwrap_or("");
-    
-    // Check if binary is in allowlist
-    if !SAFE_COMMAND_PREFIXES.contains(&binary) {
-        return Err(format!("Command '{}' not in allowlist", binary));
+    Ok(tokens.into_iter().map(|t| t.text).collect())
+}
+
+// =============================================================================
+// User-Configurable Allowlist & Aliases
+// =============================================================================
+
+/// User-configurable additions to the command safety system, persisted next
+/// to `recent.json` in `default_config_root()`. Lets a user trust extra
+/// project-local binaries and define named aliases (e.g. `"dev"` expanding
+/// to `"npm run dev"`) — the same mechanism cargo uses for `[alias]` entries
+/// resolved from its config, recast for this crate's run subsystem.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunSafetyConfig {
+    /// Additional binaries to trust beyond `SAFE_COMMAND_PREFIXES`.
+    #[serde(default)]
+    pub extra_safe_commands: Vec<String>,
+    /// Named aliases that expand to a full command before validation.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+}
+
+/// Path to the run-safety config file.
+fn run_safety_config_path() -> PathBuf {
+    crate::workspace::default_config_root().join("run_safety.json")
+}
+
+impl RunSafetyConfig {
+    /// Load the run-safety config from disk, falling back to an empty
+    /// config if it doesn't exist yet or fails to parse.
+    pub fn load() -> Self {
+        let path = run_safety_config_path();
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
     }
-    
-    // Check for dangerous patterns
-    let command_lower = command.to_lowercase();
-    for pattern in DANGEROUS_PATTERNS {
-        if command_lower.contains(pattern) {
-            return Err(format!("Command contains dangerous pattern: {}", pattern.trim()));
+
+    /// Save the run-safety config to disk.
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = run_safety_config_path();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
         }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+
+        std::fs::write(path, content)
+    }
+
+    /// Trust an extra binary beyond `SAFE_COMMAND_PREFIXES`.
+    ///
+    /// The name itself is validated (a single word, no shell metacharacters)
+    /// so this can't be used to add something that is itself a dangerous
+    /// pattern in disguise.
+    pub fn add_safe_command(&mut self, binary: String) -> Result<(), String> {
+        validate_alias_token(&binary)?;
+        if !self.extra_safe_commands.contains(&binary) {
+            self.extra_safe_commands.push(binary);
+        }
+        Ok(())
+    }
+
+    /// Define a named alias that expands to a full command, e.g. `dev` ->
+    /// `npm run dev`.
+    ///
+    /// Both the alias name and the command it expands to are validated: the
+    /// name must be a single safe word, and the expansion must structurally
+    /// validate (against the allowlist as it stands, including any
+    /// `extra_safe_commands` already added) so an alias can't smuggle in
+    /// something like `rm -rf` under a friendly name.
+    pub fn set_alias(&mut self, name: String, command: String) -> Result<(), String> {
+        validate_alias_token(&name)?;
+
+        let mut allowlist: Vec<&str> = SAFE_COMMAND_PREFIXES.to_vec();
+        allowlist.extend(self.extra_safe_commands.iter().map(String::as_str));
+        validate_against_allowlist(&command, &allowlist)?;
+
+        self.aliases.insert(name, command);
+        Ok(())
+    }
+}
+
+/// Validate that a token (alias name or extra-safe-command binary) is a
+/// single safe word — no whitespace, quoting, or shell metacharacters — so
+/// it can't itself carry a metacharacter or subcommand injection.
+fn validate_alias_token(token: &str) -> Result<(), String> {
+    if token.is_empty() || token.split_whitespace().count() != 1 {
+        return Err(format!("'{}' is not a valid alias/command name", token));
+    }
+    let tokenized = tokenize_shell(token)
+        .map_err(|e| format!("'{}' is not a valid alias/command name: {}", token, e))?;
+    match tokenized.as_slice() {
+        [single] if !single.is_unquoted_metachar && !single.has_unsafe_expansion && single.text == token => {
+            Ok(())
+        }
+        _ => Err(format!("'{}' is not a valid alias/command name", token)),
     }
-    
-    Ok(())
+}
+
+/// Expand and validate a command, taking the user's `RunSafetyConfig` into
+/// account.
+///
+/// If the command's first word is a known alias name, it's expanded to its
+/// target command (remaining arguments are preserved after the expansion,
+/// the same way cargo resolves `[alias]` entries) — but the *expanded*
+/// command still has to structurally validate against the allowlist plus
+/// any `extra_safe_commands`. Returns the resolved command to actually run.
+pub fn validate_command_safety_with_config(
+    command: &str,
+    config: &RunSafetyConfig,
+) -> Result<String, String> {
+    let trimmed = command.trim();
+    let mut parts = trimmed.splitn(2, char::is_whitespace);
+    let first_word = parts.next().unwrap_or("");
+    let rest = parts.next().unwrap_or("").trim();
+
+    let resolved = match config.aliases.get(first_word) {
+        Some(expansion) if rest.is_empty() => expansion.clone(),
+        Some(expansion) => format!("{} {}", expansion, rest),
+        None => trimmed.to_string(),
+    };
+
+    let mut allowlist: Vec<&str> = SAFE_COMMAND_PREFIXES.to_vec();
+    allowlist.extend(config.extra_safe_commands.iter().map(String::as_str));
+
+    validate_against_allowlist(&resolved, &allowlist)?;
+    Ok(resolved)
 }
 
 #[cfg(test)]
@@ -238,7 +784,143 @@ mod tests {
         assert!(validate_command_safety("sudo npm run dev").is_err());
         assert!(validate_command_safety("npm run dev; cat /etc/passwd").is_err());
     }
-    
+
+    #[test]
+    fn test_quoted_metacharacters_allowed() {
+        // The same characters inside a quoted argument are just an argument.
+        assert!(validate_command_safety(r#"npm run test -- --grep "a && b""#).is_ok());
+        assert!(validate_command_safety("npm run test -- 'a; b'").is_ok());
+    }
+
+    #[test]
+    fn test_unquoted_metacharacters_rejected_without_spaces() {
+        assert!(validate_command_safety("npm run dev&&rm -rf /").is_err());
+    }
+
+    #[test]
+    fn test_command_substitution_inside_double_quotes_rejected() {
+        assert!(validate_command_safety(r#"npm run "$(rm -rf /)""#).is_err());
+        assert!(validate_command_safety(r#"npm run "`rm -rf /`""#).is_err());
+    }
+
+    #[test]
+    fn test_command_substitution_inside_single_quotes_allowed() {
+        // Single quotes are fully literal — the shell never expands them.
+        assert!(validate_command_safety("npm run test -- '$(not expanded)'").is_ok());
+    }
+
+    #[test]
+    fn test_leading_env_assignment_allowed() {
+        assert!(validate_command_safety("PORT=3000 npm run dev").is_ok());
+        assert!(validate_command_safety("FOO=1 BAR=2 cargo run").is_ok());
+    }
+
+    #[test]
+    fn test_unterminated_quote_rejected() {
+        assert!(validate_command_safety("npm run dev --grep \"unterminated").is_err());
+        assert!(validate_command_safety("npm run dev --grep 'unterminated").is_err());
+    }
+
+    #[test]
+    fn test_argv_normalization() {
+        let argv = validate_command_safety_argv(r#"npm run test -- "has space""#).unwrap();
+        assert_eq!(argv, vec!["npm", "run", "test", "--", "has space"]);
+    }
+
+    #[test]
+    fn test_alias_expansion_and_validation() {
+        let mut config = RunSafetyConfig::default();
+        config.set_alias("dev".to_string(), "npm run dev".to_string()).unwrap();
+
+        let resolved = validate_command_safety_with_config("dev", &config).unwrap();
+        assert_eq!(resolved, "npm run dev");
+
+        // An alias name that is itself unsafe is rejected at definition time.
+        assert!(config.clone().set_alias("bad; rm".to_string(), "npm run dev".to_string()).is_err());
+        // An alias whose expansion is unsafe is rejected at definition time.
+        assert!(config
+            .clone()
+            .set_alias("evil".to_string(), "npm run dev && rm -rf /".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_condition_parses_identifiers_and_key_values() {
+        assert_eq!(parse_condition("windows"), Some(Condition::Ident("windows".to_string())));
+        assert_eq!(
+            parse_condition(r#"os = "macos""#),
+            Some(Condition::KeyValue("os".to_string(), "macos".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_condition_parses_combinators() {
+        let parsed = parse_condition(r#"all(unix, not(env = "CI"))"#).unwrap();
+        assert_eq!(
+            parsed,
+            Condition::All(vec![
+                Condition::Ident("unix".to_string()),
+                Condition::Not(Box::new(Condition::KeyValue("env".to_string(), "CI".to_string()))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_condition_unparseable_guard_is_none() {
+        assert_eq!(parse_condition(""), None);
+        assert_eq!(parse_condition("os ="), None);
+        assert_eq!(parse_condition("all(windows"), None);
+        assert_eq!(parse_condition("if you prefer Rust"), None);
+    }
+
+    #[test]
+    fn test_condition_evaluate() {
+        let ctx = RunContext {
+            os: "windows".to_string(),
+            markers: ["Dockerfile".to_string()].into_iter().collect(),
+            env_set: ["CI".to_string()].into_iter().collect(),
+        };
+
+        assert!(parse_condition(r#"os = "windows""#).unwrap().evaluate(&ctx));
+        assert!(parse_condition("docker").unwrap().evaluate(&ctx));
+        assert!(parse_condition(r#"any(macos, ci)"#).unwrap().evaluate(&ctx));
+        assert!(!parse_condition(r#"all(windows, not(ci))"#).unwrap().evaluate(&ctx));
+    }
+
+    #[test]
+    fn test_select_falls_back_to_primary_when_no_guard_matches() {
+        let analysis = RunAnalysis {
+            project_type: "Rust app".to_string(),
+            framework: None,
+            language: "Rust".to_string(),
+            command: "cargo run".to_string(),
+            command_description: "Build and run".to_string(),
+            working_dir: None,
+            alternatives: vec![RunCommand::new(
+                "docker compose up".to_string(),
+                "Run in a container".to_string(),
+                Some("docker".to_string()),
+            )],
+            prerequisites: vec![],
+            env: vec![],
+            expected_port: None,
+            expected_url: None,
+            confidence: Confidence::Medium,
+            source: Source::Heuristic,
+            from_cache: false,
+            user_saved: false,
+        };
+
+        let ctx = RunContext::default();
+        assert_eq!(analysis.select(&ctx).command, "cargo run");
+
+        let ctx_with_docker = RunContext {
+            markers: ["docker".to_string()].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(analysis.select(&ctx_with_docker).command, "docker compose up");
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let analysis = RunAnalysis {
@@ -250,6 +932,7 @@ mod tests {
             working_dir: None,
             alternatives: vec![],
             prerequisites: vec![],
+            env: vec![],
             expected_port: Some(5173),
             expected_url: Some("http://localhost:5173".to_string()),
             confidence: Confidence::High,
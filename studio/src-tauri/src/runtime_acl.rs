@@ -0,0 +1,125 @@
+//! Dynamic Per-DAG Runtime Capability ACL (RFC-109 addendum)
+//!
+//! `capability.rs` resolves a build-time manifest once per process and
+//! never changes again — it answers "is this build allowed to do X at
+//! all". The permissions a user approves through the security-first flow
+//! (`security::submit_security_approval`) are the opposite: scoped to one
+//! DAG, decided at runtime, and meant to expire. This module is that
+//! dynamic counterpart — it translates an approved `PermissionScope` into
+//! capability tokens (`filesystem_read`, `filesystem_write`, `network`,
+//! `shell`) and holds them per `dag_id`, so IPC-adjacent commands can call
+//! `require` before doing anything the user hasn't actually signed off on.
+//! That covers every command that shells out or touches the filesystem on
+//! behalf of a single DAG node — `weakness::execute_cascade_fix`'s cascade
+//! fixes as well as `dag::execute_dag_node`/`dag::resume_dag_job`'s regular
+//! and resumed goal runs — keyed by the same id the security-approval flow
+//! analyzed (`artifact_id`/`node_id`, both called `dag_id` here).
+//!
+//! Grants live in memory only (`OnceLock<Mutex<HashMap<..>>>`, the same
+//! shape as `coordinator::NEGOTIATED`) — they don't survive a restart,
+//! which is correct: an approval is only ever valid for the Studio
+//! session that collected it.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::security::PermissionScope;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+/// One DAG's approved capability tokens, and whether the grant should be
+/// dropped as soon as the DAG finishes rather than outlive it.
+struct Grant {
+    tokens: HashSet<&'static str>,
+    session_only: bool,
+}
+
+static GRANTS: OnceLock<Mutex<HashMap<String, Grant>>> = OnceLock::new();
+
+fn grants() -> &'static Mutex<HashMap<String, Grant>> {
+    GRANTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Translate an approved `PermissionScope` into capability tokens and
+/// register them for `dag_id`, replacing any prior grant for it. A token
+/// is only present if the scope actually lists something for it — an
+/// empty `filesystemWrite`, for instance, grants no `filesystem_write`.
+pub fn grant(dag_id: &str, scope: &PermissionScope, remember_for_session: bool) {
+    let mut tokens = HashSet::new();
+    if !scope.filesystem_read.is_empty() {
+        tokens.insert("filesystem_read");
+    }
+    if !scope.filesystem_write.is_empty() {
+        tokens.insert("filesystem_write");
+    }
+    if !scope.network_allow.is_empty() {
+        tokens.insert("network");
+    }
+    if !scope.shell_allow.is_empty() {
+        tokens.insert("shell");
+    }
+
+    grants().lock().unwrap().insert(
+        dag_id.to_string(),
+        Grant {
+            tokens,
+            session_only: !remember_for_session,
+        },
+    );
+}
+
+/// Revoke `dag_id`'s grant unconditionally.
+pub fn revoke(dag_id: &str) {
+    grants().lock().unwrap().remove(dag_id);
+}
+
+/// Revoke `dag_id`'s grant if it was only ever meant to last as long as
+/// the DAG's own run (`remember_for_session: false`) — call this once
+/// the DAG finishes. A grant the user asked to remember for the session
+/// is left in place.
+pub fn revoke_if_session_only(dag_id: &str) {
+    let mut grants = grants().lock().unwrap();
+    if grants.get(dag_id).is_some_and(|g| g.session_only) {
+        grants.remove(dag_id);
+    }
+}
+
+/// Require that `dag_id` currently holds `capability`
+/// (`"filesystem_read"`, `"filesystem_write"`, `"network"`, or
+/// `"shell"`). Fails closed: no grant at all, or a grant that doesn't
+/// list this token, is a denial — matching `capability::authorize`'s
+/// stance that the absence of a grant is never ambiguous.
+pub fn require(dag_id: &str, capability: &str) -> Result<(), SunwellError> {
+    let granted = grants()
+        .lock()
+        .unwrap()
+        .get(dag_id)
+        .is_some_and(|g| g.tokens.contains(capability));
+
+    if granted {
+        Ok(())
+    } else {
+        Err(SunwellError::new(
+            ErrorCode::ToolPermissionDenied,
+            format!(
+                "DAG '{}' has no approved '{}' capability",
+                dag_id, capability
+            ),
+        )
+        .with_hints(vec![
+            "Re-run the security approval flow for this DAG before retrying",
+        ]))
+    }
+}
+
+/// Revoke `dag_id`'s capability grant from the frontend once it observes
+/// the DAG finishing. There's no single "DAG finished" event in this
+/// codebase that's keyed by the same `dag_id` the security-approval flow
+/// uses (`agent.rs`'s session lifecycle is keyed by `SessionId`, not
+/// `dag_id`), so rather than guess at a mapping, the frontend — which
+/// already has to notice completion to update its own UI — calls this
+/// explicitly. `revoke_if_session_only` still honors `rememberForSession`,
+/// so calling this after every DAG is harmless.
+#[tauri::command]
+pub async fn revoke_dag_capability(dag_id: String) -> Result<(), String> {
+    revoke_if_session_only(&dag_id);
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! CLI Version & Capability Handshake (RFC-109)
+//!
+//! Every command that shells out via `sunwell_command()` has so far assumed
+//! the installed CLI understands whatever flags it sends — `compose_surface`
+//! passing `--arrangement`, `analyze_dag_permissions` passing `--detailed`,
+//! and so on, with no way to tell "the CLI is too old for this" apart from
+//! an opaque parse error. This module negotiates once per session: it runs
+//! `sunwell version --json`, parses the server's version string, its
+//! `(major, minor, patch)` protocol tuple, and the capability tokens it
+//! reports (e.g. `"surface.compose"`), and caches the result so every
+//! gated command after the first pays nothing but a mutex lock.
+//!
+//! Protocol compatibility follows semver: the major component must match
+//! exactly, and the CLI's minor must be at least the minor Studio was built
+//! against — a CLI that's ahead on minor/patch is fine (additive changes
+//! only), behind on minor is not, and a major mismatch is never compatible.
+//!
+//! Mirrors `coordinator::negotiate_cli_version`, which solves the same
+//! problem for the project-scoped `workers` protocol via
+//! `--protocol-version`; this one is session-global (`sunwell version` isn't
+//! project-specific) and is the handshake other command modules —
+//! `surface`, `security`, `weakness` — should consult directly.
+
+use crate::error::SunwellError;
+use crate::sunwell_err;
+use crate::util::sunwell_command;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, OnceLock};
+
+/// Protocol major.minor Studio was built against. Bump alongside any
+/// breaking or additive change to the Rust/Python command surface.
+const BUILT_PROTOCOL_MAJOR: u32 = 1;
+const BUILT_PROTOCOL_MINOR: u32 = 0;
+
+/// Parsed `sunwell version --json` handshake payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeVersion {
+    pub server_version: String,
+    pub protocol_version: (u32, u32, u32),
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+/// Negotiated once per session and cached — every `sunwell version --json`
+/// call after the first reuses this instead of re-spawning the CLI.
+static NEGOTIATED: OnceLock<Mutex<Option<Result<RuntimeVersion, String>>>> = OnceLock::new();
+
+fn negotiated_cache() -> &'static Mutex<Option<Result<RuntimeVersion, String>>> {
+    NEGOTIATED.get_or_init(|| Mutex::new(None))
+}
+
+/// Run (or reuse) the version handshake. Does not itself check protocol
+/// compatibility or a specific capability — see `require_protocol` and
+/// `require_capability` — so callers that only want the raw info (e.g. the
+/// `negotiate_runtime` command, for display) aren't forced through those
+/// checks.
+pub fn negotiate() -> Result<RuntimeVersion, SunwellError> {
+    let mut cache = negotiated_cache().lock().unwrap();
+    if cache.is_none() {
+        *cache = Some(probe_version().map_err(|e| e.to_json()));
+    }
+
+    match cache.as_ref().unwrap() {
+        Ok(info) => Ok(info.clone()),
+        Err(message) => Err(sunwell_err!(
+            RuntimeProtocolMismatch,
+            "sunwell CLI version check failed: {}",
+            message
+        )),
+    }
+}
+
+fn probe_version() -> Result<RuntimeVersion, SunwellError> {
+    let output = sunwell_command()
+        .args(["version", "--json"])
+        .output()
+        .map_err(SunwellError::from)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(sunwell_err!(
+            RuntimeProtocolMismatch,
+            "Failed to query sunwell CLI version: {}",
+            stderr
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout).map_err(SunwellError::from)
+}
+
+/// Check `info.protocol_version` against the major.minor Studio was built
+/// against, per the semver rule described in the module doc comment.
+fn require_protocol(info: &RuntimeVersion) -> Result<(), SunwellError> {
+    let (major, minor, patch) = info.protocol_version;
+    if major == BUILT_PROTOCOL_MAJOR && minor >= BUILT_PROTOCOL_MINOR {
+        return Ok(());
+    }
+
+    Err(sunwell_err!(
+        RuntimeProtocolMismatch,
+        "sunwell CLI {} reports protocol v{}.{}.{}; Studio requires protocol v{}.{}.x or newer",
+        info.server_version,
+        major,
+        minor,
+        patch,
+        BUILT_PROTOCOL_MAJOR,
+        BUILT_PROTOCOL_MINOR
+    ))
+}
+
+/// Require that the negotiated runtime reports `capability` (e.g.
+/// `"surface.compose"`), for commands that depend on CLI support the
+/// protocol version alone can't guarantee — an older CLI on a compatible
+/// protocol that simply hasn't shipped a given feature yet.
+fn require_capability(info: &RuntimeVersion, capability: &str) -> Result<(), SunwellError> {
+    if info.capabilities.iter().any(|c| c == capability) {
+        Ok(())
+    } else {
+        Err(sunwell_err!(
+            RuntimeCapabilityUnsupported,
+            "sunwell CLI {} does not report the '{}' capability",
+            info.server_version,
+            capability
+        ))
+    }
+}
+
+/// Negotiate (or reuse the cached negotiation), check protocol
+/// compatibility, then check that `capability` is supported. The one call
+/// a gated command needs before it shells out — on failure the returned
+/// `SunwellError` ("CLI too old, requires protocol >= X.Y") should be
+/// surfaced directly rather than let the command proceed into an opaque
+/// parse error.
+pub fn require(capability: &str) -> Result<RuntimeVersion, SunwellError> {
+    let info = negotiate()?;
+    require_protocol(&info)?;
+    require_capability(&info, capability)?;
+    Ok(info)
+}
+
+/// Run the version/capability handshake and return it as-is, for the
+/// frontend to display or to pre-flight before offering a gated feature.
+#[tauri::command]
+pub async fn negotiate_runtime() -> Result<RuntimeVersion, String> {
+    negotiate().map_err(|e| e.to_json())
+}
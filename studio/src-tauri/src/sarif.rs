@@ -0,0 +1,244 @@
+//! SARIF Export for Validation Diagnostics (RFC-113)
+//!
+//! `validate_document`/`fix_all_issues` (`writer.rs`, RFC-111) produce
+//! `ValidationWarning`s that are otherwise only ever rendered inline in
+//! Studio. This module serializes them into [SARIF 2.1.0] so the same
+//! findings can be ingested by code-scanning dashboards and CI annotation
+//! tooling that already consume that format from other linters.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+//!
+//! A warning's `rule` becomes a `reportingDescriptor` under
+//! `tool.driver.rules` (deduplicated — one descriptor per distinct rule
+//! id, not one per occurrence), its `severity` maps to a SARIF `level`,
+//! and `line`/`column` become a `physicalLocation`. `suggestion` is
+//! carried as a `fix` whose one `artifactChange` replaces the warning's
+//! line with the suggestion text — an approximation, since
+//! `ValidationWarning.suggestion` is a human-readable description rather
+//! than a literal diff; callers that need a byte-exact fix should go
+//! through `validation_rules::Rule::fix` instead.
+
+use crate::writer::ValidationWarning;
+use serde::Serialize;
+use std::collections::HashSet;
+
+const SARIF_SCHEMA: &str =
+    "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    rules: Vec<SarifReportingDescriptor>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReportingDescriptor {
+    id: String,
+    short_description: SarifMessage,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifInsertedContent,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifInsertedContent {
+    text: String,
+}
+
+/// Maps `ValidationWarning.severity` to a SARIF result level. Unrecognized
+/// severities default to `"warning"` rather than being dropped.
+fn sarif_level(severity: &str) -> &'static str {
+    match severity {
+        "error" => "error",
+        "note" | "info" => "note",
+        _ => "warning",
+    }
+}
+
+fn to_sarif(warnings: &[ValidationWarning], file_path: &str) -> SarifLog {
+    let mut seen_rules = HashSet::new();
+    let mut rules = Vec::new();
+    for warning in warnings {
+        if seen_rules.insert(warning.rule.clone()) {
+            rules.push(SarifReportingDescriptor {
+                id: warning.rule.clone(),
+                short_description: SarifMessage {
+                    text: format!("Sunwell validation rule '{}'", warning.rule),
+                },
+            });
+        }
+    }
+
+    let results = warnings
+        .iter()
+        .map(|warning| {
+            let artifact_location = SarifArtifactLocation {
+                uri: file_path.to_string(),
+            };
+            let region = SarifRegion {
+                start_line: warning.line.max(1),
+                start_column: warning.column,
+            };
+
+            let fixes = warning
+                .suggestion
+                .as_ref()
+                .map(|suggestion| {
+                    vec![SarifFix {
+                        description: SarifMessage {
+                            text: suggestion.clone(),
+                        },
+                        artifact_changes: vec![SarifArtifactChange {
+                            artifact_location: artifact_location.clone(),
+                            replacements: vec![SarifReplacement {
+                                deleted_region: region.clone(),
+                                inserted_content: SarifInsertedContent {
+                                    text: suggestion.clone(),
+                                },
+                            }],
+                        }],
+                    }]
+                })
+                .unwrap_or_default();
+
+            SarifResult {
+                rule_id: warning.rule.clone(),
+                level: sarif_level(&warning.severity),
+                message: SarifMessage {
+                    text: warning.message.clone(),
+                },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location,
+                        region,
+                    },
+                }],
+                fixes,
+            }
+        })
+        .collect();
+
+    SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "sunwell",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules,
+                },
+            },
+            results,
+        }],
+    }
+}
+
+/// Serializes `warnings` as either SARIF 2.1.0 (`format == "sarif"`) or
+/// the plain JSON array the existing UI already renders
+/// (`format == "json"`). `lens_name` isn't part of the SARIF shape itself
+/// but is accepted so callers don't need to special-case the request —
+/// it's available for a future per-lens rule catalog if one is added.
+#[tauri::command]
+pub async fn export_diagnostics(
+    warnings: Vec<ValidationWarning>,
+    lens_name: String,
+    file_path: String,
+    format: String,
+) -> Result<String, String> {
+    let _ = lens_name;
+    match format.as_str() {
+        "sarif" => serde_json::to_string_pretty(&to_sarif(&warnings, &file_path))
+            .map_err(|e| format!("Failed to serialize SARIF output: {}", e)),
+        "json" => serde_json::to_string_pretty(&warnings)
+            .map_err(|e| format!("Failed to serialize diagnostics: {}", e)),
+        other => Err(format!(
+            "Unknown diagnostics export format '{}' (expected 'sarif' or 'json')",
+            other
+        )),
+    }
+}
@@ -5,6 +5,7 @@
 
 use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
+use crate::telemetry::{self, CommandTimer};
 use crate::util::sunwell_command;
 use serde::{Deserialize, Serialize};
 
@@ -152,7 +153,10 @@ pub struct SecurityApprovalResponse {
     pub approved: bool,
 
     /// Modified permissions if user edited them.
-    #[serde(skip_serializing_if = "Option::is_none", rename = "modifiedPermissions")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "modifiedPermissions"
+    )]
     pub modified_permissions: Option<PermissionScope>,
 
     /// Whether to remember this approval for the session.
@@ -216,6 +220,18 @@ pub struct AuditEntryDisplay {
     /// Risk level at time of execution.
     #[serde(rename = "riskLevel")]
     pub risk_level: String,
+
+    /// Whether this entry's position in the independently re-verified hash
+    /// chain (see `audit_integrity`) checked out. Only populated when
+    /// `get_audit_log` was called without `since`/`limit` filters — a
+    /// filtered fetch isn't a 1:1 prefix of the raw log, so there's no
+    /// reliable index to cross-reference against, and this stays `true`.
+    #[serde(rename = "chainVerified", default = "default_chain_verified")]
+    pub chain_verified: bool,
+}
+
+fn default_chain_verified() -> bool {
+    true
 }
 
 /// Audit log integrity status.
@@ -226,6 +242,22 @@ pub struct AuditIntegrityStatus {
 
     /// Message describing the status.
     pub message: String,
+
+    /// Any drift between applied proposals' locked file hashes and disk,
+    /// folded in from `self_knowledge::self_verify_proposal_lock`.
+    #[serde(rename = "proposalLockDrift", default)]
+    pub proposal_lock_drift: Vec<crate::self_knowledge::ProposalLockDrift>,
+
+    /// Index of the first entry whose recomputed hash diverged, from the
+    /// Rust-side re-verification in `audit_integrity`, independent of
+    /// whatever the CLI itself reported.
+    #[serde(rename = "firstBrokenIndex", default)]
+    pub first_broken_index: Option<u64>,
+
+    /// How many entries from genesis verified correctly under the
+    /// Rust-side re-verification.
+    #[serde(rename = "totalVerified", default)]
+    pub total_verified: u64,
 }
 
 // =============================================================================
@@ -233,38 +265,59 @@ pub struct AuditIntegrityStatus {
 // =============================================================================
 
 /// Analyze DAG permissions before execution.
+#[tracing::instrument(skip(dag_id), fields(dag_id = %dag_id, wall_clock_ms))]
 #[tauri::command]
 pub async fn analyze_dag_permissions(dag_id: String) -> Result<SecurityApprovalDetailed, String> {
+    let timer = CommandTimer::start();
+    crate::runtime_version::require("security.scan").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+
     let output = sunwell_command()
         .args(["security", "analyze", &dag_id, "--json", "--detailed"])
         .output()
         .map_err(|e| {
-            SunwellError::from_error(ErrorCode::ToolPermissionDenied, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
+            let err = SunwellError::from_error(ErrorCode::ToolPermissionDenied, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"]);
+            telemetry::record_failure(&err);
+            err.to_json()
         })?;
 
-    if output.status.success() {
+    let result = if output.status.success() {
         serde_json::from_slice(&output.stdout).map_err(|e| {
-            sunwell_err!(ConfigInvalid, "Failed to parse security analysis: {}", e).to_json()
+            let err = sunwell_err!(ConfigInvalid, "Failed to parse security analysis: {}", e);
+            telemetry::record_failure(&err);
+            err.to_json()
         })
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(sunwell_err!(
-            ToolPermissionDenied,
-            "Security analysis failed: {}",
-            stderr
-        )
-        .with_hints(vec!["Check if the DAG ID is valid"])
-        .to_json())
-    }
+        let err = sunwell_err!(ToolPermissionDenied, "Security analysis failed: {}", stderr)
+            .with_hints(vec!["Check if the DAG ID is valid"]);
+        telemetry::record_failure(&err);
+        Err(err.to_json())
+    };
+
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+    result
 }
 
 /// Submit user's approval response.
+///
+/// `original_permissions` is the scope `analyze_dag_permissions` returned
+/// for this DAG — the frontend already has it from that earlier call.
+/// It's needed here because `response.modified_permissions` is only
+/// `Some` when the user actually edited something; an approval with no
+/// edits still needs an effective scope to grant, and that's whatever
+/// was originally analyzed.
 #[tauri::command]
-pub async fn submit_security_approval(response: SecurityApprovalResponse) -> Result<bool, String> {
-    let json = serde_json::to_string(&response)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize response: {}", e).to_json())?;
+pub async fn submit_security_approval(
+    response: SecurityApprovalResponse,
+    original_permissions: PermissionScope,
+) -> Result<bool, String> {
+    let json = serde_json::to_string(&response).map_err(|e| {
+        sunwell_err!(ConfigInvalid, "Failed to serialize response: {}", e).to_json()
+    })?;
 
     let mut cmd = sunwell_command();
     cmd.args(["security", "approve", "--json"]);
@@ -296,10 +349,29 @@ pub async fn submit_security_approval(response: SecurityApprovalResponse) -> Res
             .to_json()
     })?;
 
-    Ok(output.status.success())
+    let succeeded = output.status.success();
+    if succeeded && response.approved {
+        let effective_permissions = response
+            .modified_permissions
+            .clone()
+            .unwrap_or(original_permissions);
+        crate::runtime_acl::grant(
+            &response.dag_id,
+            &effective_permissions,
+            response.remember_for_session,
+        );
+    }
+
+    Ok(succeeded)
 }
 
 /// Get recent audit log entries for display.
+///
+/// When fetched unfiltered (no `since`/`limit`), each entry is cross-checked
+/// against the independently re-verified hash chain (`audit_integrity`) and
+/// flagged via `chain_verified` if its position is at or after the first
+/// broken entry — so the UI can highlight exactly which entries a tampered
+/// log put in question, not just that the log as a whole failed `--verify`.
 #[tauri::command]
 pub async fn get_audit_log(
     since: Option<String>,
@@ -308,8 +380,10 @@ pub async fn get_audit_log(
     let mut cmd = sunwell_command();
     cmd.args(["security", "audit", "--json"]);
 
-    if let Some(s) = since {
-        cmd.args(["--since", &s]);
+    let unfiltered = since.is_none() && limit.is_none();
+
+    if let Some(s) = &since {
+        cmd.args(["--since", s]);
     }
     if let Some(l) = limit {
         cmd.args(["--limit", &l.to_string()]);
@@ -321,20 +395,50 @@ pub async fn get_audit_log(
             .to_json()
     })?;
 
-    if output.status.success() {
-        serde_json::from_slice(&output.stdout)
-            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse audit log: {}", e).to_json())
-    } else {
+    if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(sunwell_err!(RuntimeProcessFailed, "Audit log read failed: {}", stderr)
-            .with_hints(vec!["Check if audit log exists"])
-            .to_json())
+        return Err(
+            sunwell_err!(RuntimeProcessFailed, "Audit log read failed: {}", stderr)
+                .with_hints(vec!["Check if audit log exists"])
+                .to_json(),
+        );
+    }
+
+    let mut entries: Vec<AuditEntryDisplay> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse audit log: {}", e).to_json())?;
+
+    if unfiltered {
+        let chain = crate::audit_integrity::verify_audit_log_chain();
+        if let Some(broken_index) = chain.first_broken_index {
+            for (index, entry) in entries.iter_mut().enumerate() {
+                if index as u64 >= broken_index {
+                    entry.chain_verified = false;
+                }
+            }
+        }
     }
+
+    Ok(entries)
 }
 
 /// Verify audit log integrity.
+///
+/// Folds in two checks beyond the CLI's own `--verify` report, neither of
+/// which trusts the CLI: `self_knowledge::self_verify_proposal_lock`
+/// (best-effort — a failure to check proposal locks doesn't invalidate the
+/// audit log itself), and `audit_integrity::verify_audit_log_chain`, which
+/// re-reads the raw log and recomputes its hash chain directly, so a
+/// compromised CLI can't paper over a tampered log by simply reporting
+/// `valid: true`.
 #[tauri::command]
 pub async fn verify_audit_integrity() -> Result<AuditIntegrityStatus, String> {
+    let proposal_lock_drift = crate::self_knowledge::self_verify_proposal_lock()
+        .await
+        .map(|v| v.drift)
+        .unwrap_or_default();
+
+    let chain = crate::audit_integrity::verify_audit_log_chain();
+
     let output = sunwell_command()
         .args(["security", "audit", "--verify", "--json"])
         .output()
@@ -344,21 +448,55 @@ pub async fn verify_audit_integrity() -> Result<AuditIntegrityStatus, String> {
                 .to_json()
         })?;
 
-    if output.status.success() {
+    let mut status = if output.status.success() {
         serde_json::from_slice(&output.stdout)
-            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse result: {}", e).to_json())
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse result: {}", e).to_json())?
     } else {
-        // Return error as status (not an error case - just indicates invalid audit)
-        Ok(AuditIntegrityStatus {
+        // Treat as a status (not a command error) — the CLI's own
+        // judgment is just one input among several below.
+        AuditIntegrityStatus {
             valid: false,
             message: String::from_utf8_lossy(&output.stderr).to_string(),
-        })
+            proposal_lock_drift: Vec::new(),
+            first_broken_index: None,
+            total_verified: 0,
+        }
+    };
+
+    if !proposal_lock_drift.is_empty() {
+        status.valid = false;
+        status.message = format!(
+            "{} (plus {} applied-proposal file(s) drifted)",
+            status.message,
+            proposal_lock_drift.len()
+        );
     }
+    status.proposal_lock_drift = proposal_lock_drift;
+
+    if chain.first_broken_index.is_some() || chain.truncated {
+        status.valid = false;
+        status.message = format!(
+            "{} (Rust-side re-verification found {})",
+            status.message,
+            match (chain.first_broken_index, chain.truncated) {
+                (Some(i), true) => format!("a broken hash at entry {} and a truncated tail", i),
+                (Some(i), false) => format!("a broken hash at entry {}", i),
+                (None, true) => "a truncated tail".to_string(),
+                (None, false) => unreachable!(),
+            }
+        );
+    }
+    status.first_broken_index = chain.first_broken_index;
+    status.total_verified = chain.total_verified;
+
+    Ok(status)
 }
 
 /// Scan content for security issues.
+#[tracing::instrument(skip(content), fields(wall_clock_ms))]
 #[tauri::command]
 pub async fn scan_for_security_issues(content: String) -> Result<Vec<SecurityViolation>, String> {
+    let timer = CommandTimer::start();
     let mut cmd = sunwell_command();
     cmd.args(["security", "scan", "--json"]);
 
@@ -369,31 +507,45 @@ pub async fn scan_for_security_issues(content: String) -> Result<Vec<SecurityVio
         .stderr(std::process::Stdio::piped())
         .spawn()
         .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
+            let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"]);
+            telemetry::record_failure(&err);
+            err.to_json()
         })?;
 
     if let Some(stdin) = child.stdin.as_mut() {
         use std::io::Write;
         stdin.write_all(content.as_bytes()).map_err(|e| {
-            SunwellError::from_error(ErrorCode::FileWriteFailed, e)
-                .with_hints(vec!["Check process stdin is available"])
-                .to_json()
+            let err = SunwellError::from_error(ErrorCode::FileWriteFailed, e)
+                .with_hints(vec!["Check process stdin is available"]);
+            telemetry::record_failure(&err);
+            err.to_json()
         })?;
     }
 
     let output = child.wait_with_output().map_err(|e| {
-        SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-            .with_hints(vec!["Process may have been interrupted"])
-            .to_json()
+        let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Process may have been interrupted"]);
+        telemetry::record_failure(&err);
+        err.to_json()
     })?;
 
-    if output.status.success() {
-        serde_json::from_slice(&output.stdout)
-            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse violations: {}", e).to_json())
+    let result = if output.status.success() {
+        let violations: Vec<SecurityViolation> =
+            serde_json::from_slice(&output.stdout).map_err(|e| {
+                let err = sunwell_err!(ConfigInvalid, "Failed to parse violations: {}", e);
+                telemetry::record_failure(&err);
+                err.to_json()
+            })?;
+        for violation in &violations {
+            crate::metrics::record_security_violation(&violation.violation_type);
+        }
+        Ok(violations)
     } else {
         // No violations if command fails (graceful degradation)
         Ok(vec![])
-    }
+    };
+
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+    result
 }
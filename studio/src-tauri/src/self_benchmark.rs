@@ -0,0 +1,265 @@
+//! Workload-Driven Self-Analysis Benchmarking (RFC-085 addendum)
+//!
+//! Builds on `self_knowledge::PatternReport` the same way `benchmark.rs`
+//! builds on the RFC-074/RFC-105 pipeline: a JSON "workload" file describes
+//! an ordered list of tool executions to replay, `self_run_benchmark`
+//! drives them through the CLI and aggregates latency/error outcomes into
+//! a report, and each report is persisted to disk keyed by workload name so
+//! `self_compare_benchmark` can diff a fresh run against any prior one and
+//! flag a regression.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::self_knowledge::PatternReport;
+use crate::sunwell_err;
+use crate::util::{parse_json_safe, sunwell_command};
+use crate::workspace::{default_config_root, slugify};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Default regression threshold, as a percentage worsening, when a caller
+/// doesn't supply one.
+const DEFAULT_REGRESSION_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// One tool execution within a workload's ordered step list.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkStep {
+    pub tool: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+    #[serde(default = "default_repeat")]
+    pub repeat: u32,
+}
+
+fn default_repeat() -> u32 {
+    1
+}
+
+/// A workload file: a named, ordered list of tool executions to replay,
+/// modeled on `benchmark::BenchmarkWorkload` / `eval::EvalWorkload`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SelfBenchmarkWorkload {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub steps: Vec<BenchmarkStep>,
+}
+
+/// Aggregated report from replaying a workload, reusing `PatternReport`'s
+/// shape (tool_frequencies, avg_latency_ms, error_rate, top_errors) and
+/// extending it with percentile latencies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfBenchmarkReport {
+    pub id: String,
+    pub workload_name: String,
+    #[serde(flatten)]
+    pub patterns: PatternReport,
+    pub p50_latency_ms: f64,
+    pub p95_latency_ms: f64,
+    pub p99_latency_ms: f64,
+}
+
+/// Result of diffing a fresh run against a stored baseline run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    pub baseline_id: String,
+    pub current: SelfBenchmarkReport,
+    pub avg_latency_delta_percent: f64,
+    pub p95_latency_delta_percent: f64,
+    pub error_rate_delta_percent: f64,
+    pub regressed: bool,
+}
+
+/// Replay every step of a workload file (in order, `repeat` times each)
+/// against the CLI, and return an aggregated latency/error report.
+#[tauri::command]
+pub async fn self_run_benchmark(workload_path: String) -> Result<SelfBenchmarkReport, SunwellError> {
+    let workload = load_workload(&workload_path)?;
+    let report = run_benchmark_workload(&workload);
+
+    if let Err(e) = append_benchmark_report(&report) {
+        eprintln!("self_run_benchmark: failed to persist report for '{}': {}", report.workload_name, e);
+    }
+
+    Ok(report)
+}
+
+/// Replay a workload file fresh, then diff the result against a previously
+/// persisted run for the same workload (identified by `baseline_id`,
+/// returned from an earlier `self_run_benchmark`/`self_compare_benchmark`
+/// call), flagging a regression when avg/p95 latency or error rate worsens
+/// beyond `threshold_percent` (default 10%).
+#[tauri::command]
+pub async fn self_compare_benchmark(
+    workload_path: String,
+    baseline_id: String,
+    threshold_percent: Option<f64>,
+) -> Result<BenchmarkComparison, SunwellError> {
+    let workload = load_workload(&workload_path)?;
+    let current = run_benchmark_workload(&workload);
+
+    if let Err(e) = append_benchmark_report(&current) {
+        eprintln!("self_compare_benchmark: failed to persist report for '{}': {}", current.workload_name, e);
+    }
+
+    let history = load_benchmark_history(&workload.name);
+    let baseline = history.iter().find(|r| r.id == baseline_id).ok_or_else(|| {
+        sunwell_err!(ToolNotFound, "No stored benchmark run '{}' for workload '{}'", baseline_id, workload.name)
+    })?;
+
+    let threshold = threshold_percent.unwrap_or(DEFAULT_REGRESSION_THRESHOLD_PERCENT);
+    let avg_latency_delta_percent = percent_delta(baseline.patterns.avg_latency_ms, current.patterns.avg_latency_ms);
+    let p95_latency_delta_percent = percent_delta(baseline.p95_latency_ms, current.p95_latency_ms);
+    let error_rate_delta_percent = percent_delta(baseline.patterns.error_rate, current.patterns.error_rate);
+
+    let regressed = avg_latency_delta_percent > threshold
+        || p95_latency_delta_percent > threshold
+        || error_rate_delta_percent > threshold;
+
+    Ok(BenchmarkComparison {
+        baseline_id,
+        current,
+        avg_latency_delta_percent,
+        p95_latency_delta_percent,
+        error_rate_delta_percent,
+        regressed,
+    })
+}
+
+fn load_workload(workload_path: &str) -> Result<SelfBenchmarkWorkload, SunwellError> {
+    let content = std::fs::read_to_string(workload_path)
+        .map_err(|e| sunwell_err!(FileNotFound, "Failed to read workload file {}: {}", workload_path, e))?;
+    parse_json_safe(&content).map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse workload file: {}", e))
+}
+
+/// Run every step of `workload` in order and fold the outcomes into a
+/// `SelfBenchmarkReport`. Each step's failures are recorded as errors
+/// rather than aborting the rest of the workload, so one flaky tool
+/// doesn't prevent the report from covering the others.
+fn run_benchmark_workload(workload: &SelfBenchmarkWorkload) -> SelfBenchmarkReport {
+    let mut tool_frequencies: HashMap<String, u32> = HashMap::new();
+    let mut latencies_ms: Vec<u64> = Vec::new();
+    let mut errors: Vec<String> = Vec::new();
+    let mut total_runs: u32 = 0;
+
+    for step in &workload.steps {
+        for _ in 0..step.repeat.max(1) {
+            total_runs += 1;
+            *tool_frequencies.entry(step.tool.clone()).or_insert(0) += 1;
+
+            let started = Instant::now();
+            let outcome = invoke_tool(&step.tool, &step.args);
+            latencies_ms.push(started.elapsed().as_millis() as u64);
+
+            if let Err(message) = outcome {
+                errors.push(message);
+            }
+        }
+    }
+
+    latencies_ms.sort_unstable();
+    let avg_latency_ms = if latencies_ms.is_empty() {
+        0.0
+    } else {
+        latencies_ms.iter().sum::<u64>() as f64 / latencies_ms.len() as f64
+    };
+    let error_rate = if total_runs == 0 { 0.0 } else { errors.len() as f64 / total_runs as f64 };
+
+    SelfBenchmarkReport {
+        id: new_benchmark_run_id(),
+        workload_name: workload.name.clone(),
+        patterns: PatternReport {
+            tool_frequencies,
+            avg_latency_ms,
+            error_rate,
+            top_errors: top_errors(&errors, 5),
+        },
+        p50_latency_ms: percentile(&latencies_ms, 50.0),
+        p95_latency_ms: percentile(&latencies_ms, 95.0),
+        p99_latency_ms: percentile(&latencies_ms, 99.0),
+    }
+}
+
+/// Invoke a single tool execution via the CLI, blocking until it exits.
+fn invoke_tool(tool: &str, args: &serde_json::Value) -> Result<(), String> {
+    let args_json = args.to_string();
+    let output = sunwell_command()
+        .args(["tool", "run", tool, "--args", &args_json, "--json"])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    Ok(())
+}
+
+/// Linear-interpolation-free percentile over an already-sorted sample:
+/// nearest-rank, which is the usual choice for small benchmark sample
+/// sizes where interpolation implies more precision than the data has.
+fn percentile(sorted_ms: &[u64], p: f64) -> f64 {
+    if sorted_ms.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * (sorted_ms.len() as f64 - 1.0)).round() as usize;
+    sorted_ms[rank.min(sorted_ms.len() - 1)] as f64
+}
+
+/// The `limit` most frequent distinct error messages, most common first —
+/// matches `PatternReport.top_errors`'s existing shape.
+fn top_errors(errors: &[String], limit: usize) -> Vec<String> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for error in errors {
+        *counts.entry(error.as_str()).or_insert(0) += 1;
+    }
+    let mut ranked: Vec<(&str, u32)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    ranked.into_iter().take(limit).map(|(message, _)| message.to_string()).collect()
+}
+
+fn new_benchmark_run_id() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos =
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    format!("bench-{:x}-{:x}", nanos, seq)
+}
+
+/// Path to the persisted run history for a workload, keyed by its
+/// (slugified) name: `~/Sunwell/.sunwell/benchmarks/<name>.json`.
+fn benchmark_history_path(workload_name: &str) -> PathBuf {
+    default_config_root().join("benchmarks").join(format!("{}.json", slugify(workload_name)))
+}
+
+fn load_benchmark_history(workload_name: &str) -> Vec<SelfBenchmarkReport> {
+    let path = benchmark_history_path(workload_name);
+    match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn append_benchmark_report(report: &SelfBenchmarkReport) -> std::io::Result<()> {
+    let path = benchmark_history_path(&report.workload_name);
+    let mut history = load_benchmark_history(&report.workload_name);
+    history.push(report.clone());
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(&history)?;
+    std::fs::write(path, content)
+}
+
+fn percent_delta(baseline: f64, current: f64) -> f64 {
+    if baseline == 0.0 {
+        if current == 0.0 {
+            0.0
+        } else {
+            100.0
+        }
+    } else {
+        (current - baseline) / baseline * 100.0
+    }
+}
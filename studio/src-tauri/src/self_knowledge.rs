@@ -8,7 +8,17 @@
 use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
 use crate::util::{parse_json_safe, sunwell_command};
+use notify::{Event as NotifyEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State, Window};
+use tokio::io::AsyncReadExt;
+use tokio::process::{Child, Command as TokioCommand};
+use tokio::sync::Notify;
 
 // =============================================================================
 // Types (matching Python sunwell.self.types)
@@ -320,6 +330,8 @@ pub async fn self_test_proposal(proposal_id: String) -> Result<TestResult, Strin
 /// Approve a proposal for application.
 #[tauri::command]
 pub async fn self_approve_proposal(proposal_id: String) -> Result<(), String> {
+    crate::capability::authorize("self_approve_proposal").map_err(|e| e.to_json())?;
+
     let output = sunwell_command()
         .args(["self", "proposals", "approve", &proposal_id])
         .output()
@@ -338,8 +350,23 @@ pub async fn self_approve_proposal(proposal_id: String) -> Result<(), String> {
 }
 
 /// Apply an approved proposal.
+///
+/// Before applying, snapshots the current content hash of every file the
+/// proposal touches; after a successful apply, records both hashes in
+/// `proposals.lock` (see `self_verify_proposal_lock`) so the application is
+/// tamper-evident rather than a fire-and-forget subprocess call.
 #[tauri::command]
 pub async fn self_apply_proposal(proposal_id: String) -> Result<String, String> {
+    crate::capability::authorize("self_apply_proposal").map_err(|e| e.to_json())?;
+
+    let proposal_changes = self_get_proposal(proposal_id.clone()).await.ok().map(|d| d.changes);
+    let source_root = fetch_summary().map(|s| s.source_root);
+    let before_hashes: HashMap<String, Option<String>> = proposal_changes
+        .iter()
+        .flatten()
+        .map(|c| (c.path.clone(), resolve_and_hash(source_root.as_deref(), &c.path)))
+        .collect();
+
     let output = sunwell_command()
         .args(["self", "proposals", "apply", &proposal_id, "--json"])
         .output()
@@ -354,13 +381,50 @@ pub async fn self_apply_proposal(proposal_id: String) -> Result<String, String>
         return Err(sunwell_err!(SkillExecutionFailed, "Apply failed: {}", stderr).to_json());
     }
 
+    if let Some(changes) = &proposal_changes {
+        record_proposal_lock_entry(&proposal_id, changes, &before_hashes, source_root.as_deref());
+    }
+
     let stdout = String::from_utf8_lossy(&output.stdout);
     Ok(stdout.to_string())
 }
 
 /// Rollback an applied proposal.
+///
+/// Consults `proposals.lock` first: if the proposal's locked post-apply
+/// files no longer match what's on disk, a later edit has landed on top of
+/// the apply and rolling back would clobber it, so the rollback is refused.
 #[tauri::command]
 pub async fn self_rollback_proposal(proposal_id: String) -> Result<(), String> {
+    crate::capability::authorize("self_rollback_proposal").map_err(|e| e.to_json())?;
+
+    let source_root = fetch_summary().map(|s| s.source_root);
+    let lockfile = ProposalLockfile::load();
+    let locked_files = lockfile.latest_entry(&proposal_id).map(|e| e.files.clone());
+
+    if let Some(files) = &locked_files {
+        let drifted: Vec<&str> = files
+            .iter()
+            .filter(|f| resolve_and_hash(source_root.as_deref(), &f.path) != f.after_hash)
+            .map(|f| f.path.as_str())
+            .collect();
+
+        if !drifted.is_empty() {
+            return Err(sunwell_err!(
+                ToolIntegrityMismatch,
+                "Refusing to roll back '{}': {} file(s) changed out-of-band since apply ({})",
+                proposal_id,
+                drifted.len(),
+                drifted.join(", ")
+            )
+            .with_hints(vec![
+                "Run self_verify_proposal_lock to see full drift details",
+                "Resolve the conflicting edits, then re-apply or manually rollback",
+            ])
+            .to_json());
+        }
+    }
+
     let output = sunwell_command()
         .args(["self", "proposals", "rollback", &proposal_id])
         .output()
@@ -375,6 +439,707 @@ pub async fn self_rollback_proposal(proposal_id: String) -> Result<(), String> {
         return Err(sunwell_err!(SkillExecutionFailed, "Rollback failed: {}", stderr).to_json());
     }
 
+    if let Some(files) = locked_files {
+        record_proposal_rollback_lock_entry(&proposal_id, &files, source_root.as_deref());
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Proposal Lockfile — tamper-evident apply/rollback
+// =============================================================================
+//
+// Mirrors `lens::LensLockfile`: instead of pinning a lens's content
+// checksum, each entry here pins the before/after SHA-256 of every file an
+// applied proposal touched, so a rollback can detect whether something else
+// has edited those files since and refuse rather than silently clobber them.
+
+/// Hash algorithm tag stored alongside each lock entry, matching
+/// `lens::LOCK_ALGORITHM`'s purpose of keeping old lockfiles readable across
+/// a future digest change.
+const PROPOSAL_LOCK_ALGORITHM: &str = "sha256";
+
+/// Before/after checksum for a single file touched by an applied proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalLockFileEntry {
+    pub path: String,
+    pub algorithm: String,
+    pub before_hash: Option<String>,
+    pub after_hash: Option<String>,
+}
+
+/// One applied-proposal record in `proposals.lock`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalLockEntry {
+    pub proposal_id: String,
+    pub applied_at: String,
+    pub files: Vec<ProposalLockFileEntry>,
+}
+
+/// Global proposal lockfile (`~/Sunwell/.sunwell/proposals.lock`). Global
+/// rather than project-relative like `LensLockfile` because `self_apply_proposal`
+/// takes only a `proposal_id`, not a project path.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProposalLockfile {
+    #[serde(default)]
+    pub entries: Vec<ProposalLockEntry>,
+}
+
+impl ProposalLockfile {
+    fn path() -> PathBuf {
+        crate::workspace::default_config_root().join("proposals.lock")
+    }
+
+    /// Load the lockfile, or an empty one if no proposal has been applied yet.
+    pub fn load() -> Self {
+        let lock_path = Self::path();
+        std::fs::read_to_string(&lock_path).ok().and_then(|content| serde_yaml::from_str(&content).ok()).unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<(), SunwellError> {
+        let lock_path = Self::path();
+        if let Some(parent) = lock_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))?;
+        }
+
+        let yaml = serde_yaml::to_string(self)
+            .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize proposal lockfile: {}", e))?;
+        std::fs::write(&lock_path, yaml).map_err(|e| SunwellError::from_error(ErrorCode::FileWriteFailed, e))
+    }
+
+    /// Most recent applied entry for a given proposal, if any.
+    pub fn latest_entry(&self, proposal_id: &str) -> Option<&ProposalLockEntry> {
+        self.entries.iter().rev().find(|e| e.proposal_id == proposal_id)
+    }
+}
+
+/// Drift detected between a proposal's locked post-apply file hash and what's
+/// on disk now.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalLockDrift {
+    pub proposal_id: String,
+    pub path: String,
+    pub locked_hash: Option<String>,
+    pub current_hash: Option<String>,
+}
+
+/// Result of checking every applied proposal's locked files against disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalLockVerification {
+    pub valid: bool,
+    pub drift: Vec<ProposalLockDrift>,
+}
+
+/// Hash a proposal-relative file path resolved against `source_root`, or
+/// `None` if the root is unknown or the file doesn't exist (e.g. the change
+/// was a deletion).
+fn resolve_and_hash(source_root: Option<&str>, relative_path: &str) -> Option<String> {
+    let root = source_root?;
+    let full_path = Path::new(root).join(relative_path);
+    let content = std::fs::read_to_string(&full_path).ok()?;
+    Some(crate::lens::sha256_hex(&content))
+}
+
+/// Append a lock entry recording the before/after hash of every file a just-applied
+/// proposal touched. Best-effort: a failure to persist the lock is logged, not
+/// surfaced, since the apply itself already succeeded.
+fn record_proposal_lock_entry(
+    proposal_id: &str,
+    changes: &[FileChange],
+    before_hashes: &HashMap<String, Option<String>>,
+    source_root: Option<&str>,
+) {
+    let files = changes
+        .iter()
+        .map(|c| ProposalLockFileEntry {
+            path: c.path.clone(),
+            algorithm: PROPOSAL_LOCK_ALGORITHM.to_string(),
+            before_hash: before_hashes.get(&c.path).cloned().flatten(),
+            after_hash: resolve_and_hash(source_root, &c.path),
+        })
+        .collect();
+
+    let mut lockfile = ProposalLockfile::load();
+    lockfile.entries.push(ProposalLockEntry {
+        proposal_id: proposal_id.to_string(),
+        applied_at: chrono::Utc::now().to_rfc3339(),
+        files,
+    });
+
+    if let Err(e) = lockfile.save() {
+        eprintln!("record_proposal_lock_entry: failed to persist lock for '{}': {}", proposal_id, e);
+    }
+}
+
+/// Append a lock entry recording each file's hash after a successful
+/// rollback, using the prior entry's `after_hash` as this entry's
+/// `before_hash`. Without this, `latest_entry` would keep returning the
+/// stale post-apply hashes forever, and `self_verify_proposal_lock` would
+/// report the just-restored files as permanently drifted. Best-effort, like
+/// `record_proposal_lock_entry`: a failure to persist the lock is logged,
+/// not surfaced, since the rollback itself already succeeded.
+fn record_proposal_rollback_lock_entry(
+    proposal_id: &str,
+    locked_files: &[ProposalLockFileEntry],
+    source_root: Option<&str>,
+) {
+    let files = locked_files
+        .iter()
+        .map(|f| ProposalLockFileEntry {
+            path: f.path.clone(),
+            algorithm: PROPOSAL_LOCK_ALGORITHM.to_string(),
+            before_hash: f.after_hash.clone(),
+            after_hash: resolve_and_hash(source_root, &f.path),
+        })
+        .collect();
+
+    let mut lockfile = ProposalLockfile::load();
+    lockfile.entries.push(ProposalLockEntry {
+        proposal_id: proposal_id.to_string(),
+        applied_at: chrono::Utc::now().to_rfc3339(),
+        files,
+    });
+
+    if let Err(e) = lockfile.save() {
+        eprintln!(
+            "record_proposal_rollback_lock_entry: failed to persist lock for '{}': {}",
+            proposal_id, e
+        );
+    }
+}
+
+/// Verify every applied proposal's locked files still match what's on disk,
+/// surfacing any drift so `security::verify_audit_integrity` can fold it into
+/// the overall integrity check.
+#[tauri::command]
+pub async fn self_verify_proposal_lock() -> Result<ProposalLockVerification, String> {
+    let lockfile = ProposalLockfile::load();
+    let source_root = fetch_summary().map(|s| s.source_root);
+
+    let mut drift = Vec::new();
+    for entry in &lockfile.entries {
+        for file in &entry.files {
+            let current_hash = resolve_and_hash(source_root.as_deref(), &file.path);
+            if current_hash != file.after_hash {
+                drift.push(ProposalLockDrift {
+                    proposal_id: entry.proposal_id.clone(),
+                    path: file.path.clone(),
+                    locked_hash: file.after_hash.clone(),
+                    current_hash,
+                });
+            }
+        }
+    }
+
+    Ok(ProposalLockVerification { valid: drift.is_empty(), drift })
+}
+
+// =============================================================================
+// Streaming Proposal Execution
+// =============================================================================
+//
+// `self_test_proposal` and `self_apply_proposal` block on `.output()` and
+// surface nothing until the subprocess exits, which is painful for a test
+// suite run or a multi-file apply that can take minutes. These streaming
+// variants spawn the CLI with piped stdout instead, read it line-by-line as
+// NDJSON, and emit incremental Tauri events as they arrive — mirroring
+// `demo::run_demo_streaming`'s token-keyed cancellation handle and
+// `select!`-driven read loop.
+
+/// Streamed event from `sunwell self proposals test --stream`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ProposalTestStreamEvent {
+    #[serde(rename = "progress")]
+    Progress { tests_run: u32, tests_passed: u32, tests_failed: u32 },
+    #[serde(rename = "complete")]
+    Complete(Box<TestResult>),
+    #[serde(rename = "error")]
+    Error { message: String },
+}
+
+/// Streamed event from `sunwell self proposals apply --stream`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ProposalApplyStreamEvent {
+    #[serde(rename = "file")]
+    File(FileChange),
+    #[serde(rename = "complete")]
+    Complete { summary: String },
+    #[serde(rename = "error")]
+    Error { message: String },
+}
+
+/// Result of a (possibly cancelled) `self_test_proposal_streaming` call: the
+/// session token callers registered the run under, plus the result once it
+/// finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalTestRunResult {
+    pub token: String,
+    pub result: TestResult,
+}
+
+/// Result of a (possibly cancelled) `self_apply_proposal_streaming` call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalApplyRunResult {
+    pub token: String,
+    pub summary: String,
+}
+
+/// Handle to an in-flight streaming proposal session, shared between the
+/// streaming command (which owns the read loop) and `cancel_proposal_session`
+/// (which kills the child).
+struct ProposalSessionHandle {
+    child: Arc<Mutex<Option<Child>>>,
+    cancel: Arc<Notify>,
+}
+
+/// Tauri-managed state tracking active `self_test_proposal_streaming` /
+/// `self_apply_proposal_streaming` runs by token, so a run can be cancelled
+/// from a separate command invocation after it started. Mirrors
+/// `demo::DemoManager`.
+#[derive(Default)]
+pub struct SelfProposalSessionManager {
+    handles: Mutex<HashMap<String, ProposalSessionHandle>>,
+}
+
+impl SelfProposalSessionManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn register(&self, token: String, child: Arc<Mutex<Option<Child>>>, cancel: Arc<Notify>) {
+        if let Ok(mut handles) = self.handles.lock() {
+            handles.insert(token, ProposalSessionHandle { child, cancel });
+        }
+    }
+
+    fn unregister(&self, token: &str) {
+        if let Ok(mut handles) = self.handles.lock() {
+            handles.remove(token);
+        }
+    }
+
+    /// Cancel the session registered under `token`: wake its read loop via
+    /// the cancellation `Notify` and kill the child process.
+    pub async fn cancel(&self, token: &str) -> Result<(), SunwellError> {
+        let handle = self
+            .handles
+            .lock()
+            .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session map lock poisoned"))?
+            .get(token)
+            .map(|h| (h.child.clone(), h.cancel.clone()));
+
+        let (child_slot, cancel) = handle.ok_or_else(|| {
+            SunwellError::new(ErrorCode::RuntimeStateInvalid, format!("No such proposal session: {}", token))
+        })?;
+
+        cancel.notify_one();
+
+        let child = child_slot.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = child {
+            let _ = child.kill().await;
+        }
+
+        Ok(())
+    }
+}
+
+/// Generate a unique token identifying a streaming proposal session, so
+/// `cancel_proposal_session` can address it while the run is still in flight.
+fn new_proposal_session_token() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos =
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    format!("self-proposal-{:x}-{:x}", nanos, seq)
+}
+
+/// Read `child`'s stdout to completion, framing on `\n` and dispatching each
+/// decoded line through `handle_line`, honoring `cancel` mid-read. Returns
+/// `true` if the read loop was interrupted by cancellation.
+async fn stream_child_stdout(
+    child: &mut Child,
+    cancel: &Notify,
+    mut handle_line: impl FnMut(&str),
+) -> bool {
+    let Some(mut stdout) = child.stdout.take() else { return false };
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut cancelled = false;
+
+    loop {
+        tokio::select! {
+            read_result = stdout.read(&mut chunk) => {
+                let bytes_read = match read_result {
+                    Ok(0) => break,
+                    Ok(n) => n,
+                    Err(_) => break,
+                };
+                buffer.extend_from_slice(&chunk[..bytes_read]);
+
+                while let Some(newline_pos) = buffer.iter().position(|&b| b == b'\n') {
+                    let frame: Vec<u8> = buffer.drain(..=newline_pos).collect();
+                    let line = String::from_utf8_lossy(&frame[..frame.len() - 1]).into_owned();
+                    if !line.trim().is_empty() {
+                        handle_line(&line);
+                    }
+                }
+            }
+            _ = cancel.notified() => {
+                cancelled = true;
+                break;
+            }
+        }
+    }
+
+    if !cancelled && !buffer.is_empty() {
+        let line = String::from_utf8_lossy(&buffer).into_owned();
+        if !line.trim().is_empty() {
+            handle_line(&line);
+        }
+    }
+
+    cancelled
+}
+
+/// Test a proposal in the sandbox with streaming progress.
+///
+/// Emits `self-proposal-progress` events carrying partial `TestResult`
+/// counts as the suite runs, and returns the final counts once it completes.
+#[tauri::command]
+pub async fn self_test_proposal_streaming(
+    proposal_id: String,
+    window: Window,
+    state: State<'_, AppState>,
+) -> Result<ProposalTestRunResult, SunwellError> {
+    let token = new_proposal_session_token();
+
+    let child = TokioCommand::new("sunwell")
+        .args(["self", "proposals", "test", &proposal_id, "--stream", "--json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+
+    let child_slot = Arc::new(Mutex::new(Some(child)));
+    let cancel = Arc::new(Notify::new());
+    state.self_proposals.register(token.clone(), child_slot.clone(), cancel.clone());
+
+    let mut final_result: Option<TestResult> = None;
+    let cancelled = {
+        let mut guard = child_slot.lock().map_err(|_| {
+            SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child lock poisoned")
+        })?;
+        let child = guard.as_mut().expect("just registered");
+        stream_child_stdout(child, &cancel, |line| {
+            match serde_json::from_str::<ProposalTestStreamEvent>(line) {
+                Ok(ProposalTestStreamEvent::Progress { tests_run, tests_passed, tests_failed }) => {
+                    let _ = window.emit("self-proposal-progress", serde_json::json!({
+                        "proposalId": proposal_id,
+                        "testsRun": tests_run,
+                        "testsPassed": tests_passed,
+                        "testsFailed": tests_failed,
+                    }));
+                }
+                Ok(ProposalTestStreamEvent::Complete(result)) => {
+                    final_result = Some(*result);
+                }
+                Ok(ProposalTestStreamEvent::Error { message }) => {
+                    let _ = window.emit("self-proposal-error", serde_json::json!({ "message": message }));
+                }
+                Err(e) => {
+                    let _ = window.emit("self-proposal-error", serde_json::json!({
+                        "message": format!("Failed to parse test stream event: {}", e),
+                    }));
+                }
+            }
+        }).await
+    };
+
+    state.self_proposals.unregister(&token);
+
+    if cancelled {
+        let remaining_child = child_slot.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = remaining_child {
+            let _ = child.kill().await;
+        }
+        let _ = window.emit("self-proposal-cancelled", serde_json::json!({ "token": token }));
+        return Err(SunwellError::new(ErrorCode::RuntimeCancelled, "Proposal test run was cancelled"));
+    }
+
+    let mut child = child_slot
+        .lock()
+        .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child lock poisoned"))?
+        .take()
+        .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child handle missing"))?;
+
+    let status = child.wait().await.map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+    if !status.success() {
+        return Err(SunwellError::new(ErrorCode::ValidationScriptFailed, "Proposal test run failed"));
+    }
+
+    let result = final_result
+        .ok_or_else(|| SunwellError::new(ErrorCode::ConfigInvalid, "No complete event received from test stream"))?;
+
+    Ok(ProposalTestRunResult { token, result })
+}
+
+/// Apply an approved proposal with streaming progress.
+///
+/// Emits a `self-proposal-file-change` event per file as the apply proceeds,
+/// and returns a summary once it completes. Gated by the same capability
+/// check as the blocking `self_apply_proposal`.
+#[tauri::command]
+pub async fn self_apply_proposal_streaming(
+    proposal_id: String,
+    window: Window,
+    state: State<'_, AppState>,
+) -> Result<ProposalApplyRunResult, SunwellError> {
+    crate::capability::authorize("self_apply_proposal_streaming")?;
+
+    let token = new_proposal_session_token();
+
+    let child = TokioCommand::new("sunwell")
+        .args(["self", "proposals", "apply", &proposal_id, "--stream", "--json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+
+    let child_slot = Arc::new(Mutex::new(Some(child)));
+    let cancel = Arc::new(Notify::new());
+    state.self_proposals.register(token.clone(), child_slot.clone(), cancel.clone());
+
+    let mut final_summary: Option<String> = None;
+    let cancelled = {
+        let mut guard = child_slot.lock().map_err(|_| {
+            SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child lock poisoned")
+        })?;
+        let child = guard.as_mut().expect("just registered");
+        stream_child_stdout(child, &cancel, |line| {
+            match serde_json::from_str::<ProposalApplyStreamEvent>(line) {
+                Ok(ProposalApplyStreamEvent::File(change)) => {
+                    let _ = window.emit("self-proposal-file-change", &change);
+                }
+                Ok(ProposalApplyStreamEvent::Complete { summary }) => {
+                    final_summary = Some(summary);
+                }
+                Ok(ProposalApplyStreamEvent::Error { message }) => {
+                    let _ = window.emit("self-proposal-error", serde_json::json!({ "message": message }));
+                }
+                Err(e) => {
+                    let _ = window.emit("self-proposal-error", serde_json::json!({
+                        "message": format!("Failed to parse apply stream event: {}", e),
+                    }));
+                }
+            }
+        }).await
+    };
+
+    state.self_proposals.unregister(&token);
+
+    if cancelled {
+        let remaining_child = child_slot.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(mut child) = remaining_child {
+            let _ = child.kill().await;
+        }
+        let _ = window.emit("self-proposal-cancelled", serde_json::json!({ "token": token }));
+        return Err(SunwellError::new(ErrorCode::RuntimeCancelled, "Proposal apply run was cancelled"));
+    }
+
+    let mut child = child_slot
+        .lock()
+        .map_err(|_| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child lock poisoned"))?
+        .take()
+        .ok_or_else(|| SunwellError::new(ErrorCode::RuntimeStateInvalid, "Proposal session child handle missing"))?;
+
+    let status = child.wait().await.map_err(|e| SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e))?;
+    if !status.success() {
+        return Err(SunwellError::new(ErrorCode::SkillExecutionFailed, "Proposal apply run failed"));
+    }
+
+    let summary = final_summary
+        .ok_or_else(|| SunwellError::new(ErrorCode::ConfigInvalid, "No complete event received from apply stream"))?;
+
+    Ok(ProposalApplyRunResult { token, summary })
+}
+
+/// Cancel an in-flight streaming proposal session registered under `token`
+/// (see `self_test_proposal_streaming` / `self_apply_proposal_streaming`):
+/// wakes its read loop and kills the child process.
+#[tauri::command]
+pub async fn cancel_proposal_session(token: String, state: State<'_, AppState>) -> Result<(), SunwellError> {
+    state.self_proposals.cancel(&token).await
+}
+
+// =============================================================================
+// Source Watcher (self_watch_source / self_unwatch_source)
+// =============================================================================
+//
+// Watches Sunwell's own source root and re-runs analysis on debounced
+// change, so the self-knowledge dashboard stays current without a manual
+// refresh. Mirrors `lens_watcher::LensWatcherManager` — a `notify` watcher
+// feeding a debounce thread that emits one coalesced event per settled
+// burst — except here a burst triggers a full re-analysis pass rather than
+// a per-file event, since every command in this module already fetches
+// fresh from the CLI with no Studio-side cache to invalidate.
+
+/// How long to wait for a burst of source-file events to go quiet before
+/// re-running analysis. Mirrors `lens_watcher::DEBOUNCE`.
+const SOURCE_WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Combined payload for the `self-knowledge-updated` event, emitted once a
+/// debounced source change settles and analysis has been re-run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfKnowledgeUpdated {
+    pub patterns: PatternReport,
+    pub failures: FailureReport,
+    pub summary: SelfKnowledgeSummary,
+}
+
+/// A running watcher. Dropping this stops watching (the `notify` watcher
+/// is torn down) and signals the debounce thread to exit.
+struct SourceWatcherHandle {
+    _watcher: RecommendedWatcher,
+    stop_tx: mpsc::Sender<()>,
+}
+
+/// Owns the source watcher's lifecycle, mirroring `LensWatcherManager` so
+/// it can live in `AppState` the same way.
+#[derive(Default)]
+pub struct SourceWatcherManager {
+    handle: Option<SourceWatcherHandle>,
+}
+
+impl SourceWatcherManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start watching Sunwell's source root, or do nothing if already
+    /// running.
+    pub fn start(&mut self, app: AppHandle) -> Result<(), SunwellError> {
+        if self.handle.is_some() {
+            return Ok(());
+        }
+
+        let source_root = fetch_summary()
+            .ok_or_else(|| sunwell_err!(RuntimeStateInvalid, "Could not resolve source root for watching"))?
+            .source_root;
+
+        let (event_tx, event_rx) = mpsc::channel::<NotifyEvent>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<NotifyEvent>| {
+            if let Ok(event) = res {
+                let _ = event_tx.send(event);
+            }
+        })
+        .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to create source watcher: {}", e))?;
+
+        watcher
+            .watch(Path::new(&source_root), RecursiveMode::Recursive)
+            .map_err(|e| sunwell_err!(RuntimeStateInvalid, "Failed to watch {}: {}", source_root, e))?;
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        std::thread::spawn(move || source_debounce_loop(app, event_rx, stop_rx));
+
+        self.handle = Some(SourceWatcherHandle { _watcher: watcher, stop_tx });
+        Ok(())
+    }
+
+    /// Stop watching. A no-op if not running.
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.stop_tx.send(());
+        }
+    }
+}
+
+/// Ignore Sunwell's own build output and VCS/cache metadata so edits there
+/// don't trigger spurious re-analysis.
+fn is_ignored_source_path(path: &Path) -> bool {
+    path.components()
+        .any(|c| matches!(c.as_os_str().to_str(), Some("target") | Some(".git") | Some("__pycache__")))
+}
+
+/// Coalesce a burst of source-file events into a single re-analysis pass,
+/// holding off until events go quiet for `SOURCE_WATCH_DEBOUNCE`.
+fn source_debounce_loop(app: AppHandle, event_rx: mpsc::Receiver<NotifyEvent>, stop_rx: mpsc::Receiver<()>) {
+    let mut last_event: Option<Instant> = None;
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            return;
+        }
+
+        match event_rx.recv_timeout(SOURCE_WATCH_DEBOUNCE) {
+            Ok(event) => {
+                if event.paths.iter().any(|p| !is_ignored_source_path(p)) {
+                    last_event = Some(Instant::now());
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        if let Some(seen) = last_event {
+            if seen.elapsed() >= SOURCE_WATCH_DEBOUNCE {
+                last_event = None;
+                if let Some(updated) = run_analysis_refresh() {
+                    let _ = app.emit("self-knowledge-updated", updated);
+                }
+            }
+        }
+    }
+}
+
+fn fetch_patterns() -> Option<PatternReport> {
+    let output =
+        sunwell_command().args(["self", "analysis", "patterns", "--scope", "session", "--json"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_json_safe(&String::from_utf8_lossy(&output.stdout)).ok()
+}
+
+fn fetch_failures() -> Option<FailureReport> {
+    let output = sunwell_command().args(["self", "analysis", "failures", "--limit", "20", "--json"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_json_safe(&String::from_utf8_lossy(&output.stdout)).ok()
+}
+
+fn fetch_summary() -> Option<SelfKnowledgeSummary> {
+    let output = sunwell_command().args(["self", "summary", "--json"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_json_safe(&String::from_utf8_lossy(&output.stdout)).ok()
+}
+
+/// Re-run the three analysis calls a settled source change should refresh.
+/// Best-effort: if any call fails, skip emitting rather than surface a
+/// partial update.
+fn run_analysis_refresh() -> Option<SelfKnowledgeUpdated> {
+    Some(SelfKnowledgeUpdated { patterns: fetch_patterns()?, failures: fetch_failures()?, summary: fetch_summary()? })
+}
+
+/// Start watching Sunwell's source root, if not already running. On
+/// debounced change, re-runs analysis and emits `self-knowledge-updated`.
+#[tauri::command]
+pub async fn self_watch_source(
+    app: AppHandle,
+    state: State<'_, crate::commands::AppState>,
+) -> Result<(), String> {
+    state.source_watcher.lock().unwrap().start(app).map_err(|e| e.to_json())
+}
+
+/// Stop watching Sunwell's source root, if running.
+#[tauri::command]
+pub async fn self_unwatch_source(state: State<'_, crate::commands::AppState>) -> Result<(), String> {
+    state.source_watcher.lock().unwrap().stop();
     Ok(())
 }
 
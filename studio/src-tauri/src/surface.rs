@@ -5,6 +5,7 @@
 
 use crate::error::{ErrorCode, SunwellError};
 use crate::sunwell_err;
+use crate::telemetry::{self, CommandTimer};
 use crate::util::sunwell_command;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -52,6 +53,122 @@ pub struct PrimitiveEvent {
     pub data: HashMap<String, serde_json::Value>,
 }
 
+/// Result of routing a `PrimitiveEvent` to its handlers.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PrimitiveEventResponse {
+    /// Ids of the primitives whose Python handler actually ran.
+    #[serde(rename = "handledBy", default)]
+    pub handled_by: Vec<String>,
+
+    /// Layout changes the handler(s) asked for, if any.
+    #[serde(rename = "layoutMutations", skip_serializing_if = "Option::is_none")]
+    pub layout_mutations: Option<Vec<SurfaceLayout>>,
+
+    /// `true` if this call was coalesced by the debounce window and never
+    /// reached the CLI — `handled_by` being empty doesn't mean no
+    /// primitive wanted the event, just that this particular burst was
+    /// absorbed into the next one that gets through.
+    #[serde(default)]
+    pub debounced: bool,
+}
+
+// =============================================================================
+// PRIMITIVE EVENT ROUTING
+// =============================================================================
+
+/// Which primitive categories are eligible to handle each event type.
+/// Mirrors the categories `get_primitive_registry` returns from the CLI;
+/// extend this table alongside new primitive categories or event types.
+const EVENT_TYPE_CATEGORIES: &[(&str, &[&str])] = &[
+    ("file_edit", &["editor"]),
+    ("terminal_output", &["terminal"]),
+    ("test_result", &["editor", "test_runner"]),
+    (
+        "user_action",
+        &["editor", "terminal", "test_runner", "chat"],
+    ),
+];
+
+/// How long a burst of same-typed events is coalesced before the next one
+/// is allowed through to the CLI. Only listed event types are debounced —
+/// `file_edit`/`test_result`/`user_action` are low-frequency enough that
+/// every one should reach its handler.
+const DEBOUNCE_WINDOWS_MS: &[(&str, u64)] = &[("terminal_output", 150)];
+
+/// Registered primitives grouped by category, populated from
+/// `get_primitive_registry` the first time an event needs routing.
+fn primitive_categories() -> &'static std::sync::Mutex<HashMap<String, Vec<String>>> {
+    static REGISTRY: std::sync::OnceLock<std::sync::Mutex<HashMap<String, Vec<String>>>> =
+        std::sync::OnceLock::new();
+    REGISTRY.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+fn last_forwarded() -> &'static std::sync::Mutex<HashMap<String, std::time::Instant>> {
+    static LAST: std::sync::OnceLock<std::sync::Mutex<HashMap<String, std::time::Instant>>> =
+        std::sync::OnceLock::new();
+    LAST.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+/// Whether `event_type`'s debounce window hasn't elapsed since the last
+/// event of that type was forwarded. Types with no configured window are
+/// never debounced.
+fn is_debounced(event_type: &str) -> bool {
+    let Some(&(_, window_ms)) = DEBOUNCE_WINDOWS_MS.iter().find(|(t, _)| *t == event_type) else {
+        return false;
+    };
+
+    let mut last = last_forwarded().lock().unwrap();
+    let now = std::time::Instant::now();
+    match last.get(event_type) {
+        Some(previous)
+            if now.duration_since(*previous) < std::time::Duration::from_millis(window_ms) =>
+        {
+            true
+        }
+        _ => {
+            last.insert(event_type.to_string(), now);
+            false
+        }
+    }
+}
+
+/// Resolve the primitive ids eligible to receive `event_type`, refreshing
+/// the category registry from `get_primitive_registry` the first time
+/// it's empty.
+fn resolve_handlers(event_type: &str) -> Result<Vec<String>, String> {
+    let categories = EVENT_TYPE_CATEGORIES
+        .iter()
+        .find(|(t, _)| *t == event_type)
+        .map(|(_, c)| *c)
+        .unwrap_or(&[]);
+
+    {
+        let registry = primitive_categories().lock().unwrap();
+        if !registry.is_empty() {
+            return Ok(collect_ids(&registry, categories));
+        }
+    }
+
+    let defs = get_primitive_registry()?;
+    let mut by_category: HashMap<String, Vec<String>> = HashMap::new();
+    for def in defs {
+        by_category.entry(def.category).or_default().push(def.id);
+    }
+    let registry = by_category;
+    let ids = collect_ids(&registry, categories);
+    *primitive_categories().lock().unwrap() = registry;
+    Ok(ids)
+}
+
+fn collect_ids(registry: &HashMap<String, Vec<String>>, categories: &[&str]) -> Vec<String> {
+    categories
+        .iter()
+        .filter_map(|category| registry.get(*category))
+        .flatten()
+        .cloned()
+        .collect()
+}
+
 // =============================================================================
 // TAURI COMMANDS
 // =============================================================================
@@ -78,6 +195,7 @@ pub fn get_primitive_registry() -> Result<Vec<PrimitiveDef>, String> {
 }
 
 /// Compose a surface layout for the given goal.
+#[tracing::instrument(skip(goal, project_path, lens, arrangement), fields(wall_clock_ms))]
 #[tauri::command]
 pub async fn compose_surface(
     goal: String,
@@ -85,6 +203,12 @@ pub async fn compose_surface(
     lens: Option<String>,
     arrangement: Option<String>,
 ) -> Result<SurfaceLayout, String> {
+    let timer = CommandTimer::start();
+    crate::runtime_version::require("surface.compose").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+
     let mut args = vec![
         "surface".to_string(),
         "compose".to_string(),
@@ -108,22 +232,28 @@ pub async fn compose_surface(
         args.push(arr.clone());
     }
 
-    let output = sunwell_command()
-        .args(&args)
-        .output()
-        .map_err(|e| {
-            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
-                .with_hints(vec!["Check if sunwell CLI is installed"])
-                .to_json()
-        })?;
+    let output = sunwell_command().args(&args).output().map_err(|e| {
+        let err = SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Check if sunwell CLI is installed"]);
+        telemetry::record_failure(&err);
+        err.to_json()
+    })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(sunwell_err!(SkillExecutionFailed, "Composition failed: {}", stderr).to_json());
+        let err = sunwell_err!(SkillExecutionFailed, "Composition failed: {}", stderr);
+        telemetry::record_failure(&err);
+        return Err(err.to_json());
     }
 
-    serde_json::from_slice(&output.stdout)
-        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse surface layout: {}", e).to_json())
+    let layout = serde_json::from_slice(&output.stdout).map_err(|e| {
+        let err = sunwell_err!(ConfigInvalid, "Failed to parse surface layout: {}", e);
+        telemetry::record_failure(&err);
+        err.to_json()
+    });
+
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+    layout
 }
 
 /// Save a layout as successful for future reference.
@@ -165,18 +295,81 @@ pub async fn record_layout_success(
     Ok(())
 }
 
-/// Emit an event from a primitive.
+/// Emit an event from a primitive, routing it to whichever registered
+/// primitives' Python handlers are eligible for its `event_type`.
+///
+/// Fan-out is done in one CLI round trip: every eligible primitive id is
+/// sent along with the event, and `surface event` decides server-side
+/// which of them actually handle it, returning `handled_by` plus any
+/// layout mutations those handlers asked for. High-frequency types listed
+/// in `DEBOUNCE_WINDOWS_MS` (e.g. `terminal_output`) are coalesced —
+/// calls inside the window return immediately with `debounced: true`
+/// instead of crossing the process boundary.
 #[tauri::command]
-pub async fn emit_primitive_event(event: PrimitiveEvent) -> Result<(), String> {
-    // Route to appropriate handler based on event type
-    // For now, we just acknowledge the event. Future: route to Python for processing.
+pub async fn emit_primitive_event(event: PrimitiveEvent) -> Result<PrimitiveEventResponse, String> {
     match event.event_type.as_str() {
-        "file_edit" | "terminal_output" | "test_result" | "user_action" => {
-            // Event acknowledged - can be extended to trigger Python handlers
-        }
+        "file_edit" | "terminal_output" | "test_result" | "user_action" => {}
         _ => {
-            return Err(format!("Unknown primitive event type: {}", event.event_type));
+            return Err(format!(
+                "Unknown primitive event type: {}",
+                event.event_type
+            ));
         }
     }
-    Ok(())
+
+    if is_debounced(&event.event_type) {
+        return Ok(PrimitiveEventResponse {
+            debounced: true,
+            ..Default::default()
+        });
+    }
+
+    let target_ids = resolve_handlers(&event.event_type)?;
+    if target_ids.is_empty() {
+        return Ok(PrimitiveEventResponse::default());
+    }
+
+    let payload = serde_json::json!({
+        "event": event,
+        "primitive_ids": target_ids,
+    });
+    let json = serde_json::to_string(&payload)
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to serialize event: {}", e).to_json())?;
+
+    let mut child = sunwell_command()
+        .args(["surface", "event", "--json"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+                .with_hints(vec!["Check if sunwell CLI is installed"])
+                .to_json()
+        })?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        use std::io::Write;
+        stdin.write_all(json.as_bytes()).map_err(|e| {
+            SunwellError::from_error(ErrorCode::FileWriteFailed, e)
+                .with_hints(vec!["Check process stdin is available"])
+                .to_json()
+        })?;
+    }
+
+    let output = child.wait_with_output().map_err(|e| {
+        SunwellError::from_error(ErrorCode::RuntimeProcessFailed, e)
+            .with_hints(vec!["Process may have been interrupted"])
+            .to_json()
+    })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(
+            sunwell_err!(SkillExecutionFailed, "Event handling failed: {}", stderr).to_json(),
+        );
+    }
+
+    serde_json::from_slice(&output.stdout)
+        .map_err(|e| sunwell_err!(ConfigInvalid, "Failed to parse event response: {}", e).to_json())
 }
@@ -0,0 +1,115 @@
+//! Tracing / OpenTelemetry Instrumentation (RFC-101)
+//!
+//! Gives operators visibility into where time goes in the UI → CLI
+//! bridge. The Naaru, Generative Interface, surface, security, and
+//! weakness-cascade commands open a `tracing` span per invocation
+//! (carrying attributes like mode, page_type, route_type, and dag_id) and
+//! record child-process spawn latency, time-to-first-event, and total
+//! wall-clock through [`CommandTimer`]. Export is behind the
+//! `otel` Cargo feature: enabled builds ship spans to an OTLP collector,
+//! disabled builds get a no-op `tracing` dispatcher so instrumentation
+//! costs nothing when no collector is configured.
+//!
+//! Command handlers don't talk to `opentelemetry` directly — they go
+//! through [`init_telemetry`] and [`record_failure`] here so the exporter
+//! can change without touching every `#[tauri::command]`.
+
+use crate::error::SunwellError;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Installs the global `tracing` subscriber. Call once from `main`, before
+/// the Tauri builder runs.
+#[cfg(feature = "otel")]
+pub fn init_telemetry() {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let exporter = match opentelemetry_otlp::SpanExporter::builder().with_tonic().build() {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            eprintln!("telemetry: failed to build OTLP exporter, spans will not be exported: {}", e);
+            return;
+        }
+    };
+
+    let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_batch_exporter(exporter, opentelemetry_sdk::runtime::Tokio)
+        .build();
+    let tracer = opentelemetry::trace::TracerProvider::tracer(&provider, "sunwell-studio");
+
+    let _ = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .try_init();
+}
+
+/// No-op when the `otel` feature is disabled: spans created via
+/// `#[tracing::instrument]` still run (argument formatting, `Span::record`,
+/// etc.) but nothing is collected or exported.
+#[cfg(not(feature = "otel"))]
+pub fn init_telemetry() {}
+
+/// Failure counts observed across instrumented commands, keyed by the
+/// failing [`SunwellError`]'s `error_id` (e.g. `"SW-0042"`), so the
+/// existing error taxonomy becomes an observable metric without
+/// duplicating it.
+static FAILURE_COUNTS: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+
+fn failure_counts() -> &'static Mutex<HashMap<String, u64>> {
+    FAILURE_COUNTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records one command failure: increments the in-process counter for
+/// `error`'s code and emits a `tracing` event carrying the same fields so
+/// an `otel` collector can turn it into a metric data point. Call this at
+/// the error site, before `.to_json()` — `to_json` only borrows `self`.
+pub fn record_failure(error: &SunwellError) {
+    let mut counts = failure_counts().lock().unwrap();
+    *counts.entry(error.error_id.clone()).or_insert(0) += 1;
+    drop(counts);
+
+    tracing::warn!(
+        error_id = %error.error_id,
+        category = %error.category,
+        recoverable = error.recoverable,
+        "sunwell command failed"
+    );
+}
+
+/// Snapshot of current failure counts, keyed by `error_id`. Exposed for
+/// diagnostics callers (e.g. `self_knowledge`); not itself a Tauri command
+/// since it's plumbing rather than user-facing data.
+#[allow(dead_code)] // consumed by future diagnostics surfacing, not wired to a command yet
+pub fn failure_snapshot() -> HashMap<String, u64> {
+    failure_counts().lock().unwrap().clone()
+}
+
+/// Stopwatch for the spawn → first-event → completion timeline a command
+/// span records. `mark_first_event` is a no-op after the first call, so a
+/// multi-event stream (e.g. `naaru_subscribe`) only records the earliest
+/// one.
+pub struct CommandTimer {
+    started: Instant,
+    first_event: Option<Duration>,
+}
+
+impl CommandTimer {
+    pub fn start() -> Self {
+        Self { started: Instant::now(), first_event: None }
+    }
+
+    pub fn mark_first_event(&mut self) {
+        if self.first_event.is_none() {
+            self.first_event = Some(self.started.elapsed());
+        }
+    }
+
+    pub fn time_to_first_event_ms(&self) -> Option<u128> {
+        self.first_event.map(|d| d.as_millis())
+    }
+
+    pub fn elapsed_ms(&self) -> u128 {
+        self.started.elapsed().as_millis()
+    }
+}
@@ -74,6 +74,31 @@ pub fn sunwell_command() -> Command {
     Command::new("sunwell")
 }
 
+/// Async counterpart to `sunwell_command()`, for callers that need to stream
+/// the child's output (e.g. progress events) rather than block on `.output()`.
+///
+/// # Example
+/// ```
+/// let child = sunwell_command_async()
+///     .args(["project", "analyze", "--json"])
+///     .current_dir(&project_path)
+///     .stdout(std::process::Stdio::piped())
+///     .spawn()?;
+/// ```
+pub fn sunwell_command_async() -> tokio::process::Command {
+    if which_sunwell().is_some() {
+        return tokio::process::Command::new("sunwell");
+    }
+
+    if let Some(python) = find_python() {
+        let mut cmd = tokio::process::Command::new(python);
+        cmd.args(["-m", "sunwell.cli"]);
+        return cmd;
+    }
+
+    tokio::process::Command::new("sunwell")
+}
+
 /// Check if `sunwell` is available in PATH.
 fn which_sunwell() -> Option<std::path::PathBuf> {
     which::which("sunwell").ok()
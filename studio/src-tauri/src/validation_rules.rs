@@ -0,0 +1,326 @@
+//! Native Validation Rule Engine (RFC-111)
+//!
+//! `validate_document`/`fix_all_issues` in `writer.rs` used to shell out to
+//! the Python CLI per call and get back either an opaque `Vec<ValidationWarning>`
+//! or a `FixResult` with just a changed-content string and a count. This
+//! module replaces that round trip with an in-process rule subsystem: a
+//! [`Rule`] trait produces [`ValidationWarning`]s from a [`DocContext`] and
+//! optionally proposes [`TextEdit`]s (byte offsets) to fix one of them. A
+//! [`RuleRegistry`] keyed by lens name holds the enabled rules and maps
+//! each rule's intrinsic severity to the lens-configured level.
+//!
+//! Fixing is deterministic: collect every warning's edits, sort by
+//! descending start offset, and apply in reverse so earlier offsets stay
+//! valid as later ones are consumed. Two edits that overlap can't both be
+//! applied safely — the later (lower-start) one is reported unfixable
+//! rather than silently corrupting the document.
+
+use crate::writer::ValidationWarning;
+use std::collections::HashMap;
+
+/// One proposed edit, expressed as byte offsets into the original content
+/// (`start..end`) and the text that should replace that range.
+#[derive(Debug, Clone)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+/// Everything a rule needs to check or fix a document. Borrowed, not
+/// owned — rules run read-only over the document under check.
+pub struct DocContext<'a> {
+    pub content: &'a str,
+    pub file_path: Option<&'a str>,
+    pub lens_name: &'a str,
+}
+
+/// A single lint rule. `check` is mandatory; `fix` is optional since not
+/// every warning has a safe mechanical fix (e.g. a heading-level skip
+/// needs a human to decide the right level).
+pub trait Rule: Send + Sync {
+    /// Stable identifier, also written to `ValidationWarning.rule` so a
+    /// fix can be looked back up by id without re-running every rule.
+    fn id(&self) -> &'static str;
+
+    /// Severity used when the lens config doesn't override it.
+    fn default_severity(&self) -> &'static str;
+
+    fn check(&self, ctx: &DocContext) -> Vec<ValidationWarning>;
+
+    fn fix(&self, ctx: &DocContext, warning: &ValidationWarning) -> Option<Vec<TextEdit>> {
+        let _ = (ctx, warning);
+        None
+    }
+}
+
+/// Rules enabled for a lens, alongside the severity the lens configures
+/// for each (falling back to the rule's own default when unset).
+struct RegisteredRule {
+    rule: Box<dyn Rule>,
+    severity: String,
+}
+
+/// Rules keyed by lens name. `"*"` holds rules enabled for every lens —
+/// looked up after (and merged with) any lens-specific entry, the same
+/// "specific overrides general" shape `check_lens_allowed` uses in
+/// `lens.rs`.
+pub struct RuleRegistry {
+    rules: HashMap<String, Vec<RegisteredRule>>,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self {
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Registers `rule` for `lens_name` (or every lens, via `"*"`), with an
+    /// optional severity override.
+    pub fn register(&mut self, lens_name: &str, rule: Box<dyn Rule>, severity: Option<&str>) {
+        let severity = severity
+            .map(str::to_string)
+            .unwrap_or_else(|| rule.default_severity().to_string());
+        self.rules
+            .entry(lens_name.to_string())
+            .or_default()
+            .push(RegisteredRule { rule, severity });
+    }
+
+    fn rules_for(&self, lens_name: &str) -> impl Iterator<Item = &RegisteredRule> {
+        self.rules
+            .get("*")
+            .into_iter()
+            .flatten()
+            .chain(self.rules.get(lens_name).into_iter().flatten())
+    }
+
+    /// Runs every rule enabled for `ctx.lens_name`, applying each rule's
+    /// configured severity to its warnings.
+    pub fn check(&self, ctx: &DocContext) -> Vec<ValidationWarning> {
+        self.rules_for(ctx.lens_name)
+            .flat_map(|registered| {
+                registered.rule.check(ctx).into_iter().map(|mut warning| {
+                    warning.severity = registered.severity.clone();
+                    warning
+                })
+            })
+            .collect()
+    }
+
+    /// Collects fixes for every warning that has one, applies the
+    /// non-overlapping subset, and returns the fixed content alongside
+    /// whichever warnings couldn't be fixed (none, or an overlap).
+    pub fn fix_all(&self, ctx: &DocContext, warnings: &[ValidationWarning]) -> FixOutcome {
+        let rules_by_id: HashMap<&str, &dyn Rule> = self
+            .rules_for(ctx.lens_name)
+            .map(|registered| (registered.rule.id(), registered.rule.as_ref()))
+            .collect();
+
+        let mut edits = Vec::new();
+        let mut unfixable = Vec::new();
+        for warning in warnings {
+            match rules_by_id
+                .get(warning.rule.as_str())
+                .and_then(|rule| rule.fix(ctx, warning))
+            {
+                Some(warning_edits) if !warning_edits.is_empty() => edits.extend(warning_edits),
+                _ => unfixable.push(warning.clone()),
+            }
+        }
+
+        let (content, skipped) = apply_edits(ctx.content, edits);
+        unfixable.extend(skipped.into_iter().map(|edit| ValidationWarning {
+            line: 0,
+            column: None,
+            message: format!(
+                "Fix at byte {}..{} overlaps another fix and was skipped",
+                edit.start, edit.end
+            ),
+            rule: "overlapping-edit".to_string(),
+            severity: "warning".to_string(),
+            suggestion: None,
+        }));
+
+        FixOutcome {
+            content,
+            fixed: warnings.len() - unfixable.len(),
+            unfixable,
+        }
+    }
+}
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct FixOutcome {
+    pub content: String,
+    pub fixed: usize,
+    pub unfixable: Vec<ValidationWarning>,
+}
+
+/// Applies non-overlapping edits in descending-start order so earlier
+/// offsets in `content` stay valid as later (higher-offset) edits are
+/// consumed first. Returns the result plus any edits skipped because they
+/// overlapped one already applied.
+fn apply_edits(content: &str, mut edits: Vec<TextEdit>) -> (String, Vec<TextEdit>) {
+    edits.sort_by(|a, b| b.start.cmp(&a.start));
+
+    let mut result = content.to_string();
+    let mut skipped = Vec::new();
+    let mut floor = usize::MAX;
+
+    for edit in edits {
+        let in_bounds = edit.start <= edit.end && edit.end <= result.len();
+        let overlaps_applied = edit.end > floor;
+        if !in_bounds || overlaps_applied {
+            skipped.push(edit);
+            continue;
+        }
+        result.replace_range(edit.start..edit.end, &edit.replacement);
+        floor = edit.start;
+    }
+
+    (result, skipped)
+}
+
+// =============================================================================
+// BUILT-IN RULES
+// =============================================================================
+
+/// Flags lines with trailing whitespace; fixable by trimming the trailing
+/// run of spaces/tabs.
+struct TrailingWhitespaceRule;
+
+impl Rule for TrailingWhitespaceRule {
+    fn id(&self) -> &'static str {
+        "trailing-whitespace"
+    }
+
+    fn default_severity(&self) -> &'static str {
+        "note"
+    }
+
+    fn check(&self, ctx: &DocContext) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        for (i, line) in ctx.content.lines().enumerate() {
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            if trimmed.len() != line.len() {
+                warnings.push(ValidationWarning {
+                    line: (i + 1) as i32,
+                    column: Some((trimmed.len() + 1) as i32),
+                    message: "Trailing whitespace".to_string(),
+                    rule: self.id().to_string(),
+                    severity: self.default_severity().to_string(),
+                    suggestion: Some("Remove trailing whitespace".to_string()),
+                });
+            }
+        }
+        warnings
+    }
+
+    fn fix(&self, ctx: &DocContext, warning: &ValidationWarning) -> Option<Vec<TextEdit>> {
+        let (start, line) = line_byte_range(ctx.content, warning.line)?;
+        let trimmed = line.trim_end_matches([' ', '\t']);
+        if trimmed.len() == line.len() {
+            return None;
+        }
+        Some(vec![TextEdit {
+            start: start + trimmed.len(),
+            end: start + line.len(),
+            replacement: String::new(),
+        }])
+    }
+}
+
+/// Flags runs of 3+ consecutive blank lines; fixable by collapsing the
+/// run down to a single blank line.
+struct ExcessBlankLinesRule;
+
+impl Rule for ExcessBlankLinesRule {
+    fn id(&self) -> &'static str {
+        "excess-blank-lines"
+    }
+
+    fn default_severity(&self) -> &'static str {
+        "note"
+    }
+
+    fn check(&self, ctx: &DocContext) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        let mut blank_run_start: Option<usize> = None;
+        let lines: Vec<&str> = ctx.content.lines().collect();
+        for (i, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                if blank_run_start.is_none() {
+                    blank_run_start = Some(i);
+                }
+            } else if let Some(start) = blank_run_start.take() {
+                if i - start >= 3 {
+                    warnings.push(ValidationWarning {
+                        line: (start + 1) as i32,
+                        column: None,
+                        message: format!("{} consecutive blank lines", i - start),
+                        rule: self.id().to_string(),
+                        severity: self.default_severity().to_string(),
+                        suggestion: Some("Collapse to a single blank line".to_string()),
+                    });
+                }
+            }
+        }
+        warnings
+    }
+
+    fn fix(&self, ctx: &DocContext, warning: &ValidationWarning) -> Option<Vec<TextEdit>> {
+        let (start, _) = line_byte_range(ctx.content, warning.line)?;
+        let lines: Vec<&str> = ctx.content.lines().collect();
+        let first = (warning.line - 1) as usize;
+        let mut last = first;
+        while last + 1 < lines.len() && lines[last + 1].trim().is_empty() {
+            last += 1;
+        }
+        // Delete everything from the first blank line up to (not including)
+        // the last blank line, so exactly one blank line survives.
+        let (last_start, _) = line_byte_range(ctx.content, (last + 1) as i32)?;
+        if last_start <= start {
+            return None;
+        }
+        Some(vec![TextEdit {
+            start,
+            end: last_start,
+            replacement: String::new(),
+        }])
+    }
+}
+
+/// Returns the byte offset of the start of `line` (1-indexed) and its
+/// content (excluding the trailing newline), or `None` if out of range.
+fn line_byte_range(content: &str, line: i32) -> Option<(usize, &str)> {
+    if line < 1 {
+        return None;
+    }
+    let mut offset = 0;
+    for (i, l) in content.split_inclusive('\n').enumerate() {
+        let bare = l.strip_suffix('\n').unwrap_or(l);
+        if i as i32 == line - 1 {
+            return Some((offset, bare));
+        }
+        offset += l.len();
+    }
+    None
+}
+
+/// Builds the registry used by `writer::validate_document`/`fix_all_issues`:
+/// the built-in rules above, enabled for every lens via the `"*"` bucket.
+/// Lens-specific rules (once lenses can declare their own) would register
+/// under the lens's name instead.
+pub fn default_registry() -> RuleRegistry {
+    let mut registry = RuleRegistry::new();
+    registry.register("*", Box::new(TrailingWhitespaceRule), None);
+    registry.register("*", Box::new(ExcessBlankLinesRule), None);
+    registry
+}
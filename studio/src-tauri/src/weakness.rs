@@ -2,6 +2,7 @@
 //!
 //! Bridge between frontend and Python CLI for weakness operations.
 
+use crate::telemetry::{self, CommandTimer};
 use crate::util::{parse_json_safe, sunwell_command};
 use std::path::PathBuf;
 
@@ -38,6 +39,8 @@ pub async fn scan_weaknesses(path: String) -> Result<WeaknessReport, String> {
 /// Preview cascade for a specific weakness.
 #[tauri::command]
 pub async fn preview_cascade(path: String, artifact_id: String) -> Result<CascadePreview, String> {
+    crate::runtime_version::require("cascade.preview").map_err(|e| e.to_json())?;
+
     let project_path = PathBuf::from(&path);
 
     let output = sunwell_command()
@@ -58,6 +61,7 @@ pub async fn preview_cascade(path: String, artifact_id: String) -> Result<Cascad
 }
 
 /// Execute cascade fix through agent with event streaming.
+#[tracing::instrument(skip(app, path, artifact_id), fields(artifact_id = %artifact_id, wall_clock_ms))]
 #[tauri::command]
 pub async fn execute_cascade_fix(
     app: tauri::AppHandle,
@@ -69,6 +73,21 @@ pub async fn execute_cascade_fix(
     use std::io::{BufRead, BufReader};
     use std::process::Stdio;
 
+    let timer = CommandTimer::start();
+
+    // `artifact_id` is the same id the security-approval flow analyzes as
+    // a `dag_id` (`security::analyze_dag_permissions`) — a cascade fix is
+    // exactly the DAG that flow approves. Actually fixing files requires
+    // both capabilities the user was asked to grant.
+    crate::runtime_acl::require(&artifact_id, "filesystem_write").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+    crate::runtime_acl::require(&artifact_id, "shell").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+
     let project_path = PathBuf::from(&path);
 
     // Get preview first for the initial event
@@ -155,10 +174,16 @@ pub async fn execute_cascade_fix(
     )
     .map_err(|e| format!("Failed to emit completion event: {}", e))?;
 
+    for wave in &execution.wave_confidences {
+        crate::metrics::record_cascade_wave_confidence(wave.confidence);
+    }
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+
     Ok(execution)
 }
 
 /// Start wave-by-wave cascade execution.
+#[tracing::instrument(skip(path, artifact_id), fields(artifact_id = %artifact_id, wall_clock_ms))]
 #[tauri::command]
 pub async fn start_cascade_execution(
     path: String,
@@ -166,6 +191,20 @@ pub async fn start_cascade_execution(
     auto_approve: bool,
     confidence_threshold: f32,
 ) -> Result<CascadeExecution, String> {
+    let timer = CommandTimer::start();
+    crate::runtime_version::require("cascade.preview").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+    crate::runtime_acl::require(&artifact_id, "filesystem_write").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+    crate::runtime_acl::require(&artifact_id, "shell").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+
     let project_path = PathBuf::from(&path);
 
     let mut args = vec![
@@ -190,6 +229,11 @@ pub async fn start_cascade_execution(
     let execution: CascadeExecution = serde_json::from_slice(&output.stdout)
         .map_err(|e| format!("Failed to parse execution state: {}", e))?;
 
+    for wave in &execution.wave_confidences {
+        crate::metrics::record_cascade_wave_confidence(wave.confidence);
+    }
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+
     Ok(execution)
 }
 
@@ -232,3 +276,243 @@ pub async fn extract_contract(
 
     Ok(contract)
 }
+
+// =============================================================================
+// STREAMING CASCADE EXECUTION
+// =============================================================================
+
+/// Handle to a `run_cascade` child process, kept around only so
+/// `abort_cascade` can reach it while the spawning command is still
+/// blocked reading its stdout.
+struct RunningCascade {
+    stdin: std::sync::Mutex<Option<std::process::ChildStdin>>,
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pid: u32,
+}
+
+fn running_cascades(
+) -> &'static std::sync::Mutex<std::collections::HashMap<String, std::sync::Arc<RunningCascade>>> {
+    static CASCADES: std::sync::OnceLock<
+        std::sync::Mutex<std::collections::HashMap<String, std::sync::Arc<RunningCascade>>>,
+    > = std::sync::OnceLock::new();
+    CASCADES.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()))
+}
+
+/// Run a wave-by-wave cascade fix, streaming progress to the frontend as
+/// it happens instead of returning only the final state.
+///
+/// The CLI is run with `--wave-by-wave --stream --json` and emits one
+/// `CascadeExecution` snapshot per line as the cascade progresses. This
+/// command diffs each snapshot against the previous one and re-emits the
+/// *differences* as distinct Tauri events — `cascade-wave-started`,
+/// `cascade-wave-confidence`, `cascade-paused-for-approval`,
+/// `cascade-escalated-to-human`, `cascade-low-confidence-streak` — rather
+/// than one generic blob per line, so the UI can react to a threshold
+/// crossing mid-run instead of only after the whole cascade finishes.
+#[tracing::instrument(skip(app, path, artifact_id), fields(artifact_id = %artifact_id, wall_clock_ms))]
+#[tauri::command]
+pub async fn run_cascade(
+    app: tauri::AppHandle,
+    path: String,
+    artifact_id: String,
+    auto_approve: bool,
+    confidence_threshold: f32,
+) -> Result<CascadeExecution, String> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let timer = CommandTimer::start();
+
+    crate::runtime_acl::require(&artifact_id, "filesystem_write").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+    crate::runtime_acl::require(&artifact_id, "shell").map_err(|e| {
+        telemetry::record_failure(&e);
+        e.to_json()
+    })?;
+
+    let project_path = PathBuf::from(&path);
+
+    let mut args = vec![
+        "weakness".to_string(),
+        "fix".to_string(),
+        artifact_id.clone(),
+        "--wave-by-wave".to_string(),
+        "--stream".to_string(),
+        "--json".to_string(),
+        format!("--confidence-threshold={}", confidence_threshold),
+    ];
+    if auto_approve {
+        args.push("--yes".to_string());
+    }
+
+    let mut child = sunwell_command()
+        .args(&args)
+        .current_dir(&project_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start cascade: {}", e))?;
+
+    let pid = child.id();
+    let stdin = child.stdin.take();
+    running_cascades().lock().unwrap().insert(
+        artifact_id.clone(),
+        std::sync::Arc::new(RunningCascade {
+            stdin: std::sync::Mutex::new(stdin),
+            pid,
+        }),
+    );
+
+    let stdout = child.stdout.take().ok_or("No stdout")?;
+    let reader = BufReader::new(stdout);
+
+    let mut previous: Option<CascadeExecution> = None;
+    let mut last_execution: Option<CascadeExecution> = None;
+
+    for line in reader.lines() {
+        let Ok(line) = line else { continue };
+        let Ok(execution) = parse_json_safe::<CascadeExecution>(&line) else {
+            continue;
+        };
+        emit_cascade_diff(&app, &artifact_id, previous.as_ref(), &execution);
+        previous = Some(execution.clone());
+        last_execution = Some(execution);
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for cascade: {}", e))?;
+    running_cascades().lock().unwrap().remove(&artifact_id);
+
+    let execution =
+        last_execution.ok_or_else(|| "Cascade produced no snapshots before exiting".to_string())?;
+
+    if !status.success() && !execution.aborted {
+        return Err(format!(
+            "Cascade fix failed with exit code: {:?}",
+            status.code()
+        ));
+    }
+
+    for wave in &execution.wave_confidences {
+        crate::metrics::record_cascade_wave_confidence(wave.confidence);
+    }
+    tracing::Span::current().record("wall_clock_ms", timer.elapsed_ms() as u64);
+
+    Ok(execution)
+}
+
+/// Emit whatever changed between `previous` (the last snapshot seen, or
+/// `None` for the first) and `current` as distinct `cascade-*` events.
+fn emit_cascade_diff(
+    app: &tauri::AppHandle,
+    artifact_id: &str,
+    previous: Option<&CascadeExecution>,
+    current: &CascadeExecution,
+) {
+    let prev_wave = previous.map(|p| p.current_wave).unwrap_or(0);
+    if current.current_wave > prev_wave || previous.is_none() {
+        let _ = app.emit(
+            "cascade-wave-started",
+            &serde_json::json!({"artifact_id": artifact_id, "wave": current.current_wave}),
+        );
+    }
+
+    let prev_confidence_count = previous.map(|p| p.wave_confidences.len()).unwrap_or(0);
+    if let Some(latest) = current.wave_confidences.get(prev_confidence_count..) {
+        for confidence in latest {
+            let _ = app.emit(
+                "cascade-wave-confidence",
+                &serde_json::json!({"artifact_id": artifact_id, "confidence": confidence}),
+            );
+        }
+    }
+
+    let was_paused = previous.map(|p| p.paused_for_approval).unwrap_or(false);
+    if current.paused_for_approval && !was_paused {
+        let _ = app.emit(
+            "cascade-paused-for-approval",
+            &serde_json::json!({"artifact_id": artifact_id, "wave": current.current_wave}),
+        );
+    }
+
+    let was_escalated = previous.map(|p| p.escalated_to_human).unwrap_or(false);
+    if current.escalated_to_human && !was_escalated {
+        let _ = app.emit(
+            "cascade-escalated-to-human",
+            &serde_json::json!({"artifact_id": artifact_id, "wave": current.current_wave}),
+        );
+    }
+
+    let prev_streak = previous
+        .map(|p| p.consecutive_low_confidence_count)
+        .unwrap_or(0);
+    if current.consecutive_low_confidence_count > prev_streak
+        && current.consecutive_low_confidence_count >= current.max_consecutive_low_confidence
+    {
+        let _ = app.emit(
+            "cascade-low-confidence-streak",
+            &serde_json::json!({
+                "artifact_id": artifact_id,
+                "count": current.consecutive_low_confidence_count,
+                "max": current.max_consecutive_low_confidence,
+            }),
+        );
+    }
+
+    if current.completed {
+        let _ = app.emit(
+            "cascade-completed",
+            &serde_json::json!({"artifact_id": artifact_id, "overall_confidence": current.overall_confidence}),
+        );
+    } else if current.aborted {
+        let _ = app.emit(
+            "cascade-aborted",
+            &serde_json::json!({"artifact_id": artifact_id, "reason": current.abort_reason}),
+        );
+    }
+}
+
+/// Cooperatively cancel a `run_cascade` invocation still in flight for
+/// `artifact_id`: write a single-line stop command to the child's stdin
+/// (the CLI is expected to finish the current wave, mark `aborted: true`,
+/// and exit cleanly), falling back to `SIGTERM` on Unix if the process
+/// doesn't have an open stdin anymore. Unlike `agent::AgentManager`'s
+/// `request_graceful_stop`, this doesn't force-kill after a grace period —
+/// a cascade wave is already bounded, so a stuck process is a bug to fix
+/// upstream rather than something to paper over here.
+#[tauri::command]
+pub async fn abort_cascade(artifact_id: String) -> Result<(), String> {
+    let handle = running_cascades()
+        .lock()
+        .unwrap()
+        .get(&artifact_id)
+        .cloned();
+    let Some(handle) = handle else {
+        return Err(format!("No cascade is running for '{}'", artifact_id));
+    };
+
+    let wrote_stdin = {
+        let mut guard = handle.stdin.lock().unwrap();
+        if let Some(stdin) = guard.as_mut() {
+            use std::io::Write;
+            stdin.write_all(b"{\"command\":\"abort\"}\n").is_ok() && stdin.flush().is_ok()
+        } else {
+            false
+        }
+    };
+
+    if !wrote_stdin {
+        #[cfg(unix)]
+        {
+            let _ = std::process::Command::new("kill")
+                .args(["-TERM", &handle.pid.to_string()])
+                .output();
+        }
+    }
+
+    Ok(())
+}
@@ -91,6 +91,8 @@ pub async fn start_workflow(
     chain_name: String,
     target_file: Option<String>,
 ) -> Result<WorkflowExecution, String> {
+    crate::metrics::record_workflow_chain_started(&chain_name);
+
     let mut args = vec!["workflow", "run", &chain_name, "--json"];
 
     let target_owned: String;
@@ -106,11 +108,22 @@ pub async fn start_workflow(
         .map_err(|e| format!("Failed to start workflow: {}", e))?;
 
     if !output.status.success() {
+        crate::metrics::record_workflow_chain_failed(&chain_name);
         return Err(String::from_utf8_lossy(&output.stderr).to_string());
     }
 
     let json_str = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse execution: {}", e))
+    match parse_json_safe::<WorkflowExecution>(&json_str) {
+        Ok(execution) => {
+            record_step_durations(&execution);
+            crate::metrics::record_workflow_chain_completed(&chain_name);
+            Ok(execution)
+        }
+        Err(e) => {
+            crate::metrics::record_workflow_chain_failed(&chain_name);
+            Err(format!("Failed to parse execution: {}", e))
+        }
+    }
 }
 
 /// Stop a running workflow.
@@ -129,6 +142,11 @@ pub async fn stop_workflow(execution_id: String) -> Result<(), String> {
 }
 
 /// Resume a paused workflow.
+///
+/// Unlike `start_workflow`, there's no `chain_name` to record a "started"
+/// counter against here — the chain already started earlier, this just picks
+/// its existing execution back up — so only completed/failed are recorded,
+/// using the `chain_name` the resumed execution itself reports.
 #[tauri::command]
 pub async fn resume_workflow(execution_id: String) -> Result<WorkflowExecution, String> {
     let output = sunwell_command()
@@ -141,7 +159,14 @@ pub async fn resume_workflow(execution_id: String) -> Result<WorkflowExecution,
     }
 
     let json_str = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse execution: {}", e))
+    match parse_json_safe::<WorkflowExecution>(&json_str) {
+        Ok(execution) => {
+            record_step_durations(&execution);
+            crate::metrics::record_workflow_chain_completed(&execution.chain_name);
+            Ok(execution)
+        }
+        Err(e) => Err(format!("Failed to parse execution: {}", e)),
+    }
 }
 
 /// Skip the current workflow step.
@@ -198,6 +223,17 @@ pub async fn list_active_workflows() -> Result<Vec<WorkflowExecution>, String> {
 // HELPERS
 // =============================================================================
 
+/// Feed every completed step's `duration_s` into the shared step-duration
+/// histogram. Steps that errored or were skipped before recording a duration
+/// are silently excluded, same as the `Option` they come from.
+fn record_step_durations(execution: &WorkflowExecution) {
+    for step in &execution.steps {
+        if let Some(duration_s) = step.duration_s {
+            crate::metrics::record_workflow_step_duration(duration_s);
+        }
+    }
+}
+
 fn classify_intent_fallback(input: &str) -> Intent {
     let input_lower = input.to_lowercase();
 
@@ -0,0 +1,276 @@
+//! Workload-Driven Benchmark Harness for Workflow Chains (RFC-086 addendum)
+//!
+//! Builds on `workflow::start_workflow` the same way `naaru_bench` builds on
+//! `naaru_process`: a JSON "workload" file describes one or more named runs,
+//! each replaying a workflow chain `iterations` times via the existing
+//! `sunwell workflow run --json` path, modeled on Meilisearch's `xtask
+//! bench`. The per-step `duration_s` values `WorkflowStep` already reports
+//! are folded into wall-clock totals and latency percentiles per chain, so a
+//! regression in a workflow's runtime shows up as a diffable report instead
+//! of silently drifting. `report_url`, if set, lets the caller push the
+//! report to a tracking server the same way `demo::post_workload_report`
+//! does, so results can be tracked over time without the frontend needing
+//! to persist anything itself.
+
+use crate::error::{ErrorCode, SunwellError};
+use crate::util::{parse_json_safe, sunwell_command};
+use crate::workflow::WorkflowExecution;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// One chain to replay within a workload's run list.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BenchmarkRun {
+    pub chain_name: String,
+    #[serde(default)]
+    pub target_file: Option<String>,
+    #[serde(default)]
+    pub context: Option<serde_json::Value>,
+    pub iterations: u32,
+}
+
+/// A workload file: a named list of chain runs to replay, modeled on
+/// `self_benchmark::SelfBenchmarkWorkload` / `naaru_bench::NaaruBenchWorkload`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    pub runs: Vec<BenchmarkRun>,
+}
+
+/// Machine/build snapshot a report was captured under, so a `duration_s`
+/// regression across runs on different hardware or a different `sunwell`
+/// build doesn't silently skew the comparison.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkEnv {
+    pub git_commit: Option<String>,
+    pub git_describe: Option<String>,
+    pub os: String,
+    pub cpu_count: usize,
+    pub timestamp: String,
+}
+
+impl BenchmarkEnv {
+    fn capture() -> Self {
+        let git_commit = std::process::Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+        let git_describe = std::process::Command::new("git")
+            .args(["describe", "--tags", "--always"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+        Self {
+            git_commit,
+            git_describe,
+            os: std::env::consts::OS.to_string(),
+            cpu_count: num_cpus::get(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Per-step latency stats across all iterations of one chain, keyed by step
+/// skill name so a slowdown in one step of the chain doesn't get averaged
+/// away by the others.
+#[derive(Debug, Clone, Serialize)]
+pub struct StepTiming {
+    pub skill: String,
+    pub mean_s: f64,
+    pub p50_s: f64,
+    pub p95_s: f64,
+    pub p99_s: f64,
+}
+
+/// Aggregated result of replaying one `BenchmarkRun`'s chain `iterations`
+/// times.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainBenchmarkResult {
+    pub chain_name: String,
+    pub iterations_requested: u32,
+    pub iterations_succeeded: u32,
+    pub total_wall_clock_s: f64,
+    pub steps: Vec<StepTiming>,
+    /// `false` when every iteration failed, so a caller scanning the report
+    /// doesn't mistake "zero successful iterations" for "instant, perfect
+    /// run" — the percentiles above would otherwise all collapse to NaN/0.
+    pub succeeded: bool,
+    pub errors: Vec<String>,
+}
+
+/// Full report from replaying a workload's runs, reported either to the
+/// caller directly or POSTed to `report_url`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub workload_name: String,
+    pub env: BenchmarkEnv,
+    pub chains: Vec<ChainBenchmarkResult>,
+}
+
+/// Load `workload_path`, replay every run's chain `iterations` times via
+/// `sunwell workflow run --json`, and return the aggregated report. If
+/// `report_url` is set, the report is also POSTed there as JSON (see
+/// `demo::post_workload_report` for the same pattern) so results can be
+/// tracked over time; POST failures are logged but don't fail the command,
+/// since the caller already has the report either way.
+#[tauri::command]
+pub async fn run_workflow_benchmark(
+    workload_path: String,
+    report_url: Option<String>,
+) -> Result<BenchmarkReport, SunwellError> {
+    let content = std::fs::read_to_string(&workload_path).map_err(|e| {
+        SunwellError::from_error(ErrorCode::FileNotFound, e)
+            .with_hints(vec!["Check the workload path is correct"])
+    })?;
+    let workload: Workload = parse_json_safe(&content).map_err(|e| {
+        SunwellError::new(
+            ErrorCode::ConfigInvalid,
+            format!("Failed to parse workload file: {}", e),
+        )
+    })?;
+
+    let chains = workload.runs.iter().map(run_benchmark).collect();
+    let report = BenchmarkReport {
+        workload_name: workload.name,
+        env: BenchmarkEnv::capture(),
+        chains,
+    };
+
+    if let Some(url) = &report_url {
+        if let Err(e) = post_benchmark_report(url, &report).await {
+            eprintln!(
+                "run_workflow_benchmark: failed to POST report to {}: {}",
+                url, e
+            );
+        }
+    }
+
+    Ok(report)
+}
+
+/// Replay one run's chain `iterations` times, folding every iteration's
+/// `WorkflowExecution.steps` into per-step percentiles.
+fn run_benchmark(run: &BenchmarkRun) -> ChainBenchmarkResult {
+    let mut step_durations: std::collections::HashMap<String, Vec<f64>> =
+        std::collections::HashMap::new();
+    let mut errors = Vec::new();
+    let mut succeeded_count = 0u32;
+    let started = Instant::now();
+
+    for _ in 0..run.iterations {
+        match run_chain_once(run) {
+            Ok(execution) => {
+                succeeded_count += 1;
+                for step in &execution.steps {
+                    if let Some(duration_s) = step.duration_s {
+                        step_durations
+                            .entry(step.skill.clone())
+                            .or_default()
+                            .push(duration_s);
+                    }
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    let total_wall_clock_s = started.elapsed().as_secs_f64();
+    let mut steps: Vec<StepTiming> = step_durations
+        .into_iter()
+        .map(|(skill, mut durations)| {
+            durations.sort_by(f64::total_cmp);
+            StepTiming {
+                mean_s: durations.iter().sum::<f64>() / durations.len() as f64,
+                p50_s: percentile(&durations, 50.0),
+                p95_s: percentile(&durations, 95.0),
+                p99_s: percentile(&durations, 99.0),
+                skill,
+            }
+        })
+        .collect();
+    steps.sort_by(|a, b| a.skill.cmp(&b.skill));
+
+    ChainBenchmarkResult {
+        chain_name: run.chain_name.clone(),
+        iterations_requested: run.iterations,
+        iterations_succeeded: succeeded_count,
+        total_wall_clock_s,
+        steps,
+        succeeded: succeeded_count > 0,
+        errors,
+    }
+}
+
+fn run_chain_once(run: &BenchmarkRun) -> Result<WorkflowExecution, String> {
+    let mut args = vec![
+        "workflow".to_string(),
+        "run".to_string(),
+        run.chain_name.clone(),
+        "--json".to_string(),
+    ];
+
+    if let Some(target) = &run.target_file {
+        args.push("--target".to_string());
+        args.push(target.clone());
+    }
+    if let Some(context) = &run.context {
+        args.push("--context".to_string());
+        args.push(context.to_string());
+    }
+
+    let output = sunwell_command()
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to run workflow: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let json_str = String::from_utf8_lossy(&output.stdout);
+    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse execution: {}", e))
+}
+
+/// Nearest-rank percentile over an already-sorted sample — matches
+/// `self_benchmark::percentile`'s choice of interpolation-free ranking for
+/// small benchmark sample sizes.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * (sorted.len() as f64 - 1.0)).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+/// POST the aggregated benchmark report as JSON to `report_url`.
+async fn post_benchmark_report(
+    report_url: &str,
+    report: &BenchmarkReport,
+) -> Result<(), SunwellError> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(report_url)
+        .json(report)
+        .send()
+        .await
+        .map_err(|e| {
+            SunwellError::new(
+                ErrorCode::NetworkUnreachable,
+                format!("Failed to reach report server: {}", e),
+            )
+        })?;
+
+    if !response.status().is_success() {
+        return Err(SunwellError::new(
+            ErrorCode::NetworkUnreachable,
+            format!("Report server returned status {}", response.status()),
+        ));
+    }
+
+    Ok(())
+}
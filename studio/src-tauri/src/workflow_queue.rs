@@ -0,0 +1,304 @@
+//! Background Workflow Queue (RFC-086 addendum)
+//!
+//! `start_workflow` runs exactly one chain synchronously, blocking the
+//! caller until it finishes — fine for a single interactive run, but
+//! scheduling `modernize`/`feature-docs` across many files meant the
+//! frontend had to serialize calls itself and couldn't close the app
+//! mid-batch. `WorkflowQueue`, inspired by pict-rs's and kittybox's job
+//! queues, adds a durable `pending -> running -> done/failed` job list: jobs
+//! are enqueued instantly, a background worker drains them with at most
+//! `concurrency` chains running at once (bounded by a `Semaphore`, the same
+//! idiom `demo::run_demos_parallel` uses), and the list is persisted to JSON
+//! after every state change so a crash or restart doesn't lose track of
+//! what was pending or mid-flight.
+//!
+//! Unlike `job_manager::JobManager` (msgpack, one checkpoint per live agent
+//! session, scoped to a project), this is one shared JSON file under the
+//! global state dir — chains aren't tied to a single project the way an
+//! agent run is, since `start_workflow` itself never takes a project path.
+
+use crate::util::{parse_json_safe, sunwell_command};
+use crate::workflow::WorkflowExecution;
+use crate::workspace::default_config_root;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+
+/// How many chains run at once when no `concurrency` override is given.
+const DEFAULT_QUEUE_CONCURRENCY: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuedJobStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+/// One enqueued chain run and its lifecycle state, persisted verbatim to
+/// `workflow_queue.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedJob {
+    pub id: String,
+    pub chain_name: String,
+    pub target_file: Option<String>,
+    pub status: QueuedJobStatus,
+    /// Id of the `WorkflowExecution` this job produced, once it starts
+    /// running — lets the frontend jump from the queue entry to the same
+    /// execution view `start_workflow` would have returned directly.
+    pub execution_id: Option<String>,
+    pub error: Option<String>,
+    pub enqueued_at: String,
+    pub updated_at: String,
+}
+
+/// Durable queue of chain runs, drained by a background worker bounded to
+/// `concurrency` concurrent chains.
+pub struct WorkflowQueue {
+    jobs: Mutex<Vec<QueuedJob>>,
+    state_path: PathBuf,
+    semaphore: Arc<Semaphore>,
+    /// Guards against `enqueue_workflow`/`resume_queue` both spawning a
+    /// worker loop when one is already draining the queue.
+    worker_running: Arc<AtomicBool>,
+}
+
+impl WorkflowQueue {
+    pub fn new(concurrency: usize) -> Self {
+        let state_path = queue_state_path();
+        let mut jobs = load_jobs(&state_path);
+
+        // A job left `Running` only got there because the app (or its
+        // worker) died mid-chain — there's no process left to finish it, so
+        // requeue it as `Pending` for the next `resume_queue`.
+        let mut requeued = false;
+        for job in &mut jobs {
+            if job.status == QueuedJobStatus::Running {
+                job.status = QueuedJobStatus::Pending;
+                job.updated_at = chrono::Utc::now().to_rfc3339();
+                requeued = true;
+            }
+        }
+        if requeued {
+            let _ = persist(&state_path, &jobs);
+        }
+
+        Self {
+            jobs: Mutex::new(jobs),
+            state_path,
+            semaphore: Arc::new(Semaphore::new(concurrency.max(1))),
+            worker_running: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn list(&self) -> Vec<QueuedJob> {
+        self.jobs
+            .lock()
+            .map(|jobs| jobs.clone())
+            .unwrap_or_default()
+    }
+
+    /// Append a new `Pending` job and persist, returning its id.
+    pub fn enqueue(&self, chain_name: String, target_file: Option<String>) -> String {
+        let id = new_queue_job_id();
+        let now = chrono::Utc::now().to_rfc3339();
+        let job = QueuedJob {
+            id: id.clone(),
+            chain_name,
+            target_file,
+            status: QueuedJobStatus::Pending,
+            execution_id: None,
+            error: None,
+            enqueued_at: now.clone(),
+            updated_at: now,
+        };
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.push(job);
+            let _ = persist(&self.state_path, &jobs);
+        }
+        id
+    }
+
+    /// Cancel a job that hasn't started running yet. Once a job is
+    /// `Running`, its chain is already an in-flight subprocess with no
+    /// cancellation handle wired up here, so it's left to finish rather than
+    /// silently orphaned.
+    pub fn cancel(&self, id: &str) -> Result<(), String> {
+        let mut jobs = self.jobs.lock().map_err(|e| e.to_string())?;
+        let job = jobs
+            .iter_mut()
+            .find(|j| j.id == id)
+            .ok_or_else(|| format!("No queued job {}", id))?;
+
+        if job.status != QueuedJobStatus::Pending {
+            return Err(format!(
+                "Job {} is already {:?} and can't be cancelled",
+                id, job.status
+            ));
+        }
+
+        jobs.retain(|j| j.id != id);
+        persist(&self.state_path, &jobs).map_err(|e| e.to_string())
+    }
+
+    /// Take the next `Pending` job and mark it `Running` in one step, so two
+    /// concurrent worker iterations can never both pick it up.
+    fn take_next_pending(&self) -> Option<QueuedJob> {
+        let mut jobs = self.jobs.lock().ok()?;
+        let job = jobs
+            .iter_mut()
+            .find(|j| j.status == QueuedJobStatus::Pending)?;
+        job.status = QueuedJobStatus::Running;
+        job.updated_at = chrono::Utc::now().to_rfc3339();
+        let snapshot = job.clone();
+        let _ = persist(&self.state_path, &jobs);
+        Some(snapshot)
+    }
+
+    fn finish(&self, id: &str, execution_id: Option<String>, error: Option<String>) {
+        if let Ok(mut jobs) = self.jobs.lock() {
+            if let Some(job) = jobs.iter_mut().find(|j| j.id == id) {
+                job.status = if error.is_some() {
+                    QueuedJobStatus::Failed
+                } else {
+                    QueuedJobStatus::Done
+                };
+                job.execution_id = execution_id;
+                job.error = error;
+                job.updated_at = chrono::Utc::now().to_rfc3339();
+            }
+            let _ = persist(&self.state_path, &jobs);
+        }
+    }
+
+    /// Start draining the queue if no worker is already running. Safe to
+    /// call repeatedly (from `enqueue_workflow` after every add, and from
+    /// `resume_queue` after a restart) — a second call while one worker is
+    /// already running is a no-op.
+    pub fn spawn_worker(self: &Arc<Self>) {
+        if self.worker_running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let queue = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let Some(job) = queue.take_next_pending() else {
+                    break;
+                };
+
+                let semaphore = queue.semaphore.clone();
+                let queue = queue.clone();
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire_owned().await.ok();
+                    match run_chain(&job.chain_name, job.target_file.as_deref()) {
+                        Ok(execution) => queue.finish(&job.id, Some(execution.id), None),
+                        Err(e) => queue.finish(&job.id, None, Some(e)),
+                    }
+                });
+            }
+            queue.worker_running.store(false, Ordering::SeqCst);
+        });
+    }
+}
+
+impl Default for WorkflowQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUEUE_CONCURRENCY)
+    }
+}
+
+/// Run one chain to completion via `sunwell workflow run --json`, the same
+/// invocation `workflow::start_workflow` makes.
+fn run_chain(chain_name: &str, target_file: Option<&str>) -> Result<WorkflowExecution, String> {
+    let mut args = vec!["workflow", "run", chain_name, "--json"];
+
+    if let Some(target) = target_file {
+        args.push("--target");
+        args.push(target);
+    }
+
+    let output = sunwell_command()
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to run workflow: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let json_str = String::from_utf8_lossy(&output.stdout);
+    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse execution: {}", e))
+}
+
+fn queue_state_path() -> PathBuf {
+    default_config_root().join("workflow_queue.json")
+}
+
+fn load_jobs(path: &PathBuf) -> Vec<QueuedJob> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn persist(path: &PathBuf, jobs: &[QueuedJob]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(jobs)?;
+    std::fs::write(path, content)
+}
+
+fn new_queue_job_id() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    format!("wfq-{:x}-{:x}", nanos, seq)
+}
+
+/// Enqueue a chain run and return its queue id. Kicks the worker (a no-op if
+/// one is already draining the queue) so the job starts as soon as a permit
+/// frees up, without the caller needing a separate `resume_queue` call.
+#[tauri::command]
+pub async fn enqueue_workflow(
+    queue: tauri::State<'_, Arc<WorkflowQueue>>,
+    chain_name: String,
+    target_file: Option<String>,
+) -> Result<String, String> {
+    let id = queue.enqueue(chain_name, target_file);
+    queue.spawn_worker();
+    Ok(id)
+}
+
+/// List every queued job, in enqueue order, regardless of status.
+#[tauri::command]
+pub async fn list_queued_workflows(
+    queue: tauri::State<'_, Arc<WorkflowQueue>>,
+) -> Result<Vec<QueuedJob>, String> {
+    Ok(queue.list())
+}
+
+/// Cancel a job that hasn't started running yet.
+#[tauri::command]
+pub async fn cancel_queued_workflow(
+    queue: tauri::State<'_, Arc<WorkflowQueue>>,
+    id: String,
+) -> Result<(), String> {
+    queue.cancel(&id)
+}
+
+/// Kick the worker back off, e.g. after an app restart reloaded pending and
+/// requeued running-but-interrupted jobs from disk. A no-op if a worker is
+/// already draining the queue.
+#[tauri::command]
+pub async fn resume_queue(queue: tauri::State<'_, Arc<WorkflowQueue>>) -> Result<(), String> {
+    queue.spawn_worker();
+    Ok(())
+}
@@ -20,6 +20,9 @@ pub enum ResolutionSource {
     Detected,
     /// Using default ~/Sunwell/projects/
     Default,
+    /// Explicit argument was a git remote URL; `path` is where it will live
+    /// once `clone_workspace` clones it — it may not exist on disk yet.
+    Clone,
 }
 
 /// Result of workspace resolution.
@@ -138,8 +141,22 @@ fn is_random_location(path: &Path) -> bool {
 /// 3. Walk up to find nearest project root
 /// 4. Default ~/Sunwell/projects/
 pub fn resolve_workspace(explicit: Option<&Path>, project_name: Option<&str>) -> WorkspaceResult {
-    // 1. Explicit always wins
+    // 1. Explicit always wins — but a git remote URL resolves to where it
+    // would be cloned, not to itself.
     if let Some(path) = explicit {
+        let path_str = path.to_string_lossy();
+        if is_git_remote_url(&path_str) {
+            let repo_name = repo_name_from_url(&path_str);
+            let clone_path = default_workspace_root().join(slugify(&repo_name));
+            return WorkspaceResult {
+                exists: clone_path.exists(),
+                path: clone_path,
+                source: ResolutionSource::Clone,
+                confidence: 1.0,
+                project_name: Some(project_name.map(String::from).unwrap_or(repo_name)),
+            };
+        }
+
         return WorkspaceResult {
             path: path.to_path_buf(),
             source: ResolutionSource::Explicit,
@@ -213,6 +230,92 @@ pub fn slugify(name: &str) -> String {
     slug.trim_matches('-').to_string()
 }
 
+/// Whether a string looks like a git remote rather than a local path:
+/// `http(s)://`/`git://` URLs, anything ending in `.git`, or scp-style
+/// `user@host:path` remotes.
+fn is_git_remote_url(s: &str) -> bool {
+    s.starts_with("http://")
+        || s.starts_with("https://")
+        || s.starts_with("git://")
+        || s.starts_with("ssh://")
+        || s.ends_with(".git")
+        || (s.contains('@') && s.contains(':') && !s.contains("://"))
+}
+
+/// Derive a repo name from a git remote URL, e.g.
+/// `https://github.com/user/repo.git` -> `repo`.
+fn repo_name_from_url(url: &str) -> String {
+    let trimmed = url.trim_end_matches('/').trim_end_matches(".git");
+    trimmed.rsplit(['/', ':']).next().unwrap_or(trimmed).to_string()
+}
+
+/// Clone a git repo into the path from a `ResolutionSource::Clone`
+/// resolution, unless it already exists, and record it into
+/// `RecentProjectsStore`.
+pub fn clone_workspace(result: &WorkspaceResult, url: &str) -> std::io::Result<PathBuf> {
+    if result.source != ResolutionSource::Clone {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "resolve_workspace result is not a git clone resolution",
+        ));
+    }
+
+    if !result.path.exists() {
+        // `url` starting with `-` would otherwise be parsed by `git` as an
+        // option rather than a repository (e.g. `--upload-pack=<cmd>`,
+        // a known git argument-injection vector) — reject it outright.
+        if url.starts_with('-') {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Refusing to clone '{}': looks like a git option, not a URL",
+                    url
+                ),
+            ));
+        }
+
+        // Route through the existing command-safety layer before shelling
+        // out, even though we invoke git directly rather than via a shell.
+        crate::run_analysis::validate_command_safety(&format!(
+            "git clone {} {}",
+            url,
+            result.path.display()
+        ))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+        if let Some(parent) = result.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // `--` forces everything after it to be parsed positionally, so
+        // `url`/the destination path can't be reinterpreted as options even
+        // if the checks above somehow missed something.
+        let status = std::process::Command::new("git")
+            .args(["clone", "--", url, &result.path.to_string_lossy()])
+            .status()?;
+
+        if !status.success() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("git clone exited with status {}", status),
+            ));
+        }
+    }
+
+    let name = result
+        .path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("project")
+        .to_string();
+
+    let mut recent_store = RecentProjectsStore::load();
+    recent_store.add(create_recent_project(&result.path, &name, ProjectType::General, None));
+    let _ = recent_store.save();
+
+    Ok(result.path.clone())
+}
+
 /// Ensure the workspace directory exists.
 pub fn ensure_workspace_exists(path: &Path) -> std::io::Result<()> {
     std::fs::create_dir_all(path)?;
@@ -284,10 +387,17 @@ impl RecentProjectsStore {
     }
 
     /// Add or update a project in recent list.
-    pub fn add(&mut self, project: RecentProject) {
-        // Remove existing entry with same path
-        self.projects
-            .retain(|p| p.path != project.path);
+    ///
+    /// If a project at this path is already present, its existing tags carry
+    /// forward onto the re-inserted entry rather than being dropped.
+    pub fn add(&mut self, mut project: RecentProject) {
+        // Remove existing entry with same path, preserving its tags.
+        if let Some(pos) = self.projects.iter().position(|p| p.path == project.path) {
+            let existing = self.projects.remove(pos);
+            if project.tags.is_empty() {
+                project.tags = existing.tags;
+            }
+        }
 
         // Add to front
         self.projects.insert(0, project);
@@ -305,6 +415,42 @@ impl RecentProjectsStore {
     pub fn remove(&mut self, path: &Path) {
         self.projects.retain(|p| p.path != path);
     }
+
+    /// Add a tag to a project, if it exists and doesn't already have it.
+    pub fn add_tag(&mut self, path: &Path, tag: String) {
+        if let Some(project) = self.projects.iter_mut().find(|p| p.path == path) {
+            if !project.tags.contains(&tag) {
+                project.tags.push(tag);
+            }
+        }
+    }
+
+    /// Remove a tag from a project.
+    pub fn remove_tag(&mut self, path: &Path, tag: &str) {
+        if let Some(project) = self.projects.iter_mut().find(|p| p.path == path) {
+            project.tags.retain(|t| t != tag);
+        }
+    }
+
+    /// Get all projects carrying a given tag.
+    pub fn tagged(&self, tag: &str) -> Vec<&RecentProject> {
+        self.projects
+            .iter()
+            .filter(|p| p.tags.iter().any(|t| t == tag))
+            .collect()
+    }
+
+    /// Get the set of all tags in use, sorted alphabetically.
+    pub fn all_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .projects
+            .iter()
+            .flat_map(|p| p.tags.iter().cloned())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
 }
 
 /// Create a RecentProject from a path and detected info.
@@ -325,6 +471,7 @@ pub fn create_recent_project(
         project_type,
         description: description.unwrap_or("").to_string(),
         last_opened: now,
+        tags: Vec::new(),
     }
 }
 
@@ -409,4 +556,27 @@ mod tests {
         let root = default_workspace_root();
         assert!(root.ends_with("Sunwell/projects"));
     }
+
+    #[test]
+    fn test_git_url_resolves_to_clone() {
+        let result = resolve_workspace(
+            Some(Path::new("https://github.com/example/my-repo.git")),
+            None,
+        );
+        assert_eq!(result.source, ResolutionSource::Clone);
+        assert!(result.path.ends_with("my-repo"));
+    }
+
+    #[test]
+    fn test_scp_style_git_url_resolves_to_clone() {
+        let result = resolve_workspace(Some(Path::new("git@github.com:example/my-repo.git")), None);
+        assert_eq!(result.source, ResolutionSource::Clone);
+        assert!(result.path.ends_with("my-repo"));
+    }
+
+    #[test]
+    fn test_local_path_still_resolves_explicit() {
+        let result = resolve_workspace(Some(Path::new("/tmp/some-project")), None);
+        assert_eq!(result.source, ResolutionSource::Explicit);
+    }
 }
@@ -1,14 +1,18 @@
-//! Writer Commands â€” Universal Writing Environment (RFC-086, RFC-087)
+//! Writer Commands â€” Universal Writing Environment (RFC-086, RFC-087, RFC-110)
 //!
 //! Provides Tauri commands for:
-//! - Diataxis detection
+//! - Diataxis detection (keyword-based, with an optional embedding-assisted
+//!   path — see [`Embedder`])
 //! - Document validation
 //! - Skill execution
 //! - Skill graph management (RFC-087)
 
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use crate::util::{parse_json_safe, sunwell_command};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+use tauri::Emitter;
 
 // =============================================================================
 // TYPES
@@ -62,7 +66,7 @@ pub struct LensSkill {
     pub shortcut: String,
     pub description: String,
     pub category: String,
-    
+
     // RFC-087: DAG fields (optional for backward compatibility)
     #[serde(default, rename = "dependsOn")]
     pub depends_on: Vec<SkillDependency>,
@@ -90,7 +94,10 @@ pub struct SkillWave {
     #[serde(rename = "waveIndex")]
     pub wave_index: u32,
     pub skills: Vec<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "estimatedDurationMs")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "estimatedDurationMs"
+    )]
     pub estimated_duration_ms: Option<u64>,
 }
 
@@ -138,6 +145,8 @@ pub struct SkillResult {
 pub struct FixResult {
     pub content: String,
     pub fixed: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unfixable: Vec<ValidationWarning>,
 }
 
 // =============================================================================
@@ -152,12 +161,20 @@ pub async fn detect_diataxis(
 ) -> Result<DiataxisResult, String> {
     // Try calling Python backend
     let file_arg = file_path.as_deref().unwrap_or("-");
-    
+
     let output = sunwell_command()
-        .args(["surface", "diataxis", "--json", "--content", &content, "--file", file_arg])
+        .args([
+            "surface",
+            "diataxis",
+            "--json",
+            "--content",
+            &content,
+            "--file",
+            file_arg,
+        ])
         .output()
         .map_err(|e| format!("Failed to detect diataxis: {}", e));
-    
+
     match output {
         Ok(out) if out.status.success() => {
             let json_str = String::from_utf8_lossy(&out.stdout);
@@ -171,31 +188,21 @@ pub async fn detect_diataxis(
     }
 }
 
-/// Validate document with lens validators.
+/// Validate document against the native rule engine (RFC-111). Runs
+/// entirely in-process — no CLI round trip, so fixes stay deterministic
+/// and byte offsets line up with the content the frontend actually has.
 #[tauri::command]
 pub async fn validate_document(
     content: String,
     file_path: Option<String>,
     lens_name: String,
 ) -> Result<Vec<ValidationWarning>, String> {
-    let file_arg = file_path.as_deref().unwrap_or("-");
-    
-    let output = sunwell_command()
-        .args(["lens", "validate", &lens_name, "--json", "--content", &content, "--file", file_arg])
-        .output()
-        .map_err(|e| format!("Failed to validate: {}", e));
-    
-    match output {
-        Ok(out) if out.status.success() => {
-            let json_str = String::from_utf8_lossy(&out.stdout);
-            parse_json_safe(&json_str)
-                .map_err(|e| format!("Failed to parse validation: {}", e))
-        }
-        _ => {
-            // Return empty for now
-            Ok(vec![])
-        }
-    }
+    let ctx = crate::validation_rules::DocContext {
+        content: &content,
+        file_path: file_path.as_deref(),
+        lens_name: &lens_name,
+    };
+    Ok(crate::validation_rules::default_registry().check(&ctx))
 }
 
 /// Get skills for a lens.
@@ -205,12 +212,11 @@ pub async fn get_lens_skills(lens_name: String) -> Result<Vec<LensSkill>, String
         .args(["lens", "skills", &lens_name, "--json"])
         .output()
         .map_err(|e| format!("Failed to get skills: {}", e));
-    
+
     match output {
         Ok(out) if out.status.success() => {
             let json_str = String::from_utf8_lossy(&out.stdout);
-            parse_json_safe(&json_str)
-                .map_err(|e| format!("Failed to parse skills: {}", e))
+            parse_json_safe(&json_str).map_err(|e| format!("Failed to parse skills: {}", e))
         }
         _ => {
             // Return default skills
@@ -228,56 +234,52 @@ pub async fn execute_skill(
     lens_name: String,
 ) -> Result<SkillResult, String> {
     let file_arg = file_path.as_deref().unwrap_or("-");
-    
+
     let output = sunwell_command()
         .args([
-            "skill", "exec", &skill_id,
-            "--lens", &lens_name,
+            "skill",
+            "exec",
+            &skill_id,
+            "--lens",
+            &lens_name,
             "--json",
-            "--content", &content,
-            "--file", file_arg,
+            "--content",
+            &content,
+            "--file",
+            file_arg,
         ])
         .output()
         .map_err(|e| format!("Failed to execute skill: {}", e))?;
-    
+
     if !output.status.success() {
         return Err(String::from_utf8_lossy(&output.stderr).to_string());
     }
-    
+
     let json_str = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&json_str)
-        .map_err(|e| format!("Failed to parse skill result: {}", e))
+    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse skill result: {}", e))
 }
 
-/// Fix all issues in document.
+/// Fix all issues in document that the native rule engine (RFC-111) knows
+/// how to fix. Collects each warning's proposed edits, applies the
+/// non-overlapping subset, and reports the rest as unfixable so the
+/// frontend can surface what still needs a human.
 #[tauri::command]
 pub async fn fix_all_issues(
     content: String,
     warnings: Vec<ValidationWarning>,
     lens_name: String,
 ) -> Result<FixResult, String> {
-    // Serialize warnings to pass to the command
-    let warnings_json = serde_json::to_string(&warnings)
-        .map_err(|e| format!("Failed to serialize warnings: {}", e))?;
-    
-    let output = sunwell_command()
-        .args([
-            "lens", "fix-all",
-            &lens_name,
-            "--json",
-            "--content", &content,
-            "--warnings", &warnings_json,
-        ])
-        .output()
-        .map_err(|e| format!("Failed to fix issues: {}", e))?;
-    
-    if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
-    }
-    
-    let json_str = String::from_utf8_lossy(&output.stdout);
-    parse_json_safe(&json_str)
-        .map_err(|e| format!("Failed to parse fix result: {}", e))
+    let ctx = crate::validation_rules::DocContext {
+        content: &content,
+        file_path: None,
+        lens_name: &lens_name,
+    };
+    let outcome = crate::validation_rules::default_registry().fix_all(&ctx, &warnings);
+    Ok(FixResult {
+        content: outcome.content,
+        fixed: outcome.fixed as i32,
+        unfixable: outcome.unfixable,
+    })
 }
 
 // =============================================================================
@@ -286,17 +288,29 @@ pub async fn fix_all_issues(
 
 /// Get the resolved skill graph for a lens.
 #[tauri::command]
-pub async fn get_skill_graph(lens_name: String) -> Result<SkillGraph, String> {
+pub async fn get_skill_graph(
+    project_path: String,
+    lens_name: String,
+) -> Result<SkillGraph, String> {
+    // Prefer a local `lens.toml` manifest (RFC-112) when the project has
+    // one — it resolves the graph without the CLI round trip, and works
+    // offline. Only fall back to the CLI (and then an empty graph) when no
+    // manifest exists at all.
+    if let Some(result) =
+        crate::lens_manifest::load_skill_graph(std::path::Path::new(&project_path), &lens_name)
+    {
+        return result;
+    }
+
     let output = sunwell_command()
         .args(["lens", "skill-graph", &lens_name, "--json"])
         .output()
         .map_err(|e| format!("Failed to get skill graph: {}", e));
-    
+
     match output {
         Ok(out) if out.status.success() => {
             let json_str = String::from_utf8_lossy(&out.stdout);
-            parse_json_safe(&json_str)
-                .map_err(|e| format!("Failed to parse skill graph: {}", e))
+            parse_json_safe(&json_str).map_err(|e| format!("Failed to parse skill graph: {}", e))
         }
         Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
         Err(_e) => {
@@ -318,30 +332,202 @@ pub async fn get_skill_execution_plan(
     context_hash: Option<String>,
 ) -> Result<SkillExecutionPlan, String> {
     let mut args = vec!["lens", "skill-plan", &lens_name, "--json"];
-    
+
     // Build context hash argument if provided
     let hash_arg;
     if let Some(ref hash) = context_hash {
         hash_arg = format!("--context-hash={}", hash);
         args.push(&hash_arg);
     }
-    
+
     let output = sunwell_command()
         .args(&args)
         .output()
         .map_err(|e| format!("Failed to get execution plan: {}", e));
-    
+
     match output {
         Ok(out) if out.status.success() => {
             let json_str = String::from_utf8_lossy(&out.stdout);
-            parse_json_safe(&json_str)
-                .map_err(|e| format!("Failed to parse execution plan: {}", e))
+            parse_json_safe(&json_str).map_err(|e| format!("Failed to parse execution plan: {}", e))
         }
         Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
         Err(e) => Err(e),
     }
 }
 
+/// Resolve the execution plan for a lens and run it wave by wave,
+/// actually exploiting the concurrency `SkillGraph.waves` describes:
+/// every skill in one `SkillWave` is spawned as its own task and they run
+/// concurrently, with the next wave only starting once every task in the
+/// current one has completed. Skills in the plan's `to_skip` list are
+/// served from cache without spawning a process for them — there's no
+/// per-skill cache-read command yet, so this records the skip without
+/// fabricating cached content (a real read would need `skill cache-get`,
+/// which doesn't exist in this CLI).
+///
+/// Each skill that `requires` another skill's `produces` output receives
+/// it via `--requires <json>` once its upstream dependency (within an
+/// earlier wave) has finished, so results actually flow through the DAG
+/// instead of every skill only ever seeing the original document.
+///
+/// Emits `skill-started`/`skill-finished` per skill and `wave-completed`
+/// per wave so the frontend can render a live DAG instead of waiting for
+/// the whole plan to finish.
+#[tauri::command]
+pub async fn execute_skill_plan(
+    app: tauri::AppHandle,
+    lens_name: String,
+    content: String,
+    file_path: Option<String>,
+    context_hash: Option<String>,
+) -> Result<HashMap<String, SkillResult>, String> {
+    let plan = get_skill_execution_plan(lens_name.clone(), context_hash).await?;
+    let to_skip: HashSet<String> = plan.to_skip.iter().cloned().collect();
+    // Keyed by produced output name (`LensSkill.produces`), not skill id, so
+    // a dependent skill's `requires` entries look its upstream result up by
+    // the name it actually asked for.
+    let produced: Arc<Mutex<HashMap<String, SkillResult>>> = Arc::new(Mutex::new(HashMap::new()));
+    let results: Arc<Mutex<HashMap<String, SkillResult>>> = Arc::new(Mutex::new(HashMap::new()));
+
+    for wave in &plan.graph.waves {
+        let wave_started = Instant::now();
+        let mut join_set = tokio::task::JoinSet::new();
+
+        for skill_id in &wave.skills {
+            if to_skip.contains(skill_id) {
+                results
+                    .lock()
+                    .unwrap()
+                    .entry(skill_id.clone())
+                    .or_insert(SkillResult {
+                        content: None,
+                        message: Some("served from cache".to_string()),
+                    });
+                continue;
+            }
+
+            let Some(skill) = plan.graph.skills.get(skill_id).cloned() else {
+                continue;
+            };
+
+            let upstream: HashMap<String, SkillResult> = {
+                let produced = produced.lock().unwrap();
+                skill
+                    .requires
+                    .iter()
+                    .filter_map(|name| produced.get(name).cloned().map(|r| (name.clone(), r)))
+                    .collect()
+            };
+
+            let app = app.clone();
+            let produced = produced.clone();
+            let results = results.clone();
+            let content = content.clone();
+            let file_path = file_path.clone();
+            let lens_name = lens_name.clone();
+            let skill_id = skill_id.clone();
+            let produces = skill.produces.clone();
+            let wave_index = wave.wave_index;
+
+            join_set.spawn(async move {
+                let _ = app.emit(
+                    "skill-started",
+                    &serde_json::json!({"skillId": skill_id, "wave": wave_index}),
+                );
+                let started = Instant::now();
+
+                let result = run_skill_subprocess(
+                    &skill_id,
+                    &content,
+                    file_path.as_deref(),
+                    &lens_name,
+                    &upstream,
+                );
+                if let Ok(ref skill_result) = result {
+                    results
+                        .lock()
+                        .unwrap()
+                        .insert(skill_id.clone(), skill_result.clone());
+                    let mut produced = produced.lock().unwrap();
+                    for name in &produces {
+                        produced.insert(name.clone(), skill_result.clone());
+                    }
+                }
+
+                let _ = app.emit(
+                    "skill-finished",
+                    &serde_json::json!({
+                        "skillId": skill_id,
+                        "wave": wave_index,
+                        "elapsedMs": started.elapsed().as_millis() as u64,
+                        "ok": result.is_ok(),
+                    }),
+                );
+
+                (skill_id, result)
+            });
+        }
+
+        while let Some(joined) = join_set.join_next().await {
+            let (skill_id, result) = joined.map_err(|e| format!("Skill task panicked: {}", e))?;
+            result.map_err(|e| format!("Skill '{}' failed: {}", skill_id, e))?;
+        }
+
+        let _ = app.emit(
+            "wave-completed",
+            &serde_json::json!({
+                "wave": wave.wave_index,
+                "elapsedMs": wave_started.elapsed().as_millis() as u64,
+            }),
+        );
+    }
+
+    Ok(results.lock().unwrap().clone())
+}
+
+/// Runs one skill through the CLI, threading `upstream` (this skill's
+/// `requires` already produced by earlier waves) in as `--requires`.
+fn run_skill_subprocess(
+    skill_id: &str,
+    content: &str,
+    file_path: Option<&str>,
+    lens_name: &str,
+    upstream: &HashMap<String, SkillResult>,
+) -> Result<SkillResult, String> {
+    let file_arg = file_path.unwrap_or("-");
+    let mut command = sunwell_command();
+    command.args([
+        "skill",
+        "exec",
+        skill_id,
+        "--lens",
+        lens_name,
+        "--json",
+        "--content",
+        content,
+        "--file",
+        file_arg,
+    ]);
+
+    let upstream_json;
+    if !upstream.is_empty() {
+        upstream_json = serde_json::to_string(upstream)
+            .map_err(|e| format!("Failed to serialize upstream skill outputs: {}", e))?;
+        command.args(["--requires", &upstream_json]);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to execute skill '{}': {}", skill_id, e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let json_str = String::from_utf8_lossy(&output.stdout);
+    parse_json_safe(&json_str).map_err(|e| format!("Failed to parse skill result: {}", e))
+}
+
 /// Get skill cache statistics.
 #[tauri::command]
 pub async fn get_skill_cache_stats() -> Result<SkillCacheStats, String> {
@@ -349,12 +535,11 @@ pub async fn get_skill_cache_stats() -> Result<SkillCacheStats, String> {
         .args(["skill", "cache-stats", "--json"])
         .output()
         .map_err(|e| format!("Failed to get cache stats: {}", e));
-    
+
     match output {
         Ok(out) if out.status.success() => {
             let json_str = String::from_utf8_lossy(&out.stdout);
-            parse_json_safe(&json_str)
-                .map_err(|e| format!("Failed to parse cache stats: {}", e))
+            parse_json_safe(&json_str).map_err(|e| format!("Failed to parse cache stats: {}", e))
         }
         _ => {
             // Return default stats
@@ -376,7 +561,7 @@ pub async fn clear_skill_cache() -> Result<(), String> {
         .args(["skill", "cache-clear"])
         .output()
         .map_err(|e| format!("Failed to clear cache: {}", e))?;
-    
+
     if output.status.success() {
         Ok(())
     } else {
@@ -384,6 +569,102 @@ pub async fn clear_skill_cache() -> Result<(), String> {
     }
 }
 
+// =============================================================================
+// RFC-110: EMBEDDING-ASSISTED DIATAXIS DETECTION
+// =============================================================================
+
+/// Supplies embedding vectors for the semantic half of Diataxis detection.
+/// The keyword path in `detect_diataxis_local` never goes away — this is a
+/// complementary signal that only kicks in once an embedder is actually
+/// wired up via [`set_embedder`], so builds without a local model or a
+/// Python backend configured keep the exact keyword-only behavior they had
+/// before this module existed.
+pub trait Embedder: Send + Sync {
+    fn embed(&self, text: &str) -> Option<Vec<f32>>;
+}
+
+static EMBEDDER: OnceLock<Option<Box<dyn Embedder>>> = OnceLock::new();
+
+/// Configures the embedding backend used by `detect_diataxis_local`. Call
+/// once during startup with a local-model or Python-backend-calling
+/// implementation; if this is never called, detection stays keyword-only.
+#[allow(dead_code)] // wired up once a concrete Embedder impl lands
+pub fn set_embedder(embedder: Box<dyn Embedder>) {
+    let _ = EMBEDDER.set(Some(embedder));
+}
+
+/// Exposed `pub(crate)` so other modules needing the same pluggable
+/// embedding backend (e.g. `memory::semantic_search`) don't need their own
+/// `OnceLock` and `set_embedder` call.
+pub(crate) fn embedder() -> Option<&'static dyn Embedder> {
+    EMBEDDER.get_or_init(|| None).as_deref()
+}
+
+const DIATAXIS_TYPES: [&str; 4] = ["TUTORIAL", "HOW_TO", "EXPLANATION", "REFERENCE"];
+
+/// Short hand-written exemplar per Diataxis type, embedded once to build
+/// the reference centroids. Cosine similarity only needs direction, not a
+/// large labeled corpus, so one representative paragraph per type is
+/// enough to anchor the four clusters.
+fn seed_text(dtype: &str) -> &'static str {
+    match dtype {
+        "TUTORIAL" => "In this tutorial you will learn step by step how to get started from scratch, following along as we build a first working example together.",
+        "HOW_TO" => "This guide shows how to configure and deploy a specific feature, covering the steps needed to accomplish one particular task.",
+        "EXPLANATION" => "This document explains the architecture and concepts behind the system, discussing why it works the way it does and the reasoning involved.",
+        "REFERENCE" => "API reference: parameters, configuration options, and return values for each function, described exhaustively and precisely.",
+        _ => "",
+    }
+}
+
+static CENTROIDS: OnceLock<Option<HashMap<&'static str, Vec<f32>>>> = OnceLock::new();
+
+/// Lazily embeds the seed texts into reference centroids, once, the first
+/// time an embedder is available. Returns `None` (and stays `None`) for
+/// the lifetime of the process if no embedder is configured.
+fn centroids() -> Option<&'static HashMap<&'static str, Vec<f32>>> {
+    CENTROIDS
+        .get_or_init(|| {
+            let embedder = embedder()?;
+            let mut map = HashMap::new();
+            for dtype in DIATAXIS_TYPES {
+                map.insert(dtype, embedder.embed(seed_text(dtype))?);
+            }
+            Some(map)
+        })
+        .as_ref()
+}
+
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    (dot / (norm_a * norm_b)) as f64
+}
+
+/// Ranks each type 1..=N within a score map, best score first, for use as
+/// one method's input to Reciprocal Rank Fusion.
+fn rank_by_score(scores: &HashMap<String, f64>) -> HashMap<String, usize> {
+    let mut sorted: Vec<_> = scores.iter().collect();
+    sorted.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, (k, _))| (k.clone(), i + 1))
+        .collect()
+}
+
+/// Reciprocal Rank Fusion constant. 60 is the standard choice from the
+/// original RRF paper; it flattens how much either method's top pick
+/// dominates the fused score, so keyword and embedding signals contribute
+/// comparably instead of one runaway-winning rank swamping the other.
+const RRF_K: f64 = 60.0;
+
 // =============================================================================
 // HELPERS
 // =============================================================================
@@ -394,19 +675,19 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
         .and_then(|p| p.split('/').last())
         .unwrap_or("")
         .to_lowercase();
-    
-    let mut scores: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
-    scores.insert("TUTORIAL".to_string(), 0.0);
-    scores.insert("HOW_TO".to_string(), 0.0);
-    scores.insert("EXPLANATION".to_string(), 0.0);
-    scores.insert("REFERENCE".to_string(), 0.0);
-    
+
+    let mut keyword_scores: HashMap<String, f64> = HashMap::new();
+    keyword_scores.insert("TUTORIAL".to_string(), 0.0);
+    keyword_scores.insert("HOW_TO".to_string(), 0.0);
+    keyword_scores.insert("EXPLANATION".to_string(), 0.0);
+    keyword_scores.insert("REFERENCE".to_string(), 0.0);
+
     let mut signals = Vec::new();
-    
+
     // Tutorial signals
     for kw in ["tutorial", "getting-started", "learn", "quickstart"] {
         if filename.contains(kw) || content_lower[..content_lower.len().min(500)].contains(kw) {
-            *scores.get_mut("TUTORIAL").unwrap() += 0.3;
+            *keyword_scores.get_mut("TUTORIAL").unwrap() += 0.3;
             signals.push(DiataxisSignal {
                 dtype: "TUTORIAL".to_string(),
                 weight: 0.3,
@@ -414,11 +695,11 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
             });
         }
     }
-    
+
     // How-to signals
     for kw in ["how-to", "guide", "configure", "deploy"] {
         if filename.contains(kw) || content_lower[..content_lower.len().min(500)].contains(kw) {
-            *scores.get_mut("HOW_TO").unwrap() += 0.3;
+            *keyword_scores.get_mut("HOW_TO").unwrap() += 0.3;
             signals.push(DiataxisSignal {
                 dtype: "HOW_TO".to_string(),
                 weight: 0.3,
@@ -426,11 +707,11 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
             });
         }
     }
-    
+
     // Explanation signals
     for kw in ["architecture", "concepts", "overview", "understand"] {
         if filename.contains(kw) || content_lower[..content_lower.len().min(500)].contains(kw) {
-            *scores.get_mut("EXPLANATION").unwrap() += 0.3;
+            *keyword_scores.get_mut("EXPLANATION").unwrap() += 0.3;
             signals.push(DiataxisSignal {
                 dtype: "EXPLANATION".to_string(),
                 weight: 0.3,
@@ -438,11 +719,11 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
             });
         }
     }
-    
+
     // Reference signals
     for kw in ["reference", "api", "parameters", "configuration"] {
         if filename.contains(kw) || content_lower[..content_lower.len().min(500)].contains(kw) {
-            *scores.get_mut("REFERENCE").unwrap() += 0.3;
+            *keyword_scores.get_mut("REFERENCE").unwrap() += 0.3;
             signals.push(DiataxisSignal {
                 dtype: "REFERENCE".to_string(),
                 weight: 0.3,
@@ -450,14 +731,71 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
             });
         }
     }
-    
+
+    // Semantic path: only runs once an Embedder is actually configured.
+    // Scores each type by cosine similarity of the whole document against
+    // that type's reference centroid.
+    let embedding_scores = embedder().and_then(|embedder| {
+        let centroids = centroids()?;
+        let vector = embedder.embed(content)?;
+        let mut map = HashMap::new();
+        for dtype in DIATAXIS_TYPES {
+            let centroid = centroids.get(dtype)?;
+            map.insert(dtype.to_string(), cosine_similarity(&vector, centroid));
+        }
+        Some(map)
+    });
+
+    if let Some(ref embedding_scores) = embedding_scores {
+        let mut sorted: Vec<_> = embedding_scores.iter().collect();
+        sorted.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
+        if let Some((closest_type, similarity)) = sorted.first() {
+            signals.push(DiataxisSignal {
+                dtype: (*closest_type).clone(),
+                weight: **similarity,
+                reason: format!(
+                    "closest to {} centroid, cosine {:.2}",
+                    closest_type, similarity
+                ),
+            });
+        }
+    }
+
+    // Fuse the keyword-derived ranking with the embedding-derived ranking
+    // via Reciprocal Rank Fusion when both are available; fall back to the
+    // raw keyword scores untouched when no embedder is configured, so
+    // existing behavior doesn't shift for builds without one.
+    let scores = match &embedding_scores {
+        Some(embedding_scores) => {
+            let keyword_rank = rank_by_score(&keyword_scores);
+            let embedding_rank = rank_by_score(embedding_scores);
+            DIATAXIS_TYPES
+                .iter()
+                .map(|dtype| {
+                    let kw_rank = keyword_rank
+                        .get(*dtype)
+                        .copied()
+                        .unwrap_or(DIATAXIS_TYPES.len());
+                    let emb_rank = embedding_rank
+                        .get(*dtype)
+                        .copied()
+                        .unwrap_or(DIATAXIS_TYPES.len());
+                    let fused = 1.0 / (RRF_K + kw_rank as f64) + 1.0 / (RRF_K + emb_rank as f64);
+                    (dtype.to_string(), fused)
+                })
+                .collect::<HashMap<String, f64>>()
+        }
+        None => keyword_scores,
+    };
+
     // Find best type
     let total: f64 = scores.values().sum();
     let (detected_type, confidence) = if total > 0.0 {
-        let best = scores.iter()
+        let best = scores
+            .iter()
             .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
             .map(|(k, v)| (k.clone(), *v));
-        
+
         match best {
             Some((dtype, score)) if score / total > 0.4 => (Some(dtype), score / total),
             _ => (None, 0.0),
@@ -465,25 +803,31 @@ fn detect_diataxis_local(content: &str, file_path: Option<&str>) -> DiataxisResu
     } else {
         (None, 0.0)
     };
-    
+
     // Check for mixed content warning
     let mut warnings = Vec::new();
     let mut sorted_scores: Vec<_> = scores.iter().collect();
     sorted_scores.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
-    
+
     if sorted_scores.len() >= 2 {
         let (first_type, first_score) = sorted_scores[0];
         let (second_type, second_score) = sorted_scores[1];
-        
+
         if *first_score > 0.0 && *second_score > first_score * 0.3 {
             warnings.push(DiataxisWarning {
-                message: format!("Mixed content types detected: {} + {}", first_type, second_type),
-                suggestion: Some(format!("Consider splitting into separate {} and {} pages", first_type, second_type)),
+                message: format!(
+                    "Mixed content types detected: {} + {}",
+                    first_type, second_type
+                ),
+                suggestion: Some(format!(
+                    "Consider splitting into separate {} and {} pages",
+                    first_type, second_type
+                )),
                 severity: "warning".to_string(),
             });
         }
     }
-    
+
     DiataxisResult {
         detection: DiataxisDetection {
             detected_type,